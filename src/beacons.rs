@@ -0,0 +1,402 @@
+//! Placeable beacons: tall glowing pillars with floating labels that mark interesting hexes.
+//!
+//! B drops a [`Beacon`] on the [`ActiveHex`] while flying. Each beacon is a thin emissive
+//! [`BeaconPillar`] standing on its hex's [`HCell`] and follows height edits and
+//! regenerations. Beacons off the current grid are kept but not drawn. Labels are painted
+//! with egui above the pillar tops. While `Inspecting`, a panel lists the beacons to rename,
+//! teleport to, or delete. Beacons are saved with the rest of the [`crate::session::Session`]
+//! and come back with `--continue`.
+
+use bevy::prelude::*;
+use bevy_egui::egui;
+use hexx::Hex;
+use serde::{Deserialize, Serialize};
+
+use crate::controls::{Binding, BindingCategory, RegisterBinding, Trigger};
+use crate::drone::Player;
+use crate::h_terrain::{ActiveHex, HCell, TeleportToHex};
+use crate::locale::Locale;
+use crate::neon_ui::window_to_points;
+use crate::toast::Toast;
+use crate::{GameState, PlayerPos};
+
+/// Per-plugin configuration for beacons.
+#[derive(Resource, Clone, Debug, Reflect)]
+pub struct BeaconsConfig {
+    /// Pillar height above the hex face.
+    pub height: f32,
+    /// Pillar diameter.
+    pub width: f32,
+    /// Pillar color; also tints the label.
+    pub color: Color,
+    /// Emissive multiplier on `color` (drives bloom).
+    pub glow: f32,
+    /// Labels farther than this from the camera are not drawn.
+    pub label_distance: f32,
+}
+
+impl Default for BeaconsConfig {
+    fn default() -> Self {
+        Self {
+            height: 14.0,
+            width: 0.15,
+            color: Color::srgb(1.0, 0.8, 0.2),
+            glow: 6.0,
+            label_distance: 200.0,
+        }
+    }
+}
+
+/// A marked hex.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Beacon {
+    /// User-given name; empty shows as `Beacon <n>`.
+    #[serde(default)]
+    pub name: String,
+    /// Axial hex coordinate `[q, r]`.
+    pub hex: [i32; 2],
+}
+
+impl Beacon {
+    /// The marked hex.
+    pub fn hex(&self) -> Hex {
+        Hex::from(self.hex)
+    }
+}
+
+/// Dropped beacons, in drop order.
+#[derive(Resource, Default, Debug, PartialEq)]
+pub struct Beacons(pub Vec<Beacon>);
+
+impl Beacons {
+    /// Adds an unnamed beacon on `hex`; returns its index, or `None` if one is already there.
+    pub fn drop_at(&mut self, hex: Hex) -> Option<usize> {
+        if self.0.iter().any(|b| b.hex() == hex) {
+            return None;
+        }
+        self.0.push(Beacon {
+            name: String::new(),
+            hex: hex.to_array(),
+        });
+        Some(self.0.len() - 1)
+    }
+
+    /// Display name of beacon `index`: its [`Beacon::name`], or `Beacon <index + 1>`
+    /// (localized) when unnamed.
+    pub fn label(&self, index: usize, locale: &Locale) -> String {
+        match self.0.get(index) {
+            Some(b) if !b.name.is_empty() => b.name.clone(),
+            _ => locale.fmt("Beacon {0}", &[&(index + 1)]),
+        }
+    }
+}
+
+/// Pillar entity for [`Beacons`] entry `.0`.
+#[derive(Component, Reflect)]
+pub struct BeaconPillar(pub usize);
+
+/// Shared pillar mesh and material.
+#[derive(Resource)]
+struct BeaconAssets {
+    mesh: Handle<Mesh>,
+    material: Handle<StandardMaterial>,
+}
+
+/// Beacons plugin.
+pub struct BeaconsPlugin {
+    /// Per-plugin configuration.
+    pub config: BeaconsConfig,
+}
+
+impl Plugin for BeaconsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<BeaconsConfig>()
+            .register_type::<BeaconPillar>()
+            .insert_resource(self.config.clone())
+            .init_resource::<Beacons>()
+            .register_binding(Binding::new(
+                BindingCategory::Bookmarks,
+                "Drop beacon",
+                [Trigger::Key(KeyCode::KeyB)],
+            ))
+            .add_systems(Startup, create_beacon_assets);
+
+        app.add_systems(
+            Update,
+            drop_beacon
+                .after(crate::h_terrain::HTerrainPhase::TrackFov)
+                .run_if(in_state(GameState::Running)),
+        )
+        .add_systems(
+            Update,
+            place_beacons.run_if(
+                resource_changed::<Beacons>
+                    .or(any_match_filter::<(With<HCell>, Changed<GlobalTransform>)>),
+            ),
+        )
+        .add_systems(
+            Update,
+            draw_beacon_labels
+                .after(place_beacons)
+                .run_if(in_state(GameState::Running).or(in_state(GameState::Inspecting))),
+        )
+        .add_systems(
+            Update,
+            draw_beacon_panel.run_if(in_state(GameState::Inspecting)),
+        );
+    }
+}
+
+/// Builds the shared pillar mesh (base at the origin) and emissive material.
+fn create_beacon_assets(
+    cfg: Res<BeaconsConfig>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut commands: Commands,
+) {
+    let mesh = Mesh::from(Cylinder::new(cfg.width / 2.0, cfg.height))
+        .translated_by(Vec3::Y * cfg.height / 2.0);
+    commands.insert_resource(BeaconAssets {
+        mesh: meshes.add(mesh),
+        material: materials.add(StandardMaterial {
+            base_color: cfg.color,
+            emissive: LinearRgba::from(cfg.color) * cfg.glow,
+            ..default()
+        }),
+    });
+}
+
+/// B drops a beacon on the [`ActiveHex`].
+fn drop_beacon(
+    keys: Res<ButtonInput<KeyCode>>,
+    active: Res<ActiveHex>,
+    mut beacons: ResMut<Beacons>,
+    mut toasts: MessageWriter<Toast>,
+    locale: Res<Locale>,
+) {
+    if !keys.just_pressed(KeyCode::KeyB) {
+        return;
+    }
+    let Some(hex) = active.current else {
+        return;
+    };
+    match beacons.drop_at(hex) {
+        Some(index) => {
+            toasts.write(Toast(
+                locale.fmt("{0} dropped", &[&beacons.label(index, &locale)]),
+            ));
+        }
+        None => {
+            toasts.write(Toast(locale.t("A beacon already marks this hex").into()));
+        }
+    }
+}
+
+/// Respawns the pillars on their cells after beacons or cell positions change.
+fn place_beacons(
+    beacons: Res<Beacons>,
+    assets: Res<BeaconAssets>,
+    cells: Query<(&HCell, &GlobalTransform)>,
+    pillars: Query<Entity, With<BeaconPillar>>,
+    mut commands: Commands,
+) {
+    for pillar in &pillars {
+        commands.entity(pillar).despawn();
+    }
+    for (cell, gt) in &cells {
+        let Some(index) = beacons.0.iter().position(|b| b.hex() == cell.hex) else {
+            continue;
+        };
+        commands.spawn((
+            Name::new("BeaconPillar"),
+            BeaconPillar(index),
+            Mesh3d(assets.mesh.clone()),
+            MeshMaterial3d(assets.material.clone()),
+            Transform::from_translation(gt.translation()),
+        ));
+    }
+}
+
+/// Paints each beacon's label above its pillar.
+fn draw_beacon_labels(
+    mut egui_ctx: Single<&mut bevy_egui::EguiContext>,
+    cfg: Res<BeaconsConfig>,
+    beacons: Res<Beacons>,
+    window: Single<&Window>,
+    camera: Single<(&Camera, &GlobalTransform), With<Player>>,
+    pillars: Query<(&BeaconPillar, &Transform)>,
+    locale: Res<Locale>,
+) {
+    let (camera, cam_gt) = *camera;
+    let Some(viewport) = camera.logical_viewport_size() else {
+        return;
+    };
+    let ctx = egui_ctx.get_mut();
+    // The camera may render to a scaled offscreen target: map viewport → window pixels → points.
+    let to_points = window.size() / viewport * window_to_points(ctx, &window);
+    let [r, g, b, _] = cfg.color.to_srgba().to_u8_array();
+
+    egui::Area::new(egui::Id::new("beacon_labels"))
+        .fixed_pos(egui::pos2(0.0, 0.0))
+        .interactable(false)
+        .order(egui::Order::Background)
+        .show(ctx, |ui| {
+            let painter = ui.painter();
+            for (pillar, tf) in &pillars {
+                let top = tf.translation + Vec3::Y * cfg.height;
+                if top.distance(cam_gt.translation()) > cfg.label_distance {
+                    continue;
+                }
+                let Ok(vp) = camera.world_to_viewport(cam_gt, top) else {
+                    continue;
+                };
+                let p = vp * to_points;
+                painter.text(
+                    egui::pos2(p.x, p.y - 4.0),
+                    egui::Align2::CENTER_BOTTOM,
+                    beacons.label(pillar.0, &locale),
+                    egui::FontId::proportional(14.0),
+                    egui::Color32::from_rgb(r, g, b),
+                );
+            }
+        });
+}
+
+/// Change requested from the beacon panel this frame.
+enum BeaconAction {
+    Teleport(Hex),
+    Delete(usize),
+}
+
+/// Lists beacons with their hex and distance from the player; renames, teleports to, and
+/// deletes them.
+fn draw_beacon_panel(
+    mut egui_ctx: Single<&mut bevy_egui::EguiContext>,
+    player: Res<PlayerPos>,
+    pillars: Query<(&BeaconPillar, &Transform)>,
+    mut beacons: ResMut<Beacons>,
+    mut teleport: MessageWriter<TeleportToHex>,
+    locale: Res<Locale>,
+) {
+    let mut action = None;
+    egui::Window::new(locale.t("Beacons"))
+        .id(egui::Id::new("beacon_panel"))
+        .default_pos([8.0, 320.0])
+        .resizable(false)
+        .show(egui_ctx.get_mut(), |ui| {
+            if beacons.0.is_empty() {
+                ui.label(locale.t("No beacons yet (B while flying)."));
+            }
+            egui::Grid::new("beacons")
+                .num_columns(5)
+                .spacing([6.0, 2.0])
+                .show(ui, |ui| {
+                    for (index, beacon) in beacons.0.iter_mut().enumerate() {
+                        let distance = pillars
+                            .iter()
+                            .find(|(p, _)| p.0 == index)
+                            .map(|(_, tf)| tf.translation.xz().distance(player.xz));
+                        ui.monospace(format!("{:+},{:+}", beacon.hex[0], beacon.hex[1]));
+                        ui.add(
+                            egui::TextEdit::singleline(&mut beacon.name)
+                                .desired_width(110.0)
+                                .hint_text(locale.fmt("Beacon {0}", &[&(index + 1)])),
+                        );
+                        ui.monospace(
+                            distance.map_or_else(|| "     -".into(), |d| format!("{d:>6.1}")),
+                        );
+                        if ui
+                            .add_enabled(
+                                distance.is_some(),
+                                egui::Button::new(locale.t("Teleport")),
+                            )
+                            .clicked()
+                        {
+                            action = Some(BeaconAction::Teleport(beacon.hex()));
+                        }
+                        if ui.button("✖").on_hover_text(locale.t("Delete")).clicked() {
+                            action = Some(BeaconAction::Delete(index));
+                        }
+                        ui.end_row();
+                    }
+                });
+        });
+
+    match action {
+        Some(BeaconAction::Teleport(hex)) => {
+            teleport.write(TeleportToHex(hex));
+        }
+        Some(BeaconAction::Delete(index)) => {
+            beacons.0.remove(index);
+        }
+        None => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn drop_skips_marked_hexes_and_labels_fall_back() {
+        let mut beacons = Beacons::default();
+        assert_eq!(beacons.drop_at(Hex::new(2, -1)), Some(0));
+        assert_eq!(beacons.drop_at(Hex::new(0, 3)), Some(1));
+        assert_eq!(beacons.drop_at(Hex::new(2, -1)), None);
+        beacons.0[1].name = "spire".into();
+
+        let locale = Locale::default();
+        assert_eq!(beacons.label(0, &locale), "Beacon 1");
+        assert_eq!(beacons.label(1, &locale), "spire");
+    }
+
+    #[test]
+    fn pillars_stand_on_their_cells_and_follow_them() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_plugins(AssetPlugin::default())
+            .init_asset::<Mesh>()
+            .init_asset::<StandardMaterial>()
+            .init_resource::<BeaconsConfig>()
+            .init_resource::<Beacons>()
+            .add_systems(Startup, create_beacon_assets)
+            .add_systems(
+                Update,
+                place_beacons.run_if(
+                    resource_changed::<Beacons>
+                        .or(any_match_filter::<(With<HCell>, Changed<GlobalTransform>)>),
+                ),
+            );
+        let cell = app
+            .world_mut()
+            .spawn((
+                HCell {
+                    hex: Hex::new(1, 0),
+                },
+                GlobalTransform::from(Transform::from_xyz(3.0, 2.0, 1.0)),
+            ))
+            .id();
+        {
+            let mut beacons = app.world_mut().resource_mut::<Beacons>();
+            beacons.drop_at(Hex::new(1, 0));
+            // Off the grid: kept, but no pillar.
+            beacons.drop_at(Hex::new(40, 40));
+        }
+        app.update();
+
+        let pillars = |app: &mut App| {
+            let w = app.world_mut();
+            w.query::<(&BeaconPillar, &Transform)>()
+                .iter(w)
+                .map(|(p, tf)| (p.0, tf.translation))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(pillars(&mut app), [(0, Vec3::new(3.0, 2.0, 1.0))]);
+
+        app.world_mut()
+            .entity_mut(cell)
+            .insert(GlobalTransform::from(Transform::from_xyz(3.0, 5.0, 1.0)));
+        app.update();
+        assert_eq!(pillars(&mut app), [(0, Vec3::new(3.0, 5.0, 1.0))]);
+    }
+}
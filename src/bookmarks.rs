@@ -0,0 +1,419 @@
+//! Camera bookmarks for comparing the same viewpoint across config tweaks.
+//!
+//! Ctrl+1..9 stores the current [`PlayerPos`] + camera rotation into a slot;
+//! pressing 1..9 recalls it with an eased fly-to tween. Slots persist to
+//! [`BookmarksConfig::path`] as RON (native only).
+//!
+//! While `Inspecting`, a waypoint panel lists the slots with names and distances from
+//! the player, and can store, rename, reorder, delete, or fly to them.
+
+use std::collections::BTreeMap;
+
+use bevy::prelude::*;
+use bevy::window::CursorOptions;
+use bevy_egui::egui;
+use serde::{Deserialize, Serialize};
+
+use crate::controls::{Binding, BindingCategory, RegisterBinding, Trigger};
+use crate::drone::Player;
+use crate::locale::Locale;
+use crate::toast::Toast;
+use crate::{GameState, PlayerMoved, PlayerPos, math, persist, set_cursor_free};
+
+/// Per-plugin configuration for camera bookmarks.
+#[derive(Resource, Clone, Debug, Reflect)]
+pub struct BookmarksConfig {
+    /// File the bookmarks are persisted to (native only).
+    pub path: String,
+    /// Duration of the fly-to tween when recalling a bookmark (seconds).
+    pub fly_duration: f32,
+}
+
+impl Default for BookmarksConfig {
+    fn default() -> Self {
+        Self {
+            path: "bookmarks.ron".into(),
+            fly_duration: 1.2,
+        }
+    }
+}
+
+/// A stored viewpoint: player position plus camera rotation.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Bookmark {
+    /// User-given name; empty shows as `Bookmark <slot>`.
+    #[serde(default)]
+    pub name: String,
+    /// Horizontal position on the terrain plane.
+    pub xz: [f32; 2],
+    /// Vertical offset above ground.
+    pub offset: f32,
+    /// Camera rotation quaternion `[x, y, z, w]`.
+    pub rotation: [f32; 4],
+}
+
+impl Bookmark {
+    /// Captures the current player position and camera rotation.
+    pub fn capture(pos: &PlayerPos, rotation: Quat) -> Self {
+        Self {
+            name: String::new(),
+            xz: pos.xz.to_array(),
+            offset: pos.offset,
+            rotation: rotation.to_array(),
+        }
+    }
+
+    /// Interpolates toward `other`: position lerp + rotation slerp.
+    ///
+    /// Returns `(xz, offset, rotation)` at progress `t` in `[0, 1]`.
+    pub fn lerp(&self, other: &Self, t: f32) -> (Vec2, f32, Quat) {
+        let xz = Vec2::from(self.xz).lerp(Vec2::from(other.xz), t);
+        let offset = self.offset + (other.offset - self.offset) * t;
+        let rotation = Quat::from_array(self.rotation).slerp(Quat::from_array(other.rotation), t);
+        (xz, offset, rotation)
+    }
+}
+
+/// Slot number (1..9) → stored viewpoint.
+#[derive(Resource, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Bookmarks(pub BTreeMap<u8, Bookmark>);
+
+impl Bookmarks {
+    /// Display name of `slot`: its [`Bookmark::name`], or `Bookmark <slot>` (localized) when
+    /// unnamed.
+    pub fn label(&self, slot: u8, locale: &Locale) -> String {
+        match self.0.get(&slot) {
+            Some(b) if !b.name.is_empty() => b.name.clone(),
+            _ => locale.fmt("Bookmark {0}", &[&slot]),
+        }
+    }
+
+    /// Lowest free slot in 1..=9.
+    pub fn free_slot(&self) -> Option<u8> {
+        (1..=9).find(|slot| !self.0.contains_key(slot))
+    }
+
+    /// Exchanges the contents of two slots (either may be empty).
+    pub fn swap_slots(&mut self, a: u8, b: u8) {
+        let (from_a, from_b) = (self.0.remove(&a), self.0.remove(&b));
+        if let Some(bookmark) = from_a {
+            self.0.insert(b, bookmark);
+        }
+        if let Some(bookmark) = from_b {
+            self.0.insert(a, bookmark);
+        }
+    }
+}
+
+/// In-progress fly-to tween toward a recalled bookmark.
+#[derive(Resource, Default)]
+struct BookmarkFlight(Option<Flight>);
+
+impl BookmarkFlight {
+    /// Starts a tween from `from` to `to`, replacing any flight in progress.
+    fn start(&mut self, from: Bookmark, to: Bookmark) {
+        self.0 = Some(Flight {
+            from,
+            to,
+            elapsed: 0.0,
+        });
+    }
+}
+
+struct Flight {
+    from: Bookmark,
+    to: Bookmark,
+    elapsed: f32,
+}
+
+/// Number keys mapped to bookmark slots.
+const SLOT_KEYS: [(KeyCode, u8); 9] = [
+    (KeyCode::Digit1, 1),
+    (KeyCode::Digit2, 2),
+    (KeyCode::Digit3, 3),
+    (KeyCode::Digit4, 4),
+    (KeyCode::Digit5, 5),
+    (KeyCode::Digit6, 6),
+    (KeyCode::Digit7, 7),
+    (KeyCode::Digit8, 8),
+    (KeyCode::Digit9, 9),
+];
+
+/// Camera bookmarks plugin.
+pub struct BookmarksPlugin {
+    /// Per-plugin configuration.
+    pub config: BookmarksConfig,
+}
+
+impl Plugin for BookmarksPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<BookmarksConfig>()
+            .insert_resource(self.config.clone())
+            .init_resource::<Bookmarks>()
+            .init_resource::<BookmarkFlight>()
+            .register_binding(
+                Binding::new(
+                    BindingCategory::Bookmarks,
+                    "Store viewpoint",
+                    [Trigger::Keys(KeyCode::Digit1, KeyCode::Digit9)],
+                )
+                .with_modifier(KeyCode::ControlLeft),
+            )
+            .register_binding(Binding::new(
+                BindingCategory::Bookmarks,
+                "Fly to viewpoint",
+                [Trigger::Keys(KeyCode::Digit1, KeyCode::Digit9)],
+            ));
+
+        app.add_systems(Startup, load_bookmarks)
+            .add_systems(
+                Update,
+                (
+                    handle_bookmark_keys,
+                    tick_bookmark_flight.before(crate::drone::systems::fly),
+                )
+                    .chain()
+                    .run_if(in_state(GameState::Running)),
+            )
+            .add_systems(
+                Update,
+                draw_waypoint_panel.run_if(in_state(GameState::Inspecting)),
+            );
+    }
+}
+
+/// Reads persisted bookmarks from disk, if present.
+fn load_bookmarks(cfg: Res<BookmarksConfig>, mut bookmarks: ResMut<Bookmarks>) {
+    *bookmarks = persist::load_ron(&cfg.path, "bookmarks");
+}
+
+/// Writes bookmarks to disk (no-op on WASM).
+pub(crate) fn save_bookmarks(bookmarks: &Bookmarks, path: &str) {
+    persist::save_ron(bookmarks, path, "bookmarks");
+}
+
+/// Ctrl+digit stores the current viewpoint; plain digit starts a fly-to tween.
+#[allow(clippy::too_many_arguments)]
+fn handle_bookmark_keys(
+    keys: Res<ButtonInput<KeyCode>>,
+    cfg: Res<BookmarksConfig>,
+    player: Res<PlayerPos>,
+    camera: Single<&Transform, With<Player>>,
+    mut bookmarks: ResMut<Bookmarks>,
+    mut flight: ResMut<BookmarkFlight>,
+    mut toasts: MessageWriter<Toast>,
+    locale: Res<Locale>,
+) {
+    let storing = keys.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]);
+    for (key, slot) in SLOT_KEYS {
+        if !keys.just_pressed(key) {
+            continue;
+        }
+        let here = Bookmark::capture(&player, camera.rotation);
+        if storing {
+            bookmarks.0.insert(slot, here);
+            save_bookmarks(&bookmarks, &cfg.path);
+            toasts.write(Toast(
+                locale.fmt("{0} stored", &[&bookmarks.label(slot, &locale)]),
+            ));
+        } else if let Some(to) = bookmarks.0.get(&slot) {
+            flight.start(here, to.clone());
+            toasts.write(Toast(
+                locale.fmt("Flying to {0}", &[&bookmarks.label(slot, &locale)]),
+            ));
+        }
+    }
+}
+
+/// Advances the fly-to tween, writing [`PlayerPos`] and camera rotation.
+///
+/// Runs before [`fly`](crate::drone::systems::fly) so the drone applies the
+/// tweened position in the same frame.
+fn tick_bookmark_flight(
+    time: Res<Time>,
+    cfg: Res<BookmarksConfig>,
+    mut flight: ResMut<BookmarkFlight>,
+    mut player: ResMut<PlayerPos>,
+    mut moved: ResMut<PlayerMoved>,
+    mut camera: Single<&mut Transform, With<Player>>,
+) {
+    let Some(f) = flight.0.as_mut() else {
+        return;
+    };
+    f.elapsed += time.delta_secs();
+    let t = (f.elapsed / cfg.fly_duration.max(f32::EPSILON)).min(1.0);
+    let (xz, offset, rotation) = f.from.lerp(&f.to, math::ease_out_cubic(t));
+    player.xz = xz;
+    player.offset = offset;
+    moved.0 = true;
+    camera.rotation = rotation;
+    if t >= 1.0 {
+        flight.0 = None;
+    }
+}
+
+/// Change requested from the waypoint panel this frame.
+enum WaypointAction {
+    Store(u8),
+    Rename,
+    Swap(u8, u8),
+    Delete(u8),
+    FlyTo(u8),
+}
+
+/// Lists bookmarks with distance from the player; stores, renames, reorders, deletes,
+/// and flies to them. Flying resumes `Running` so the tween plays immediately.
+#[allow(clippy::too_many_arguments)]
+fn draw_waypoint_panel(
+    mut egui_ctx: Single<&mut bevy_egui::EguiContext>,
+    cfg: Res<BookmarksConfig>,
+    player: Res<PlayerPos>,
+    camera: Single<&Transform, With<Player>>,
+    mut bookmarks: ResMut<Bookmarks>,
+    mut flight: ResMut<BookmarkFlight>,
+    mut next: ResMut<NextState<GameState>>,
+    mut windows: Query<(&mut CursorOptions, &mut Window)>,
+    mut toasts: MessageWriter<Toast>,
+    locale: Res<Locale>,
+) {
+    let mut action = None;
+    egui::Window::new(locale.t("Waypoints"))
+        .id(egui::Id::new("waypoint_panel"))
+        .default_pos([8.0, 120.0])
+        .resizable(false)
+        .show(egui_ctx.get_mut(), |ui| {
+            let slots: Vec<u8> = bookmarks.0.keys().copied().collect();
+            if slots.is_empty() {
+                ui.label(locale.t("No bookmarks yet (Ctrl+1–9 while flying)."));
+            }
+            egui::Grid::new("waypoints")
+                .num_columns(5)
+                .spacing([6.0, 2.0])
+                .show(ui, |ui| {
+                    for slot in slots {
+                        let Some(bookmark) = bookmarks.0.get_mut(&slot) else {
+                            continue;
+                        };
+                        let distance = Vec2::from(bookmark.xz).distance(player.xz);
+                        ui.monospace(slot.to_string());
+                        let name = ui.add(
+                            egui::TextEdit::singleline(&mut bookmark.name)
+                                .desired_width(110.0)
+                                .hint_text(locale.fmt("Bookmark {0}", &[&slot])),
+                        );
+                        if name.lost_focus() {
+                            action = Some(WaypointAction::Rename);
+                        }
+                        ui.monospace(format!("{distance:>6.1}"));
+                        ui.horizontal(|ui| {
+                            if ui.add_enabled(slot > 1, egui::Button::new("▲")).clicked() {
+                                action = Some(WaypointAction::Swap(slot, slot - 1));
+                            }
+                            if ui.add_enabled(slot < 9, egui::Button::new("▼")).clicked() {
+                                action = Some(WaypointAction::Swap(slot, slot + 1));
+                            }
+                            if ui.button("✖").on_hover_text(locale.t("Delete")).clicked() {
+                                action = Some(WaypointAction::Delete(slot));
+                            }
+                        });
+                        if ui.button(locale.t("Fly here")).clicked() {
+                            action = Some(WaypointAction::FlyTo(slot));
+                        }
+                        ui.end_row();
+                    }
+                });
+            if let Some(slot) = bookmarks.free_slot()
+                && ui.button(locale.t("Store current view")).clicked()
+            {
+                action = Some(WaypointAction::Store(slot));
+            }
+        });
+
+    let Some(action) = action else {
+        return;
+    };
+    match action {
+        WaypointAction::Store(slot) => {
+            let here = Bookmark::capture(&player, camera.rotation);
+            bookmarks.0.insert(slot, here);
+            toasts.write(Toast(
+                locale.fmt("{0} stored", &[&bookmarks.label(slot, &locale)]),
+            ));
+        }
+        WaypointAction::Rename => {}
+        WaypointAction::Swap(a, b) => bookmarks.swap_slots(a, b),
+        WaypointAction::Delete(slot) => {
+            bookmarks.0.remove(&slot);
+        }
+        WaypointAction::FlyTo(slot) => {
+            if let Some(to) = bookmarks.0.get(&slot) {
+                flight.start(Bookmark::capture(&player, camera.rotation), to.clone());
+                toasts.write(Toast(
+                    locale.fmt("Flying to {0}", &[&bookmarks.label(slot, &locale)]),
+                ));
+                next.set(GameState::Running);
+                set_cursor_free(&mut windows, false);
+            }
+            return;
+        }
+    }
+    save_bookmarks(&bookmarks, &cfg.path);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(x: f32) -> Bookmark {
+        Bookmark {
+            name: String::new(),
+            xz: [x, -x],
+            offset: 2.0 + x,
+            rotation: Quat::from_rotation_y(x).to_array(),
+        }
+    }
+
+    #[test]
+    fn swap_slots_moves_into_empty_slots_and_labels_fall_back() {
+        let mut bookmarks = Bookmarks::default();
+        bookmarks.0.insert(
+            1,
+            Bookmark {
+                name: "ridge".into(),
+                ..sample(0.5)
+            },
+        );
+        bookmarks.0.insert(2, sample(1.5));
+        bookmarks.swap_slots(1, 2);
+        let locale = Locale::default();
+        assert_eq!(bookmarks.label(2, &locale), "ridge");
+        assert_eq!(bookmarks.label(1, &locale), "Bookmark 1");
+        let german = Locale::new(crate::locale::Language::German);
+        assert_eq!(bookmarks.label(1, &german), "Lesezeichen 1");
+
+        bookmarks.swap_slots(2, 3);
+        assert!(!bookmarks.0.contains_key(&2));
+        assert_eq!(bookmarks.label(3, &locale), "ridge");
+        assert_eq!(bookmarks.free_slot(), Some(2));
+    }
+
+    #[test]
+    fn unnamed_bookmarks_from_older_files_still_load() {
+        let text = "({1: (xz: (1.0, 2.0), offset: 3.0, rotation: (0.0, 0.0, 0.0, 1.0))})";
+        let bookmarks: Bookmarks = ron::from_str(text).unwrap();
+        assert_eq!(bookmarks.0[&1].name, "");
+        assert_eq!(bookmarks.0[&1].offset, 3.0);
+    }
+
+    #[test]
+    fn lerp_hits_endpoints() {
+        let (a, b) = (sample(0.0), sample(1.0));
+        let (xz, offset, rot) = a.lerp(&b, 1.0);
+        assert!((xz - Vec2::from(b.xz)).length() < 1e-6);
+        assert!((offset - b.offset).abs() < 1e-6);
+        assert!(rot.angle_between(Quat::from_array(b.rotation)) < 1e-4);
+
+        let (xz, _, _) = a.lerp(&b, 0.0);
+        assert!((xz - Vec2::from(a.xz)).length() < 1e-6);
+    }
+}
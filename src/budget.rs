@@ -0,0 +1,285 @@
+//! Entity budgets: a HUD warning when terrain entity counts outgrow configurable limits.
+//!
+//! [`check_entity_budgets`] recounts gap meshes ([`Quad`](crate::h_terrain::Quad) +
+//! [`Tri`](crate::h_terrain::Tri), the petals between hexes), edge lines
+//! ([`QuadEdge`](crate::h_terrain::QuadEdge)), in-FoV gaps, and live
+//! [`RevealParticle`](crate::h_terrain::RevealParticle)s with [`MarkerCounts`] every
+//! [`BudgetConfig::check_secs`] and stores the overruns in [`BudgetStatus`]; the HUD strip
+//! shows them as a warning line. Gap and edge totals follow the grid radius, so they only
+//! warn. In-FoV gaps and particles scale with the reveal radius
+//! ([`HGridSettings::fov_reach`](crate::h_terrain::HGridSettings)); with
+//! [`BudgetConfig::auto_reduce_reach`] an overrun there lowers the reach by one ring (down to
+//! [`BudgetConfig::min_reach`]); the FoV pass picks up the new reach without a rebuild. There
+//! are no pole entities in this tree.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::time::common_conditions::on_real_timer;
+
+use crate::GameState;
+use crate::h_terrain::HTerrainConfig;
+use crate::locale::Locale;
+use crate::perf::MarkerCounts;
+use crate::settings::SettingsDraft;
+use crate::toast::Toast;
+
+/// Per-plugin configuration for entity budgets.
+#[derive(Resource, Clone, Debug, Reflect)]
+pub struct BudgetConfig {
+    /// Gap meshes (quads + tris) in the whole grid.
+    pub gaps: usize,
+    /// Edge lines in the whole grid.
+    pub edges: usize,
+    /// Gap meshes inside the field of view.
+    pub fov_gaps: usize,
+    /// Live reveal particles.
+    pub particles: usize,
+    /// Lower the reveal radius when a reach-bound budget is exceeded.
+    pub auto_reduce_reach: bool,
+    /// Reveal radius auto-reduction stops at.
+    pub min_reach: u32,
+    /// Seconds between recounts (real time).
+    pub check_secs: f32,
+}
+
+impl Default for BudgetConfig {
+    fn default() -> Self {
+        Self {
+            gaps: 20_000,
+            edges: 60_000,
+            fov_gaps: 400,
+            particles: 1_500,
+            auto_reduce_reach: false,
+            min_reach: 1,
+            check_secs: 2.0,
+        }
+    }
+}
+
+/// A budgeted entity group.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+pub enum BudgetKind {
+    /// [`Quad`] + [`Tri`] gap meshes.
+    Gaps,
+    /// [`QuadEdge`] lines.
+    Edges,
+    /// Gap meshes tagged [`InFov`].
+    FovGaps,
+    /// [`RevealParticle`]s.
+    Particles,
+}
+
+impl BudgetKind {
+    /// Name shown in the HUD warning (a [`Locale`] key).
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Gaps => "gaps",
+            Self::Edges => "edges",
+            Self::FovGaps => "FoV gaps",
+            Self::Particles => "particles",
+        }
+    }
+
+    /// Whether lowering the reveal radius shrinks this group.
+    pub fn reach_bound(self) -> bool {
+        matches!(self, Self::FovGaps | Self::Particles)
+    }
+}
+
+/// One exceeded budget.
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+pub struct Overrun {
+    /// Budgeted group.
+    pub kind: BudgetKind,
+    /// Current count.
+    pub count: usize,
+    /// Configured limit.
+    pub budget: usize,
+}
+
+/// Entity counts of the budgeted groups.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Reflect)]
+pub struct EntityCounts {
+    /// Gap meshes.
+    pub gaps: usize,
+    /// Edge lines.
+    pub edges: usize,
+    /// In-FoV gap meshes.
+    pub fov_gaps: usize,
+    /// Live reveal particles.
+    pub particles: usize,
+}
+
+impl EntityCounts {
+    /// Groups whose count exceeds their budget in `cfg`.
+    pub fn overruns(&self, cfg: &BudgetConfig) -> Vec<Overrun> {
+        [
+            (BudgetKind::Gaps, self.gaps, cfg.gaps),
+            (BudgetKind::Edges, self.edges, cfg.edges),
+            (BudgetKind::FovGaps, self.fov_gaps, cfg.fov_gaps),
+            (BudgetKind::Particles, self.particles, cfg.particles),
+        ]
+        .into_iter()
+        .filter(|&(_, count, budget)| count > budget)
+        .map(|(kind, count, budget)| Overrun {
+            kind,
+            count,
+            budget,
+        })
+        .collect()
+    }
+}
+
+/// Reveal radius to fall back to for `overruns`, if auto-reduction applies.
+pub fn reduced_reach(overruns: &[Overrun], reach: u32, min_reach: u32) -> Option<u32> {
+    (reach > min_reach && overruns.iter().any(|o| o.kind.reach_bound())).then(|| reach - 1)
+}
+
+/// Latest counts and exceeded budgets, read by the HUD.
+#[derive(Resource, Default, Reflect)]
+pub struct BudgetStatus {
+    /// Counts from the last check.
+    pub counts: EntityCounts,
+    /// Budgets exceeded at the last check.
+    pub overruns: Vec<Overrun>,
+}
+
+/// Entity budget plugin.
+pub struct BudgetPlugin {
+    /// Per-plugin configuration.
+    pub config: BudgetConfig,
+}
+
+impl Plugin for BudgetPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<BudgetConfig>()
+            .register_type::<BudgetStatus>()
+            .insert_resource(self.config.clone())
+            .init_resource::<BudgetStatus>()
+            .add_systems(
+                Update,
+                check_entity_budgets.run_if(in_state(GameState::Running).and(on_real_timer(
+                    Duration::from_secs_f32(self.config.check_secs),
+                ))),
+            );
+    }
+}
+
+/// Recounts the budgeted groups and, when enabled, lowers the reveal radius on overrun.
+pub fn check_entity_budgets(
+    cfg: Res<BudgetConfig>,
+    markers: MarkerCounts,
+    mut status: ResMut<BudgetStatus>,
+    mut terrain: ResMut<HTerrainConfig>,
+    mut draft: ResMut<SettingsDraft>,
+    mut toasts: MessageWriter<Toast>,
+    locale: Res<Locale>,
+) {
+    let counts = markers.entity_counts();
+    let overruns = counts.overruns(&cfg);
+    if status.counts != counts || status.overruns != overruns {
+        *status = BudgetStatus {
+            counts,
+            overruns: overruns.clone(),
+        };
+    }
+    if !cfg.auto_reduce_reach {
+        return;
+    }
+    let Some(reach) = reduced_reach(&overruns, terrain.grid.fov_reach, cfg.min_reach) else {
+        return;
+    };
+    terrain.grid.fov_reach = reach;
+    if let Some(grid) = draft.grid.as_mut() {
+        grid.fov_reach = reach;
+    }
+    toasts.write(Toast(locale.fmt(
+        "Entity budget exceeded: reveal radius lowered to {0}",
+        &[&reach],
+    )));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::h_terrain::{InFov, Quad, QuadEdge, RegenerateTerrain, Tri};
+
+    #[test]
+    fn overruns_list_exceeded_budgets() {
+        let cfg = BudgetConfig {
+            gaps: 10,
+            edges: 40,
+            fov_gaps: 5,
+            particles: 20,
+            ..default()
+        };
+        let counts = EntityCounts {
+            gaps: 10,
+            edges: 41,
+            fov_gaps: 3,
+            particles: 25,
+        };
+        let overruns = counts.overruns(&cfg);
+        assert_eq!(
+            overruns.iter().map(|o| o.kind).collect::<Vec<_>>(),
+            [BudgetKind::Edges, BudgetKind::Particles]
+        );
+        assert_eq!((overruns[0].count, overruns[0].budget), (41, 40));
+    }
+
+    #[test]
+    fn reach_drops_only_for_reach_bound_overruns() {
+        let over = |kind| Overrun {
+            kind,
+            count: 2,
+            budget: 1,
+        };
+        assert_eq!(reduced_reach(&[over(BudgetKind::Particles)], 3, 1), Some(2));
+        assert_eq!(reduced_reach(&[over(BudgetKind::FovGaps)], 1, 1), None);
+        assert_eq!(reduced_reach(&[over(BudgetKind::Edges)], 3, 1), None);
+        assert_eq!(reduced_reach(&[], 3, 1), None);
+    }
+
+    #[test]
+    fn check_counts_entities_and_lowers_reach() {
+        let mut app = App::new();
+        app.add_message::<RegenerateTerrain>()
+            .add_message::<Toast>()
+            .insert_resource(BudgetConfig {
+                fov_gaps: 1,
+                auto_reduce_reach: true,
+                ..default()
+            })
+            .init_resource::<BudgetStatus>()
+            .init_resource::<HTerrainConfig>()
+            .init_resource::<SettingsDraft>()
+            .init_resource::<Locale>()
+            .add_systems(Update, check_entity_budgets);
+        app.world_mut().spawn((Quad, InFov));
+        app.world_mut().spawn((Tri, InFov));
+        app.world_mut().spawn(Quad);
+        app.world_mut().spawn_batch([QuadEdge, QuadEdge, QuadEdge]);
+        let reach = app.world().resource::<HTerrainConfig>().grid.fov_reach;
+
+        app.update();
+        let status = app.world().resource::<BudgetStatus>();
+        assert_eq!(
+            status.counts,
+            EntityCounts {
+                gaps: 3,
+                edges: 3,
+                fov_gaps: 2,
+                particles: 0,
+            }
+        );
+        assert_eq!(status.overruns.len(), 1);
+        assert_eq!(
+            app.world().resource::<HTerrainConfig>().grid.fov_reach,
+            reach - 1
+        );
+        // The FoV pass applies the new reach; regenerating would wipe the explored state.
+        let regenerations = app.world().resource::<Messages<RegenerateTerrain>>();
+        assert!(regenerations.is_empty());
+    }
+}
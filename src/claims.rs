@@ -0,0 +1,508 @@
+//! Hex claiming and the faction coloring layer.
+//!
+//! An [`Owner`] on an [`HCell`] marks it as held by a faction. [`restyle_claims`] covers the
+//! cell's [`HexFace`] with a translucent [`ClaimOverlay`] tinted in the faction's color from
+//! [`ClaimsConfig::colors`], recolors it when the owner changes, and removes it when the
+//! [`Owner`] goes away. The face's own material is never touched, so FoV fades and theme
+//! changes keep running underneath.
+//!
+//! C ([`claim_hex`]) claims the hex under the player for [`PLAYER`]. [`can_claim`] enforces the
+//! rules: the hex must not already be held, and (with [`ClaimsConfig::require_adjacent`]) it
+//! must border a hex the player already holds. The first claim can be anywhere. The HUD strip
+//! shows the claimed count.
+//!
+//! In inspect mode the territory brush ([`TerritoryBrush`], toggled from [`draw_brush_panel`])
+//! paints ownership straight onto the map for diagramming: holding the left button sets the
+//! brush faction on every hex within [`TerritoryBrush::radius`] rings of the [`HoveredHex`],
+//! the right button (or the eraser) clears it ([`paint_territory`]). Painting skips the claim
+//! rules, and while the brush is on, clicks don't pick hexes ([`HTerrainPhase::PickHex`] is
+//! gated off), so strokes leave the selection and path ends alone.
+
+use std::collections::HashSet;
+
+use bevy::prelude::*;
+use bevy_egui::egui;
+use hexx::Hex;
+
+use crate::GameState;
+use crate::controls::{Binding, BindingCategory, RegisterBinding, Trigger};
+use crate::h_terrain::{ActiveHex, HCell, HTerrainPhase, HexFace, HoveredHex, overlay_lift};
+use crate::locale::Locale;
+use crate::toast::Toast;
+
+/// Faction index of the player.
+pub const PLAYER: u8 = 0;
+
+/// Per-plugin configuration for hex claiming.
+#[derive(Resource, Clone, Debug, Reflect)]
+pub struct ClaimsConfig {
+    /// Overlay color per faction, indexed by [`Owner`]; the player is first.
+    pub colors: Vec<Color>,
+    /// Overlay opacity in `0.0..=1.0`.
+    pub opacity: f32,
+    /// Emissive multiplier on the faction color (drives bloom).
+    pub glow: f32,
+    /// Claims must border a hex the faction already holds (after its first claim).
+    pub require_adjacent: bool,
+}
+
+impl Default for ClaimsConfig {
+    fn default() -> Self {
+        Self {
+            colors: vec![
+                Color::srgb(0.0, 1.0, 0.8),
+                Color::srgb(1.0, 0.35, 0.1),
+                Color::srgb(0.7, 0.2, 1.0),
+            ],
+            opacity: 0.35,
+            glow: 1.5,
+            require_adjacent: true,
+        }
+    }
+}
+
+/// Faction holding an [`HCell`].
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+pub struct Owner(pub u8);
+
+/// Marker on the faction-colored overlay child of a [`HexFace`] (see [`overlay_lift`]).
+#[derive(Component, Reflect)]
+pub struct ClaimOverlay;
+
+/// Shared overlay material per faction, in [`ClaimsConfig::colors`] order.
+#[derive(Resource)]
+struct ClaimMaterials(Vec<Handle<StandardMaterial>>);
+
+impl ClaimMaterials {
+    /// Material for `owner`, wrapping around when there are more factions than colors.
+    fn get(&self, owner: Owner) -> Handle<StandardMaterial> {
+        self.0[usize::from(owner.0) % self.0.len()].clone()
+    }
+}
+
+/// Largest brush radius offered by the panel, in hex rings.
+const MAX_BRUSH_RADIUS: u32 = 5;
+
+/// Inspect-mode brush that paints [`Owner`] over the map.
+#[derive(Resource, Clone, Debug, Default, Reflect)]
+pub struct TerritoryBrush {
+    /// Mouse buttons paint while on.
+    pub enabled: bool,
+    /// Rings around the hovered hex the brush covers (0 = that hex only).
+    pub radius: u32,
+    /// Faction painted with the left button; `None` is the eraser.
+    pub faction: Option<u8>,
+}
+
+/// Why a claim was refused.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ClaimError {
+    /// Some faction already holds the hex.
+    AlreadyOwned(Owner),
+    /// The hex doesn't border any hex the faction holds.
+    NotAdjacent,
+}
+
+impl ClaimError {
+    /// English toast text (translated through [`Locale`]).
+    pub fn message(self) -> &'static str {
+        match self {
+            Self::AlreadyOwned(Owner(PLAYER)) => "Hex already claimed",
+            Self::AlreadyOwned(_) => "Hex held by another faction",
+            Self::NotAdjacent => "Claims must border a claimed hex",
+        }
+    }
+}
+
+/// Checks whether `hex` may be claimed given its current `owner` and the hexes the
+/// claiming faction already `held`.
+pub fn can_claim(
+    hex: Hex,
+    owner: Option<Owner>,
+    held: &HashSet<Hex>,
+    require_adjacent: bool,
+) -> Result<(), ClaimError> {
+    if let Some(owner) = owner {
+        return Err(ClaimError::AlreadyOwned(owner));
+    }
+    if require_adjacent && !held.is_empty() && !hex.all_neighbors().iter().any(|n| held.contains(n))
+    {
+        return Err(ClaimError::NotAdjacent);
+    }
+    Ok(())
+}
+
+/// Hex claiming plugin.
+pub struct ClaimsPlugin {
+    /// Per-plugin configuration.
+    pub config: ClaimsConfig,
+}
+
+impl Plugin for ClaimsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<ClaimsConfig>()
+            .register_type::<Owner>()
+            .register_type::<ClaimOverlay>()
+            .register_type::<TerritoryBrush>()
+            .insert_resource(self.config.clone())
+            .insert_resource(TerritoryBrush {
+                faction: Some(PLAYER),
+                ..default()
+            })
+            .configure_sets(
+                Update,
+                HTerrainPhase::PickHex.run_if(|brush: Res<TerritoryBrush>| !brush.enabled),
+            )
+            .add_systems(Startup, create_claim_materials)
+            .add_systems(
+                Update,
+                claim_hex
+                    .after(HTerrainPhase::TrackFov)
+                    .run_if(in_state(GameState::Running)),
+            )
+            .add_systems(
+                Update,
+                (draw_brush_panel, paint_territory)
+                    .chain()
+                    .before(restyle_claims)
+                    .run_if(in_state(GameState::Inspecting)),
+            )
+            .add_systems(Update, restyle_claims)
+            .register_binding(Binding::new(
+                BindingCategory::Drone,
+                "Claim hex under the drone",
+                [Trigger::Key(KeyCode::KeyC)],
+            ))
+            .register_binding(Binding::new(
+                BindingCategory::Terrain,
+                "Paint / erase territory (brush on, inspect mode)",
+                [
+                    Trigger::Mouse(MouseButton::Left),
+                    Trigger::Mouse(MouseButton::Right),
+                ],
+            ));
+    }
+}
+
+/// Creates one translucent overlay material per faction color.
+fn create_claim_materials(
+    cfg: Res<ClaimsConfig>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut commands: Commands,
+) {
+    let colors = if cfg.colors.is_empty() {
+        vec![Color::WHITE]
+    } else {
+        cfg.colors.clone()
+    };
+    let handles = colors
+        .into_iter()
+        .map(|color| {
+            materials.add(StandardMaterial {
+                base_color: color.with_alpha(cfg.opacity),
+                emissive: LinearRgba::from(color) * cfg.glow,
+                alpha_mode: AlphaMode::Blend,
+                unlit: true,
+                ..default()
+            })
+        })
+        .collect();
+    commands.insert_resource(ClaimMaterials(handles));
+}
+
+/// Claims the hex under the player on C, or toasts why it can't be claimed.
+fn claim_hex(
+    keys: Res<ButtonInput<KeyCode>>,
+    cfg: Res<ClaimsConfig>,
+    active: Res<ActiveHex>,
+    cells: Query<(&HCell, Option<&Owner>)>,
+    mut toasts: MessageWriter<Toast>,
+    locale: Res<Locale>,
+    mut commands: Commands,
+) {
+    if !keys.just_pressed(KeyCode::KeyC) {
+        return;
+    }
+    let Some(entity) = active.cell else {
+        return;
+    };
+    let Ok((cell, owner)) = cells.get(entity) else {
+        return;
+    };
+    let held: HashSet<Hex> = cells
+        .iter()
+        .filter(|(_, o)| *o == Some(&Owner(PLAYER)))
+        .map(|(c, _)| c.hex)
+        .collect();
+    match can_claim(cell.hex, owner.copied(), &held, cfg.require_adjacent) {
+        Ok(()) => {
+            commands.entity(entity).insert(Owner(PLAYER));
+            toasts.write(Toast(locale.fmt(
+                "Claimed hex {0}",
+                &[&format!("{:+},{:+}", cell.hex.x, cell.hex.y)],
+            )));
+        }
+        Err(err) => {
+            toasts.write(Toast(locale.t(err.message()).into()));
+        }
+    }
+}
+
+/// Keeps each owned cell's face overlay in its faction color; drops it when unowned.
+fn restyle_claims(
+    materials: Res<ClaimMaterials>,
+    owned: Query<(&Owner, &Children), Changed<Owner>>,
+    mut removed: RemovedComponents<Owner>,
+    children: Query<&Children>,
+    faces: Query<&Mesh3d, With<HexFace>>,
+    mut overlays: Query<&mut MeshMaterial3d<StandardMaterial>, With<ClaimOverlay>>,
+    mut commands: Commands,
+) {
+    for cell in removed.read() {
+        for child in children.iter_descendants(cell) {
+            if overlays.contains(child) {
+                commands.entity(child).despawn();
+            }
+        }
+    }
+    for (&owner, cell_children) in &owned {
+        let material = materials.get(owner);
+        for face in cell_children.iter() {
+            let Ok(mesh) = faces.get(face) else {
+                continue;
+            };
+            let mut existing = overlays.iter_many_mut(children.get(face).into_iter().flatten());
+            if let Some(mut overlay) = existing.fetch_next() {
+                overlay.0 = material.clone();
+            } else {
+                commands.entity(face).with_child((
+                    ClaimOverlay,
+                    Mesh3d(mesh.0.clone()),
+                    MeshMaterial3d(material.clone()),
+                    Transform::from_xyz(0.0, overlay_lift::CLAIM, 0.0),
+                ));
+            }
+        }
+    }
+}
+
+/// Brush toggle, radius slider and faction palette.
+fn draw_brush_panel(
+    mut egui_ctx: Single<&mut bevy_egui::EguiContext>,
+    cfg: Res<ClaimsConfig>,
+    mut brush: ResMut<TerritoryBrush>,
+    locale: Res<Locale>,
+) {
+    egui::Window::new(locale.t("Territory brush"))
+        .id(egui::Id::new("territory_brush"))
+        .default_pos([8.0, 420.0])
+        .resizable(false)
+        .show(egui_ctx.get_mut(), |ui| {
+            ui.checkbox(&mut brush.enabled, locale.t("Paint with the mouse"));
+            ui.add(
+                egui::Slider::new(&mut brush.radius, 0..=MAX_BRUSH_RADIUS).text(locale.t("Rings")),
+            );
+            ui.horizontal(|ui| {
+                for (faction, color) in (0..).zip(&cfg.colors) {
+                    let [r, g, b, _] = color.to_srgba().to_u8_array();
+                    let selected = brush.faction == Some(faction);
+                    let swatch = egui::Button::new("    ")
+                        .fill(egui::Color32::from_rgb(r, g, b))
+                        .selected(selected);
+                    if ui
+                        .add(swatch)
+                        .on_hover_text(locale.fmt("Faction {0}", &[&faction]))
+                        .clicked()
+                    {
+                        brush.faction = Some(faction);
+                    }
+                }
+                if ui
+                    .selectable_label(brush.faction.is_none(), locale.t("Eraser"))
+                    .clicked()
+                {
+                    brush.faction = None;
+                }
+            });
+        });
+}
+
+/// Paints the brush faction (left button) or erases (right button) around the hovered hex.
+fn paint_territory(
+    mouse: Res<ButtonInput<MouseButton>>,
+    brush: Res<TerritoryBrush>,
+    hovered: Res<HoveredHex>,
+    cells: Query<(Entity, &HCell, Option<&Owner>)>,
+    mut commands: Commands,
+) {
+    let Some(center) = hovered.0.filter(|_| brush.enabled) else {
+        return;
+    };
+    let paint = if mouse.pressed(MouseButton::Right) {
+        None
+    } else if mouse.pressed(MouseButton::Left) {
+        brush.faction.map(Owner)
+    } else {
+        return;
+    };
+    for (entity, cell, owner) in &cells {
+        // Only touch cells that change, so held buttons don't re-trigger `Changed<Owner>`.
+        if center.unsigned_distance_to(cell.hex) > brush.radius || owner.copied() == paint {
+            continue;
+        }
+        match paint {
+            Some(owner) => commands.entity(entity).insert(owner),
+            None => commands.entity(entity).remove::<Owner>(),
+        };
+    }
+}
+
+/// Number of cells held by `faction`.
+pub fn claimed_count<'a>(owners: impl IntoIterator<Item = &'a Owner>, faction: u8) -> usize {
+    owners.into_iter().filter(|o| o.0 == faction).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use hexx::shapes;
+
+    use super::*;
+
+    #[test]
+    fn claims_must_border_held_hexes() {
+        let mut held = HashSet::new();
+        // First claim: anywhere.
+        assert_eq!(can_claim(Hex::new(5, 5), None, &held, true), Ok(()));
+        held.insert(Hex::new(5, 5));
+        assert_eq!(
+            can_claim(Hex::new(7, 5), None, &held, true),
+            Err(ClaimError::NotAdjacent)
+        );
+        assert_eq!(can_claim(Hex::new(7, 5), None, &held, false), Ok(()));
+        assert_eq!(can_claim(Hex::new(6, 5), None, &held, true), Ok(()));
+        assert_eq!(
+            can_claim(Hex::new(6, 5), Some(Owner(1)), &held, true),
+            Err(ClaimError::AlreadyOwned(Owner(1)))
+        );
+    }
+
+    #[test]
+    fn claiming_flips_owner_and_restyles_the_face() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_plugins(AssetPlugin::default())
+            .init_asset::<Mesh>()
+            .init_asset::<StandardMaterial>()
+            .add_message::<Toast>()
+            .init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<ClaimsConfig>()
+            .init_resource::<ActiveHex>()
+            .init_resource::<Locale>()
+            .add_systems(Startup, create_claim_materials)
+            .add_systems(Update, (claim_hex, restyle_claims).chain());
+        let mesh = app
+            .world_mut()
+            .resource_mut::<Assets<Mesh>>()
+            .add(Cuboid::default());
+        let spawn_cell = |app: &mut App, hex| {
+            let cell = app.world_mut().spawn(HCell { hex }).id();
+            app.world_mut()
+                .spawn((HexFace, Mesh3d(mesh.clone()), ChildOf(cell)));
+            cell
+        };
+        let first = spawn_cell(&mut app, Hex::ZERO);
+        let far = spawn_cell(&mut app, Hex::new(3, 0));
+        let next = spawn_cell(&mut app, Hex::new(1, 0));
+
+        let press = |app: &mut App, cell| {
+            app.world_mut().resource_mut::<ActiveHex>().cell = Some(cell);
+            let mut keys = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+            keys.release(KeyCode::KeyC);
+            keys.clear();
+            keys.press(KeyCode::KeyC);
+            app.update();
+        };
+        press(&mut app, first);
+        press(&mut app, far);
+        press(&mut app, next);
+
+        let w = app.world_mut();
+        assert_eq!(w.get::<Owner>(first), Some(&Owner(PLAYER)));
+        assert_eq!(w.get::<Owner>(far), None);
+        assert_eq!(w.get::<Owner>(next), Some(&Owner(PLAYER)));
+        assert_eq!(claimed_count(w.query::<&Owner>().iter(w), PLAYER), 2);
+        assert_eq!(w.query::<&ClaimOverlay>().iter(w).count(), 2);
+
+        // A rival takes one over: same overlay, new color.
+        w.entity_mut(next).insert(Owner(1));
+        app.update();
+        let w = app.world_mut();
+        let rival = w.resource::<ClaimMaterials>().get(Owner(1));
+        let colors: Vec<_> = w
+            .query_filtered::<&MeshMaterial3d<StandardMaterial>, With<ClaimOverlay>>()
+            .iter(w)
+            .map(|m| m.0.clone())
+            .collect();
+        assert_eq!(colors.len(), 2);
+        assert!(colors.contains(&rival));
+
+        w.entity_mut(first).remove::<Owner>();
+        app.update();
+        let w = app.world_mut();
+        assert_eq!(w.query::<&ClaimOverlay>().iter(w).count(), 1);
+    }
+
+    #[test]
+    fn the_brush_paints_and_erases_rings_around_the_hovered_hex() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<ButtonInput<MouseButton>>()
+            .init_resource::<HoveredHex>()
+            .insert_resource(TerritoryBrush {
+                enabled: true,
+                radius: 1,
+                faction: Some(2),
+            })
+            .add_systems(Update, paint_territory);
+        for hex in shapes::hexagon(Hex::ZERO, 3) {
+            app.world_mut().spawn(HCell { hex });
+        }
+        let owned = |app: &mut App| {
+            let w = app.world_mut();
+            let mut owners: Vec<(Hex, u8)> = w
+                .query::<(&HCell, &Owner)>()
+                .iter(w)
+                .map(|(c, o)| (c.hex, o.0))
+                .collect();
+            owners.sort_unstable_by_key(|(h, _)| (h.x, h.y));
+            owners
+        };
+
+        // Buttons up: nothing happens.
+        app.world_mut().resource_mut::<HoveredHex>().0 = Some(Hex::ZERO);
+        app.update();
+        assert!(owned(&mut app).is_empty());
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .press(MouseButton::Left);
+        app.update();
+        let painted = owned(&mut app);
+        assert_eq!(painted.len(), 7);
+        assert!(painted.iter().all(|&(h, f)| h.ulength() <= 1 && f == 2));
+
+        // Right button erases a single hex.
+        app.world_mut().resource_mut::<TerritoryBrush>().radius = 0;
+        let mut mouse = app.world_mut().resource_mut::<ButtonInput<MouseButton>>();
+        mouse.release(MouseButton::Left);
+        mouse.press(MouseButton::Right);
+        app.update();
+        assert_eq!(owned(&mut app).len(), 6);
+
+        // Brush off: the buttons are left to picking.
+        app.world_mut().resource_mut::<TerritoryBrush>().enabled = false;
+        app.world_mut().resource_mut::<HoveredHex>().0 = Some(Hex::new(1, 0));
+        app.update();
+        assert_eq!(owned(&mut app).len(), 6);
+    }
+}
@@ -0,0 +1,456 @@
+//! Drop-down command console (` / ~ to toggle).
+//!
+//! Lines are parsed by [`parse_command`] into [`ConsoleCommand`] messages, which
+//! [`apply_console_commands`] turns into terrain/drone resource edits and the existing
+//! [`RegenerateTerrain`] / [`TeleportToHex`] / [`StartQuake`] messages. `export` writes the
+//! current terrain with the [`headless`](crate::headless) writers (native only). While the
+//! console is open, keyboard state is cleared after input processing so typing doesn't fly
+//! the drone.
+
+use bevy::input::InputSystems;
+use bevy::prelude::*;
+use bevy_egui::egui;
+use hexx::Hex;
+
+use crate::controls::{Binding, BindingCategory, RegisterBinding, Trigger};
+use crate::drone::DroneConfig;
+use crate::h_terrain::{
+    GlowLevel, HGrid, HGridLayout, HTerrainConfig, RegenerateTerrain, TeleportToHex,
+};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::headless::{self, HeightExport};
+use crate::quakes::StartQuake;
+use crate::settings::SettingsDraft;
+
+/// Lines kept in the console scrollback.
+const MAX_LOG_LINES: usize = 200;
+
+/// One-line usage shown by `help` and on parse errors.
+const USAGE: &[&str] = &[
+    "tp <q> <r>                 teleport to hex",
+    "seed <height> [<radius>]   set noise seeds and regenerate",
+    "set <name> <value>         reveal_radius | move_speed | glow",
+    "quake [<q> <r>]            start a quake (random region without a hex)",
+    "export <format> <path>     gltf (face mesh) | ron (heights) | heightmap (16-bit PGM)",
+    "clear                      clear the log",
+];
+
+/// Runtime value editable with `set`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Setting {
+    /// FoV reach in hex rings
+    /// ([`HGridSettings::fov_reach`](crate::h_terrain::HGridSettings)).
+    RevealRadius,
+    /// Drone [`DroneConfig::move_speed`].
+    MoveSpeed,
+    /// Global [`GlowLevel`].
+    Glow,
+}
+
+impl Setting {
+    fn parse(name: &str) -> Result<Self, String> {
+        match name {
+            "reveal_radius" | "fov_reach" => Ok(Self::RevealRadius),
+            "move_speed" | "speed" => Ok(Self::MoveSpeed),
+            "glow" => Ok(Self::Glow),
+            _ => Err(format!(
+                "unknown setting `{name}` (reveal_radius, move_speed, glow)"
+            )),
+        }
+    }
+}
+
+/// File format of the `export` command.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ExportFormat {
+    /// Hex faces as a binary glTF mesh ([`terrain_glb`](crate::headless::terrain_glb)).
+    Gltf,
+    /// Hex heights as RON ([`HeightExport`](crate::headless::HeightExport)).
+    Ron,
+    /// 16-bit PGM heightmap ([`heightmap_pgm`](crate::headless::heightmap_pgm)).
+    Heightmap,
+}
+
+impl ExportFormat {
+    fn parse(name: &str) -> Result<Self, String> {
+        match name {
+            "gltf" | "glb" => Ok(Self::Gltf),
+            "ron" => Ok(Self::Ron),
+            "heightmap" | "pgm" => Ok(Self::Heightmap),
+            _ => Err(format!("unknown format `{name}` (gltf, ron, heightmap)")),
+        }
+    }
+}
+
+/// A parsed console line.
+#[derive(Message, Clone, Debug, PartialEq)]
+pub enum ConsoleCommand {
+    /// List commands.
+    Help,
+    /// Empty the scrollback.
+    Clear,
+    /// Move the player to a hex.
+    Teleport(Hex),
+    /// New noise seeds; `radius` keeps the current radius seed when `None`.
+    Seed {
+        /// Height noise seed.
+        height: u32,
+        /// Radius noise seed.
+        radius: Option<u32>,
+    },
+    /// Set a runtime value.
+    Set(Setting, f32),
+    /// Start a quake at a hex, or at a seeded pick.
+    Quake(Option<Hex>),
+    /// Export the terrain to a file.
+    Export {
+        /// Export format.
+        format: ExportFormat,
+        /// Output path.
+        path: String,
+    },
+}
+
+/// Parses one console line, e.g. `tp 3 -2`, `seed 99`, `set reveal_radius 4`.
+pub fn parse_command(line: &str) -> Result<ConsoleCommand, String> {
+    fn arg<T: std::str::FromStr>(word: Option<&str>, what: &str) -> Result<T, String> {
+        let word = word.ok_or_else(|| format!("missing {what}"))?;
+        word.parse().map_err(|_| format!("invalid {what} `{word}`"))
+    }
+
+    let mut words = line.split_whitespace();
+    let Some(name) = words.next() else {
+        return Err("empty command".into());
+    };
+    let command = match name {
+        "help" | "?" => ConsoleCommand::Help,
+        "clear" => ConsoleCommand::Clear,
+        "tp" => {
+            ConsoleCommand::Teleport(Hex::new(arg(words.next(), "q")?, arg(words.next(), "r")?))
+        }
+        "seed" => ConsoleCommand::Seed {
+            height: arg(words.next(), "height seed")?,
+            radius: words
+                .next()
+                .map(|w| arg(Some(w), "radius seed"))
+                .transpose()?,
+        },
+        "set" => {
+            let setting = Setting::parse(words.next().ok_or("missing setting name")?)?;
+            let word = words.next();
+            // `f32::from_str` accepts `nan` and `inf`, which clamping would let through.
+            match arg::<f32>(word, "value")? {
+                value if value.is_finite() => ConsoleCommand::Set(setting, value),
+                _ => return Err(format!("invalid value `{}`", word.unwrap_or_default())),
+            }
+        }
+        "quake" => ConsoleCommand::Quake(match words.next() {
+            Some(q) => Some(Hex::new(arg(Some(q), "q")?, arg(words.next(), "r")?)),
+            None => None,
+        }),
+        "export" => ConsoleCommand::Export {
+            format: ExportFormat::parse(words.next().ok_or("missing format")?)?,
+            path: arg(words.next(), "path")?,
+        },
+        _ => return Err(format!("unknown command `{name}` (try `help`)")),
+    };
+    match words.next() {
+        Some(extra) => Err(format!("unexpected argument `{extra}`")),
+        None => Ok(command),
+    }
+}
+
+/// Console visibility, input line, and scrollback.
+#[derive(Resource, Default)]
+pub struct ConsoleState {
+    /// Whether the console is shown (and keyboard flight suppressed).
+    pub open: bool,
+    /// Line being typed.
+    pub input: String,
+    /// Echoed commands and their results, oldest first.
+    pub log: Vec<String>,
+}
+
+impl ConsoleState {
+    /// Appends a line, dropping the oldest past [`MAX_LOG_LINES`].
+    pub fn print(&mut self, line: impl Into<String>) {
+        self.log.push(line.into());
+        let excess = self.log.len().saturating_sub(MAX_LOG_LINES);
+        self.log.drain(..excess);
+    }
+}
+
+/// Console plugin.
+pub struct ConsolePlugin;
+
+impl Plugin for ConsolePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ConsoleState>()
+            .add_message::<ConsoleCommand>()
+            .register_binding(Binding::new(
+                BindingCategory::App,
+                "Command console",
+                [Trigger::Key(KeyCode::Backquote)],
+            ))
+            .add_systems(PreUpdate, toggle_console.after(InputSystems))
+            .add_systems(
+                Update,
+                (
+                    draw_console.run_if(|c: Res<ConsoleState>| c.open),
+                    apply_console_commands.run_if(on_message::<ConsoleCommand>),
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// Toggles the console on ` and, while open, clears keyboard state so gameplay ignores typing.
+fn toggle_console(mut keys: ResMut<ButtonInput<KeyCode>>, mut console: ResMut<ConsoleState>) {
+    if keys.just_pressed(KeyCode::Backquote) {
+        console.open = !console.open;
+    }
+    if console.open {
+        keys.reset_all();
+    }
+}
+
+/// Draws the console along the top edge and submits the input line on Enter.
+fn draw_console(
+    mut egui_ctx: Single<&mut bevy_egui::EguiContext>,
+    mut console: ResMut<ConsoleState>,
+    mut commands: MessageWriter<ConsoleCommand>,
+) {
+    let mut submitted = None;
+    egui::TopBottomPanel::top("console")
+        .frame(
+            egui::Frame::new()
+                .fill(egui::Color32::from_black_alpha(210))
+                .inner_margin(egui::Margin::same(6)),
+        )
+        .show(egui_ctx.get_mut(), |ui| {
+            egui::ScrollArea::vertical()
+                .max_height(180.0)
+                .stick_to_bottom(true)
+                .show(ui, |ui| {
+                    for line in &console.log {
+                        ui.label(
+                            egui::RichText::new(line)
+                                .color(egui::Color32::from_rgb(0, 255, 128))
+                                .monospace(),
+                        );
+                    }
+                });
+            // The toggle key also arrives as text; keep it out of the line.
+            console.input.retain(|c| c != '`' && c != '~');
+            let response = ui.add(
+                egui::TextEdit::singleline(&mut console.input)
+                    .desired_width(f32::INFINITY)
+                    .font(egui::TextStyle::Monospace)
+                    .hint_text("help"),
+            );
+            if response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)) {
+                submitted = Some(std::mem::take(&mut console.input));
+            }
+            response.request_focus();
+        });
+
+    let Some(line) = submitted.filter(|l| !l.trim().is_empty()) else {
+        return;
+    };
+    console.print(format!("> {line}"));
+    match parse_command(&line) {
+        Ok(command) => {
+            commands.write(command);
+        }
+        Err(err) => console.print(format!("error: {err}")),
+    }
+}
+
+/// Applies parsed commands and reports the result in the console log.
+#[allow(clippy::too_many_arguments)]
+pub fn apply_console_commands(
+    mut commands: MessageReader<ConsoleCommand>,
+    mut console: ResMut<ConsoleState>,
+    mut terrain: ResMut<HTerrainConfig>,
+    mut drone: ResMut<DroneConfig>,
+    mut glow: ResMut<GlowLevel>,
+    mut draft: ResMut<SettingsDraft>,
+    mut regenerate: MessageWriter<RegenerateTerrain>,
+    mut teleport: MessageWriter<TeleportToHex>,
+    mut quake: MessageWriter<StartQuake>,
+    grid: Option<Single<&HGrid>>,
+) {
+    for command in commands.read() {
+        let reply = match command {
+            ConsoleCommand::Help => USAGE.join("\n"),
+            ConsoleCommand::Clear => {
+                console.log.clear();
+                continue;
+            }
+            &ConsoleCommand::Teleport(hex) => {
+                teleport.write(TeleportToHex(hex));
+                format!("teleporting to ({}, {})", hex.x, hex.y)
+            }
+            &ConsoleCommand::Seed { height, radius } => {
+                let radius = radius.unwrap_or(terrain.grid.radius_noise_seed);
+                terrain.grid.height_noise_seed = height;
+                terrain.grid.radius_noise_seed = radius;
+                if let Some(grid) = draft.grid.as_mut() {
+                    grid.height_noise_seed = height;
+                    grid.radius_noise_seed = radius;
+                }
+                regenerate.write(RegenerateTerrain);
+                format!("seeds: height {height}, radius {radius}")
+            }
+            &ConsoleCommand::Set(Setting::RevealRadius, value) => {
+                let reach = value.round().clamp(0.0, 6.0) as u32;
+                terrain.grid.fov_reach = reach;
+                if let Some(grid) = draft.grid.as_mut() {
+                    grid.fov_reach = reach;
+                }
+                format!("reveal_radius = {reach}")
+            }
+            &ConsoleCommand::Set(Setting::MoveSpeed, value) => {
+                drone.move_speed = value.max(0.0);
+                format!("move_speed = {}", drone.move_speed)
+            }
+            &ConsoleCommand::Set(Setting::Glow, value) => {
+                glow.0 = value.clamp(0.0, GlowLevel::MAX);
+                format!("glow = {:.2}", glow.0)
+            }
+            &ConsoleCommand::Quake(hex) => {
+                quake.write(StartQuake(hex));
+                match hex {
+                    Some(hex) => format!("quake at ({}, {})", hex.x, hex.y),
+                    None => "quake".into(),
+                }
+            }
+            ConsoleCommand::Export { format, path } => match &grid {
+                Some(grid) => match write_export(*format, path, &terrain, &grid.terrain) {
+                    Ok(()) => format!("exported {format:?} to {path}"),
+                    Err(e) => format!("error: {e}"),
+                },
+                None => "error: no terrain to export".into(),
+            },
+        };
+        for line in reply.lines() {
+            console.print(line);
+        }
+    }
+}
+
+/// Writes the current terrain to `path` in `format`.
+#[cfg(not(target_arch = "wasm32"))]
+fn write_export(
+    format: ExportFormat,
+    path: &str,
+    terrain: &HTerrainConfig,
+    layout: &HGridLayout,
+) -> Result<(), String> {
+    let grid = &terrain.grid;
+    let bytes = match format {
+        ExportFormat::Gltf => headless::terrain_glb(layout),
+        ExportFormat::Ron => HeightExport::new(grid, layout)
+            .to_ron()
+            .map_err(|e| e.to_string())?
+            .into_bytes(),
+        ExportFormat::Heightmap => {
+            let heights = layout
+                .hexes()
+                .filter_map(|hex| Some((hex, layout.height(&hex)?)))
+                .collect();
+            headless::heightmap_pgm(&heights, grid.radius, grid.max_height)
+        }
+    };
+    std::fs::write(path, bytes).map_err(|e| format!("{path}: {e}"))
+}
+
+/// File export is unavailable in the browser.
+#[cfg(target_arch = "wasm32")]
+fn write_export(
+    _format: ExportFormat,
+    _path: &str,
+    _terrain: &HTerrainConfig,
+    _layout: &HGridLayout,
+) -> Result<(), String> {
+    Err("export is not available on the web".into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_commands() {
+        assert_eq!(
+            parse_command("tp 3 -2"),
+            Ok(ConsoleCommand::Teleport(Hex::new(3, -2)))
+        );
+        assert_eq!(
+            parse_command("  seed 99 "),
+            Ok(ConsoleCommand::Seed {
+                height: 99,
+                radius: None
+            })
+        );
+        assert_eq!(
+            parse_command("seed 1 2"),
+            Ok(ConsoleCommand::Seed {
+                height: 1,
+                radius: Some(2)
+            })
+        );
+        assert_eq!(
+            parse_command("set reveal_radius 4"),
+            Ok(ConsoleCommand::Set(Setting::RevealRadius, 4.0))
+        );
+        assert_eq!(parse_command("quake"), Ok(ConsoleCommand::Quake(None)));
+        assert_eq!(
+            parse_command("quake -1 2"),
+            Ok(ConsoleCommand::Quake(Some(Hex::new(-1, 2))))
+        );
+        assert_eq!(
+            parse_command("export gltf out.glb"),
+            Ok(ConsoleCommand::Export {
+                format: ExportFormat::Gltf,
+                path: "out.glb".into()
+            })
+        );
+        assert_eq!(
+            parse_command("export heightmap h.pgm"),
+            Ok(ConsoleCommand::Export {
+                format: ExportFormat::Heightmap,
+                path: "h.pgm".into()
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_commands() {
+        assert!(parse_command("").is_err());
+        assert!(parse_command("fly").is_err());
+        assert!(parse_command("tp 3").is_err());
+        assert!(parse_command("tp 3 x").is_err());
+        assert!(parse_command("seed -1").is_err());
+        assert!(parse_command("set gravity 2").is_err());
+        assert_eq!(
+            parse_command("set glow nan"),
+            Err("invalid value `nan`".into())
+        );
+        assert!(parse_command("set move_speed inf").is_err());
+        assert!(parse_command("tp 1 2 3").is_err());
+        assert!(parse_command("quake 1").is_err());
+        assert!(parse_command("export obj out.obj").is_err());
+        assert!(parse_command("export gltf").is_err());
+    }
+
+    #[test]
+    fn log_keeps_most_recent_lines() {
+        let mut console = ConsoleState::default();
+        for i in 0..MAX_LOG_LINES + 5 {
+            console.print(i.to_string());
+        }
+        assert_eq!(console.log.len(), MAX_LOG_LINES);
+        assert_eq!(console.log[0], "5");
+    }
+}
@@ -0,0 +1,317 @@
+//! Keybinding registry and F1 cheat-sheet overlay.
+//!
+//! Plugins describe the inputs they read with [`RegisterBinding::register_binding`] next to
+//! the systems that read them; the overlay lists the resulting [`InputMap`] grouped by
+//! [`BindingCategory`], so only bindings of plugins actually added show up.
+
+use bevy::prelude::*;
+use bevy_egui::egui;
+
+use crate::GameState;
+use crate::locale::Locale;
+
+/// Grouping for the cheat sheet, listed in declaration order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Reflect)]
+pub enum BindingCategory {
+    /// Horizontal movement and altitude.
+    Flight,
+    /// Camera orientation.
+    Look,
+    /// Laser and view modes.
+    Drone,
+    /// Terrain look: theme, hologram, glow, heatmap.
+    Terrain,
+    /// Stored viewpoints.
+    Bookmarks,
+    /// Inspector, help, quit.
+    App,
+}
+
+impl BindingCategory {
+    /// Heading shown above the category's bindings.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Flight => "Flight",
+            Self::Look => "Look",
+            Self::Drone => "Drone",
+            Self::Terrain => "Terrain",
+            Self::Bookmarks => "Bookmarks",
+            Self::App => "App",
+        }
+    }
+}
+
+/// One physical input that triggers a binding.
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+pub enum Trigger {
+    /// A single key.
+    Key(KeyCode),
+    /// A contiguous run of keys, e.g. `Digit1..=Digit9`.
+    Keys(KeyCode, KeyCode),
+    /// A mouse button.
+    Mouse(MouseButton),
+    /// The mouse wheel.
+    Wheel,
+    /// Mouse movement.
+    Motion,
+}
+
+impl Trigger {
+    /// Short human-readable name, e.g. `W`, `1–9`, `LMB`.
+    pub fn label(self) -> String {
+        match self {
+            Self::Key(key) => key_label(key),
+            Self::Keys(first, last) => format!("{}–{}", key_label(first), key_label(last)),
+            Self::Mouse(MouseButton::Left) => "LMB".into(),
+            Self::Mouse(MouseButton::Right) => "RMB".into(),
+            Self::Mouse(MouseButton::Middle) => "MMB".into(),
+            Self::Mouse(button) => format!("{button:?}"),
+            Self::Wheel => "Wheel".into(),
+            Self::Motion => "Mouse".into(),
+        }
+    }
+}
+
+/// Short name for a key: `KeyW` → `W`, `Digit1` → `1`, `ArrowUp` → `↑`.
+pub fn key_label(key: KeyCode) -> String {
+    let label = match key {
+        KeyCode::ArrowUp => "↑",
+        KeyCode::ArrowDown => "↓",
+        KeyCode::ArrowLeft => "←",
+        KeyCode::ArrowRight => "→",
+        KeyCode::Equal => "=",
+        KeyCode::Minus => "-",
+        KeyCode::NumpadAdd => "Num+",
+        KeyCode::NumpadSubtract => "Num-",
+        KeyCode::Escape => "Esc",
+        KeyCode::ControlLeft | KeyCode::ControlRight => "Ctrl",
+        _ => {
+            let name = format!("{key:?}");
+            return name
+                .strip_prefix("Key")
+                .or_else(|| name.strip_prefix("Digit"))
+                .unwrap_or(&name)
+                .to_string();
+        }
+    };
+    label.into()
+}
+
+/// A described action and the inputs that trigger it.
+#[derive(Clone, Debug, Reflect)]
+pub struct Binding {
+    /// Cheat-sheet group.
+    pub category: BindingCategory,
+    /// What the inputs do, e.g. "Fire laser".
+    pub action: &'static str,
+    /// Alternative inputs; any one triggers the action.
+    pub triggers: Vec<Trigger>,
+    /// Key held together with the triggers (e.g. Ctrl).
+    pub modifier: Option<KeyCode>,
+}
+
+impl Binding {
+    /// Binding without a modifier.
+    pub fn new(
+        category: BindingCategory,
+        action: &'static str,
+        triggers: impl IntoIterator<Item = Trigger>,
+    ) -> Self {
+        Self {
+            category,
+            action,
+            triggers: triggers.into_iter().collect(),
+            modifier: None,
+        }
+    }
+
+    /// Requires `modifier` to be held with the triggers.
+    pub fn with_modifier(mut self, modifier: KeyCode) -> Self {
+        self.modifier = Some(modifier);
+        self
+    }
+
+    /// Triggers joined with ` / `, each prefixed by the modifier.
+    pub fn keys_label(&self) -> String {
+        let prefix = self
+            .modifier
+            .map(|m| format!("{}+", key_label(m)))
+            .unwrap_or_default();
+        self.triggers
+            .iter()
+            .map(|t| format!("{prefix}{}", t.label()))
+            .collect::<Vec<_>>()
+            .join(" / ")
+    }
+}
+
+/// All registered bindings, in registration order.
+#[derive(Resource, Default, Reflect)]
+pub struct InputMap {
+    /// Registered bindings.
+    pub bindings: Vec<Binding>,
+}
+
+impl InputMap {
+    /// Bindings grouped by category (in [`BindingCategory`] order), registration order within.
+    pub fn grouped(&self) -> Vec<(BindingCategory, Vec<&Binding>)> {
+        let mut groups: Vec<(BindingCategory, Vec<&Binding>)> = Vec::new();
+        let mut sorted: Vec<&Binding> = self.bindings.iter().collect();
+        sorted.sort_by_key(|b| b.category);
+        for binding in sorted {
+            match groups.last_mut() {
+                Some((category, list)) if *category == binding.category => list.push(binding),
+                _ => groups.push((binding.category, vec![binding])),
+            }
+        }
+        groups
+    }
+}
+
+/// Adds [`App::register_binding`](RegisterBinding::register_binding).
+pub trait RegisterBinding {
+    /// Appends `binding` to the [`InputMap`], creating the resource if needed.
+    fn register_binding(&mut self, binding: Binding) -> &mut Self;
+}
+
+impl RegisterBinding for App {
+    fn register_binding(&mut self, binding: Binding) -> &mut Self {
+        self.init_resource::<InputMap>()
+            .world_mut()
+            .resource_mut::<InputMap>()
+            .bindings
+            .push(binding);
+        self
+    }
+}
+
+/// Whether the cheat sheet is shown.
+#[derive(Resource, Default, Reflect)]
+pub struct CheatSheetVisible(pub bool);
+
+/// Cheat-sheet plugin.
+pub struct ControlsPlugin;
+
+impl Plugin for ControlsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<InputMap>()
+            .register_type::<CheatSheetVisible>()
+            .init_resource::<InputMap>()
+            .init_resource::<CheatSheetVisible>()
+            .register_binding(Binding::new(
+                BindingCategory::App,
+                "Show/hide this help",
+                [Trigger::Key(KeyCode::F1)],
+            ))
+            .add_systems(
+                Update,
+                (
+                    toggle_cheat_sheet,
+                    draw_cheat_sheet.run_if(|v: Res<CheatSheetVisible>| v.0),
+                )
+                    .chain()
+                    .run_if(in_state(GameState::Running).or(in_state(GameState::Inspecting))),
+            );
+    }
+}
+
+/// Toggles [`CheatSheetVisible`] on F1.
+fn toggle_cheat_sheet(keys: Res<ButtonInput<KeyCode>>, mut visible: ResMut<CheatSheetVisible>) {
+    if keys.just_pressed(KeyCode::F1) {
+        visible.0 = !visible.0;
+    }
+}
+
+/// Draws the [`InputMap`] as a centered two-column table per category.
+fn draw_cheat_sheet(
+    mut egui_ctx: Single<&mut bevy_egui::EguiContext>,
+    map: Res<InputMap>,
+    locale: Res<Locale>,
+) {
+    egui::Area::new(egui::Id::new("cheat_sheet"))
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .interactable(false)
+        .show(egui_ctx.get_mut(), |ui| {
+            egui::Frame::new()
+                .fill(egui::Color32::from_black_alpha(200))
+                .inner_margin(egui::Margin::same(12))
+                .corner_radius(6.0)
+                .show(ui, |ui| {
+                    for (category, bindings) in map.grouped() {
+                        ui.label(
+                            egui::RichText::new(locale.t(category.label()))
+                                .color(egui::Color32::from_rgb(0, 255, 128))
+                                .strong(),
+                        );
+                        egui::Grid::new(("cheat_sheet", category.label()))
+                            .num_columns(2)
+                            .spacing([16.0, 2.0])
+                            .show(ui, |ui| {
+                                for binding in bindings {
+                                    ui.monospace(binding.keys_label());
+                                    ui.label(locale.t(binding.action));
+                                    ui.end_row();
+                                }
+                            });
+                        ui.add_space(6.0);
+                    }
+                });
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn key_labels_strip_prefixes() {
+        assert_eq!(key_label(KeyCode::KeyW), "W");
+        assert_eq!(key_label(KeyCode::Digit3), "3");
+        assert_eq!(key_label(KeyCode::Space), "Space");
+        assert_eq!(key_label(KeyCode::ArrowLeft), "←");
+        assert_eq!(
+            Binding::new(
+                BindingCategory::Bookmarks,
+                "Store",
+                [Trigger::Keys(KeyCode::Digit1, KeyCode::Digit9)],
+            )
+            .with_modifier(KeyCode::ControlLeft)
+            .keys_label(),
+            "Ctrl+1–9"
+        );
+    }
+
+    #[test]
+    fn registered_bindings_group_by_category() {
+        let mut app = App::new();
+        app.register_binding(Binding::new(
+            BindingCategory::App,
+            "Quit",
+            [Trigger::Key(KeyCode::Escape)],
+        ))
+        .register_binding(Binding::new(
+            BindingCategory::Flight,
+            "Forward",
+            [Trigger::Key(KeyCode::KeyW)],
+        ))
+        .register_binding(Binding::new(
+            BindingCategory::App,
+            "Inspector",
+            [Trigger::Key(KeyCode::Tab)],
+        ));
+
+        let map = app.world().resource::<InputMap>();
+        let groups: Vec<(BindingCategory, Vec<&str>)> = map
+            .grouped()
+            .into_iter()
+            .map(|(c, b)| (c, b.iter().map(|b| b.action).collect()))
+            .collect();
+        assert_eq!(
+            groups,
+            vec![
+                (BindingCategory::Flight, vec!["Forward"]),
+                (BindingCategory::App, vec!["Quit", "Inspector"]),
+            ]
+        );
+    }
+}
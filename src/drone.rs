@@ -1,7 +1,7 @@
 //! First-person drone controller.
 //!
-//! WASD + mouse look + Q/E/scroll altitude. Writes to [`PlayerPos`](crate::PlayerPos)
-//! for terrain to consume. Spawns the Camera3d entity with bloom.
+//! WASD + mouse (or arrow/IJKL) look + Q/E/scroll altitude. Writes to
+//! [`PlayerPos`](crate::PlayerPos) for terrain to consume. Spawns the Camera3d entity with bloom.
 
 mod entities;
 pub(crate) mod materials;
@@ -10,12 +10,26 @@ pub(crate) mod systems;
 mod tests;
 
 pub use entities::Player;
+pub use systems::fly;
 
 use bevy::ecs::schedule::InternedSystemSet;
+use bevy::post_process::bloom::BloomCompositeMode;
 use bevy::prelude::*;
 
 use crate::GameState;
-use crate::h_terrain::HTerrainPhase;
+use crate::controls::{Binding, BindingCategory, RegisterBinding, Trigger};
+use crate::h_terrain::{GlowLevel, HTerrainPhase, look_changed};
+
+/// How the drone's height follows the terrain beneath it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+pub enum AltitudeMode {
+    /// Snap up on rising ground, lerp down on descent (reacts to the current ground only).
+    #[default]
+    Reactive,
+    /// Hold a constant altitude above ground, pre-climbing using
+    /// [`GroundAhead`](crate::GroundAhead) look-ahead samples.
+    Contour,
+}
 
 /// Per-plugin configuration for the drone controller.
 #[derive(Resource, Clone, Debug, Reflect)]
@@ -28,12 +42,33 @@ pub struct DroneConfig {
     pub mouse_sensitivity_y: f32,
     /// Pixel margin from window edge that triggers cursor recentering.
     pub edge_margin: f32,
+    /// Arrow-key / IJKL look speed (radians per second).
+    pub key_look_speed: f32,
     /// Margin from vertical to prevent camera flip (radians).
     pub pitch_margin: f32,
     /// Altitude change per scroll line.
     pub scroll_sensitivity: f32,
     /// Bloom post-processing intensity.
     pub bloom_intensity: f32,
+    /// How bloom mips are combined: `Additive` brightens (neon look), `EnergyConserving` blends.
+    pub bloom_composite_mode: BloomCompositeMode,
+    /// Extra strength of the wide, low-frequency bloom halo.
+    pub bloom_low_frequency_boost: f32,
+    /// Prefilter threshold: HDR values below it do not bloom (0 blooms everything).
+    pub bloom_threshold: f32,
+    /// Prefilter threshold softness (0 = hard cut, 1 = fully soft).
+    pub bloom_threshold_softness: f32,
+    /// Spawn the camera without `Hdr`, bloom, or tonemapping (and skip auto-exposure), for
+    /// GPUs/drivers where the HDR pipeline is broken or slow.
+    pub low_gfx: bool,
+    /// Ease bloom and exposure down when lit edges fill the view (low-altitude blowouts).
+    pub auto_exposure: bool,
+    /// Edge glare (near-edge equivalents) the view tolerates before compensating.
+    pub auto_exposure_glare_limit: f32,
+    /// Lowest bloom/exposure scale auto-exposure may reach.
+    pub auto_exposure_min: f32,
+    /// Auto-exposure easing rate (per second).
+    pub auto_exposure_speed: f32,
     /// Height lerp factor for smooth camera Y transitions.
     pub height_lerp: f32,
     /// Minimum offset above terrain (spawn height + floor for Q/scroll).
@@ -50,6 +85,22 @@ pub struct DroneConfig {
     pub aim_speed: f32,
     /// Duration of the pipe swing-in animation (seconds).
     pub arm_duration: f32,
+    /// Terrain-following strategy for the camera height.
+    pub altitude_mode: AltitudeMode,
+    /// Visible world-space height of the orthographic survey view (V to toggle).
+    pub survey_span: f32,
+    /// Distance from the camera where fog begins.
+    pub fog_start: f32,
+    /// Distance from the camera where fog is fully opaque.
+    pub fog_end: f32,
+    /// Fog color; `None` follows the current [`ClearColor`] so far hexes fade into the background.
+    pub fog_color: Option<Color>,
+    /// Number of flight-path samples kept in the light trail (0 disables the trail).
+    pub trail_length: usize,
+    /// Light trail ribbon width in world units.
+    pub trail_width: f32,
+    /// Minimum distance flown between trail samples.
+    pub trail_spacing: f32,
 }
 
 impl Default for DroneConfig {
@@ -59,9 +110,19 @@ impl Default for DroneConfig {
             mouse_sensitivity_x: 0.003,
             mouse_sensitivity_y: 0.002,
             edge_margin: 100.0,
+            key_look_speed: 1.5,
             pitch_margin: 0.05,
             scroll_sensitivity: 3.0,
             bloom_intensity: 0.3,
+            bloom_composite_mode: BloomCompositeMode::Additive,
+            bloom_low_frequency_boost: 0.7,
+            bloom_threshold: 0.0,
+            bloom_threshold_softness: 0.0,
+            low_gfx: false,
+            auto_exposure: true,
+            auto_exposure_glare_limit: 60.0,
+            auto_exposure_min: 0.35,
+            auto_exposure_speed: 2.0,
             height_lerp: 0.1,
             lowest_offset: 2.0,
             pipe_offset: Vec3::new(-0.5, -0.5, -1.0),
@@ -70,6 +131,14 @@ impl Default for DroneConfig {
             laser_thickness: 0.015,
             aim_speed: 12.0,
             arm_duration: 0.6,
+            altitude_mode: AltitudeMode::Reactive,
+            survey_span: 120.0,
+            fog_start: 30.0,
+            fog_end: 90.0,
+            fog_color: None,
+            trail_length: 80,
+            trail_width: 0.12,
+            trail_spacing: 0.5,
         }
     }
 }
@@ -86,11 +155,63 @@ impl Plugin for DronePlugin {
     fn build(&self, app: &mut App) {
         app.register_type::<Player>()
             .register_type::<DroneConfig>()
+            .register_type::<AltitudeMode>()
             .register_type::<entities::Elbow>()
             .register_type::<entities::LaserPipe>()
             .register_type::<entities::LaserRay>()
+            .register_type::<entities::LightTrail>()
+            .register_type::<entities::AutoExposure>()
             .insert_resource(self.config.clone())
-            .init_resource::<entities::CursorRecentered>();
+            .init_resource::<entities::CursorRecentered>()
+            .init_resource::<entities::SurveyView>();
+
+        let key = Trigger::Key;
+        for binding in [
+            Binding::new(
+                BindingCategory::Flight,
+                "Forward / back",
+                [key(KeyCode::KeyW), key(KeyCode::KeyS)],
+            ),
+            Binding::new(
+                BindingCategory::Flight,
+                "Strafe left / right",
+                [key(KeyCode::KeyA), key(KeyCode::KeyD)],
+            ),
+            Binding::new(
+                BindingCategory::Flight,
+                "Climb / descend",
+                [key(KeyCode::KeyE), key(KeyCode::KeyQ), Trigger::Wheel],
+            ),
+            Binding::new(BindingCategory::Look, "Look around", [Trigger::Motion]),
+            Binding::new(
+                BindingCategory::Look,
+                "Turn left / right",
+                [
+                    key(KeyCode::ArrowLeft),
+                    key(KeyCode::ArrowRight),
+                    key(KeyCode::KeyJ),
+                    key(KeyCode::KeyL),
+                ],
+            ),
+            Binding::new(
+                BindingCategory::Look,
+                "Pitch up / down",
+                [
+                    key(KeyCode::ArrowUp),
+                    key(KeyCode::ArrowDown),
+                    key(KeyCode::KeyI),
+                    key(KeyCode::KeyK),
+                ],
+            ),
+            Binding::new(
+                BindingCategory::Drone,
+                "Fire laser",
+                [key(KeyCode::Space), Trigger::Mouse(MouseButton::Left)],
+            ),
+            Binding::new(BindingCategory::Drone, "Survey view", [key(KeyCode::KeyV)]),
+        ] {
+            app.register_binding(binding);
+        }
 
         app.add_systems(Startup, systems::create_drone_materials);
 
@@ -121,7 +242,10 @@ impl Plugin for DronePlugin {
         app.add_systems(Startup, systems::hide_cursor)
             .add_systems(
                 Update,
-                systems::recenter_cursor.run_if(not(in_state(GameState::Inspecting))),
+                systems::recenter_cursor.run_if(not(in_state(GameState::Inspecting)
+                    .or(in_state(GameState::Paused))
+                    .or(in_state(GameState::Map))
+                    .or(in_state(GameState::Summary)))),
             )
             .add_systems(
                 Update,
@@ -134,6 +258,39 @@ impl Plugin for DronePlugin {
             Update,
             systems::draw_crosshair.run_if(in_state(GameState::Running)),
         )
+        .add_systems(
+            Update,
+            systems::sync_fog_color.run_if(resource_exists_and_changed::<ClearColor>),
+        )
+        .add_systems(Update, systems::sync_trail_color.run_if(look_changed))
+        .add_systems(
+            Update,
+            (
+                systems::apply_bloom_shape.run_if(resource_changed::<DroneConfig>),
+                systems::apply_glow_level.run_if(
+                    resource_exists::<GlowLevel>
+                        .and(resource_changed::<GlowLevel>.or(resource_changed::<DroneConfig>)),
+                ),
+            ),
+        )
+        .add_systems(
+            Update,
+            systems::adapt_exposure
+                .after(HTerrainPhase::TrackFov)
+                .run_if(|cfg: Res<DroneConfig>| cfg.auto_exposure && !cfg.low_gfx),
+        )
+        .add_systems(
+            Update,
+            systems::update_trail
+                .after(systems::fly)
+                .run_if(in_state(GameState::Running)),
+        )
+        .add_systems(
+            Update,
+            systems::toggle_survey_view
+                .before(systems::fly)
+                .run_if(in_state(GameState::Running)),
+        )
         .add_systems(
             Update,
             (
@@ -149,7 +306,10 @@ impl Plugin for DronePlugin {
         app.add_systems(Update, systems::fly.run_if(in_state(GameState::Running)))
             .add_systems(
                 Update,
-                systems::lock_cursor_on_click.run_if(not(in_state(GameState::Inspecting))),
+                systems::lock_cursor_on_click.run_if(not(in_state(GameState::Inspecting)
+                    .or(in_state(GameState::Paused))
+                    .or(in_state(GameState::Map))
+                    .or(in_state(GameState::Summary)))),
             );
     }
 }
@@ -1,10 +1,13 @@
+use std::collections::VecDeque;
+
 use bevy::animation::AnimationEvent;
 use bevy::ecs::system::SystemParam;
 use bevy::input::mouse::{MouseMotion, MouseWheel};
 use bevy::prelude::*;
 
 use super::DroneConfig;
-use crate::{GroundLevel, PlayerMoved, PlayerPos};
+use crate::wind::Wind;
+use crate::{GroundAhead, GroundLevel, PlayerMoved, PlayerPos};
 
 /// Marker component for the player-controlled drone entity.
 #[derive(Component, Reflect)]
@@ -22,6 +25,20 @@ pub struct LaserPipe;
 #[derive(Component, Reflect)]
 pub struct LaserRay;
 
+/// Fading light ribbon behind the drone (root entity, world-space mesh).
+#[derive(Component, Reflect, Default)]
+pub struct LightTrail {
+    /// Recent flight-path samples, oldest first.
+    pub points: VecDeque<Vec3>,
+}
+
+/// Eased exposure compensation on the [`Player`] camera (see [`DroneConfig::auto_exposure`]).
+#[derive(Component, Reflect)]
+pub struct AutoExposure {
+    /// Current bloom/exposure scale (1 = no compensation).
+    pub factor: f32,
+}
+
 /// Animation event fired when the arming pipe swing-in animation completes.
 #[derive(Clone, AnimationEvent, Reflect)]
 pub struct ArmingComplete;
@@ -35,6 +52,12 @@ pub struct IntroComplete;
 #[derive(Resource, Default)]
 pub struct CursorRecentered(pub bool);
 
+/// Camera state stashed while the orthographic top-down survey view is active.
+///
+/// `Some((rotation, projection))` holds the free-fly view to restore on exit.
+#[derive(Resource, Default)]
+pub struct SurveyView(pub Option<(Quat, Projection)>);
+
 /// Bundled system parameters for the drone flight system.
 #[derive(SystemParam)]
 pub struct DroneInput<'w, 's> {
@@ -43,8 +66,12 @@ pub struct DroneInput<'w, 's> {
     pub mouse_motion: MessageReader<'w, 's, MouseMotion>,
     pub scroll: MessageReader<'w, 's, MouseWheel>,
     pub recentered: Res<'w, CursorRecentered>,
+    pub survey: Res<'w, SurveyView>,
     pub cfg: Res<'w, DroneConfig>,
     pub ground: Res<'w, GroundLevel>,
+    pub ahead: Res<'w, GroundAhead>,
     pub player: ResMut<'w, PlayerPos>,
     pub moved: ResMut<'w, PlayerMoved>,
+    /// Drift from the wind field (absent without the wind plugin).
+    pub wind: Option<Res<'w, Wind>>,
 }
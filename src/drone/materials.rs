@@ -1,9 +1,14 @@
 use bevy::prelude::*;
 
+/// Laser ray emissive at [`GlowLevel`](crate::h_terrain::GlowLevel) 1.0 (intense red bloom).
+pub const LASER_EMISSIVE: LinearRgba = LinearRgba::new(10.0, 0.0, 0.0, 1.0);
+
 #[derive(Resource)]
 pub struct DroneMaterials {
     pub pipe: Handle<StandardMaterial>,
     pub laser_ray: Handle<StandardMaterial>,
+    /// Additive light trail; color follows the theme (see `sync_trail_color`).
+    pub trail: Handle<StandardMaterial>,
 }
 
 impl DroneMaterials {
@@ -17,7 +22,14 @@ impl DroneMaterials {
             }),
             laser_ray: materials.add(StandardMaterial {
                 base_color: Color::BLACK,
-                emissive: LinearRgba::new(10.0, 0.0, 0.0, 1.0), // intense red bloom
+                emissive: LASER_EMISSIVE,
+                ..default()
+            }),
+            trail: materials.add(StandardMaterial {
+                base_color: Color::srgb(0.0, 0.5, 1.0),
+                unlit: true,
+                alpha_mode: AlphaMode::Add,
+                cull_mode: None,
                 ..default()
             }),
         }
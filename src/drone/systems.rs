@@ -1,8 +1,12 @@
 use bevy::animation::{AnimatedBy, AnimationTargetId, animated_field, prelude::*};
+use bevy::asset::RenderAssetUsages;
+use bevy::camera::{Exposure, ScalingMode};
 use bevy::core_pipeline::tonemapping::Tonemapping;
 use bevy::input::mouse::MouseScrollUnit;
 use bevy::math::curve::{Interval, adaptors::ConstantCurve, easing::EasingCurve};
-use bevy::post_process::bloom::{Bloom, BloomCompositeMode};
+use bevy::mesh::{Indices, PrimitiveTopology};
+use bevy::pbr::{DistanceFog, FogFalloff};
+use bevy::post_process::bloom::{Bloom, BloomPrefilter};
 use bevy::prelude::*;
 use bevy::render::view::Hdr;
 #[cfg(not(target_arch = "wasm32"))]
@@ -11,15 +15,19 @@ use bevy::window::{CursorGrabMode, CursorOptions};
 
 use bevy_egui::egui;
 
-use super::DroneConfig;
 #[cfg(not(target_arch = "wasm32"))]
 use super::entities::CursorRecentered;
 use super::entities::{
-    ArmingComplete, DroneInput, Elbow, IntroComplete, LaserPipe, LaserRay, Player,
+    ArmingComplete, AutoExposure, DroneInput, Elbow, IntroComplete, LaserPipe, LaserRay,
+    LightTrail, Player, SurveyView,
+};
+use super::materials::{DroneMaterials, LASER_EMISSIVE};
+use super::{AltitudeMode, DroneConfig};
+use crate::h_terrain::{
+    GlowLevel, InFov, InSight, QuadEdge, TargetHex, TargetKind, Theme, edge_cuboid_transform,
 };
-use super::materials::DroneMaterials;
-use crate::h_terrain::{InSight, edge_cuboid_transform};
 use crate::intro::IntroConfig;
+use crate::locale::Locale;
 use crate::math;
 
 /// Creates and inserts the [`DroneMaterials`] resource.
@@ -130,42 +138,49 @@ pub fn spawn_drone(
     let mut anim_player = AnimationPlayer::default();
     anim_player.play(intro_node);
 
-    commands
-        .spawn((
-            player_name,
-            Camera3d::default(),
-            Hdr,
-            Tonemapping::TonyMcMapface,
-            Bloom {
-                intensity: cfg.bloom_intensity,
-                composite_mode: BloomCompositeMode::Additive,
-                ..Bloom::NATURAL
+    let mut player = commands.spawn((
+        player_name,
+        Camera3d::default(),
+        Exposure::default(),
+        AutoExposure { factor: 1.0 },
+        DistanceFog {
+            color: cfg.fog_color.unwrap_or(Color::BLACK),
+            falloff: FogFalloff::Linear {
+                start: cfg.fog_start,
+                end: cfg.fog_end,
             },
-            spawn_transform,
-            Player,
-            player_target,
-            AnimationGraphHandle(graphs.add(graph)),
-            anim_player,
-        ))
-        .with_children(|parent| {
-            parent
-                .spawn((
-                    elbow_name,
-                    Elbow,
-                    Visibility::default(),
-                    Transform::from_translation(cfg.pipe_offset).with_rotation(hidden_quat()),
-                    elbow_target,
-                ))
-                .with_children(|elbow| {
-                    elbow.spawn((
-                        Name::new("LaserPipe"),
-                        LaserPipe,
-                        Mesh3d(meshes.add(Cylinder::new(cfg.pipe_radius, cfg.pipe_length / 2.0))),
-                        MeshMaterial3d(drone_mats.pipe.clone()),
-                        Transform::from_translation(Vec3::NEG_Y * (cfg.pipe_length / 4.0)),
-                    ));
-                });
-        });
+            ..default()
+        },
+        spawn_transform,
+        Player,
+        player_target,
+        AnimationGraphHandle(graphs.add(graph)),
+        anim_player,
+    ));
+    if cfg.low_gfx {
+        player.insert(Tonemapping::None);
+    } else {
+        player.insert((Hdr, Tonemapping::TonyMcMapface, bloom_from_config(&cfg)));
+    }
+    player.with_children(|parent| {
+        parent
+            .spawn((
+                elbow_name,
+                Elbow,
+                Visibility::default(),
+                Transform::from_translation(cfg.pipe_offset).with_rotation(hidden_quat()),
+                elbow_target,
+            ))
+            .with_children(|elbow| {
+                elbow.spawn((
+                    Name::new("LaserPipe"),
+                    LaserPipe,
+                    Mesh3d(meshes.add(Cylinder::new(cfg.pipe_radius, cfg.pipe_length / 2.0))),
+                    MeshMaterial3d(drone_mats.pipe.clone()),
+                    Transform::from_translation(Vec3::NEG_Y * (cfg.pipe_length / 4.0)),
+                ));
+            });
+    });
 
     // Observers for animation completion events (global, triggered on AnimationPlayer).
     // Each observer stops the finished clip so the animation system no longer overwrites
@@ -193,6 +208,20 @@ pub fn spawn_drone(
     commands.insert_resource(ArmingAnimNode(arming_node));
     commands.insert_resource(IntroAnimNode(intro_node));
 
+    // Light trail as root entity (world-space ribbon), seeded with a degenerate segment
+    if cfg.trail_length > 0 {
+        let start = spawn_transform.translation + Vec3::NEG_Y * TRAIL_DROP;
+        commands.spawn((
+            Name::new("LightTrail"),
+            LightTrail {
+                points: [start].into(),
+            },
+            Mesh3d(meshes.add(trail_mesh(&[start, start], cfg.trail_width))),
+            MeshMaterial3d(drone_mats.trail.clone()),
+            Transform::default(),
+        ));
+    }
+
     // Laser ray as root entity (world-space positioning)
     commands.spawn((
         Name::new("LaserRay"),
@@ -233,7 +262,7 @@ pub fn start_arming(
     player_q.play(arming_node.0);
 }
 
-/// WASD + mouse look + Q/E/scroll offset. Writes to [`PlayerPos`].
+/// WASD + mouse/keyboard look + Q/E/scroll offset + wind drift. Writes to [`PlayerPos`].
 pub fn fly(mut input: DroneInput, mut transform: Single<&mut Transform, With<Player>>) {
     // Mouse look: yaw (horizontal) + pitch (vertical)
     let mut yaw = 0.0;
@@ -246,10 +275,26 @@ pub fn fly(mut input: DroneInput, mut transform: Single<&mut Transform, With<Pla
             pitch -= ev.delta.y * input.cfg.mouse_sensitivity_y;
         }
     }
+
+    // Keyboard look: arrows / IJKL for users without a mouse
+    let key_step = input.cfg.key_look_speed * input.time.delta_secs();
+    if input.keys.any_pressed([KeyCode::ArrowLeft, KeyCode::KeyJ]) {
+        yaw += key_step;
+    }
+    if input.keys.any_pressed([KeyCode::ArrowRight, KeyCode::KeyL]) {
+        yaw -= key_step;
+    }
+    if input.keys.any_pressed([KeyCode::ArrowUp, KeyCode::KeyI]) {
+        pitch += key_step;
+    }
+    if input.keys.any_pressed([KeyCode::ArrowDown, KeyCode::KeyK]) {
+        pitch -= key_step;
+    }
     if yaw != 0.0 {
         transform.rotate_y(yaw);
     }
-    if pitch != 0.0 {
+    // Survey view stays locked looking down; only yaw rotates the map.
+    if pitch != 0.0 && input.survey.0.is_none() {
         let (_, current_pitch, _) = transform.rotation.to_euler(EulerRot::YXZ);
         let pitch_delta = math::clamp_pitch(current_pitch, pitch, input.cfg.pitch_margin);
         transform.rotate_local_x(pitch_delta);
@@ -280,6 +325,18 @@ pub fn fly(mut input: DroneInput, mut transform: Single<&mut Transform, With<Pla
         let delta = direction * input.cfg.move_speed * input.time.delta_secs();
         input.player.xz.x += delta.x;
         input.player.xz.y += delta.z;
+        input.player.heading = Vec2::new(direction.x, direction.z);
+        input.moved.0 = true;
+    } else {
+        input.player.heading = Vec2::ZERO;
+    }
+
+    // Wind drift: pushes the drone whether or not it is steering.
+    if let Some(wind) = &input.wind
+        && wind.velocity != Vec2::ZERO
+    {
+        let drift = wind.velocity * input.time.delta_secs();
+        input.player.xz += drift;
         input.moved.0 = true;
     }
 
@@ -308,16 +365,66 @@ pub fn fly(mut input: DroneInput, mut transform: Single<&mut Transform, With<Pla
 
     // Apply position from PlayerPos + GroundLevel
     let ground_y = input.ground.0.unwrap_or(0.0);
-    let target_y = ground_y + input.player.offset;
     transform.translation.x = input.player.xz.x;
     transform.translation.z = input.player.xz.y;
-    if target_y > transform.translation.y {
-        transform.translation.y = target_y;
-    } else {
-        transform.translation.y += (target_y - transform.translation.y) * input.cfg.height_lerp;
+    match input.cfg.altitude_mode {
+        AltitudeMode::Reactive => {
+            let target_y = ground_y + input.player.offset;
+            if target_y > transform.translation.y {
+                transform.translation.y = target_y;
+            } else {
+                transform.translation.y +=
+                    (target_y - transform.translation.y) * input.cfg.height_lerp;
+            }
+        }
+        AltitudeMode::Contour => {
+            // Climb early toward the highest ground ahead, but never dip below the floor.
+            let ahead_y = input.ahead.0.map_or(ground_y, |a| a.max(ground_y));
+            let target_y = ahead_y + input.player.offset;
+            transform.translation.y += (target_y - transform.translation.y) * input.cfg.height_lerp;
+            transform.translation.y = transform
+                .translation
+                .y
+                .max(ground_y + input.cfg.lowest_offset);
+        }
     }
 }
 
+/// Toggles the orthographic top-down survey view on V.
+///
+/// Entering stashes the free-fly rotation and projection in [`SurveyView`], then
+/// pitches the camera (almost) straight down, keeping yaw so WASD stays aligned.
+/// Leaving restores both.
+pub fn toggle_survey_view(
+    keys: Res<ButtonInput<KeyCode>>,
+    cfg: Res<DroneConfig>,
+    mut survey: ResMut<SurveyView>,
+    camera: Single<(&mut Transform, &mut Projection), With<Player>>,
+) {
+    if !keys.just_pressed(KeyCode::KeyV) {
+        return;
+    }
+    let (mut transform, mut projection) = camera.into_inner();
+    if let Some((rotation, saved)) = survey.0.take() {
+        transform.rotation = rotation;
+        *projection = saved;
+        return;
+    }
+    survey.0 = Some((transform.rotation, projection.clone()));
+    let (yaw, _, _) = transform.rotation.to_euler(EulerRot::YXZ);
+    let down = -(std::f32::consts::FRAC_PI_2 - cfg.pitch_margin);
+    transform.rotation = Quat::from_euler(EulerRot::YXZ, yaw, down, 0.0);
+    *projection = Projection::Orthographic(OrthographicProjection {
+        scaling_mode: ScalingMode::FixedVertical {
+            viewport_height: cfg.survey_span,
+        },
+        // The drone hovers just above ground; a negative near plane keeps
+        // peaks taller than the camera from being clipped.
+        near: -cfg.survey_span,
+        ..OrthographicProjection::default_3d()
+    });
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 pub fn hide_cursor(mut q: Query<(&mut CursorOptions, &mut Window)>) {
     for (mut opts, mut window) in &mut q {
@@ -381,17 +488,207 @@ pub fn lock_cursor_on_click(
     }
 }
 
-/// Draws a small white crosshair at screen center so the player can see the aim point.
-pub fn draw_crosshair(mut egui_ctx: Single<&mut bevy_egui::EguiContext>, window: Single<&Window>) {
-    let cx = window.width() / 2.0;
-    let cy = window.height() / 2.0;
+/// Keeps the camera fog matching [`ClearColor`] unless [`DroneConfig::fog_color`] pins it.
+pub fn sync_fog_color(
+    clear: Res<ClearColor>,
+    cfg: Res<DroneConfig>,
+    mut fog: Single<&mut DistanceFog, With<Player>>,
+) {
+    if cfg.fog_color.is_none() {
+        fog.color = clear.0;
+    }
+}
+
+/// Trail anchor distance below the camera, so the ribbon is visible when looking down.
+const TRAIL_DROP: f32 = 0.6;
+/// HDR multiplier on the theme edge color for the trail (feeds bloom).
+const TRAIL_GLOW: f32 = 3.0;
+
+/// Builds the trail ribbon: a flat strip through `points`, fading from transparent (oldest)
+/// to opaque (newest) via vertex-color alpha.
+pub fn trail_mesh(points: &[Vec3], width: f32) -> Mesh {
+    let n = points.len();
+    let half = width / 2.0;
+    let mut positions = Vec::with_capacity(n * 2);
+    let mut colors = Vec::with_capacity(n * 2);
+    for (i, &p) in points.iter().enumerate() {
+        let prev = points[i.saturating_sub(1)];
+        let next = points[(i + 1).min(n - 1)];
+        let side = (next - prev).cross(Vec3::Y).normalize_or(Vec3::X) * half;
+        let alpha = i as f32 / (n - 1).max(1) as f32;
+        positions.extend([p - side, p + side]);
+        colors.extend([[1.0, 1.0, 1.0, alpha]; 2]);
+    }
+    let indices = (0..n.saturating_sub(1) as u32)
+        .flat_map(|i| {
+            let a = i * 2;
+            [a, a + 1, a + 2, a + 1, a + 3, a + 2]
+        })
+        .collect();
+    Mesh::new(
+        PrimitiveTopology::TriangleList,
+        RenderAssetUsages::RENDER_WORLD | RenderAssetUsages::MAIN_WORLD,
+    )
+    .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+    .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, vec![[0.0, 1.0, 0.0]; n * 2])
+    .with_inserted_attribute(Mesh::ATTRIBUTE_COLOR, colors)
+    .with_inserted_indices(Indices::U32(indices))
+}
+
+/// Samples the drone position into the [`LightTrail`] and rebuilds its ribbon mesh.
+pub fn update_trail(
+    cfg: Res<DroneConfig>,
+    player: Single<&Transform, With<Player>>,
+    trail: Single<(&mut LightTrail, &Mesh3d)>,
+    mut meshes: ResMut<Assets<Mesh>>,
+) {
+    let (mut trail, mesh) = trail.into_inner();
+    let anchor = player.translation + Vec3::NEG_Y * TRAIL_DROP;
+    if trail
+        .points
+        .back()
+        .is_some_and(|last| last.distance(anchor) < cfg.trail_spacing)
+    {
+        return;
+    }
+    trail.points.push_back(anchor);
+    while trail.points.len() > cfg.trail_length {
+        trail.points.pop_front();
+    }
+    if let Some(m) = meshes.get_mut(&mesh.0) {
+        *m = trail_mesh(trail.points.make_contiguous(), cfg.trail_width);
+    }
+}
+
+/// Recolors the light trail from the theme edge color and [`GlowLevel`].
+pub fn sync_trail_color(
+    theme: Res<Theme>,
+    level: Res<GlowLevel>,
+    mats: Res<DroneMaterials>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    if let Some(mat) = materials.get_mut(&mats.trail) {
+        mat.base_color = Color::from(LinearRgba::from(theme.edge.base) * TRAIL_GLOW * level.0);
+    }
+}
+
+/// Camera [`Bloom`] built from the [`DroneConfig`] bloom settings.
+pub fn bloom_from_config(cfg: &DroneConfig) -> Bloom {
+    Bloom {
+        intensity: cfg.bloom_intensity,
+        low_frequency_boost: cfg.bloom_low_frequency_boost,
+        prefilter: BloomPrefilter {
+            threshold: cfg.bloom_threshold,
+            threshold_softness: cfg.bloom_threshold_softness,
+        },
+        composite_mode: cfg.bloom_composite_mode,
+        ..Bloom::NATURAL
+    }
+}
+
+/// Re-applies the composite mode, low-frequency boost, and threshold when [`DroneConfig`]
+/// changes at runtime. Intensity stays with [`apply_glow_level`] and [`adapt_exposure`].
+pub fn apply_bloom_shape(cfg: Res<DroneConfig>, bloom: Option<Single<&mut Bloom, With<Player>>>) {
+    let Some(mut bloom) = bloom else {
+        return;
+    };
+    let intensity = bloom.intensity;
+    **bloom = Bloom {
+        intensity,
+        ..bloom_from_config(&cfg)
+    };
+}
+
+/// Scales the camera bloom (absent in low-gfx mode) and laser emissive by [`GlowLevel`].
+pub fn apply_glow_level(
+    level: Res<GlowLevel>,
+    cfg: Res<DroneConfig>,
+    camera: Single<(Option<&mut Bloom>, Option<&AutoExposure>), With<Player>>,
+    mats: Res<DroneMaterials>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let (bloom, auto) = camera.into_inner();
+    if let Some(mut bloom) = bloom {
+        bloom.intensity = cfg.bloom_intensity * level.0 * auto.map_or(1.0, |a| a.factor);
+    }
+    if let Some(mat) = materials.get_mut(&mats.laser_ray) {
+        mat.emissive = LASER_EMISSIVE * level.0;
+    }
+}
+
+/// Distance within which a lit edge counts as one full unit of glare.
+const GLARE_NEAR: f32 = 4.0;
+
+/// Approximate on-screen edge glow seen from `camera`: each lit edge contributes by inverse
+/// square distance, capped at 1 within [`GLARE_NEAR`].
+pub fn edge_glare(camera: Vec3, edges: impl IntoIterator<Item = Vec3>) -> f32 {
+    edges
+        .into_iter()
+        .map(|p| (GLARE_NEAR * GLARE_NEAR / p.distance_squared(camera)).min(1.0))
+        .sum()
+}
+
+/// Bloom/exposure scale that brings `glare` back to the configured limit.
+pub fn exposure_target(glare: f32, cfg: &DroneConfig) -> f32 {
+    if glare <= cfg.auto_exposure_glare_limit {
+        1.0
+    } else {
+        (cfg.auto_exposure_glare_limit / glare)
+            .sqrt()
+            .max(cfg.auto_exposure_min)
+    }
+}
+
+/// Eases bloom intensity and tonemapping exposure toward the edge-glare target.
+///
+/// Scene luminance is approximated from the lit ([`InFov`]) edge lines around the camera,
+/// which dominate the image when flying low over a dense grid.
+pub fn adapt_exposure(
+    cfg: Res<DroneConfig>,
+    level: Option<Res<GlowLevel>>,
+    time: Res<Time>,
+    edges: Query<&GlobalTransform, (With<QuadEdge>, With<InFov>)>,
+    camera: Single<
+        (
+            &GlobalTransform,
+            &mut AutoExposure,
+            &mut Bloom,
+            &mut Exposure,
+        ),
+        With<Player>,
+    >,
+) {
+    let (tf, mut auto, mut bloom, mut exposure) = camera.into_inner();
+    let glare = edge_glare(tf.translation(), edges.iter().map(|e| e.translation()));
+    let target = exposure_target(glare, &cfg);
+    let k = 1.0 - (-cfg.auto_exposure_speed * time.delta_secs()).exp();
+    auto.factor += (target - auto.factor) * k;
+    bloom.intensity = cfg.bloom_intensity * level.map_or(1.0, |l| l.0) * auto.factor;
+    // Halving the light is one stop: raise EV100 by -log2(factor).
+    exposure.ev100 = Exposure::EV100_BLENDER - auto.factor.log2();
+}
+
+/// Draws a small crosshair at screen center with the aimed hex's coordinate and height beside
+/// it, tinted orange on a cliff and gold on a landmark peak.
+pub fn draw_crosshair(
+    mut egui_ctx: Single<&mut bevy_egui::EguiContext>,
+    target: Res<TargetHex>,
+    locale: Res<Locale>,
+) {
+    let ctx = egui_ctx.get_mut();
+    let egui::Pos2 { x: cx, y: cy } = ctx.content_rect().center();
     let half = 8.0;
-    let stroke = egui::Stroke::new(1.5, egui::Color32::WHITE);
+    let (color, tag) = match target.kind {
+        TargetKind::Plain => (egui::Color32::WHITE, ""),
+        TargetKind::Cliff => (egui::Color32::from_rgb(255, 140, 0), locale.t("cliff")),
+        TargetKind::Landmark => (egui::Color32::from_rgb(255, 215, 0), locale.t("landmark")),
+    };
+    let stroke = egui::Stroke::new(1.5, color);
 
     egui::Area::new(egui::Id::new("crosshair"))
         .fixed_pos(egui::pos2(0.0, 0.0))
         .interactable(false)
-        .show(egui_ctx.get_mut(), |ui| {
+        .show(ctx, |ui| {
             let painter = ui.painter();
             painter.line_segment(
                 [egui::pos2(cx - half, cy), egui::pos2(cx + half, cy)],
@@ -401,6 +698,15 @@ pub fn draw_crosshair(mut egui_ctx: Single<&mut bevy_egui::EguiContext>, window:
                 [egui::pos2(cx, cy - half), egui::pos2(cx, cy + half)],
                 stroke,
             );
+            if let Some(hex) = target.hex {
+                painter.text(
+                    egui::pos2(cx + half * 2.0, cy + half),
+                    egui::Align2::LEFT_TOP,
+                    format!("{},{}  h {:.1} {tag}", hex.x, hex.y, target.height),
+                    egui::FontId::monospace(12.0),
+                    color,
+                );
+            }
         });
 }
 
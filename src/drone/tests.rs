@@ -3,23 +3,63 @@
 use std::time::Duration;
 
 use bevy::animation::AnimationPlugin;
+use bevy::camera::Exposure;
+use bevy::core_pipeline::tonemapping::Tonemapping;
 use bevy::input::mouse::{MouseMotion, MouseWheel};
+use bevy::pbr::DistanceFog;
+use bevy::post_process::bloom::{Bloom, BloomCompositeMode};
 use bevy::prelude::*;
+use bevy::render::view::Hdr;
 use bevy::state::app::StatesPlugin;
 use bevy::time::TimeUpdateStrategy;
 
-use super::DroneConfig;
-use super::entities::{CursorRecentered, Elbow, LaserPipe, LaserRay, Player};
+use super::entities::{
+    AutoExposure, CursorRecentered, Elbow, LaserPipe, LaserRay, LightTrail, Player, SurveyView,
+};
+use super::materials::{DroneMaterials, LASER_EMISSIVE};
 use super::systems;
-use crate::h_terrain::InSight;
+use super::{AltitudeMode, DroneConfig};
+use crate::h_terrain::{GlowLevel, InFov, InSight, QuadEdge};
 use crate::intro::IntroConfig;
-use crate::{GameState, GroundLevel, PlayerMoved, PlayerPos};
+use crate::{GameState, GroundAhead, GroundLevel, PlayerMoved, PlayerPos};
 
 /// Builds a test app that goes through the full Intro → Arming → Running lifecycle.
 ///
 /// Returns the app in `GameState::Running` with animations completed and stopped,
 /// so `aim_pipe` and `fly` have full control of transforms.
 fn test_app() -> App {
+    test_app_with_config(DroneConfig::default())
+}
+
+/// [`test_app`] with a custom [`DroneConfig`].
+fn test_app_with_config(cfg: DroneConfig) -> App {
+    let mut app = intro_app(cfg);
+
+    // Tick through Intro (0.3s total at 100ms/tick = 3-4 ticks + margin)
+    for _ in 0..6 {
+        app.update();
+    }
+    assert_eq!(
+        *app.world().resource::<State<GameState>>().get(),
+        GameState::Arming,
+        "Should have transitioned to Arming after intro"
+    );
+
+    // Tick through Arming (0.6s default at 100ms/tick = 6-7 ticks + margin)
+    for _ in 0..10 {
+        app.update();
+    }
+    assert_eq!(
+        *app.world().resource::<State<GameState>>().get(),
+        GameState::Running,
+        "Should have transitioned to Running after arming"
+    );
+
+    app
+}
+
+/// Builds the drone test app and runs Startup, leaving it at the start of the intro.
+fn intro_app(cfg: DroneConfig) -> App {
     let mut app = App::new();
     app.add_plugins(MinimalPlugins)
         .add_plugins(AssetPlugin::default())
@@ -28,7 +68,7 @@ fn test_app() -> App {
         .add_plugins(AnimationPlugin)
         .init_asset::<Mesh>()
         .init_asset::<StandardMaterial>()
-        .insert_resource(DroneConfig::default())
+        .insert_resource(cfg)
         .insert_resource(IntroConfig {
             // Short durations so we can tick through quickly
             tilt_up_duration: 0.1,
@@ -39,7 +79,10 @@ fn test_app() -> App {
         .init_resource::<PlayerPos>()
         .init_resource::<PlayerMoved>()
         .insert_resource(GroundLevel(Some(0.0)))
+        .init_resource::<GroundAhead>()
         .init_resource::<CursorRecentered>()
+        .init_resource::<SurveyView>()
+        .insert_resource(ClearColor(Color::srgb(0.1, 0.2, 0.3)))
         .init_resource::<ButtonInput<KeyCode>>()
         .init_resource::<ButtonInput<MouseButton>>()
         .add_message::<MouseMotion>()
@@ -59,39 +102,35 @@ fn test_app() -> App {
         systems::link_elbow_animation.after(systems::spawn_drone),
     );
     app.add_systems(OnEnter(GameState::Arming), systems::start_arming);
+    app.add_systems(
+        Update,
+        systems::sync_fog_color.run_if(resource_exists_and_changed::<ClearColor>),
+    );
+    app.add_systems(
+        Update,
+        (
+            systems::apply_bloom_shape.run_if(resource_changed::<DroneConfig>),
+            systems::apply_glow_level.run_if(
+                resource_exists::<GlowLevel>
+                    .and(resource_changed::<GlowLevel>.or(resource_changed::<DroneConfig>)),
+            ),
+        ),
+    );
+    app.add_systems(Update, systems::adapt_exposure);
     app.add_systems(
         Update,
         (
+            systems::toggle_survey_view.before(systems::fly),
             systems::fly,
             systems::aim_pipe,
             systems::fire_laser.after(systems::aim_pipe),
+            systems::update_trail.after(systems::fly),
         )
             .run_if(in_state(GameState::Running)),
     );
 
     // Startup (spawns entities, starts intro animation)
     app.update();
-
-    // Tick through Intro (0.3s total at 100ms/tick = 3-4 ticks + margin)
-    for _ in 0..6 {
-        app.update();
-    }
-    assert_eq!(
-        *app.world().resource::<State<GameState>>().get(),
-        GameState::Arming,
-        "Should have transitioned to Arming after intro"
-    );
-
-    // Tick through Arming (0.6s default at 100ms/tick = 6-7 ticks + margin)
-    for _ in 0..10 {
-        app.update();
-    }
-    assert_eq!(
-        *app.world().resource::<State<GameState>>().get(),
-        GameState::Running,
-        "Should have transitioned to Running after arming"
-    );
-
     app
 }
 
@@ -280,7 +319,9 @@ fn intro_animation_tilts_camera() {
         .init_resource::<PlayerPos>()
         .init_resource::<PlayerMoved>()
         .insert_resource(GroundLevel(Some(0.0)))
+        .init_resource::<GroundAhead>()
         .init_resource::<CursorRecentered>()
+        .init_resource::<SurveyView>()
         .init_resource::<ButtonInput<KeyCode>>()
         .init_resource::<ButtonInput<MouseButton>>()
         .add_message::<MouseMotion>()
@@ -340,6 +381,38 @@ fn intro_animation_tilts_camera() {
     );
 }
 
+#[test]
+fn space_skips_the_intro_to_its_final_pose() {
+    let mut app = intro_app(DroneConfig::default());
+    app.add_systems(
+        Update,
+        crate::intro::skip_intro.run_if(in_state(GameState::Intro)),
+    );
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(KeyCode::Space);
+    app.update();
+    app.update();
+    assert_eq!(
+        *app.world().resource::<State<GameState>>().get(),
+        GameState::Arming,
+        "Space should jump straight to Arming"
+    );
+
+    let w = app.world_mut();
+    let rot = w
+        .query_filtered::<&Transform, With<Player>>()
+        .single(w)
+        .unwrap()
+        .rotation;
+    let (_, pitch, _) = rot.to_euler(EulerRot::YXZ);
+    let expected = -IntroConfig::default().tilt_down_angle.to_radians();
+    assert!(
+        (pitch - expected).abs() < 1e-4,
+        "pitch {pitch} should be the tilt-down angle {expected}"
+    );
+}
+
 // ── Fly movement ────────────────────────────────────────────────
 
 #[test]
@@ -364,6 +437,225 @@ fn fly_wasd_updates_pos() {
     assert!(moved, "PlayerMoved should be set");
 }
 
+#[test]
+fn fly_drifts_with_the_wind_without_steering() {
+    let mut app = test_app();
+    app.insert_resource(crate::wind::Wind {
+        velocity: Vec2::new(0.0, -5.0),
+    });
+    app.world_mut().resource_mut::<PlayerMoved>().0 = false;
+    let before = app.world().resource::<PlayerPos>().xz;
+    app.update();
+    app.update();
+
+    let after = app.world().resource::<PlayerPos>().xz;
+    assert_eq!(after.x, before.x);
+    assert!(after.y < before.y, "wind should push toward -Z: {after:?}");
+    assert!(app.world().resource::<PlayerMoved>().0);
+    assert_eq!(app.world().resource::<PlayerPos>().heading, Vec2::ZERO);
+}
+
+#[test]
+fn trail_follows_flight_path() {
+    let mut app = test_app();
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(KeyCode::KeyW);
+    for _ in 0..10 {
+        app.update();
+    }
+
+    let w = app.world_mut();
+    let (trail, mesh) = w.query::<(&LightTrail, &Mesh3d)>().single(w).unwrap();
+    let (len, newest) = (trail.points.len(), *trail.points.back().unwrap());
+    assert!(len > 2, "flying should add trail samples, got {len}");
+    let mesh = w.resource::<Assets<Mesh>>().get(&mesh.0).unwrap();
+    assert_eq!(mesh.count_vertices(), len * 2);
+
+    let cam = w
+        .query_filtered::<&Transform, With<Player>>()
+        .single(w)
+        .unwrap();
+    assert!(newest.xz().distance(cam.translation.xz()) < 1e-3);
+}
+
+#[test]
+fn low_gfx_camera_skips_hdr_bloom_and_tonemapping() {
+    let mut app = test_app_with_config(DroneConfig {
+        low_gfx: true,
+        ..default()
+    });
+    app.insert_resource(GlowLevel(2.0));
+    app.update();
+
+    let w = app.world_mut();
+    let (player, tonemapping) = w
+        .query_filtered::<(Entity, &Tonemapping), With<Player>>()
+        .single(w)
+        .unwrap();
+    assert_eq!(*tonemapping, Tonemapping::None);
+    assert!(w.get::<Hdr>(player).is_none());
+    assert!(w.get::<Bloom>(player).is_none());
+    // The glow dial still reaches the laser without a bloom to scale.
+    let mats = w.resource::<DroneMaterials>();
+    let laser = w
+        .resource::<Assets<StandardMaterial>>()
+        .get(&mats.laser_ray)
+        .unwrap();
+    assert_eq!(laser.emissive, LASER_EMISSIVE * 2.0);
+}
+
+#[test]
+fn bloom_follows_config_at_spawn_and_runtime() {
+    let mut app = test_app_with_config(DroneConfig {
+        bloom_composite_mode: BloomCompositeMode::EnergyConserving,
+        bloom_low_frequency_boost: 0.2,
+        bloom_threshold: 0.8,
+        bloom_threshold_softness: 0.3,
+        ..default()
+    });
+    app.update();
+
+    let w = app.world_mut();
+    let bloom = w
+        .query_filtered::<&Bloom, With<Player>>()
+        .single(w)
+        .unwrap();
+    assert_eq!(bloom.composite_mode, BloomCompositeMode::EnergyConserving);
+    assert_eq!(bloom.low_frequency_boost, 0.2);
+    assert_eq!(bloom.prefilter.threshold, 0.8);
+    assert_eq!(bloom.prefilter.threshold_softness, 0.3);
+
+    {
+        let mut cfg = app.world_mut().resource_mut::<DroneConfig>();
+        cfg.bloom_composite_mode = BloomCompositeMode::Additive;
+        cfg.bloom_threshold = 0.0;
+    }
+    app.update();
+
+    let w = app.world_mut();
+    let bloom = w
+        .query_filtered::<&Bloom, With<Player>>()
+        .single(w)
+        .unwrap();
+    assert_eq!(bloom.composite_mode, BloomCompositeMode::Additive);
+    assert_eq!(bloom.prefilter.threshold, 0.0);
+    assert_eq!(bloom.low_frequency_boost, 0.2);
+    assert!(bloom.intensity > 0.0);
+}
+
+#[test]
+fn glow_level_scales_bloom_and_laser() {
+    let mut app = test_app();
+    app.insert_resource(GlowLevel(2.0));
+    app.update();
+
+    let w = app.world_mut();
+    let bloom = w
+        .query_filtered::<&Bloom, With<Player>>()
+        .single(w)
+        .unwrap();
+    assert_eq!(
+        bloom.intensity,
+        DroneConfig::default().bloom_intensity * 2.0
+    );
+    let mats = w.resource::<DroneMaterials>();
+    let laser = w
+        .resource::<Assets<StandardMaterial>>()
+        .get(&mats.laser_ray)
+        .unwrap();
+    assert_eq!(laser.emissive, LASER_EMISSIVE * 2.0);
+}
+
+#[test]
+fn auto_exposure_eases_down_under_edge_glare_and_recovers() {
+    let mut app = test_app();
+    app.insert_resource(GlowLevel::default());
+    app.update();
+    let cfg = DroneConfig::default();
+    let factor = |app: &mut App| {
+        let w = app.world_mut();
+        let (auto, bloom, exposure) = w
+            .query_filtered::<(&AutoExposure, &Bloom, &Exposure), With<Player>>()
+            .single(w)
+            .unwrap();
+        assert!((bloom.intensity - cfg.bloom_intensity * auto.factor).abs() < 1e-5);
+        assert!(exposure.ev100 >= Exposure::EV100_BLENDER - 1e-5);
+        auto.factor
+    };
+    assert_eq!(factor(&mut app), 1.0);
+
+    // A wall of lit edges right at the camera: 4x the glare limit.
+    let cam = {
+        let w = app.world_mut();
+        w.query_filtered::<&GlobalTransform, With<Player>>()
+            .single(w)
+            .unwrap()
+            .translation()
+    };
+    let edges: Vec<Entity> = (0..(cfg.auto_exposure_glare_limit * 4.0) as usize)
+        .map(|_| {
+            app.world_mut()
+                .spawn((QuadEdge, InFov, Transform::from_translation(cam)))
+                .id()
+        })
+        .collect();
+    for _ in 0..50 {
+        app.update();
+    }
+    assert!((factor(&mut app) - 0.5).abs() < 0.01);
+
+    for e in edges {
+        app.world_mut().despawn(e);
+    }
+    for _ in 0..50 {
+        app.update();
+    }
+    assert!(factor(&mut app) > 0.99);
+}
+
+#[test]
+fn trail_mesh_fades_in_toward_newest() {
+    let points = [Vec3::ZERO, Vec3::X, Vec3::X * 2.0];
+    let mesh = systems::trail_mesh(&points, 0.2);
+    let Some(bevy::mesh::VertexAttributeValues::Float32x4(colors)) =
+        mesh.attribute(Mesh::ATTRIBUTE_COLOR)
+    else {
+        panic!("trail mesh should carry vertex colors");
+    };
+    assert_eq!(colors[0][3], 0.0);
+    assert_eq!(colors[5][3], 1.0);
+    assert_eq!(mesh.indices().unwrap().len(), 12);
+}
+
+#[test]
+fn fly_arrow_keys_rotate_camera() {
+    let mut app = test_app();
+
+    let yaw_of = |app: &mut App| {
+        let w = app.world_mut();
+        let rot = w
+            .query_filtered::<&Transform, With<Player>>()
+            .single(w)
+            .unwrap()
+            .rotation;
+        rot.to_euler(EulerRot::YXZ).0
+    };
+    let before = yaw_of(&mut app);
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(KeyCode::ArrowLeft);
+    app.update();
+
+    let after = yaw_of(&mut app);
+    let expected = app.world().resource::<DroneConfig>().key_look_speed * 0.1;
+    assert!(
+        ((after - before) - expected).abs() < 1e-3,
+        "ArrowLeft should yaw left by key_look_speed * dt: before={before} after={after}"
+    );
+}
+
 #[test]
 fn fly_offset_clamps_to_lowest() {
     let mut app = test_app();
@@ -382,8 +674,111 @@ fn fly_offset_clamps_to_lowest() {
     );
 }
 
+#[test]
+fn fly_sets_heading_while_moving() {
+    let mut app = test_app();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(KeyCode::KeyW);
+    app.update();
+    let heading = app.world().resource::<PlayerPos>().heading;
+    assert!(
+        (heading.length() - 1.0).abs() < 1e-4,
+        "heading should be a unit vector while moving, got {heading:?}"
+    );
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .release(KeyCode::KeyW);
+    app.update();
+    let heading = app.world().resource::<PlayerPos>().heading;
+    assert_eq!(heading, Vec2::ZERO, "heading should reset when idle");
+}
+
+#[test]
+fn contour_mode_pre_climbs_toward_ground_ahead() {
+    let mut app = test_app();
+    let lowest = app.world().resource::<DroneConfig>().lowest_offset;
+
+    app.world_mut().resource_mut::<DroneConfig>().altitude_mode = AltitudeMode::Contour;
+    app.world_mut().resource_mut::<GroundAhead>().0 = Some(10.0);
+    app.update();
+
+    let w = app.world_mut();
+    let y = w
+        .query_filtered::<&Transform, With<Player>>()
+        .single(w)
+        .unwrap()
+        .translation
+        .y;
+    assert!(
+        y > lowest,
+        "contour mode should climb above ground + offset ({lowest}) toward the hill ahead, got {y}"
+    );
+    assert!(
+        y < 10.0 + lowest,
+        "contour climb should be smoothed, not snapped, got {y}"
+    );
+}
+
+// ── Survey view ─────────────────────────────────────────────────
+
+#[test]
+fn survey_view_toggles_projection() {
+    let mut app = test_app();
+
+    let press_v = |app: &mut App| {
+        let mut keys = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        keys.release(KeyCode::KeyV);
+        keys.clear();
+        keys.press(KeyCode::KeyV);
+        app.update();
+    };
+
+    press_v(&mut app);
+    {
+        let w = app.world_mut();
+        let (tf, proj) = w
+            .query_filtered::<(&Transform, &Projection), With<Player>>()
+            .single(w)
+            .unwrap();
+        assert!(matches!(proj, Projection::Orthographic(_)));
+        let (_, pitch, _) = tf.rotation.to_euler(EulerRot::YXZ);
+        assert!(pitch < -1.4, "survey view should look down, pitch={pitch}");
+    }
+
+    press_v(&mut app);
+    let w = app.world_mut();
+    let proj = w
+        .query_filtered::<&Projection, With<Player>>()
+        .single(w)
+        .unwrap();
+    assert!(matches!(proj, Projection::Perspective(_)));
+}
+
 // ── Arming animation ────────────────────────────────────────────
 
+#[test]
+fn fog_follows_clear_color() {
+    let mut app = test_app();
+    let w = app.world_mut();
+    let fog = w
+        .query_filtered::<&DistanceFog, With<Player>>()
+        .single(w)
+        .unwrap();
+    assert_eq!(fog.color, Color::srgb(0.1, 0.2, 0.3));
+
+    app.insert_resource(ClearColor(Color::srgb(0.5, 0.0, 0.0)));
+    app.update();
+    let w = app.world_mut();
+    let fog = w
+        .query_filtered::<&DistanceFog, With<Player>>()
+        .single(w)
+        .unwrap();
+    assert_eq!(fog.color, Color::srgb(0.5, 0.0, 0.0));
+}
+
 #[test]
 fn arming_animation_reaches_armed_rotation() {
     // test_app() already runs through Intro → Arming → Running.
@@ -0,0 +1,276 @@
+//! Elevation bands: altitude crossing events and a default per-band look.
+//!
+//! While flying, [`track_elevation_band`] sorts the drone's height into an
+//! [`ElevationBand`] ([`classify_band`]) and writes [`EnteredBand`] whenever it changes (and
+//! once when flight starts), keeping the result in [`CurrentBand`]. The height is either the
+//! offset above the ground or the absolute camera height ([`BandReference`]); thresholds get
+//! [`ElevationConfig::hysteresis`] so hovering on a boundary doesn't flicker.
+//!
+//! Any system can read [`EnteredBand`] for altitude-based behavior. The default listener,
+//! [`apply_band_style`], scales the camera fog distance and sets the [`BandTint`] the HUD
+//! strip is drawn in. There is no audio in this tree, so bands don't change any sound.
+
+use bevy::pbr::{DistanceFog, FogFalloff};
+use bevy::prelude::*;
+
+use crate::drone::{DroneConfig, Player};
+use crate::h_terrain::HTerrainPhase;
+use crate::{GameState, GroundLevel, PlayerPos};
+
+/// Altitude band, low to high.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+pub enum ElevationBand {
+    /// Below [`ElevationConfig::low_max`].
+    Low,
+    /// Between the thresholds.
+    Mid,
+    /// At or above [`ElevationConfig::high_min`].
+    High,
+}
+
+impl ElevationBand {
+    /// Index into the per-band config arrays.
+    pub fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// Which height the bands are measured on.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+pub enum BandReference {
+    /// Offset above the ground under the drone ([`PlayerPos::offset`]).
+    #[default]
+    Terrain,
+    /// World height (ground + offset).
+    Absolute,
+}
+
+/// Per-plugin configuration for elevation bands.
+#[derive(Resource, Clone, Debug, Reflect)]
+pub struct ElevationConfig {
+    /// Height the thresholds apply to.
+    pub reference: BandReference,
+    /// Heights below this are [`ElevationBand::Low`].
+    pub low_max: f32,
+    /// Heights at or above this are [`ElevationBand::High`].
+    pub high_min: f32,
+    /// Distance past a threshold needed to leave the current band.
+    pub hysteresis: f32,
+    /// Run the default listener ([`apply_band_style`]).
+    pub style: bool,
+    /// Fog distance multiplier per band (on [`DroneConfig::fog_start`] / `fog_end`).
+    pub fog_scale: [f32; 3],
+    /// HUD strip tint per band.
+    pub tints: [Color; 3],
+}
+
+impl Default for ElevationConfig {
+    fn default() -> Self {
+        Self {
+            reference: BandReference::Terrain,
+            low_max: 6.0,
+            high_min: 30.0,
+            hysteresis: 1.0,
+            style: true,
+            fog_scale: [0.7, 1.0, 1.6],
+            tints: [
+                Color::srgb(1.0, 0.6, 0.1),
+                Color::srgb(0.0, 1.0, 0.78),
+                Color::srgb(0.55, 0.7, 1.0),
+            ],
+        }
+    }
+}
+
+/// Written when the drone enters an [`ElevationBand`].
+#[derive(Message, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EnteredBand(pub ElevationBand);
+
+/// Band the drone is in (`None` before the first flying frame).
+#[derive(Resource, Clone, Copy, Debug, Default, Reflect)]
+pub struct CurrentBand(pub Option<ElevationBand>);
+
+/// HUD strip color set by [`apply_band_style`].
+#[derive(Resource, Clone, Copy, Debug, Reflect)]
+pub struct BandTint(pub Color);
+
+/// Band for `height`. From `current`, the height must clear the band's edges by
+/// [`ElevationConfig::hysteresis`] to move to another.
+pub fn classify_band(
+    height: f32,
+    cfg: &ElevationConfig,
+    current: Option<ElevationBand>,
+) -> ElevationBand {
+    let h = cfg.hysteresis;
+    let stays = match current {
+        None => false,
+        Some(ElevationBand::Low) => height < cfg.low_max + h,
+        Some(ElevationBand::Mid) => (cfg.low_max - h..cfg.high_min + h).contains(&height),
+        Some(ElevationBand::High) => height >= cfg.high_min - h,
+    };
+    match current {
+        Some(band) if stays => band,
+        _ if height < cfg.low_max => ElevationBand::Low,
+        _ if height >= cfg.high_min => ElevationBand::High,
+        _ => ElevationBand::Mid,
+    }
+}
+
+/// Elevation bands plugin.
+pub struct ElevationPlugin {
+    /// Per-plugin configuration.
+    pub config: ElevationConfig,
+}
+
+impl Plugin for ElevationPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<ElevationConfig>()
+            .register_type::<CurrentBand>()
+            .register_type::<BandTint>()
+            .insert_resource(self.config.clone())
+            .insert_resource(BandTint(self.config.tints[ElevationBand::Mid.index()]))
+            .init_resource::<CurrentBand>()
+            .add_message::<EnteredBand>()
+            .add_systems(
+                Update,
+                (
+                    track_elevation_band,
+                    apply_band_style.run_if(|cfg: Res<ElevationConfig>| cfg.style),
+                )
+                    .chain()
+                    .after(HTerrainPhase::UpdateGround)
+                    .run_if(in_state(GameState::Running)),
+            );
+    }
+}
+
+/// Classifies the drone's height and announces band changes.
+fn track_elevation_band(
+    cfg: Res<ElevationConfig>,
+    player: Res<PlayerPos>,
+    ground: Res<GroundLevel>,
+    mut current: ResMut<CurrentBand>,
+    mut entered: MessageWriter<EnteredBand>,
+) {
+    let height = match cfg.reference {
+        BandReference::Terrain => player.offset,
+        BandReference::Absolute => {
+            let Some(ground) = ground.0 else {
+                return;
+            };
+            ground + player.offset
+        }
+    };
+    let band = classify_band(height, &cfg, current.0);
+    if current.0 != Some(band) {
+        current.0 = Some(band);
+        entered.write(EnteredBand(band));
+    }
+}
+
+/// Default listener: per-band fog distance and HUD tint.
+fn apply_band_style(
+    cfg: Res<ElevationConfig>,
+    drone: Res<DroneConfig>,
+    mut entered: MessageReader<EnteredBand>,
+    mut fog: Query<&mut DistanceFog, With<Player>>,
+    mut tint: ResMut<BandTint>,
+) {
+    let Some(&EnteredBand(band)) = entered.read().last() else {
+        return;
+    };
+    let scale = cfg.fog_scale[band.index()];
+    for mut fog in &mut fog {
+        fog.falloff = FogFalloff::Linear {
+            start: drone.fog_start * scale,
+            end: drone.fog_end * scale,
+        };
+    }
+    tint.0 = cfg.tints[band.index()];
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::state::app::StatesPlugin;
+
+    use super::*;
+
+    #[test]
+    fn bands_split_at_the_thresholds_with_hysteresis() {
+        let cfg = ElevationConfig::default();
+        assert_eq!(classify_band(2.0, &cfg, None), ElevationBand::Low);
+        assert_eq!(classify_band(6.0, &cfg, None), ElevationBand::Mid);
+        assert_eq!(classify_band(30.0, &cfg, None), ElevationBand::High);
+        // Just past a threshold: stay put.
+        let low = Some(ElevationBand::Low);
+        assert_eq!(classify_band(6.5, &cfg, low), ElevationBand::Low);
+        assert_eq!(classify_band(7.5, &cfg, low), ElevationBand::Mid);
+        let high = Some(ElevationBand::High);
+        assert_eq!(classify_band(29.5, &cfg, high), ElevationBand::High);
+        assert_eq!(classify_band(2.0, &cfg, high), ElevationBand::Low);
+        let mid = Some(ElevationBand::Mid);
+        assert_eq!(classify_band(5.5, &cfg, mid), ElevationBand::Mid);
+        assert_eq!(classify_band(30.5, &cfg, mid), ElevationBand::Mid);
+        assert_eq!(classify_band(31.5, &cfg, mid), ElevationBand::High);
+    }
+
+    /// Bands announced so far.
+    #[derive(Resource, Default)]
+    struct Entered(Vec<ElevationBand>);
+
+    fn record(mut messages: MessageReader<EnteredBand>, mut entered: ResMut<Entered>) {
+        entered.0.extend(messages.read().map(|m| m.0));
+    }
+
+    #[test]
+    fn crossings_are_announced_once_and_restyle_fog_and_hud() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin))
+            .insert_state(GameState::Running)
+            .init_resource::<DroneConfig>()
+            .init_resource::<PlayerPos>()
+            .init_resource::<GroundLevel>()
+            .init_resource::<Entered>()
+            .add_plugins(ElevationPlugin {
+                config: ElevationConfig::default(),
+            })
+            .add_systems(Update, record.after(apply_band_style));
+        app.world_mut().spawn((Player, DistanceFog::default()));
+        let fly = |app: &mut App, offset| {
+            app.world_mut().resource_mut::<PlayerPos>().offset = offset;
+            app.update();
+        };
+        fly(&mut app, 15.0);
+        fly(&mut app, 16.0);
+        fly(&mut app, 2.0);
+        fly(&mut app, 40.0);
+        fly(&mut app, 29.5);
+
+        let w = app.world_mut();
+        assert_eq!(
+            w.resource::<Entered>().0,
+            vec![ElevationBand::Mid, ElevationBand::Low, ElevationBand::High]
+        );
+        let cfg = ElevationConfig::default();
+        assert_eq!(w.resource::<BandTint>().0, cfg.tints[2]);
+        let drone = DroneConfig::default();
+        let fog = w.query::<&DistanceFog>().single(w).unwrap();
+        let FogFalloff::Linear { start, end } = fog.falloff else {
+            panic!("linear fog");
+        };
+        assert_eq!(start, drone.fog_start * cfg.fog_scale[2]);
+        assert_eq!(end, drone.fog_end * cfg.fog_scale[2]);
+
+        // Absolute heights wait for the ground.
+        w.resource_mut::<ElevationConfig>().reference = BandReference::Absolute;
+        w.resource_mut::<CurrentBand>().0 = None;
+        fly(&mut app, 2.0);
+        assert_eq!(app.world().resource::<CurrentBand>().0, None);
+        app.world_mut().resource_mut::<GroundLevel>().0 = Some(50.0);
+        fly(&mut app, 2.0);
+        assert_eq!(
+            app.world().resource::<CurrentBand>().0,
+            Some(ElevationBand::High)
+        );
+    }
+}
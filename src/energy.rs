@@ -0,0 +1,341 @@
+//! Survival energy: flight drains [`Energy`], charger hexes refill it, and running dry forces
+//! a slow descent.
+//!
+//! Everything here runs only in [`GameMode::Survival`] (`--mode survival`); the viewer never
+//! gets an [`Energy`] resource, chargers, or the HUD `NRG` field. At startup the tank is
+//! filled to [`EnergyConfig::capacity`]. [`scatter_chargers`] tags a seeded subset of new
+//! cells as [`Charger`]s with a green [`ChargerOverlay`]. While flying, [`spend_energy`]
+//! drains the tank per second, per unit flown, and per unit climbed ([`energy_step`]), or
+//! refills it while the drone sits low (within [`EnergyConfig::landing_offset`]) over a
+//! charger. With the tank empty, climbing is blocked and the offset sinks by
+//! [`EnergyConfig::descent_speed`] down to the drone's lowest offset.
+
+use bevy::prelude::*;
+
+use crate::drone::DroneConfig;
+use crate::h_terrain::{ActiveHex, HCell, HTerrainConfig, HTerrainPhase, HexFace, overlay_lift};
+use crate::locale::Locale;
+use crate::math::hash_roll;
+use crate::toast::Toast;
+use crate::{GameMode, GameState, PlayerMoved, PlayerPos};
+
+/// Per-plugin configuration for survival energy.
+#[derive(Resource, Clone, Debug, Reflect)]
+pub struct EnergyConfig {
+    /// Full tank.
+    pub capacity: f32,
+    /// Drain per second, even while hovering.
+    pub idle_drain: f32,
+    /// Drain per world unit flown horizontally.
+    pub move_drain: f32,
+    /// Drain per world unit climbed.
+    pub climb_drain: f32,
+    /// Moves longer than this in one frame (teleports) cost nothing.
+    pub max_step: f32,
+    /// Share of hexes that are chargers.
+    pub charger_density: f32,
+    /// Mixed into the grid's height seed to pick charger hexes.
+    pub charger_seed: u64,
+    /// Refill per second while landed on a charger.
+    pub charge_rate: f32,
+    /// Maximum offset above ground that counts as landed.
+    pub landing_offset: f32,
+    /// Offset lost per second with an empty tank.
+    pub descent_speed: f32,
+    /// Charger overlay color.
+    pub color: Color,
+    /// Emissive multiplier on `color` (drives bloom).
+    pub glow: f32,
+}
+
+impl Default for EnergyConfig {
+    fn default() -> Self {
+        Self {
+            capacity: 100.0,
+            idle_drain: 0.2,
+            move_drain: 0.1,
+            climb_drain: 0.3,
+            max_step: 20.0,
+            charger_density: 0.05,
+            charger_seed: 0x0e_4e72,
+            charge_rate: 25.0,
+            landing_offset: 4.0,
+            descent_speed: 3.0,
+            color: Color::srgb(0.3, 1.0, 0.2),
+            glow: 3.0,
+        }
+    }
+}
+
+/// Energy left in the tank (survival mode only).
+#[derive(Resource, Clone, Debug, Reflect)]
+pub struct Energy {
+    /// Current level in `0.0..=capacity`.
+    pub level: f32,
+    /// Full tank.
+    pub capacity: f32,
+}
+
+impl Energy {
+    /// Full tank for `cfg`.
+    pub fn full(cfg: &EnergyConfig) -> Self {
+        Self {
+            level: cfg.capacity,
+            capacity: cfg.capacity,
+        }
+    }
+
+    /// Level as a share of the capacity.
+    pub fn fraction(&self) -> f32 {
+        if self.capacity > 0.0 {
+            self.level / self.capacity
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Marker on charger [`HCell`]s.
+#[derive(Component, Reflect)]
+pub struct Charger;
+
+/// Marker on the overlay child of a charger's [`HexFace`].
+#[derive(Component, Reflect)]
+pub struct ChargerOverlay;
+
+/// Shared charger overlay material.
+#[derive(Resource)]
+struct ChargerMaterial(Handle<StandardMaterial>);
+
+/// Energy level after `dt` seconds in which the drone flew `moved` units and climbed
+/// `climbed`, or sat on a charger when `charging`. Clamped to `0.0..=capacity`.
+pub fn energy_step(
+    level: f32,
+    cfg: &EnergyConfig,
+    dt: f32,
+    moved: f32,
+    climbed: f32,
+    charging: bool,
+) -> f32 {
+    let next = if charging {
+        level + cfg.charge_rate * dt
+    } else {
+        level - cfg.idle_drain * dt - cfg.move_drain * moved - cfg.climb_drain * climbed.max(0.0)
+    };
+    next.clamp(0.0, cfg.capacity)
+}
+
+/// Run condition: the survival rules are on.
+fn survival(mode: Res<GameMode>) -> bool {
+    *mode == GameMode::Survival
+}
+
+/// Survival energy plugin.
+pub struct EnergyPlugin {
+    /// Per-plugin configuration.
+    pub config: EnergyConfig,
+}
+
+impl Plugin for EnergyPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<EnergyConfig>()
+            .register_type::<Energy>()
+            .register_type::<Charger>()
+            .register_type::<ChargerOverlay>()
+            .insert_resource(self.config.clone())
+            .init_resource::<GameMode>()
+            .add_systems(Startup, setup_energy.run_if(survival))
+            .add_systems(
+                Update,
+                scatter_chargers.run_if(survival.and(any_match_filter::<Added<HCell>>)),
+            )
+            .add_systems(
+                Update,
+                spend_energy
+                    .after(crate::drone::systems::fly)
+                    .before(HTerrainPhase::UpdateGround)
+                    .run_if(in_state(GameState::Running).and(survival)),
+            );
+    }
+}
+
+/// Fills the tank and creates the charger overlay material.
+fn setup_energy(
+    cfg: Res<EnergyConfig>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut commands: Commands,
+) {
+    commands.insert_resource(Energy::full(&cfg));
+    commands.insert_resource(ChargerMaterial(materials.add(StandardMaterial {
+        base_color: cfg.color.with_alpha(0.4),
+        emissive: LinearRgba::from(cfg.color) * cfg.glow,
+        alpha_mode: AlphaMode::Blend,
+        unlit: true,
+        ..default()
+    })));
+}
+
+/// Tags freshly generated charger cells and overlays their faces.
+fn scatter_chargers(
+    cfg: Res<EnergyConfig>,
+    terrain: Res<HTerrainConfig>,
+    material: Res<ChargerMaterial>,
+    cells: Query<(Entity, &HCell, &Children), Added<HCell>>,
+    faces: Query<&Mesh3d, With<HexFace>>,
+    mut commands: Commands,
+) {
+    let seed = cfg.charger_seed ^ u64::from(terrain.grid.height_noise_seed);
+    for (entity, cell, children) in &cells {
+        if hash_roll(cell.hex, seed) >= cfg.charger_density {
+            continue;
+        }
+        commands.entity(entity).insert(Charger);
+        for face in children.iter() {
+            let Ok(mesh) = faces.get(face) else {
+                continue;
+            };
+            commands.entity(face).with_child((
+                ChargerOverlay,
+                Mesh3d(mesh.0.clone()),
+                MeshMaterial3d(material.0.clone()),
+                Transform::from_xyz(0.0, overlay_lift::CHARGER, 0.0),
+            ));
+        }
+    }
+}
+
+/// Drains or recharges the tank from this frame's flight; sinks the drone when it's empty.
+#[allow(clippy::too_many_arguments)]
+fn spend_energy(
+    time: Res<Time>,
+    cfg: Res<EnergyConfig>,
+    drone: Res<DroneConfig>,
+    active: Res<ActiveHex>,
+    chargers: Query<(), With<Charger>>,
+    mut energy: ResMut<Energy>,
+    mut player: ResMut<PlayerPos>,
+    mut moved: ResMut<PlayerMoved>,
+    mut last: Local<Option<(Vec2, f32)>>,
+    mut toasts: MessageWriter<Toast>,
+    locale: Res<Locale>,
+) {
+    let dt = time.delta_secs();
+    let (from_xz, from_offset) = last.unwrap_or((player.xz, player.offset));
+    let step = player.xz.distance(from_xz);
+    let flown = if step <= cfg.max_step { step } else { 0.0 };
+    let charging = player.offset <= cfg.landing_offset
+        && active.cell.is_some_and(|cell| chargers.contains(cell));
+    let before = energy.level;
+    energy.level = energy_step(
+        before,
+        &cfg,
+        dt,
+        flown,
+        player.offset - from_offset,
+        charging,
+    );
+    if energy.level <= 0.0 {
+        if before > 0.0 {
+            toasts.write(Toast(locale.t("Out of energy: descending").into()));
+        }
+        // No power to climb: sink toward the lowest offset.
+        let sunk = player.offset.min(from_offset) - cfg.descent_speed * dt;
+        player.offset = sunk.max(drone.lowest_offset);
+        moved.0 = true;
+    } else if charging && before < energy.capacity && energy.level >= energy.capacity {
+        toasts.write(Toast(locale.t("Energy recharged").into()));
+    }
+    *last = Some((player.xz, player.offset));
+}
+
+#[cfg(test)]
+mod tests {
+    use hexx::Hex;
+
+    use super::*;
+
+    #[test]
+    fn flight_drains_and_chargers_refill_within_the_tank() {
+        let cfg = EnergyConfig::default();
+        let hover = energy_step(50.0, &cfg, 1.0, 0.0, 0.0, false);
+        assert!((hover - 49.8).abs() < 1e-4);
+        let flight = energy_step(50.0, &cfg, 1.0, 10.0, 5.0, false);
+        assert!((flight - (50.0 - 0.2 - 1.0 - 1.5)).abs() < 1e-4);
+        // Descending is free.
+        assert_eq!(energy_step(50.0, &cfg, 0.0, 0.0, -5.0, false), 50.0);
+        assert_eq!(energy_step(1.0, &cfg, 1.0, 50.0, 0.0, false), 0.0);
+        assert_eq!(energy_step(90.0, &cfg, 1.0, 0.0, 0.0, true), cfg.capacity);
+    }
+
+    fn test_app(cfg: EnergyConfig) -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_plugins(AssetPlugin::default())
+            .init_asset::<Mesh>()
+            .init_asset::<StandardMaterial>()
+            .add_message::<Toast>()
+            .insert_resource(GameMode::Survival)
+            .insert_resource(cfg)
+            .init_resource::<DroneConfig>()
+            .init_resource::<HTerrainConfig>()
+            .init_resource::<ActiveHex>()
+            .init_resource::<PlayerPos>()
+            .init_resource::<PlayerMoved>()
+            .init_resource::<Locale>()
+            .add_systems(Startup, setup_energy.run_if(survival))
+            .add_systems(
+                Update,
+                (
+                    scatter_chargers.run_if(any_match_filter::<Added<HCell>>),
+                    spend_energy,
+                )
+                    .chain()
+                    .run_if(survival),
+            );
+        app
+    }
+
+    #[test]
+    fn an_empty_tank_sinks_the_drone_and_a_charger_refills_it() {
+        let mut app = test_app(EnergyConfig {
+            charger_density: 1.0,
+            idle_drain: 0.0,
+            descent_speed: 1e9,
+            ..default()
+        });
+        let mesh = app
+            .world_mut()
+            .resource_mut::<Assets<Mesh>>()
+            .add(Cuboid::default());
+        let cell = app.world_mut().spawn(HCell { hex: Hex::ZERO }).id();
+        app.world_mut()
+            .spawn((HexFace, Mesh3d(mesh), ChildOf(cell)));
+        app.world_mut().resource_mut::<PlayerPos>().offset = 30.0;
+        app.update();
+        let w = app.world_mut();
+        assert!(w.get::<Charger>(cell).is_some());
+        assert_eq!(w.query::<&ChargerOverlay>().iter(w).count(), 1);
+
+        // Dry: climbing is undone and the drone sinks to the floor.
+        w.resource_mut::<Energy>().level = 0.0;
+        w.resource_mut::<PlayerPos>().offset = 40.0;
+        app.update();
+        app.update();
+        let lowest = app.world().resource::<DroneConfig>().lowest_offset;
+        assert_eq!(app.world().resource::<PlayerPos>().offset, lowest);
+
+        // Landed on the charger: the tank fills back up.
+        app.world_mut().resource_mut::<ActiveHex>().cell = Some(cell);
+        app.update();
+        app.update();
+        assert!(app.world().resource::<Energy>().level > 0.0);
+    }
+
+    #[test]
+    fn viewer_mode_has_no_energy() {
+        let mut app = test_app(EnergyConfig::default());
+        app.insert_resource(GameMode::Viewer);
+        app.update();
+        assert!(app.world().get_resource::<Energy>().is_none());
+    }
+}
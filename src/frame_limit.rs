@@ -0,0 +1,133 @@
+//! Optional frame-rate cap.
+//!
+//! With a [`FpsCap`] set, [`limit_frame_rate`] sleeps at the end of each frame until the
+//! cap's frame time has passed since the previous frame ended. This is the fallback for
+//! present modes that don't wait on vsync (native only; browsers pace frames themselves).
+//! The settings panel edits both the cap and the primary window's [`PresentMode`].
+//!
+//! [`PresentMode`]: bevy::window::PresentMode
+
+use std::time::Duration;
+
+#[cfg(not(target_arch = "wasm32"))]
+use bevy::platform::time::Instant;
+use bevy::prelude::*;
+
+/// Frame-rate cap choices.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+pub enum FpsCap {
+    /// 30 frames per second.
+    Fps30,
+    /// 60 frames per second.
+    Fps60,
+    /// 120 frames per second.
+    Fps120,
+    /// No cap beyond the present mode.
+    #[default]
+    Uncapped,
+}
+
+impl FpsCap {
+    /// All caps in picker order.
+    pub const ALL: [FpsCap; 4] = [Self::Fps30, Self::Fps60, Self::Fps120, Self::Uncapped];
+
+    /// Short name for the picker.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Fps30 => "30",
+            Self::Fps60 => "60",
+            Self::Fps120 => "120",
+            Self::Uncapped => "uncapped",
+        }
+    }
+
+    /// Minimum time per frame, or `None` when uncapped.
+    pub fn frame_time(self) -> Option<Duration> {
+        let fps = match self {
+            Self::Fps30 => 30,
+            Self::Fps60 => 60,
+            Self::Fps120 => 120,
+            Self::Uncapped => return None,
+        };
+        Some(Duration::from_secs(1) / fps)
+    }
+
+    /// Parses a CLI value: `30`, `60`, `120`, or `0` / `uncapped`.
+    pub fn parse(s: &str) -> Result<Self, String> {
+        match s {
+            "30" => Ok(Self::Fps30),
+            "60" => Ok(Self::Fps60),
+            "120" => Ok(Self::Fps120),
+            "0" | "uncapped" => Ok(Self::Uncapped),
+            _ => Err(format!("invalid fps cap `{s}` (30, 60, 120, uncapped)")),
+        }
+    }
+}
+
+/// Per-plugin configuration for the frame limiter.
+#[derive(Resource, Clone, Debug, Default, Reflect)]
+pub struct FrameLimitConfig {
+    /// Active frame-rate cap.
+    pub fps_cap: FpsCap,
+}
+
+/// When the previous frame finished sleeping.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Resource, Default)]
+struct LastFrameEnd(Option<Instant>);
+
+/// Time left to sleep when `elapsed` has passed since the last frame under `cap`.
+pub fn remaining_sleep(cap: FpsCap, elapsed: Duration) -> Duration {
+    cap.frame_time()
+        .map_or(Duration::ZERO, |t| t.saturating_sub(elapsed))
+}
+
+/// Frame limiter plugin.
+pub struct FrameLimitPlugin {
+    /// Per-plugin configuration.
+    pub config: FrameLimitConfig,
+}
+
+impl Plugin for FrameLimitPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<FrameLimitConfig>()
+            .insert_resource(self.config.clone());
+
+        #[cfg(not(target_arch = "wasm32"))]
+        app.init_resource::<LastFrameEnd>()
+            .add_systems(Last, limit_frame_rate);
+    }
+}
+
+/// Sleeps out the rest of the capped frame time.
+#[cfg(not(target_arch = "wasm32"))]
+fn limit_frame_rate(cfg: Res<FrameLimitConfig>, mut last: ResMut<LastFrameEnd>) {
+    if let Some(prev) = last.0 {
+        let sleep = remaining_sleep(cfg.fps_cap, prev.elapsed());
+        if !sleep.is_zero() {
+            std::thread::sleep(sleep);
+        }
+    }
+    last.0 = Some(Instant::now());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sleeps_only_the_remainder_of_a_capped_frame() {
+        let ms = Duration::from_millis;
+        assert_eq!(remaining_sleep(FpsCap::Uncapped, ms(1)), Duration::ZERO);
+        assert_eq!(remaining_sleep(FpsCap::Fps60, ms(20)), Duration::ZERO);
+        let left = remaining_sleep(FpsCap::Fps30, ms(13));
+        assert!(left > ms(20) && left < ms(21), "{left:?}");
+    }
+
+    #[test]
+    fn parses_cli_caps() {
+        assert_eq!(FpsCap::parse("60"), Ok(FpsCap::Fps60));
+        assert_eq!(FpsCap::parse("0"), Ok(FpsCap::Uncapped));
+        assert!(FpsCap::parse("45").is_err());
+    }
+}
@@ -0,0 +1,257 @@
+//! Checkpoint rings: floating torus gates the drone flies through.
+//!
+//! A [`Gate`] hovers [`GatesConfig::height`] above a hex (spawn it with [`gate_bundle`] as a
+//! child of the [`HCell`](crate::h_terrain::HCell)) and faces along [`Gate::normal`].
+//! [`dress_gates`] gives new gates the shared ring mesh and glow material. Each frame
+//! [`detect_gates`] checks the drone's path since the last frame against every gate's plane:
+//! crossing it within [`GatesConfig::radius`] of the center writes [`GatePassed`], crossing it
+//! up to [`GatesConfig::miss_factor`] radii out writes [`GateMissed`]. Farther crossings are
+//! ignored. The race mode chains gates into its courses.
+
+use bevy::prelude::*;
+
+use crate::GameState;
+use crate::drone::Player;
+use crate::h_terrain::HTerrainPhase;
+
+/// Per-plugin configuration for gates.
+#[derive(Resource, Clone, Debug, Reflect)]
+pub struct GatesConfig {
+    /// Height of the ring center above the hex face.
+    pub height: f32,
+    /// Inner radius the drone must pass within.
+    pub radius: f32,
+    /// Ring tube radius.
+    pub thickness: f32,
+    /// Crossings out to this many radii count as a miss.
+    pub miss_factor: f32,
+    /// Ring color.
+    pub color: Color,
+    /// Emissive multiplier on `color` (drives bloom).
+    pub glow: f32,
+}
+
+impl Default for GatesConfig {
+    fn default() -> Self {
+        Self {
+            height: 8.0,
+            radius: 3.0,
+            thickness: 0.2,
+            miss_factor: 3.0,
+            color: Color::srgb(0.2, 0.6, 1.0),
+            glow: 6.0,
+        }
+    }
+}
+
+/// A ring to fly through.
+#[derive(Component, Clone, Copy, Debug, Reflect)]
+pub struct Gate {
+    /// Caller-chosen id reported in [`GatePassed`] / [`GateMissed`] (the race's checkpoint index).
+    pub id: usize,
+    /// Horizontal unit direction the ring faces (its axis).
+    pub normal: Vec3,
+}
+
+/// The drone flew through a gate.
+#[derive(Message, Clone, Copy, Debug, PartialEq)]
+pub struct GatePassed {
+    /// The gate entity.
+    pub gate: Entity,
+    /// Its [`Gate::id`].
+    pub id: usize,
+}
+
+/// The drone crossed a gate's plane outside the ring.
+#[derive(Message, Clone, Copy, Debug, PartialEq)]
+pub struct GateMissed {
+    /// The gate entity.
+    pub gate: Entity,
+    /// Its [`Gate::id`].
+    pub id: usize,
+}
+
+/// Shared ring mesh and material.
+#[derive(Resource)]
+struct GateAssets {
+    mesh: Handle<Mesh>,
+    material: Handle<StandardMaterial>,
+}
+
+/// Gate `id` facing `normal`, to spawn as a child of its hex cell.
+pub fn gate_bundle(id: usize, normal: Vec3, cfg: &GatesConfig) -> impl Bundle {
+    let normal = normal.with_y(0.0).try_normalize().unwrap_or(Vec3::Z);
+    (
+        Name::new("Gate"),
+        Gate { id, normal },
+        // The torus lies in the XZ plane: stand it up so its axis is the normal.
+        Transform::from_xyz(0.0, cfg.height, 0.0)
+            .with_rotation(Quat::from_rotation_arc(Vec3::Y, normal)),
+    )
+}
+
+/// Distance from `center` at which the segment `from → to` crosses the plane through
+/// `center` with `normal`, or `None` when it doesn't cross (either direction counts).
+pub fn crossing_offset(from: Vec3, to: Vec3, center: Vec3, normal: Vec3) -> Option<f32> {
+    let d0 = (from - center).dot(normal);
+    let d1 = (to - center).dot(normal);
+    if (d0 < 0.0) == (d1 < 0.0) {
+        return None;
+    }
+    let hit = from.lerp(to, d0 / (d0 - d1));
+    Some(hit.distance(center))
+}
+
+/// Gates plugin.
+pub struct GatesPlugin {
+    /// Per-plugin configuration.
+    pub config: GatesConfig,
+}
+
+impl Plugin for GatesPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<GatesConfig>()
+            .register_type::<Gate>()
+            .add_message::<GatePassed>()
+            .add_message::<GateMissed>()
+            .insert_resource(self.config.clone())
+            .add_systems(Startup, create_gate_assets)
+            .add_systems(Update, dress_gates.run_if(any_match_filter::<Added<Gate>>))
+            .add_systems(
+                Update,
+                detect_gates
+                    .after(HTerrainPhase::UpdateGround)
+                    .run_if(in_state(GameState::Running)),
+            );
+    }
+}
+
+/// Builds the shared ring mesh and emissive material.
+fn create_gate_assets(
+    cfg: Res<GatesConfig>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut commands: Commands,
+) {
+    commands.insert_resource(GateAssets {
+        mesh: meshes.add(Torus::new(cfg.radius, cfg.radius + cfg.thickness)),
+        material: materials.add(StandardMaterial {
+            base_color: cfg.color,
+            emissive: LinearRgba::from(cfg.color) * cfg.glow,
+            ..default()
+        }),
+    });
+}
+
+/// Gives new gates the ring mesh and material.
+fn dress_gates(assets: Res<GateAssets>, gates: Query<Entity, Added<Gate>>, mut commands: Commands) {
+    for gate in &gates {
+        commands.entity(gate).insert((
+            Mesh3d(assets.mesh.clone()),
+            MeshMaterial3d(assets.material.clone()),
+        ));
+    }
+}
+
+/// Checks the drone's path since last frame against every gate.
+pub fn detect_gates(
+    cfg: Res<GatesConfig>,
+    drone: Single<&GlobalTransform, With<Player>>,
+    gates: Query<(Entity, Ref<Gate>, &GlobalTransform)>,
+    mut last: Local<Option<Vec3>>,
+    mut passed: MessageWriter<GatePassed>,
+    mut missed: MessageWriter<GateMissed>,
+) {
+    let now = drone.translation();
+    let Some(from) = last.replace(now) else {
+        return;
+    };
+    for (entity, gate, gt) in &gates {
+        // Fresh gates sit at the origin until transforms propagate.
+        if gate.is_added() {
+            continue;
+        }
+        let Some(offset) = crossing_offset(from, now, gt.translation(), gate.normal) else {
+            continue;
+        };
+        if offset <= cfg.radius {
+            passed.write(GatePassed {
+                gate: entity,
+                id: gate.id,
+            });
+        } else if offset <= cfg.radius * cfg.miss_factor {
+            missed.write(GateMissed {
+                gate: entity,
+                id: gate.id,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crossings_report_the_offset_from_center() {
+        let c = Vec3::new(0.0, 5.0, 0.0);
+        let n = Vec3::Z;
+        let through = crossing_offset(Vec3::new(1.0, 5.0, -2.0), Vec3::new(1.0, 5.0, 2.0), c, n);
+        assert_eq!(through, Some(1.0));
+        // Backwards counts too.
+        let back = crossing_offset(Vec3::new(0.0, 9.0, 1.0), Vec3::new(0.0, 9.0, -3.0), c, n);
+        assert_eq!(back, Some(4.0));
+        // Staying on one side, or sliding along the plane, doesn't.
+        assert_eq!(
+            crossing_offset(Vec3::new(0.0, 5.0, 1.0), Vec3::new(0.0, 5.0, 3.0), c, n),
+            None
+        );
+        assert_eq!(crossing_offset(c, c + Vec3::X, c, n), None);
+    }
+
+    #[test]
+    fn flying_through_passes_and_beside_misses() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, TransformPlugin))
+            .add_message::<GatePassed>()
+            .add_message::<GateMissed>()
+            .init_resource::<GatesConfig>()
+            .add_systems(Update, detect_gates);
+        let cfg = GatesConfig::default();
+        let near = app.world_mut().spawn(gate_bundle(0, Vec3::Z, &cfg)).id();
+        let far = app
+            .world_mut()
+            .spawn(Transform::from_xyz(7.0, 0.0, 0.0))
+            .with_child(gate_bundle(1, Vec3::Z, &cfg))
+            .id();
+        let start = Transform::from_xyz(0.5, cfg.height, -1.0);
+        let drone = app
+            .world_mut()
+            .spawn((Player, start, GlobalTransform::from(start)))
+            .id();
+        app.update();
+        app.update();
+        // Global transforms propagate in PostUpdate; the crossing shows up a frame later.
+        app.world_mut()
+            .get_mut::<Transform>(drone)
+            .unwrap()
+            .translation
+            .z = 1.0;
+        app.update();
+        app.update();
+
+        let passed = app.world().resource::<Messages<GatePassed>>();
+        let passed: Vec<_> = passed.iter_current_update_messages().collect();
+        assert_eq!(passed, [&GatePassed { gate: near, id: 0 }]);
+        let missed = app.world().resource::<Messages<GateMissed>>();
+        let missed: Vec<_> = missed.iter_current_update_messages().collect();
+        let far_gate = app.world().get::<Children>(far).unwrap()[0];
+        assert_eq!(
+            missed,
+            [&GateMissed {
+                gate: far_gate,
+                id: 1
+            }]
+        );
+    }
+}
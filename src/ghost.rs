@@ -0,0 +1,497 @@
+//! Flight recording and ghost replay.
+//!
+//! R ([`toggle_recording`]) starts and stops recording: while on, [`record_flight`] samples
+//! the camera pose every [`GhostConfig::sample_secs`] of virtual time (up to
+//! [`GhostConfig::max_secs`]). A stopped recording becomes the [`SavedFlight`], persisted to
+//! [`GhostConfig::path`] when [`GhostConfig::persist`] is set (native only) and loaded again at
+//! startup. O ([`toggle_ghost`]) replays it as a translucent [`Ghost`] drone flying the old
+//! path at its recorded pace while the player keeps flying, for comparing routes or racing a
+//! previous run. [`fly_ghost`] moves it along [`FlightRecording::pose_at`], and the path is
+//! drawn as a faint trail.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::controls::{Binding, BindingCategory, RegisterBinding, Trigger};
+use crate::drone::Player;
+use crate::locale::Locale;
+use crate::race::format_race_time;
+use crate::toast::Toast;
+use crate::{GameState, persist};
+
+/// Per-plugin configuration for flight recording and ghosts.
+#[derive(Resource, Clone, Debug, Reflect)]
+pub struct GhostConfig {
+    /// Seconds of virtual time between recorded samples.
+    pub sample_secs: f32,
+    /// Longest recording; recording stops on its own past this.
+    pub max_secs: f32,
+    /// Save the last recording to [`GhostConfig::path`] and load it at startup (native only).
+    pub persist: bool,
+    /// File the last recording is persisted to.
+    pub path: String,
+    /// Restart the ghost when it reaches the end instead of removing it.
+    pub looped: bool,
+    /// Ghost body width in world units.
+    pub size: f32,
+    /// Ghost color (alpha is its translucency).
+    pub color: Color,
+    /// Emissive multiplier on `color` (drives bloom).
+    pub glow: f32,
+    /// Draw the recorded path as a trail while the ghost flies.
+    pub trail: bool,
+}
+
+impl Default for GhostConfig {
+    fn default() -> Self {
+        Self {
+            sample_secs: 0.1,
+            max_secs: 600.0,
+            persist: true,
+            path: "flight.ron".into(),
+            looped: false,
+            size: 0.8,
+            color: Color::srgba(0.6, 0.85, 1.0, 0.35),
+            glow: 3.0,
+            trail: true,
+        }
+    }
+}
+
+/// One recorded camera pose.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FlightSample {
+    /// Seconds since the recording started.
+    pub t: f32,
+    /// Camera position.
+    pub position: [f32; 3],
+    /// Camera rotation quaternion `[x, y, z, w]`.
+    pub rotation: [f32; 4],
+}
+
+/// A recorded flight, samples in time order.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct FlightRecording {
+    /// Recorded poses, oldest first.
+    pub samples: Vec<FlightSample>,
+}
+
+impl FlightRecording {
+    /// Appends the pose at `t` seconds.
+    pub fn push(&mut self, t: f32, position: Vec3, rotation: Quat) {
+        self.samples.push(FlightSample {
+            t,
+            position: position.to_array(),
+            rotation: rotation.to_array(),
+        });
+    }
+
+    /// Seconds from the first sample to the last.
+    pub fn duration(&self) -> f32 {
+        match (self.samples.first(), self.samples.last()) {
+            (Some(first), Some(last)) => last.t - first.t,
+            _ => 0.0,
+        }
+    }
+
+    /// Pose `t` seconds into the recording, interpolated between samples and held at the
+    /// ends; `None` without samples.
+    pub fn pose_at(&self, t: f32) -> Option<(Vec3, Quat)> {
+        let first = self.samples.first()?;
+        let t = first.t + t;
+        let i = self.samples.partition_point(|s| s.t <= t);
+        let pose = |s: &FlightSample| {
+            (
+                Vec3::from_array(s.position),
+                Quat::from_array(s.rotation).normalize(),
+            )
+        };
+        let (Some(a), Some(b)) = (
+            i.checked_sub(1).map(|i| &self.samples[i]),
+            self.samples.get(i),
+        ) else {
+            return self.samples.get(i.saturating_sub(1)).map(pose);
+        };
+        let s = ((t - a.t) / (b.t - a.t).max(f32::EPSILON)).clamp(0.0, 1.0);
+        let ((pa, ra), (pb, rb)) = (pose(a), pose(b));
+        Some((pa.lerp(pb, s), ra.slerp(rb, s)))
+    }
+}
+
+/// Recording in progress (`None` while not recording).
+#[derive(Resource, Default)]
+pub struct Recorder(pub Option<Recording>);
+
+/// State of a recording in progress.
+#[derive(Debug, Default)]
+pub struct Recording {
+    /// Seconds since the recording started.
+    pub elapsed: f32,
+    /// Seconds since the last sample.
+    pub since_sample: f32,
+    /// Samples so far.
+    pub flight: FlightRecording,
+}
+
+/// The last finished recording, replayed by the ghost.
+#[derive(Resource, Default)]
+pub struct SavedFlight(pub Option<FlightRecording>);
+
+/// A replaying ghost drone.
+#[derive(Component, Reflect)]
+pub struct Ghost {
+    /// Seconds into the recording.
+    pub elapsed: f32,
+}
+
+/// Shared ghost mesh and material.
+#[derive(Resource)]
+struct GhostAssets {
+    mesh: Handle<Mesh>,
+    material: Handle<StandardMaterial>,
+}
+
+/// Flight recording and ghost replay plugin.
+pub struct GhostPlugin {
+    /// Per-plugin configuration.
+    pub config: GhostConfig,
+}
+
+impl Plugin for GhostPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<GhostConfig>()
+            .register_type::<Ghost>()
+            .insert_resource(self.config.clone())
+            .init_resource::<Recorder>()
+            .init_resource::<SavedFlight>()
+            .add_systems(Startup, create_ghost_assets);
+
+        app.add_systems(
+            Startup,
+            load_saved_flight.run_if(|cfg: Res<GhostConfig>| cfg.persist),
+        );
+
+        app.add_systems(
+            Update,
+            (
+                toggle_recording,
+                record_flight.run_if(|recorder: Res<Recorder>| recorder.0.is_some()),
+                toggle_ghost,
+                (
+                    fly_ghost,
+                    draw_ghost_trail.run_if(|cfg: Res<GhostConfig>| cfg.trail),
+                )
+                    .chain()
+                    .run_if(any_with_component::<Ghost>),
+            )
+                .chain()
+                .after(crate::drone::systems::fly)
+                .run_if(in_state(GameState::Running)),
+        )
+        .register_binding(Binding::new(
+            BindingCategory::Drone,
+            "Start / stop flight recording",
+            [Trigger::Key(KeyCode::KeyR)],
+        ))
+        .register_binding(Binding::new(
+            BindingCategory::Drone,
+            "Replay the recorded flight as a ghost",
+            [Trigger::Key(KeyCode::KeyO)],
+        ));
+    }
+}
+
+/// Builds the shared flattened-sphere ghost mesh and translucent emissive material.
+fn create_ghost_assets(
+    cfg: Res<GhostConfig>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut commands: Commands,
+) {
+    commands.insert_resource(GhostAssets {
+        mesh: meshes.add(Sphere::new(0.5)),
+        material: materials.add(StandardMaterial {
+            base_color: cfg.color,
+            emissive: LinearRgba::from(cfg.color.with_alpha(1.0)) * cfg.glow,
+            alpha_mode: AlphaMode::Blend,
+            ..default()
+        }),
+    });
+}
+
+/// Reads the persisted recording from disk, if present.
+fn load_saved_flight(cfg: Res<GhostConfig>, mut saved: ResMut<SavedFlight>) {
+    let flight: FlightRecording = persist::load_ron(&cfg.path, "flight recording");
+    if !flight.samples.is_empty() {
+        saved.0 = Some(flight);
+    }
+}
+
+/// Writes the recording to disk when [`GhostConfig::persist`] is set (no-op on WASM).
+fn save_flight(flight: &FlightRecording, cfg: &GhostConfig) {
+    if cfg.persist {
+        persist::save_ron(flight, &cfg.path, "flight recording");
+    }
+}
+
+/// Moves a finished recording into [`SavedFlight`] and persists it.
+fn finish_recording(
+    recording: Recording,
+    cfg: &GhostConfig,
+    saved: &mut SavedFlight,
+    toasts: &mut MessageWriter<Toast>,
+    locale: &Locale,
+) {
+    let flight = recording.flight;
+    toasts.write(Toast(locale.fmt(
+        "Flight recorded: {0}",
+        &[&format_race_time(flight.duration())],
+    )));
+    save_flight(&flight, cfg);
+    saved.0 = Some(flight);
+}
+
+/// R starts a recording, or stops and keeps the current one.
+fn toggle_recording(
+    keys: Res<ButtonInput<KeyCode>>,
+    cfg: Res<GhostConfig>,
+    mut recorder: ResMut<Recorder>,
+    mut saved: ResMut<SavedFlight>,
+    mut toasts: MessageWriter<Toast>,
+    locale: Res<Locale>,
+) {
+    if !keys.just_pressed(KeyCode::KeyR) {
+        return;
+    }
+    match recorder.0.take() {
+        Some(recording) => finish_recording(recording, &cfg, &mut saved, &mut toasts, &locale),
+        None => {
+            recorder.0 = Some(Recording {
+                // Sample on the first frame.
+                since_sample: cfg.sample_secs,
+                ..default()
+            });
+            toasts.write(Toast(locale.t("Recording flight").into()));
+        }
+    }
+}
+
+/// Samples the camera pose; stops the recording at [`GhostConfig::max_secs`].
+fn record_flight(
+    time: Res<Time>,
+    cfg: Res<GhostConfig>,
+    camera: Single<&GlobalTransform, With<Player>>,
+    mut recorder: ResMut<Recorder>,
+    mut saved: ResMut<SavedFlight>,
+    mut toasts: MessageWriter<Toast>,
+    locale: Res<Locale>,
+) {
+    let Some(recording) = recorder.0.as_mut() else {
+        return;
+    };
+    let dt = time.delta_secs();
+    recording.elapsed += dt;
+    recording.since_sample += dt;
+    if recording.since_sample >= cfg.sample_secs {
+        recording.since_sample = 0.0;
+        let (_, rotation, position) = camera.to_scale_rotation_translation();
+        recording.flight.push(recording.elapsed, position, rotation);
+    }
+    if recording.elapsed >= cfg.max_secs
+        && let Some(recording) = recorder.0.take()
+    {
+        finish_recording(recording, &cfg, &mut saved, &mut toasts, &locale);
+    }
+}
+
+/// O spawns a ghost for the saved flight, or removes the one flying.
+#[allow(clippy::too_many_arguments)]
+fn toggle_ghost(
+    keys: Res<ButtonInput<KeyCode>>,
+    cfg: Res<GhostConfig>,
+    assets: Res<GhostAssets>,
+    saved: Res<SavedFlight>,
+    ghosts: Query<Entity, With<Ghost>>,
+    mut toasts: MessageWriter<Toast>,
+    locale: Res<Locale>,
+    mut commands: Commands,
+) {
+    if !keys.just_pressed(KeyCode::KeyO) {
+        return;
+    }
+    if !ghosts.is_empty() {
+        for ghost in &ghosts {
+            commands.entity(ghost).despawn();
+        }
+        toasts.write(Toast(locale.t("Ghost stopped").into()));
+        return;
+    }
+    let Some((position, rotation)) = saved.0.as_ref().and_then(|f| f.pose_at(0.0)) else {
+        toasts.write(Toast(locale.t("No recorded flight (R to record)").into()));
+        return;
+    };
+    commands.spawn((
+        Name::new("Ghost"),
+        Ghost { elapsed: 0.0 },
+        Mesh3d(assets.mesh.clone()),
+        MeshMaterial3d(assets.material.clone()),
+        Transform {
+            translation: position,
+            rotation,
+            scale: Vec3::new(cfg.size, cfg.size * 0.35, cfg.size),
+        },
+    ));
+    toasts.write(Toast(locale.t("Ghost replay").into()));
+}
+
+/// Advances each ghost along the saved flight at its recorded pace.
+fn fly_ghost(
+    time: Res<Time>,
+    cfg: Res<GhostConfig>,
+    saved: Res<SavedFlight>,
+    mut ghosts: Query<(Entity, &mut Ghost, &mut Transform)>,
+    mut toasts: MessageWriter<Toast>,
+    locale: Res<Locale>,
+    mut commands: Commands,
+) {
+    for (entity, mut ghost, mut tf) in &mut ghosts {
+        let Some(flight) = saved.0.as_ref() else {
+            commands.entity(entity).despawn();
+            continue;
+        };
+        ghost.elapsed += time.delta_secs();
+        if ghost.elapsed > flight.duration() {
+            if !cfg.looped {
+                commands.entity(entity).despawn();
+                toasts.write(Toast(locale.t("Ghost finished").into()));
+                continue;
+            }
+            ghost.elapsed = 0.0;
+        }
+        if let Some((position, rotation)) = flight.pose_at(ghost.elapsed) {
+            tf.translation = position;
+            tf.rotation = rotation;
+        }
+    }
+}
+
+/// Draws the saved flight's path as a faint line.
+fn draw_ghost_trail(mut gizmos: Gizmos, cfg: Res<GhostConfig>, saved: Res<SavedFlight>) {
+    let Some(flight) = saved.0.as_ref() else {
+        return;
+    };
+    gizmos.linestrip(
+        flight.samples.iter().map(|s| Vec3::from_array(s.position)),
+        cfg.color.with_alpha(cfg.color.alpha() * 0.5),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::state::app::StatesPlugin;
+    use bevy::time::TimeUpdateStrategy;
+
+    use super::*;
+
+    fn line(points: &[(f32, Vec3)]) -> FlightRecording {
+        let mut flight = FlightRecording::default();
+        for &(t, p) in points {
+            flight.push(t, p, Quat::IDENTITY);
+        }
+        flight
+    }
+
+    #[test]
+    fn poses_interpolate_between_samples_and_hold_at_the_ends() {
+        let mut flight = line(&[(1.0, Vec3::ZERO), (2.0, Vec3::X * 10.0)]);
+        flight.samples[1].rotation = Quat::from_rotation_y(1.0).to_array();
+        assert_eq!(flight.duration(), 1.0);
+        let (p, r) = flight.pose_at(0.5).unwrap();
+        assert!(p.abs_diff_eq(Vec3::X * 5.0, 1e-5));
+        assert!(r.abs_diff_eq(Quat::from_rotation_y(0.5), 1e-5));
+        assert_eq!(flight.pose_at(-1.0).unwrap().0, Vec3::ZERO);
+        assert_eq!(flight.pose_at(5.0).unwrap().0, Vec3::X * 10.0);
+        assert_eq!(FlightRecording::default().pose_at(0.0), None);
+    }
+
+    #[test]
+    fn a_recorded_flight_replays_as_a_ghost_beside_the_player() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin, AssetPlugin::default()))
+            .init_asset::<Mesh>()
+            .init_asset::<StandardMaterial>()
+            .insert_state(GameState::Running)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                100,
+            )))
+            .init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<Locale>()
+            .add_message::<Toast>()
+            .add_plugins(GhostPlugin {
+                config: GhostConfig {
+                    persist: false,
+                    trail: false,
+                    ..default()
+                },
+            });
+        let player = app
+            .world_mut()
+            .spawn((Player, GlobalTransform::default()))
+            .id();
+        let press = |app: &mut App, key| {
+            let mut keys = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+            keys.release_all();
+            keys.clear();
+            keys.press(key);
+            app.update();
+            app.world_mut()
+                .resource_mut::<ButtonInput<KeyCode>>()
+                .clear();
+        };
+        let fly_to = |app: &mut App, x: f32| {
+            let tf = GlobalTransform::from_translation(Vec3::new(x, 5.0, 0.0));
+            app.world_mut().entity_mut(player).insert(tf);
+            app.update();
+        };
+
+        app.update();
+        // Nothing to replay yet.
+        press(&mut app, KeyCode::KeyO);
+        let w = app.world_mut();
+        assert_eq!(w.query::<&Ghost>().iter(w).count(), 0);
+
+        press(&mut app, KeyCode::KeyR);
+        for x in 1..=10 {
+            fly_to(&mut app, x as f32);
+        }
+        press(&mut app, KeyCode::KeyR);
+        let saved = app.world().resource::<SavedFlight>().0.clone().unwrap();
+        assert!(app.world().resource::<Recorder>().0.is_none());
+        assert_eq!(saved.samples.first().unwrap().position, [0.0, 0.0, 0.0]);
+        assert_eq!(saved.samples.last().unwrap().position, [10.0, 5.0, 0.0]);
+
+        // The ghost retraces the path while the player stays put.
+        press(&mut app, KeyCode::KeyO);
+        for _ in 0..5 {
+            app.update();
+        }
+        let w = app.world_mut();
+        let (ghost, tf) = w.query::<(&Ghost, &Transform)>().single(w).unwrap();
+        let expected = saved.pose_at(ghost.elapsed).unwrap().0;
+        assert!(tf.translation.abs_diff_eq(expected, 1e-4));
+        assert!(tf.translation.x > 0.0 && tf.translation.x < 10.0);
+        assert_eq!(
+            w.get::<GlobalTransform>(player).unwrap().translation().x,
+            10.0
+        );
+
+        // It leaves at the end of the recording.
+        for _ in 0..20 {
+            app.update();
+        }
+        let w = app.world_mut();
+        assert_eq!(w.query::<&Ghost>().iter(w).count(), 0);
+    }
+}
@@ -1,27 +1,72 @@
 //! Height-based terrain: pivot-point grid with per-hex corners.
 
+mod debug_view;
+mod edge_material;
 mod entities;
+mod environment;
+mod face_grid;
 mod gaps;
+mod glitch;
+mod grid_floor;
 mod h_grid_layout;
+mod heatmap;
+mod hex_panel;
+mod labels;
+mod landmarks;
+mod lens_flare;
 pub(crate) mod materials;
 mod math;
+mod minimap;
+mod notes;
+mod outline;
+mod pathfinding;
+mod picking;
+mod reveal_fx;
+mod reveal_strategy;
+mod scanner;
+mod sightline;
 mod startup_systems;
+mod stats;
+mod sun;
 mod systems;
+mod target;
 #[cfg(test)]
 mod tests;
+mod theme;
+mod tooltip;
 
+use bevy::asset::embedded_asset;
+use bevy::diagnostic::{Diagnostic, DiagnosticPath, RegisterDiagnostic};
 use bevy::ecs::schedule::InternedSystemSet;
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
+use crate::controls::{Binding, BindingCategory, RegisterBinding, Trigger};
 use crate::{DebugFlag, GameState};
 
-pub use entities::InSight;
-pub use materials::OrigPalette;
+pub use entities::{
+    EditHexHeight, HCell, HGrid, HexFace, HexPicked, HexRevealed, InFov, InSight, Quad, QuadEdge,
+    RegenerateTerrain, Revealed, TeleportToHex, Tri, Visited,
+};
+pub use h_grid_layout::{HGridLayout, NoiseSample};
+pub use labels::HexLabelMode;
+pub use landmarks::LandmarkMarker;
 pub use math::edge_cuboid_transform;
+pub use notes::HexNotes;
+pub use picking::{HoveredHex, SelectedHex};
+pub use reveal_fx::RevealParticle;
+pub use reveal_strategy::RevealStrategy;
+pub use scanner::{RevealMode, ScannerCooldown, ScannerPulse};
+pub use stats::TerrainStats;
+pub use target::{TargetHex, TargetKind, classify_target};
+pub use theme::{FaceFinish, GlowLevel, HeightGradient, Palette, Theme, ThemeKind, look_changed};
 
 /// Pipeline ordering for h_terrain update systems.
 #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
 pub enum HTerrainPhase {
+    /// Samples [`GroundAhead`](crate::GroundAhead) before player movement, so the flight
+    /// system reads a look-ahead computed the same frame.
+    LookAhead,
     /// Sets [`GroundLevel`](crate::GroundLevel) from terrain interpolation.
     UpdateGround,
     /// Tags nearby [`entities::HCell`] entities with [`entities::InFov`].
@@ -30,8 +75,38 @@ pub enum HTerrainPhase {
     Highlight,
     /// Raycasts screen center to tag the aimed hex face with [`InSight`].
     Sight,
+    /// Turns left clicks in inspect mode into [`HexPicked`]; other plugins that own the
+    /// click (e.g. the territory brush) gate this set off.
+    PickHex,
 }
 
+/// Heights of the face-mesh overlays above their hex face, lowest first.
+///
+/// An overlay is a child of a [`HexFace`] that reuses the face mesh, lifted by one of these
+/// heights and tinted by its feature. Every overlay gets its own layer so two of them on the
+/// same hex never z-fight. Because an overlay would win any ray cast against the face below,
+/// the aim (`materials::find_aimed_hex_face`), tooltip, and lens-flare casts filter their hits
+/// to hex faces: covered hexes stay aimable and minable.
+pub mod overlay_lift {
+    /// Claim tint ([`crate::claims`]).
+    pub const CLAIM: f32 = 0.004;
+    /// Hazard tint ([`crate::hazards`]).
+    pub const HAZARD: f32 = 0.0045;
+    /// Charger tint ([`crate::energy`]).
+    pub const CHARGER: f32 = 0.005;
+    /// Teleporter pad tint ([`crate::teleporters`]).
+    pub const TELEPORTER: f32 = 0.0055;
+    /// Height heatmap bands.
+    pub const HEATMAP: f32 = 0.006;
+    /// Selected-hex line-of-sight view.
+    pub const SIGHTLINE: f32 = 0.007;
+    /// Face grid lines.
+    pub const FACE_GRID: f32 = 0.01;
+}
+
+/// Duration of the last FoV reveal pass ([`systems::track_player_fov`]), in milliseconds.
+pub const REVEAL_TIME: DiagnosticPath = DiagnosticPath::const_new("h_terrain/reveal_time");
+
 /// Laser mining strength, controlling extraction rate and tick interval.
 #[derive(Resource, Reflect)]
 pub struct LaserStrength {
@@ -53,19 +128,166 @@ impl Default for LaserStrength {
     }
 }
 
+/// The hex the player is currently over, updated in [`HTerrainPhase::TrackFov`].
+#[derive(Resource, Default, Reflect)]
+pub struct ActiveHex {
+    /// Hex coordinate under the player (`None` before the first update).
+    #[reflect(ignore)]
+    pub current: Option<hexx::Hex>,
+    /// The [`entities::HCell`] entity at `current` (`None` off the grid).
+    pub cell: Option<Entity>,
+}
+
 /// Configuration for the height-based terrain subsystem.
 #[derive(Resource, Clone, Debug, Reflect)]
 pub struct HTerrainConfig {
     /// Grid generation settings.
     pub grid: HGridSettings,
-    /// Background clear color.
-    pub clear_color: Color,
+    /// Color theme preset for materials and the clear color.
+    pub theme: ThemeKind,
+    /// Color-vision palette applied on top of the theme preset.
+    pub palette: Palette,
     /// Duration of the fov highlight fade in seconds.
     pub fov_transition_secs: f32,
+    /// Distance ahead of the player (world units) sampled for [`GroundAhead`](crate::GroundAhead).
+    pub lookahead_distance: f32,
+    /// Number of evenly spaced height samples taken along the look-ahead.
+    pub lookahead_samples: u32,
+    /// Minimum edge line width in world units.
+    pub edge_width: f32,
+    /// Edge line width per unit of camera distance (keeps far lines visible).
+    pub edge_angular_width: f32,
+    /// Camera distance where edge lines start to fade.
+    pub edge_fade_start: f32,
+    /// Camera distance where edge lines are fully faded.
+    pub edge_fade_end: f32,
+    /// Edge pulse angular speed (radians per second).
+    pub edge_pulse_speed: f32,
+    /// Edge pulse brightness swing as a fraction of the theme emissive (0 disables).
+    pub edge_pulse_amplitude: f32,
+    /// Pulse phase lag per hex ring outward from the origin (radians).
+    pub edge_pulse_ring_phase: f32,
+    /// Draw the infinite neon grid plane at y=0 beneath the terrain.
+    pub grid_floor: bool,
+    /// Distance between grid floor lines in world units.
+    pub grid_floor_cell_size: f32,
+    /// Grid floor line brightness as a fraction of the theme edge color.
+    pub grid_floor_intensity: f32,
+    /// Camera distance where the grid floor is fully faded (also the plane's half-size).
+    pub grid_floor_fade_end: f32,
+    /// Draw procedural ring/spoke lines on every hex face.
+    pub face_grid: bool,
+    /// Face grid rings per world unit of hex radius.
+    pub face_grid_ring_density: f32,
+    /// Face grid line brightness as a fraction of the theme edge color.
+    pub face_grid_intensity: f32,
+    /// Environment map brightness for reflective face themes (cd/m²).
+    pub reflection_intensity: f32,
+    /// Outline the selected hex face (aimed face while flying, active hex in inspect mode).
+    pub selection_outline: bool,
+    /// Width of each selection outline band (bright line, dark halo) in world units.
+    pub selection_outline_width: f32,
+    /// Low-graphics look for the no-HDR pipeline: the theme is [`Theme::flattened`] into
+    /// brighter unlit colors without emissive glow.
+    pub low_gfx: bool,
+    /// Face/gap opacity while the theme is in hologram mode.
+    pub hologram_opacity: f32,
+    /// Dim hex faces of cells that have never been revealed (fog of war).
+    pub fog_of_war: bool,
+    /// Brightness of fogged faces as a fraction of their theme color and emissive.
+    pub fog_of_war_brightness: f32,
+    /// Occasionally desaturate and jitter random edge lines for a few frames.
+    pub glitch: bool,
+    /// Average edge glitches started per second.
+    pub glitch_rate: f32,
+    /// Frames each glitch lasts.
+    pub glitch_frames: u32,
+    /// Maximum per-axis edge jitter while glitching (world units).
+    pub glitch_jitter: f32,
+    /// Draw horizontal lens-flare streaks over the highest visible peaks.
+    pub lens_flare: bool,
+    /// Number of peaks that get a flare.
+    pub lens_flare_count: usize,
+    /// Streak length in logical pixels.
+    pub lens_flare_width: f32,
+    /// Angle off the view direction (radians) at which a flare has faded out.
+    pub lens_flare_fade_angle: f32,
+    /// Flare opacity easing rate (per second).
+    pub lens_flare_fade_speed: f32,
+    /// Show a tooltip with hex info for the face under the cursor (Inspecting) or crosshair.
+    pub hex_tooltip: bool,
+    /// What the floating per-hex labels show ([`HexLabelMode::Off`] hides them).
+    pub hex_labels: HexLabelMode,
+    /// Camera distance beyond which hex labels are hidden.
+    pub hex_label_distance: f32,
+    /// Hex label font size in logical pixels.
+    pub hex_label_font_size: f32,
+    /// Height change per nudge button in the hex inspection panel.
+    pub hex_edit_step: f32,
+    /// Camera distance beyond which hex notes are hidden.
+    pub hex_note_distance: f32,
+    /// Extra path cost per unit of height difference between neighboring hexes.
+    pub path_climb_cost: f32,
+    /// Height difference above which a path step is impassable.
+    pub path_max_climb: f32,
+    /// Path segment thickness in world units.
+    pub path_width: f32,
+    /// Eye height above the origin hex for line-of-sight checks.
+    pub sight_eye_height: f32,
+    /// Height above a target hex the eye has to see for it to count as visible.
+    pub sight_target_height: f32,
+    /// Show the corner minimap of revealed hexes.
+    pub minimap: bool,
+    /// Minimap side length in logical pixels.
+    pub minimap_size: f32,
+    /// Particles emitted around a hex's perimeter when it enters the FoV (0 disables).
+    pub reveal_particle_count: u32,
+    /// Reveal particle lifetime in seconds.
+    pub reveal_particle_lifetime: f32,
+    /// Duration of the hex face scale-in on first reveal (0 disables).
+    pub reveal_anim_secs: f32,
+    /// Scale-in delay per hex ring away from the player's hex.
+    pub reveal_stagger_secs: f32,
+    /// How hexes get revealed: entering the FoV, or scanner pulses (F).
+    pub reveal_mode: RevealMode,
+    /// Shape of the FoV: the `fov_reach` ring, or the camera's forward cone.
+    pub reveal_strategy: RevealStrategy,
+    /// Scanner pulse wavefront speed (world units per second).
+    pub scanner_speed: f32,
+    /// Radius at which a scanner pulse fades out.
+    pub scanner_range: f32,
+    /// Seconds between scanner pulses.
+    pub scanner_cooldown_secs: f32,
+    /// Adds a height-derived glow to every hex face (`None` keeps the flat theme emissive).
+    pub face_height_gradient: Option<HeightGradient>,
+    /// Spawn a shadow-casting directional sun light for a lit view of the terrain.
+    pub sun: bool,
+    /// Sun illuminance in lux.
+    pub sun_illuminance: f32,
+    /// Sun angle above the horizon in degrees.
+    pub sun_elevation: f32,
+    /// Sun compass direction in degrees (rotation about +Y).
+    pub sun_azimuth: f32,
+    /// Camera distance covered by the shadow cascades.
+    pub sun_shadow_distance: f32,
+    /// Active-hex face pulse angular speed (radians per second).
+    pub active_pulse_speed: f32,
+    /// Active-hex face pulse peak as a multiple of the FoV face emissive (0 disables).
+    pub active_pulse_amplitude: f32,
+    /// Build landmark structures on the highest peak, deepest basin, and widest cell.
+    pub landmarks: bool,
+    /// Landmark structure height above its face.
+    pub landmark_height: f32,
+    /// Landmark beam thickness in world units.
+    pub landmark_width: f32,
+    /// Landmark beam color; also marks landmarks on the minimap.
+    pub landmark_color: Color,
+    /// Emissive multiplier on `landmark_color` (drives bloom).
+    pub landmark_glow: f32,
 }
 
 /// Grid layout and noise parameters.
-#[derive(Clone, Debug, Reflect)]
+#[derive(Clone, Debug, PartialEq, Reflect, Serialize, Deserialize)]
 pub struct HGridSettings {
     /// Number of hex rings around the origin (~1200 hexes at 20).
     pub radius: u32,
@@ -110,17 +332,96 @@ impl Default for HTerrainConfig {
                 min_hex_radius: 0.2,
                 max_hex_radius: 2.6,
             },
-            clear_color: OrigPalette::ClearColor.into(),
+            theme: ThemeKind::default(),
+            palette: Palette::default(),
             fov_transition_secs: 0.5,
+            lookahead_distance: 6.0,
+            lookahead_samples: 3,
+            edge_width: 0.03,
+            edge_angular_width: 0.002,
+            edge_fade_start: 40.0,
+            edge_fade_end: 110.0,
+            edge_pulse_speed: 3.0,
+            edge_pulse_amplitude: 0.6,
+            edge_pulse_ring_phase: 0.5,
+            grid_floor: true,
+            grid_floor_cell_size: 4.0,
+            grid_floor_intensity: 0.15,
+            grid_floor_fade_end: 150.0,
+            face_grid: true,
+            face_grid_ring_density: 1.5,
+            face_grid_intensity: 0.2,
+            reflection_intensity: 600.0,
+            selection_outline: true,
+            selection_outline_width: 0.06,
+            low_gfx: false,
+            hologram_opacity: 0.2,
+            fog_of_war: true,
+            fog_of_war_brightness: 0.25,
+            glitch: false,
+            glitch_rate: 4.0,
+            glitch_frames: 4,
+            glitch_jitter: 0.02,
+            lens_flare: false,
+            lens_flare_count: 3,
+            lens_flare_width: 240.0,
+            lens_flare_fade_angle: 0.6,
+            lens_flare_fade_speed: 6.0,
+            hex_tooltip: true,
+            hex_labels: HexLabelMode::Off,
+            hex_label_distance: 30.0,
+            hex_label_font_size: 12.0,
+            hex_edit_step: 0.25,
+            hex_note_distance: 60.0,
+            path_climb_cost: 0.5,
+            path_max_climb: 6.0,
+            path_width: 0.06,
+            sight_eye_height: 1.5,
+            sight_target_height: 0.5,
+            minimap: true,
+            minimap_size: 180.0,
+            reveal_particle_count: 12,
+            reveal_particle_lifetime: 0.8,
+            reveal_anim_secs: 0.4,
+            reveal_stagger_secs: 0.08,
+            reveal_mode: RevealMode::Passive,
+            reveal_strategy: RevealStrategy::Radial,
+            scanner_speed: 25.0,
+            scanner_range: 60.0,
+            scanner_cooldown_secs: 4.0,
+            face_height_gradient: None,
+            sun: false,
+            sun_illuminance: 8_000.0,
+            sun_elevation: 35.0,
+            sun_azimuth: 30.0,
+            sun_shadow_distance: 120.0,
+            active_pulse_speed: 4.0,
+            active_pulse_amplitude: 1.5,
+            landmarks: true,
+            landmark_height: 12.0,
+            landmark_width: 0.12,
+            landmark_color: Color::srgb(1.0, 0.2, 0.8),
+            landmark_glow: 5.0,
         }
     }
 }
 
+/// Run condition: the height heatmap analysis mode is on.
+fn heatmap_on(mode: Res<heatmap::HeatmapMode>) -> bool {
+    mode.0
+}
+
+/// Run condition: [`HTerrainConfig::face_height_gradient`] is set.
+fn has_height_gradient(cfg: Res<HTerrainConfig>) -> bool {
+    cfg.face_height_gradient.is_some()
+}
+
 /// Height-based terrain plugin.
 pub struct HTerrainPlugin {
     /// Terrain configuration.
     pub config: HTerrainConfig,
-    /// Optional system set that player-height updates must run after.
+    /// Optional system set that player-height updates must run after (and the ground
+    /// look-ahead before).
     pub after_player_movement: Option<InternedSystemSet>,
     /// Optional Startup set to place `seed_ground_level` in (for ordering).
     pub terrain_seeded_set: Option<InternedSystemSet>,
@@ -128,8 +429,22 @@ pub struct HTerrainPlugin {
 
 impl Plugin for HTerrainPlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<LaserStrength>()
+        let mut theme = Theme::from(self.config.theme).with_palette(self.config.palette);
+        if self.config.low_gfx {
+            theme = theme.flattened();
+        }
+        embedded_asset!(app, "h_terrain/edge_line.wgsl");
+        embedded_asset!(app, "h_terrain/grid_floor.wgsl");
+        embedded_asset!(app, "h_terrain/face_grid.wgsl");
+        app.add_plugins(MaterialPlugin::<edge_material::EdgeMaterial>::default())
+            .add_plugins(MaterialPlugin::<grid_floor::GridFloorMaterial>::default())
+            .add_plugins(MaterialPlugin::<face_grid::FaceGridMaterial>::default())
+            .init_resource::<LaserStrength>()
             .register_type::<LaserStrength>()
+            .init_resource::<ActiveHex>()
+            .register_type::<ActiveHex>()
+            .init_resource::<TargetHex>()
+            .register_type::<TargetHex>()
             .register_type::<HTerrainConfig>()
             .register_type::<entities::HCell>()
             .register_type::<entities::Corner>()
@@ -142,6 +457,7 @@ impl Plugin for HTerrainPlugin {
             .register_type::<entities::TriPos2Emitter>()
             .register_type::<entities::Quad>()
             .register_type::<entities::QuadEdge>()
+            .register_type::<entities::EdgeRing>()
             .register_type::<entities::Tri>()
             .register_type::<entities::InFov>()
             .register_type::<entities::HexFace>()
@@ -149,11 +465,91 @@ impl Plugin for HTerrainPlugin {
             .register_type::<entities::InSight>()
             .register_type::<entities::PreSightMaterial>()
             .register_type::<entities::AimStar>()
+            .register_type::<grid_floor::GridFloor>()
+            .register_type::<face_grid::FaceGrid>()
+            .register_type::<outline::SelectionOutline>()
+            .register_type::<glitch::Glitch>()
+            .init_resource::<glitch::GlitchClock>()
+            .register_type::<heatmap::HeatmapMode>()
+            .register_type::<debug_view::DebugView>()
+            .init_resource::<debug_view::DebugView>()
+            .register_type::<entities::CornerSphere>()
+            .register_type::<heatmap::HeatmapOverlay>()
+            .init_resource::<heatmap::HeatmapMode>()
+            .init_resource::<lens_flare::LensFlares>()
+            .register_type::<HexLabelMode>()
+            .register_type::<LandmarkMarker>()
+            .register_type::<TerrainStats>()
+            .init_resource::<TerrainStats>()
+            .register_type::<sun::Sun>()
+            .register_type::<reveal_fx::RevealParticle>()
+            .register_type::<entities::Revealed>()
+            .register_type::<entities::Visited>()
+            .register_type::<entities::RevealAnim>()
+            .register_type::<ScannerCooldown>()
+            .register_type::<ScannerPulse>()
+            .init_resource::<ScannerCooldown>()
+            .add_message::<entities::HexRevealed>()
+            .add_message::<RegenerateTerrain>()
+            .add_message::<TeleportToHex>()
+            .add_message::<EditHexHeight>()
+            .add_message::<HexPicked>()
+            .register_type::<SelectedHex>()
+            .init_resource::<SelectedHex>()
+            .init_resource::<HexNotes>()
+            .register_type::<HoveredHex>()
+            .init_resource::<HoveredHex>()
+            .register_type::<pathfinding::PathEnds>()
+            .register_type::<pathfinding::HexPath>()
+            .register_type::<pathfinding::PathSegment>()
+            .init_resource::<pathfinding::PathEnds>()
+            .init_resource::<pathfinding::HexPath>()
+            .register_type::<sightline::Sightlines>()
+            .register_type::<sightline::SightlineOverlay>()
+            .init_resource::<sightline::Sightlines>()
+            .register_diagnostic(Diagnostic::new(REVEAL_TIME).with_suffix("ms"))
             .insert_resource(self.config.clone())
-            .insert_resource(ClearColor(self.config.clear_color))
+            .register_type::<Theme>()
+            .register_type::<GlowLevel>()
+            .register_type::<FaceFinish>()
+            .register_type::<entities::HeightGlow>()
+            .insert_resource(ClearColor(theme.clear_color))
+            .insert_resource(theme)
+            .init_resource::<GlowLevel>()
+            .register_binding(Binding::new(
+                BindingCategory::Terrain,
+                "Cycle theme",
+                [Trigger::Key(KeyCode::KeyT)],
+            ))
+            .register_binding(Binding::new(
+                BindingCategory::Terrain,
+                "Toggle hologram",
+                [Trigger::Key(KeyCode::KeyH)],
+            ))
+            .register_binding(Binding::new(
+                BindingCategory::Terrain,
+                "Glow up / down",
+                [
+                    Trigger::Key(KeyCode::Equal),
+                    Trigger::Key(KeyCode::Minus),
+                    Trigger::Key(KeyCode::NumpadAdd),
+                    Trigger::Key(KeyCode::NumpadSubtract),
+                ],
+            ))
+            .register_binding(Binding::new(
+                BindingCategory::Terrain,
+                "Toggle height heatmap",
+                [Trigger::Key(KeyCode::KeyG)],
+            ))
+            .register_binding(Binding::new(
+                BindingCategory::Terrain,
+                "Scanner pulse (scanner reveal mode)",
+                [Trigger::Key(KeyCode::KeyF)],
+            ))
             .configure_sets(
                 Update,
                 (
+                    HTerrainPhase::LookAhead.before(HTerrainPhase::UpdateGround),
                     HTerrainPhase::UpdateGround,
                     HTerrainPhase::TrackFov.after(HTerrainPhase::UpdateGround),
                     HTerrainPhase::Highlight.after(HTerrainPhase::TrackFov),
@@ -161,6 +557,39 @@ impl Plugin for HTerrainPlugin {
                 ),
             )
             .add_systems(Startup, startup_systems::generate_h_grid)
+            .add_systems(
+                Startup,
+                grid_floor::spawn_grid_floor.run_if(|cfg: Res<HTerrainConfig>| cfg.grid_floor),
+            )
+            .add_systems(
+                Startup,
+                face_grid::spawn_face_grids
+                    .after(startup_systems::generate_h_grid)
+                    .run_if(|cfg: Res<HTerrainConfig>| cfg.face_grid),
+            )
+            .add_systems(
+                Startup,
+                landmarks::spawn_landmarks
+                    .after(startup_systems::generate_h_grid)
+                    .run_if(|cfg: Res<HTerrainConfig>| cfg.landmarks),
+            )
+            .add_systems(Startup, heatmap::create_heatmap_materials)
+            .add_systems(Startup, sightline::create_sightline_materials)
+            .add_systems(
+                Startup,
+                outline::spawn_selection_outline
+                    .run_if(|cfg: Res<HTerrainConfig>| cfg.selection_outline),
+            )
+            .add_systems(
+                Startup,
+                sun::spawn_sun.run_if(|cfg: Res<HTerrainConfig>| cfg.sun),
+            )
+            .add_systems(
+                Startup,
+                materials::apply_height_glow
+                    .after(startup_systems::generate_h_grid)
+                    .run_if(has_height_gradient),
+            )
             .add_systems(
                 Startup,
                 startup_systems::verify_gap_counts
@@ -178,22 +607,259 @@ impl Plugin for HTerrainPlugin {
         }
 
         if let Some(movement_set) = self.after_player_movement {
-            app.configure_sets(Update, HTerrainPhase::UpdateGround.after(movement_set));
+            app.configure_sets(
+                Update,
+                (
+                    HTerrainPhase::LookAhead.before(movement_set),
+                    HTerrainPhase::UpdateGround.after(movement_set),
+                ),
+            );
         }
 
         app.add_systems(
+            Update,
+            (
+                startup_systems::clear_h_grid,
+                startup_systems::generate_h_grid,
+                (
+                    startup_systems::seed_ground_level,
+                    face_grid::spawn_face_grids.run_if(|cfg: Res<HTerrainConfig>| cfg.face_grid),
+                    landmarks::spawn_landmarks.run_if(|cfg: Res<HTerrainConfig>| cfg.landmarks),
+                    materials::apply_height_glow.run_if(has_height_gradient),
+                ),
+            )
+                .chain()
+                .before(HTerrainPhase::UpdateGround)
+                .run_if(on_message::<RegenerateTerrain>),
+        )
+        .add_systems(
+            Update,
+            systems::teleport_to_hex
+                .before(HTerrainPhase::UpdateGround)
+                .run_if(on_message::<TeleportToHex>),
+        )
+        .add_systems(
+            Update,
+            systems::apply_height_edits
+                .after(systems::extract_ore)
+                .run_if(on_message::<EditHexHeight>),
+        )
+        .add_systems(
             Update,
             (
                 systems::update_ground_level.in_set(HTerrainPhase::UpdateGround),
+                systems::update_ground_ahead.in_set(HTerrainPhase::LookAhead),
+                grid_floor::follow_player.after(HTerrainPhase::UpdateGround),
                 systems::track_player_fov.in_set(HTerrainPhase::TrackFov),
+                (systems::track_active_hex, systems::track_visited)
+                    .chain()
+                    .in_set(HTerrainPhase::TrackFov),
                 materials::start_fov_transitions.in_set(HTerrainPhase::Highlight),
+                reveal_fx::emit_reveal_particles.in_set(HTerrainPhase::Highlight),
+                reveal_fx::animate_reveal_particles.after(HTerrainPhase::Highlight),
+                (
+                    reveal_fx::announce_reveals.run_if(scanner::passive_reveal),
+                    reveal_fx::start_reveal_anims,
+                )
+                    .chain()
+                    .in_set(HTerrainPhase::Highlight),
+                (
+                    scanner::emit_scanner_pulse,
+                    scanner::advance_scanner_pulses,
+                    scanner::lift_fog,
+                )
+                    .chain()
+                    .after(HTerrainPhase::TrackFov)
+                    .before(reveal_fx::start_reveal_anims),
+                reveal_fx::animate_reveal.after(HTerrainPhase::Highlight),
                 materials::animate_fov_transitions
                     .after(HTerrainPhase::Highlight)
                     .before(HTerrainPhase::Sight),
+                materials::animate_edge_transitions
+                    .after(HTerrainPhase::Highlight)
+                    .before(HTerrainPhase::Sight),
                 materials::track_in_sight.in_set(HTerrainPhase::Sight),
+                target::track_target_hex.after(HTerrainPhase::Sight),
                 systems::extract_ore.after(HTerrainPhase::Sight),
+                // Chained rather than ordered by type: the regeneration chain also runs
+                // `apply_height_glow`, which would make the type set ambiguous.
+                (
+                    materials::apply_height_glow
+                        .after(systems::apply_height_edits)
+                        .run_if(has_height_gradient),
+                    materials::pulse_active_hex.after(materials::animate_fov_transitions),
+                )
+                    .chain(),
+                (glitch::start_glitches, glitch::animate_glitches)
+                    .chain()
+                    .after(materials::animate_edge_transitions)
+                    .after(systems::apply_height_edits)
+                    .run_if(|cfg: Res<HTerrainConfig>| cfg.glitch),
+                glitch::clear_glitches
+                    .after(materials::animate_edge_transitions)
+                    .run_if(|cfg: Res<HTerrainConfig>| !cfg.glitch),
             )
                 .run_if(in_state(GameState::Running)),
+        )
+        .add_systems(
+            Update,
+            outline::update_selection_outline
+                .after(HTerrainPhase::Sight)
+                .run_if(|cfg: Res<HTerrainConfig>| cfg.selection_outline),
+        )
+        .add_systems(
+            Update,
+            (
+                stats::compute_terrain_stats,
+                stats::draw_terrain_stats.run_if(in_state(GameState::Inspecting)),
+            )
+                .chain()
+                .after(startup_systems::generate_h_grid),
+        )
+        .add_systems(
+            Update,
+            (
+                debug_view::draw_debug_panel.run_if(in_state(GameState::Inspecting)),
+                debug_view::apply_debug_view
+                    .after(startup_systems::generate_h_grid)
+                    .run_if(
+                        resource_changed::<debug_view::DebugView>
+                            .or(any_match_filter::<Added<entities::HGrid>>),
+                    ),
+                debug_view::draw_gap_normals
+                    .run_if(|view: Res<debug_view::DebugView>| view.normals),
+            )
+                .chain(),
+        )
+        .add_systems(
+            Update,
+            (
+                picking::track_hovered_hex,
+                picking::pick_hex_on_click.in_set(HTerrainPhase::PickHex),
+                picking::select_picked_hex,
+                hex_panel::draw_hex_panel,
+            )
+                .chain()
+                .before(systems::apply_height_edits)
+                .before(outline::update_selection_outline)
+                .run_if(in_state(GameState::Inspecting)),
+        )
+        .add_systems(OnExit(GameState::Inspecting), picking::clear_selected_hex)
+        .add_systems(
+            Update,
+            pathfinding::set_path_ends
+                .after(picking::pick_hex_on_click)
+                .run_if(in_state(GameState::Inspecting)),
+        )
+        .add_systems(
+            Update,
+            (
+                pathfinding::solve_hex_path.run_if(
+                    resource_changed::<pathfinding::PathEnds>
+                        .or(on_message::<EditHexHeight>)
+                        .or(any_match_filter::<Added<entities::HGrid>>),
+                ),
+                pathfinding::draw_hex_path.run_if(resource_changed::<pathfinding::HexPath>),
+            )
+                .chain()
+                .after(pathfinding::set_path_ends)
+                .after(systems::apply_height_edits)
+                .after(startup_systems::generate_h_grid),
+        )
+        .add_systems(
+            Update,
+            (
+                sightline::update_sightlines.run_if(
+                    resource_changed::<debug_view::DebugView>
+                        .or(resource_changed::<SelectedHex>)
+                        .or(on_message::<EditHexHeight>)
+                        .or(any_match_filter::<Added<entities::HGrid>>),
+                ),
+                sightline::draw_sightlines.run_if(
+                    resource_changed::<sightline::Sightlines>
+                        .or(any_match_filter::<Added<entities::HGrid>>),
+                ),
+            )
+                .chain()
+                .after(picking::select_picked_hex)
+                .after(systems::apply_height_edits)
+                .after(startup_systems::generate_h_grid),
+        )
+        .add_systems(
+            Update,
+            tooltip::draw_hex_tooltip
+                .after(HTerrainPhase::Sight)
+                .run_if(in_state(GameState::Running).or(in_state(GameState::Inspecting)))
+                .run_if(|cfg: Res<HTerrainConfig>| cfg.hex_tooltip),
+        )
+        .add_systems(
+            Update,
+            labels::draw_hex_labels
+                .after(HTerrainPhase::Sight)
+                .run_if(in_state(GameState::Running).or(in_state(GameState::Inspecting)))
+                .run_if(|cfg: Res<HTerrainConfig>| cfg.hex_labels != HexLabelMode::Off),
+        )
+        .add_systems(
+            Update,
+            notes::draw_hex_notes
+                .after(HTerrainPhase::Sight)
+                .run_if(in_state(GameState::Running).or(in_state(GameState::Inspecting)))
+                .run_if(|notes: Res<HexNotes>| !notes.0.is_empty()),
+        )
+        .add_systems(
+            Update,
+            minimap::draw_minimap
+                .after(HTerrainPhase::Sight)
+                .run_if(in_state(GameState::Running).or(in_state(GameState::Inspecting)))
+                .run_if(|cfg: Res<HTerrainConfig>| cfg.minimap),
+        )
+        .add_systems(
+            Update,
+            (lens_flare::update_lens_flares, lens_flare::draw_lens_flares)
+                .chain()
+                .after(HTerrainPhase::TrackFov)
+                .run_if(in_state(GameState::Running))
+                .run_if(|cfg: Res<HTerrainConfig>| cfg.lens_flare),
+        )
+        .add_systems(
+            Update,
+            (
+                heatmap::toggle_heatmap,
+                heatmap::sync_heatmap
+                    .run_if(
+                        resource_changed::<heatmap::HeatmapMode>
+                            .or(any_match_filter::<Added<entities::HGrid>>),
+                    )
+                    .after(startup_systems::generate_h_grid),
+                (
+                    heatmap::update_heatmap.after(systems::apply_height_edits),
+                    heatmap::draw_heatmap_legend,
+                )
+                    .run_if(heatmap_on),
+            )
+                .chain()
+                .run_if(in_state(GameState::Running)),
+        )
+        .add_systems(
+            Update,
+            (
+                (
+                    theme::cycle_theme,
+                    theme::toggle_hologram,
+                    theme::adjust_glow_level,
+                )
+                    .run_if(in_state(GameState::Running)),
+                (
+                    materials::apply_theme,
+                    materials::apply_edge_theme,
+                    grid_floor::apply_grid_floor_theme,
+                    face_grid::apply_face_grid_theme,
+                    environment::sync_environment_map.run_if(resource_changed::<Theme>),
+                )
+                    .run_if(look_changed),
+            )
+                .chain()
+                .before(HTerrainPhase::Highlight),
         );
     }
 }
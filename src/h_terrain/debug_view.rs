@@ -0,0 +1,172 @@
+//! Debug visualization toggles (egui panel while `Inspecting`).
+//!
+//! Gap faces ([`Quad`] / [`Tri`]), edge lines ([`QuadEdge`]), and the debug-mode
+//! [`CornerSphere`]s are shown or hidden through their [`Visibility`]; edges are children of
+//! their quad, so with gap faces hidden they are forced [`Visibility::Visible`] instead of
+//! inheriting. Hex labels switch [`HTerrainConfig::hex_labels`] between `Axial` and `Off`,
+//! normals are drawn as gizmo lines from each in-FoV gap's centroid, and line of sight shades
+//! the faces by what the selected hex can see ([`Sightlines`]).
+
+use bevy::prelude::*;
+use bevy_egui::egui;
+
+use super::entities::{CornerSphere, InFov, Quad, QuadEdge, Tri};
+use super::sightline::Sightlines;
+use super::{HTerrainConfig, HexLabelMode};
+use crate::DebugFlag;
+
+/// Length of a drawn normal, in world units.
+const NORMAL_LENGTH: f32 = 0.6;
+
+/// Which debug layers are visible.
+#[derive(Resource, Clone, Debug, PartialEq, Reflect)]
+pub struct DebugView {
+    /// Quad and Tri gap meshes.
+    pub gap_faces: bool,
+    /// Quad edge lines.
+    pub edge_lines: bool,
+    /// Corner spheres (spawned with `--debug` only).
+    pub corner_spheres: bool,
+    /// Gap face normals as gizmo lines (in-FoV gaps only).
+    pub normals: bool,
+    /// Shade faces by whether the selected hex can see them ([`Sightlines`]).
+    pub line_of_sight: bool,
+}
+
+impl Default for DebugView {
+    fn default() -> Self {
+        Self {
+            gap_faces: true,
+            edge_lines: true,
+            corner_spheres: true,
+            normals: false,
+            line_of_sight: false,
+        }
+    }
+}
+
+/// Visibility of a [`QuadEdge`] under `view` (edges are children of their quad).
+pub fn edge_visibility(view: &DebugView) -> Visibility {
+    match (view.edge_lines, view.gap_faces) {
+        (false, _) => Visibility::Hidden,
+        (true, false) => Visibility::Visible,
+        (true, true) => Visibility::Inherited,
+    }
+}
+
+/// Shown/hidden visibility for a toggle.
+fn shown(on: bool) -> Visibility {
+    if on {
+        Visibility::Inherited
+    } else {
+        Visibility::Hidden
+    }
+}
+
+/// Applies [`DebugView`] to the gap, edge, and corner sphere entities.
+#[allow(clippy::type_complexity)]
+pub(super) fn apply_debug_view(
+    view: Res<DebugView>,
+    mut gaps: Query<&mut Visibility, Or<(With<Quad>, With<Tri>)>>,
+    mut edges: Query<&mut Visibility, (With<QuadEdge>, Without<Quad>, Without<Tri>)>,
+    mut spheres: Query<
+        &mut Visibility,
+        (
+            With<CornerSphere>,
+            Without<Quad>,
+            Without<Tri>,
+            Without<QuadEdge>,
+        ),
+    >,
+) {
+    for mut vis in &mut gaps {
+        vis.set_if_neq(shown(view.gap_faces));
+    }
+    let edge = edge_visibility(&view);
+    for mut vis in &mut edges {
+        vis.set_if_neq(edge);
+    }
+    for mut vis in &mut spheres {
+        vis.set_if_neq(shown(view.corner_spheres));
+    }
+}
+
+/// Draws each in-FoV gap's face normal from its centroid.
+#[allow(clippy::type_complexity)]
+pub(super) fn draw_gap_normals(
+    mut gizmos: Gizmos,
+    meshes: Res<Assets<Mesh>>,
+    gaps: Query<(&Mesh3d, &GlobalTransform), (Or<(With<Quad>, With<Tri>)>, With<InFov>)>,
+) {
+    for (mesh, gt) in &gaps {
+        let Some(mesh) = meshes.get(&mesh.0) else {
+            continue;
+        };
+        let (Some(positions), Some(normals)) = (
+            mesh.attribute(Mesh::ATTRIBUTE_POSITION)
+                .and_then(|a| a.as_float3()),
+            mesh.attribute(Mesh::ATTRIBUTE_NORMAL)
+                .and_then(|a| a.as_float3()),
+        ) else {
+            continue;
+        };
+        let Some(&normal) = normals.first() else {
+            continue;
+        };
+        let centroid =
+            positions.iter().map(|&p| Vec3::from(p)).sum::<Vec3>() / positions.len().max(1) as f32;
+        let from = gt.transform_point(centroid);
+        let dir = gt
+            .affine()
+            .transform_vector3(Vec3::from(normal))
+            .normalize_or_zero();
+        gizmos.line(from, from + dir * NORMAL_LENGTH, Color::srgb(1.0, 0.2, 0.8));
+    }
+}
+
+/// Draws the debug view panel and applies its toggles.
+pub(super) fn draw_debug_panel(
+    mut egui_ctx: Single<&mut bevy_egui::EguiContext>,
+    debug: Res<DebugFlag>,
+    mut view: ResMut<DebugView>,
+    mut cfg: ResMut<HTerrainConfig>,
+    sightlines: Res<Sightlines>,
+) {
+    let mut v = view.clone();
+    let mut labels = cfg.hex_labels != HexLabelMode::Off;
+
+    egui::Window::new("Debug view")
+        .default_pos([8.0, 360.0])
+        .resizable(false)
+        .show(egui_ctx.get_mut(), |ui| {
+            ui.checkbox(&mut labels, "hex labels");
+            ui.checkbox(&mut v.gap_faces, "gap faces");
+            ui.checkbox(&mut v.edge_lines, "edge lines");
+            ui.add_enabled_ui(debug.0, |ui| {
+                ui.checkbox(&mut v.corner_spheres, "corner spheres")
+                    .on_disabled_hover_text("spawned with --debug only");
+            });
+            ui.checkbox(&mut v.normals, "normals");
+            ui.checkbox(&mut v.line_of_sight, "line of sight")
+                .on_hover_text("from the selected hex");
+            if let Some(origin) = sightlines.origin {
+                ui.monospace(format!(
+                    "{} hexes visible from {:+},{:+}",
+                    sightlines.visible.len(),
+                    origin.x,
+                    origin.y
+                ));
+            }
+        });
+
+    if v != *view {
+        *view = v;
+    }
+    if labels != (cfg.hex_labels != HexLabelMode::Off) {
+        cfg.hex_labels = if labels {
+            HexLabelMode::Axial
+        } else {
+            HexLabelMode::Off
+        };
+    }
+}
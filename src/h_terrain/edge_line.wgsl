@@ -0,0 +1,94 @@
+// Camera-facing glow lines for terrain edges.
+//
+// The mesh is a unit quad collapsed onto the local X axis (x in -0.5..0.5, uv.y = -1 | +1 per
+// side). The vertex shader widens it perpendicular to both the segment and the view direction,
+// so every edge stays a flat ribbon facing the camera with a width that grows with distance.
+//
+// All edges share one mesh and one material so they batch into a single instanced draw.
+// Per-instance state comes from the mesh tag: bits 0..16 hold the hex ring (pulse phase),
+// bits 16..24 the FoV highlight amount (0..255), bits 24..32 the glitch amount (0..255).
+
+#import bevy_pbr::{
+    mesh_functions::{get_tag, get_world_from_local, mesh_position_local_to_world},
+    mesh_view_bindings::{globals, view},
+    view_transformations::position_world_to_clip,
+}
+
+struct EdgeLine {
+    color: vec4<f32>,
+    highlight: vec4<f32>,
+    width: f32,
+    angular_width: f32,
+    fade_start: f32,
+    fade_end: f32,
+    pulse_speed: f32,
+    pulse_amplitude: f32,
+    pulse_ring_phase: f32,
+}
+
+@group(#{MATERIAL_BIND_GROUP}) @binding(0) var<uniform> material: EdgeLine;
+
+struct Vertex {
+    @builtin(instance_index) instance_index: u32,
+    @location(0) position: vec3<f32>,
+    @location(2) uv: vec2<f32>,
+}
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) across: f32,
+    @location(1) distance: f32,
+    @location(2) color: vec3<f32>,
+}
+
+@vertex
+fn vertex(v: Vertex) -> VertexOutput {
+    let world_from_local = get_world_from_local(v.instance_index);
+    let axis_pos = mesh_position_local_to_world(world_from_local, vec4(v.position.x, 0.0, 0.0, 1.0)).xyz;
+    let dir = normalize((world_from_local * vec4(1.0, 0.0, 0.0, 0.0)).xyz);
+
+    let to_cam = view.world_position - axis_pos;
+    let dist = max(length(to_cam), 1e-4);
+    var side = cross(dir, to_cam / dist);
+    if (dot(side, side) < 1e-8) {
+        // Looking straight down the segment: any perpendicular will do.
+        side = cross(dir, vec3(0.0, 1.0, 0.0));
+    }
+    side = normalize(side);
+
+    let half_width = 0.5 * max(material.width, dist * material.angular_width);
+
+    var out: VertexOutput;
+    out.clip_position = position_world_to_clip(axis_pos + side * v.uv.y * half_width);
+    out.across = v.uv.y;
+    out.distance = dist;
+
+    let tag = get_tag(v.instance_index);
+    let ring = f32(tag & 0xffffu);
+    let highlight = f32((tag >> 16u) & 0xffu) / 255.0;
+    let phase = globals.time * material.pulse_speed - ring * material.pulse_ring_phase;
+    let pulse = max(1.0 + material.pulse_amplitude * sin(phase), 0.0);
+    let color = mix(material.color.rgb * pulse, material.highlight.rgb, highlight);
+
+    // Glitch flicker: drain the saturation and rotate the channels so the line reads as a
+    // mis-registered signal for the few frames it is tagged.
+    let glitch = f32(tag >> 24u) / 255.0;
+    let luma = dot(color, vec3(0.2126, 0.7152, 0.0722));
+    out.color = mix(color, mix(vec3(luma), color.brg, 0.35), glitch);
+    return out;
+}
+
+@fragment
+fn fragment(in: VertexOutput) -> @location(0) vec4<f32> {
+    let d = abs(in.across);
+    let aa = fwidth(in.across);
+
+    // Solid anti-aliased core plus a soft falloff out to the ribbon edge.
+    let core = 1.0 - smoothstep(0.35 - aa, 0.35 + aa, d);
+    let glow = exp(-5.0 * d * d) * (1.0 - smoothstep(1.0 - aa, 1.0, d));
+    let fade = 1.0 - smoothstep(material.fade_start, material.fade_end, in.distance);
+
+    let intensity = max(core, 0.6 * glow) * fade;
+    // Additive blend: output is premultiplied.
+    return vec4(in.color * intensity, intensity);
+}
@@ -0,0 +1,170 @@
+//! Custom line material for terrain edges: anti-aliased, distance-attenuated glow ribbons.
+//!
+//! Every [`QuadEdge`](super::entities::QuadEdge) shares one [`edge_line_mesh`] and one
+//! [`EdgeMaterial`], so the whole wireframe renders as a single instanced draw. The transform
+//! carries the segment (midpoint, direction, length in `scale.x`), the [`MeshTag`] carries the
+//! per-instance ring, FoV highlight, and glitch amount (see [`edge_tag`], [`with_glitch`]), and
+//! `edge_line.wgsl` expands the collapsed quad to face the camera and runs the ring pulse on the
+//! GPU.
+
+use bevy::asset::RenderAssetUsages;
+use bevy::mesh::{Indices, MeshTag};
+use bevy::prelude::*;
+use bevy::render::render_resource::{AsBindGroup, PrimitiveTopology};
+use bevy::shader::ShaderRef;
+
+use super::HTerrainConfig;
+use super::theme::Theme;
+
+/// Asset path of the embedded edge shader.
+const SHADER_PATH: &str = "embedded://hex_terrain/h_terrain/edge_line.wgsl";
+
+/// Glowing camera-facing line material for terrain edges.
+#[derive(Asset, TypePath, AsBindGroup, Clone, Debug)]
+pub struct EdgeMaterial {
+    /// Linear line color outside the FoV: the theme's edge base color (at most 1.0), scaled by
+    /// the [`GlowLevel`](super::GlowLevel). It needs no HDR headroom to glow, since the default
+    /// bloom threshold of 0 blooms every lit pixel.
+    #[uniform(0)]
+    pub color: LinearRgba,
+    /// Line color for fully highlighted (in-FoV) edges.
+    #[uniform(0)]
+    pub highlight: LinearRgba,
+    /// Minimum line width in world units (near the camera).
+    #[uniform(0)]
+    pub width: f32,
+    /// Line width per unit of camera distance, keeping far lines visible.
+    #[uniform(0)]
+    pub angular_width: f32,
+    /// Camera distance where the line starts to fade.
+    #[uniform(0)]
+    pub fade_start: f32,
+    /// Camera distance where the line is fully faded.
+    #[uniform(0)]
+    pub fade_end: f32,
+    /// Pulse angular speed (radians per second).
+    #[uniform(0)]
+    pub pulse_speed: f32,
+    /// Pulse brightness swing as a fraction of `color` (0 disables).
+    #[uniform(0)]
+    pub pulse_amplitude: f32,
+    /// Pulse phase lag per hex ring (radians).
+    #[uniform(0)]
+    pub pulse_ring_phase: f32,
+}
+
+impl EdgeMaterial {
+    /// Builds the shared edge material from theme colors and line/pulse parameters in `cfg`.
+    pub fn new(theme: &Theme, cfg: &HTerrainConfig) -> Self {
+        Self {
+            color: theme.edge.base.into(),
+            highlight: theme.edge_fov.base.into(),
+            width: cfg.edge_width,
+            angular_width: cfg.edge_angular_width,
+            fade_start: cfg.edge_fade_start,
+            fade_end: cfg.edge_fade_end,
+            pulse_speed: cfg.edge_pulse_speed,
+            pulse_amplitude: cfg.edge_pulse_amplitude,
+            pulse_ring_phase: cfg.edge_pulse_ring_phase,
+        }
+    }
+}
+
+/// Packs an edge's hex ring and FoV highlight amount (0..=1) into its [`MeshTag`].
+pub fn edge_tag(ring: u32, highlight: f32) -> MeshTag {
+    let highlight = (highlight.clamp(0.0, 1.0) * 255.0).round() as u32;
+    MeshTag((ring & 0xffff) | (highlight << 16))
+}
+
+/// FoV highlight amount (0..=1) stored in an edge [`MeshTag`].
+#[cfg(test)]
+pub fn edge_tag_highlight(tag: &MeshTag) -> f32 {
+    ((tag.0 >> 16) & 0xff) as f32 / 255.0
+}
+
+/// Returns `tag` with its glitch amount (0..=1, desaturation and color offset) replaced.
+pub fn with_glitch(tag: &MeshTag, amount: f32) -> MeshTag {
+    let amount = (amount.clamp(0.0, 1.0) * 255.0).round() as u32;
+    MeshTag((tag.0 & 0x00ff_ffff) | (amount << 24))
+}
+
+/// Glitch amount (0..=1) stored in an edge [`MeshTag`].
+#[cfg(test)]
+pub fn edge_tag_glitch(tag: &MeshTag) -> f32 {
+    (tag.0 >> 24) as f32 / 255.0
+}
+
+impl Material for EdgeMaterial {
+    fn vertex_shader() -> ShaderRef {
+        SHADER_PATH.into()
+    }
+
+    fn fragment_shader() -> ShaderRef {
+        SHADER_PATH.into()
+    }
+
+    fn alpha_mode(&self) -> AlphaMode {
+        AlphaMode::Add
+    }
+
+    fn enable_prepass() -> bool {
+        false
+    }
+
+    fn enable_shadows() -> bool {
+        false
+    }
+}
+
+/// Unit line mesh: a quad collapsed onto the local X axis from -0.5 to 0.5.
+///
+/// `uv.y` is -1 / +1 for the two sides; the shader uses it to widen the ribbon.
+pub fn edge_line_mesh() -> Mesh {
+    let positions = vec![
+        [-0.5, 0.0, 0.0],
+        [0.5, 0.0, 0.0],
+        [0.5, 0.0, 0.0],
+        [-0.5, 0.0, 0.0],
+    ];
+    let normals = vec![[0.0, 1.0, 0.0]; 4];
+    let uvs = vec![[0.0, -1.0], [1.0, -1.0], [1.0, 1.0], [0.0, 1.0]];
+    Mesh::new(
+        PrimitiveTopology::TriangleList,
+        RenderAssetUsages::RENDER_WORLD | RenderAssetUsages::MAIN_WORLD,
+    )
+    .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+    .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, normals)
+    .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, uvs)
+    .with_inserted_indices(Indices::U16(vec![0, 1, 2, 0, 2, 3]))
+}
+
+/// Transform placing the unit [`edge_line_mesh`] on the segment `from → to`.
+pub fn edge_line_transform(from: Vec3, to: Vec3) -> Transform {
+    let (midpoint, length, rotation) = super::math::edge_cuboid_transform(from, to);
+    Transform::from_translation(midpoint)
+        .with_rotation(rotation)
+        .with_scale(Vec3::new(length, 1.0, 1.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn edge_tag_roundtrip() {
+        let tag = edge_tag(17, 1.0);
+        assert_eq!(tag.0 & 0xffff, 17);
+        assert_eq!(edge_tag_highlight(&tag), 1.0);
+        assert_eq!(edge_tag_highlight(&edge_tag(3, 0.0)), 0.0);
+        assert!((edge_tag_highlight(&edge_tag(3, 0.5)) - 0.5).abs() < 1.0 / 255.0);
+    }
+
+    #[test]
+    fn glitch_bits_leave_ring_and_highlight_alone() {
+        let tag = with_glitch(&edge_tag(17, 0.5), 1.0);
+        assert_eq!(tag.0 & 0xffff, 17);
+        assert!((edge_tag_highlight(&tag) - 0.5).abs() < 1.0 / 255.0);
+        assert_eq!(edge_tag_glitch(&tag), 1.0);
+        assert_eq!(with_glitch(&tag, 0.0), edge_tag(17, 0.5));
+    }
+}
@@ -21,6 +21,68 @@ pub struct HGrid {
 #[derive(Component, Reflect)]
 pub struct InFov;
 
+/// Marker on [`HCell`] entities that have entered the FoV at least once.
+#[derive(Component, Reflect)]
+pub struct Revealed;
+
+/// Marker on [`HCell`] entities the player has flown over (the hex under the player).
+///
+/// Unlike [`Revealed`], which covers the whole FoV, this records the actual flight path.
+/// Only the cell's hex face takes the visited tint; its edges share one instanced material and
+/// its gaps are shared with the neighboring cells, so both keep their usual styling.
+#[derive(Component, Reflect)]
+pub struct Visited;
+
+/// Requests a full rebuild of the [`HGrid`] from the current
+/// [`HTerrainConfig::grid`](super::HTerrainConfig) settings (settings panel "Apply & Regenerate").
+#[derive(Message, Debug, Clone, Copy, Default)]
+pub struct RegenerateTerrain;
+
+/// Moves the player to the center of a hex (ignored if the hex is outside the [`HGrid`]).
+#[derive(Message, Debug, Clone, Copy)]
+pub struct TeleportToHex(pub Hex);
+
+/// Raises (or, with a negative `delta`, lowers) one hex in place: laser mining and the
+/// inspection panel's nudge buttons.
+#[derive(Message, Debug, Clone, Copy)]
+pub struct EditHexHeight {
+    /// Hex to edit (ignored if outside the [`HGrid`]).
+    pub hex: Hex,
+    /// Height change in world units.
+    pub delta: f32,
+}
+
+/// A hex face clicked in inspect mode (see [`pick_hex`](super::picking::pick_hex)); drives
+/// the selection outline and the inspection panel.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HexPicked(pub Hex);
+
+/// Written once per [`HCell`] the first time it is [`Revealed`], so other systems can key
+/// effects off the reveal wavefront.
+#[derive(Message, Debug, Clone, Copy)]
+pub struct HexRevealed {
+    /// The revealed [`HCell`] entity.
+    pub cell: Entity,
+    /// Seconds until the wavefront reaches this cell (ring distance × stagger).
+    pub delay: f32,
+}
+
+/// Scale-in tween on a [`HexFace`] whose cell was just revealed.
+#[derive(Component, Reflect)]
+pub struct RevealAnim {
+    /// Seconds to wait before growing (staggered by ring distance).
+    pub delay: f32,
+    /// Seconds since the reveal.
+    pub elapsed: f32,
+    /// Final face scale.
+    pub target: Vec3,
+}
+
+/// Height-gradient emissive added on top of a [`HexFace`]'s themed emissive
+/// (see [`HTerrainConfig::face_height_gradient`](super::HTerrainConfig::face_height_gradient)).
+#[derive(Component, Reflect)]
+pub struct HeightGlow(pub LinearRgba);
+
 /// Per-hex cell entity, positioned at the hex center.
 #[derive(Component, Reflect)]
 pub struct HCell {
@@ -35,6 +97,11 @@ pub struct Corner {
     pub index: u8,
 }
 
+/// Debug-mode sphere (with an outline edge to the next corner) on a [`Corner`]; a child so
+/// it can be hidden without hiding the gap meshes the corner owns.
+#[derive(Component, Reflect)]
+pub struct CornerSphere;
+
 pub(crate) mod gap_marks {
     use bevy::prelude::*;
 
@@ -179,6 +246,10 @@ pub struct HexFace;
 #[derive(Component, Reflect)]
 pub struct QuadEdge;
 
+/// Hex ring distance from the origin of a [`QuadEdge`]'s owner; selects its pulse material.
+#[derive(Component, Reflect)]
+pub struct EdgeRing(pub u32);
+
 /// Tracks an in-progress color transition between original and highlight materials.
 #[derive(Component, Reflect)]
 pub struct FovTransition {
@@ -186,6 +257,8 @@ pub struct FovTransition {
     pub progress: f32,
     /// +1.0 when fading toward highlight, -1.0 when fading toward original.
     pub direction: f32,
+    /// Fade in from the fog-of-war material (first reveal) instead of the original.
+    pub from_fog: bool,
 }
 
 /// Marker on the single hex face the camera is looking directly at.
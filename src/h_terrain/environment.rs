@@ -0,0 +1,120 @@
+//! Procedural neon environment map for reflective hex faces.
+//!
+//! A small cubemap is painted from the active [`Theme`]: clear color overhead and below, with
+//! a glowing band of the edge color at the horizon. It is attached to the drone camera only
+//! while the theme uses [`FaceFinish::Reflective`] or hologram mode (whose Fresnel rim glow
+//! reflects it), so matte themes keep their flat lighting.
+
+use bevy::asset::RenderAssetUsages;
+use bevy::light::EnvironmentMapLight;
+use bevy::prelude::*;
+use bevy::render::render_resource::{
+    Extent3d, TextureDimension, TextureFormat, TextureViewDescriptor, TextureViewDimension,
+};
+
+use super::HTerrainConfig;
+use super::theme::{FaceFinish, Theme};
+use crate::drone::Player;
+
+/// Edge length of each cubemap face in texels.
+const FACE_SIZE: u32 = 32;
+/// Half-height of the horizon glow band, in units of the direction's Y component.
+const HORIZON_BAND: f32 = 0.3;
+
+/// World direction through texel `(u, v)` (both -1..1) of cubemap `face` (+X, -X, +Y, -Y, +Z, -Z).
+fn cube_dir(face: u32, u: f32, v: f32) -> Vec3 {
+    match face {
+        0 => Vec3::new(1.0, -v, -u),
+        1 => Vec3::new(-1.0, -v, u),
+        2 => Vec3::new(u, 1.0, v),
+        3 => Vec3::new(u, -1.0, -v),
+        4 => Vec3::new(u, -v, 1.0),
+        _ => Vec3::new(-u, -v, -1.0),
+    }
+    .normalize()
+}
+
+/// Environment color for a direction with vertical component `y`.
+fn sky_color(theme: &Theme, y: f32) -> LinearRgba {
+    let sky = LinearRgba::from(theme.clear_color);
+    let base = if y < 0.0 { sky * 0.5 } else { sky };
+    let glow = (1.0 - y.abs() / HORIZON_BAND).max(0.0).powi(2);
+    base.mix(&LinearRgba::from(theme.edge.base), glow)
+}
+
+/// Builds the neon horizon cubemap for `theme`.
+pub fn neon_environment_map(theme: &Theme) -> Image {
+    let mut data = Vec::with_capacity((FACE_SIZE * FACE_SIZE * 4 * 6) as usize);
+    let to_uv = |i: u32| (i as f32 + 0.5) / FACE_SIZE as f32 * 2.0 - 1.0;
+    for face in 0..6 {
+        for row in 0..FACE_SIZE {
+            for col in 0..FACE_SIZE {
+                let dir = cube_dir(face, to_uv(col), to_uv(row));
+                let color = Color::from(sky_color(theme, dir.y));
+                data.extend_from_slice(&color.to_srgba().to_u8_array());
+            }
+        }
+    }
+    let mut image = Image::new(
+        Extent3d {
+            width: FACE_SIZE,
+            height: FACE_SIZE,
+            depth_or_array_layers: 6,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::RENDER_WORLD,
+    );
+    image.texture_view_descriptor = Some(TextureViewDescriptor {
+        dimension: Some(TextureViewDimension::Cube),
+        ..default()
+    });
+    image
+}
+
+/// Attaches (or removes) the theme's environment map on the drone camera when [`Theme`] changes.
+pub(super) fn sync_environment_map(
+    theme: Res<Theme>,
+    cfg: Res<HTerrainConfig>,
+    camera: Single<Entity, With<Player>>,
+    mut images: ResMut<Assets<Image>>,
+    mut commands: Commands,
+) {
+    let mut camera = commands.entity(*camera);
+    if theme.face_finish == FaceFinish::Reflective || theme.hologram {
+        let map = images.add(neon_environment_map(&theme));
+        camera.insert(EnvironmentMapLight {
+            diffuse_map: map.clone(),
+            specular_map: map,
+            intensity: cfg.reflection_intensity,
+            ..default()
+        });
+    } else {
+        camera.remove::<EnvironmentMapLight>();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn horizon_glows_with_edge_color() {
+        let theme = Theme::default();
+        let horizon = sky_color(&theme, 0.0);
+        let zenith = sky_color(&theme, 1.0);
+        assert_eq!(horizon, LinearRgba::from(theme.edge.base));
+        assert_eq!(zenith, LinearRgba::from(theme.clear_color));
+    }
+
+    #[test]
+    fn cubemap_has_six_square_faces() {
+        let image = neon_environment_map(&Theme::default());
+        assert_eq!(image.texture_descriptor.size.depth_or_array_layers, 6);
+        assert_eq!(
+            image.data.as_ref().map(Vec::len),
+            Some((FACE_SIZE * FACE_SIZE * 4 * 6) as usize)
+        );
+    }
+}
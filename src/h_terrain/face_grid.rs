@@ -0,0 +1,122 @@
+//! Procedural grid-line detail on hex faces.
+//!
+//! Each [`HexFace`] gets a [`FaceGrid`] child that reuses the face's unit hex mesh and draws
+//! concentric hex rings and corner spokes additively on top (`face_grid.wgsl`). The ring count
+//! scales with the face radius carried in the [`MeshTag`], so large hexes get richer detail
+//! without extra geometry, and all overlays batch into one instanced draw. The face's own
+//! `StandardMaterial` keeps driving FoV, theme, and aim colors underneath.
+
+use bevy::mesh::MeshTag;
+use bevy::prelude::*;
+use bevy::render::render_resource::AsBindGroup;
+use bevy::shader::ShaderRef;
+
+use super::HTerrainConfig;
+use super::entities::{HCell, HGrid, HexFace};
+use super::overlay_lift;
+use super::theme::{GlowLevel, Theme};
+
+/// Asset path of the embedded face grid shader.
+const SHADER_PATH: &str = "embedded://hex_terrain/h_terrain/face_grid.wgsl";
+/// Marker on the line overlay child of a [`HexFace`].
+#[derive(Component, Reflect)]
+pub struct FaceGrid;
+
+/// Additive ring/spoke line material shared by every [`FaceGrid`].
+#[derive(Asset, TypePath, AsBindGroup, Clone, Debug)]
+pub struct FaceGridMaterial {
+    /// Linear line color.
+    #[uniform(0)]
+    pub color: LinearRgba,
+    /// Concentric rings per world unit of hex radius.
+    #[uniform(0)]
+    pub ring_density: f32,
+    /// Angle of the first hex corner in the face's local XZ plane (radians).
+    #[uniform(0)]
+    pub corner_angle: f32,
+    /// Camera distance where the lines are fully faded.
+    #[uniform(0)]
+    pub fade_end: f32,
+}
+
+impl Material for FaceGridMaterial {
+    fn vertex_shader() -> ShaderRef {
+        SHADER_PATH.into()
+    }
+
+    fn fragment_shader() -> ShaderRef {
+        SHADER_PATH.into()
+    }
+
+    fn alpha_mode(&self) -> AlphaMode {
+        AlphaMode::Add
+    }
+
+    fn enable_prepass() -> bool {
+        false
+    }
+
+    fn enable_shadows() -> bool {
+        false
+    }
+}
+
+/// Theme edge color dimmed by [`HTerrainConfig::face_grid_intensity`] and scaled by `level`.
+fn face_grid_color(theme: &Theme, cfg: &HTerrainConfig, level: f32) -> LinearRgba {
+    LinearRgba::from(theme.edge.base) * cfg.face_grid_intensity * level
+}
+
+/// Packs a face radius into the overlay's [`MeshTag`] (thousandths of a world unit).
+pub fn face_grid_tag(radius: f32) -> MeshTag {
+    MeshTag((radius.max(0.0) * 1000.0).round() as u32)
+}
+
+/// Adds a [`FaceGrid`] overlay under every hex face.
+#[allow(clippy::too_many_arguments)]
+pub(super) fn spawn_face_grids(
+    grid: Single<&HGrid>,
+    faces: Query<(Entity, &Mesh3d, &ChildOf), With<HexFace>>,
+    cells: Query<&HCell>,
+    theme: Res<Theme>,
+    level: Res<GlowLevel>,
+    cfg: Res<HTerrainConfig>,
+    mut materials: ResMut<Assets<FaceGridMaterial>>,
+    mut commands: Commands,
+) {
+    let corner = grid.terrain.unit_corner(0);
+    let material = materials.add(FaceGridMaterial {
+        color: face_grid_color(&theme, &cfg, level.0),
+        ring_density: cfg.face_grid_ring_density,
+        corner_angle: corner.y.atan2(corner.x),
+        fade_end: cfg.edge_fade_end,
+    });
+    for (face, mesh, parent) in &faces {
+        let Some(radius) = cells
+            .get(parent.parent())
+            .ok()
+            .and_then(|cell| grid.terrain.radius(&cell.hex))
+        else {
+            continue;
+        };
+        commands.entity(face).with_child((
+            FaceGrid,
+            Mesh3d(mesh.0.clone()),
+            MeshMaterial3d(material.clone()),
+            face_grid_tag(radius),
+            // The face is scaled by its radius on XZ only, so the lift stays in world units.
+            Transform::from_xyz(0.0, overlay_lift::FACE_GRID, 0.0),
+        ));
+    }
+}
+
+/// Recolors the face grid lines when [`Theme`] or [`GlowLevel`] changes.
+pub(super) fn apply_face_grid_theme(
+    theme: Res<Theme>,
+    level: Res<GlowLevel>,
+    cfg: Res<HTerrainConfig>,
+    mut assets: ResMut<Assets<FaceGridMaterial>>,
+) {
+    for (_, mat) in assets.iter_mut() {
+        mat.color = face_grid_color(&theme, &cfg, level.0);
+    }
+}
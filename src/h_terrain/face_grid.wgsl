@@ -0,0 +1,77 @@
+// Procedural line detail on hex faces: concentric hex rings plus radial spokes to the corners.
+//
+// Drawn on a copy of the unit hex face mesh (circumradius 1 in local XZ) parented just above
+// each face. The mesh tag carries the face's world radius in thousandths, so the ring count
+// grows with hex size while every face shares one mesh and one material (single instanced draw).
+
+#import bevy_pbr::{
+    mesh_functions::{get_tag, get_world_from_local, mesh_position_local_to_world},
+    mesh_view_bindings::view,
+    view_transformations::position_world_to_clip,
+}
+
+struct FaceGrid {
+    color: vec4<f32>,
+    ring_density: f32,
+    corner_angle: f32,
+    fade_end: f32,
+}
+
+@group(#{MATERIAL_BIND_GROUP}) @binding(0) var<uniform> material: FaceGrid;
+
+struct Vertex {
+    @builtin(instance_index) instance_index: u32,
+    @location(0) position: vec3<f32>,
+}
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) local: vec2<f32>,
+    @location(1) distance: f32,
+    @location(2) @interpolate(flat) rings: f32,
+}
+
+const SQRT3_2: f32 = 0.8660254;
+const PI_3: f32 = 1.0471976;
+
+@vertex
+fn vertex(v: Vertex) -> VertexOutput {
+    let world_from_local = get_world_from_local(v.instance_index);
+    let world = mesh_position_local_to_world(world_from_local, vec4(v.position, 1.0)).xyz;
+
+    var out: VertexOutput;
+    out.clip_position = position_world_to_clip(world);
+    out.local = v.position.xz;
+    out.distance = distance(world, view.world_position);
+    let radius = f32(get_tag(v.instance_index)) / 1000.0;
+    out.rings = max(round(radius * material.ring_density), 1.0);
+    return out;
+}
+
+@fragment
+fn fragment(in: VertexOutput) -> @location(0) vec4<f32> {
+    // Hex distance: 0 at the center, 1 on the border (max projection onto the side normals).
+    var hex_d = 0.0;
+    var spoke_d = 1e4;
+    for (var i = 0; i < 3; i++) {
+        let corner = material.corner_angle + f32(i) * PI_3;
+        let side = corner + 0.5 * PI_3;
+        hex_d = max(hex_d, abs(dot(in.local, vec2(cos(side), sin(side)))) / SQRT3_2);
+        // Spokes: distance to the line through the center and two opposite corners.
+        spoke_d = min(spoke_d, abs(dot(in.local, vec2(-sin(corner), cos(corner)))));
+    }
+
+    let ring_coord = hex_d * in.rings;
+    let ring_aa = max(fwidth(ring_coord), 1e-4);
+    let ring = 1.0 - min(abs(fract(ring_coord + 0.5) - 0.5) / ring_aa, 1.0);
+    let spoke_aa = max(fwidth(spoke_d), 1e-4);
+    let spoke = 1.0 - min(spoke_d / spoke_aa, 1.0);
+
+    // Leave the border itself to the edge lines.
+    let inside = 1.0 - smoothstep(0.97, 1.0, hex_d);
+    let fade = 1.0 - smoothstep(0.4 * material.fade_end, material.fade_end, in.distance);
+
+    let intensity = max(ring, spoke) * inside * fade;
+    // Additive blend: output is premultiplied.
+    return vec4(material.color.rgb * intensity, intensity);
+}
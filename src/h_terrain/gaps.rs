@@ -10,9 +10,10 @@ use bevy::prelude::*;
 use bevy::render::render_resource::PrimitiveTopology;
 use hexx::{EdgeDirection, Hex, VertexDirection};
 
+use super::edge_material::{EdgeMaterial, edge_line_transform, edge_tag};
 use super::entities::{
-    HCell, Quad, QuadEdge, QuadOwner, QuadPos1Emitter, QuadPos2Emitter, QuadTail, Tri, TriOwner,
-    TriPos1Emitter, TriPos2Emitter,
+    EdgeRing, HCell, Quad, QuadEdge, QuadOwner, QuadPos1Emitter, QuadPos2Emitter, QuadTail, Tri,
+    TriOwner, TriPos1Emitter, TriPos2Emitter,
 };
 use super::h_grid_layout::HGridLayout;
 use super::math;
@@ -26,7 +27,8 @@ use super::math;
 /// participating [`Corner`](super::entities::Corner) entities so downstream
 /// systems can navigate from corner to gap mesh without hierarchy traversal.
 ///
-/// Four emissive [`QuadEdge`] cuboids are spawned as children of the mesh.
+/// Four [`QuadEdge`] lines are spawned as children of the mesh, sharing `edge_mesh` and
+/// `edge_material`, tagged with the owner's [`EdgeRing`] (also packed into their `MeshTag`).
 ///
 /// Returns `None` (no-op) when the neighbor or any corner entity is missing,
 /// which happens for hexes on the grid boundary.
@@ -35,7 +37,8 @@ pub(super) fn spawn_quad(
     commands: &mut Commands,
     meshes: &mut ResMut<Assets<Mesh>>,
     gap_material: &Handle<StandardMaterial>,
-    edge_material: &Handle<StandardMaterial>,
+    edge_mesh: &Handle<Mesh>,
+    edge_material: &Handle<EdgeMaterial>,
     terrain: &HGridLayout,
     corner_entities: &HashMap<(Hex, u8), Entity>,
     hex_entities: &HashMap<Hex, Entity>,
@@ -87,19 +90,17 @@ pub(super) fn spawn_quad(
     commands.entity(tail_entity).insert(QuadTail);
 
     // Spawn edge lines as children of the Quad mesh entity
-    let edge_thickness = 0.03;
     let origin = v0;
     let edges = [(v0, v3), (v1, v2), (v0, v1), (v3, v2)];
     for (from, to) in edges {
-        let local_from = from - origin;
-        let local_to = to - origin;
-        let (midpoint, length, rotation) = math::edge_cuboid_transform(local_from, local_to);
         let edge_entity = commands
             .spawn((
                 QuadEdge,
-                Mesh3d(meshes.add(Cuboid::new(length, edge_thickness, edge_thickness))),
+                EdgeRing(hex.ulength()),
+                edge_tag(hex.ulength(), 0.0),
+                Mesh3d(edge_mesh.clone()),
                 MeshMaterial3d(edge_material.clone()),
-                Transform::from_translation(midpoint).with_rotation(rotation),
+                edge_line_transform(from - origin, to - origin),
             ))
             .id();
         commands.entity(mesh_entity).add_child(edge_entity);
@@ -322,8 +323,8 @@ impl GapMeshAccess<'_, '_> {
         Some(())
     }
 
-    /// Recomputes [`QuadEdge`] transforms and meshes from the parent gap's
-    /// current vertex positions. No-op for Tri meshes (no edge children).
+    /// Recomputes [`QuadEdge`] transforms from the parent gap's current vertex
+    /// positions. No-op for Tri meshes (no edge children).
     fn reposition_edges(&mut self, gap: Entity) {
         let handle = &self.mesh_handles.get(gap).ok();
         let Some(handle) = handle else { return };
@@ -355,23 +356,14 @@ impl GapMeshAccess<'_, '_> {
         }
 
         let p: Vec<Vec3> = positions.iter().map(|p| Vec3::from_array(*p)).collect();
-        let edge_thickness = 0.03;
         let edges = [(p[0], p[3]), (p[1], p[2]), (p[0], p[1]), (p[3], p[2])];
 
         for (i, (from, to)) in edges.iter().enumerate() {
             let Some(&edge_entity) = edge_entities.get(i) else {
                 break;
             };
-            let (midpoint, length, rotation) = math::edge_cuboid_transform(*from, *to);
-
             if let Ok(mut tf) = self.edge_transforms.get_mut(edge_entity) {
-                *tf = Transform::from_translation(midpoint).with_rotation(rotation);
-            }
-
-            if let Ok(mesh3d) = self.mesh_handles.get(edge_entity)
-                && let Some(edge_mesh) = self.meshes.get_mut(&mesh3d.0)
-            {
-                *edge_mesh = Cuboid::new(length, edge_thickness, edge_thickness).into();
+                *tf = edge_line_transform(*from, *to);
             }
         }
     }
@@ -508,21 +500,19 @@ mod tests {
     /// Spawns 4 QuadEdge children on a gap, matching the spawn order in `spawn_quad`.
     fn spawn_quad_edges(app: &mut App, gap: Entity, positions: &[[f32; 3]; 4]) -> [Entity; 4] {
         let p: Vec<Vec3> = positions.iter().map(|p| Vec3::from_array(*p)).collect();
-        let edge_thickness = 0.03;
         let pairs = [(p[0], p[3]), (p[1], p[2]), (p[0], p[1]), (p[3], p[2])];
+        let mesh = app
+            .world_mut()
+            .resource_mut::<Assets<Mesh>>()
+            .add(super::super::edge_material::edge_line_mesh());
         let mut edges = [Entity::PLACEHOLDER; 4];
         for (i, (from, to)) in pairs.iter().enumerate() {
-            let (midpoint, length, rotation) = math::edge_cuboid_transform(*from, *to);
-            let mesh = app
-                .world_mut()
-                .resource_mut::<Assets<Mesh>>()
-                .add(Cuboid::new(length, edge_thickness, edge_thickness));
             let edge = app
                 .world_mut()
                 .spawn((
                     QuadEdge,
-                    Mesh3d(mesh),
-                    Transform::from_translation(midpoint).with_rotation(rotation),
+                    Mesh3d(mesh.clone()),
+                    edge_line_transform(*from, *to),
                 ))
                 .id();
             app.world_mut().entity_mut(gap).add_child(edge);
@@ -633,24 +623,11 @@ mod tests {
             "edge rotation should match"
         );
 
-        // Edge mesh should be a cuboid with the new length
-        let meshes = app.world().resource::<Assets<Mesh>>();
-        let edge_handle = &app.world().get::<Mesh3d>(edge_entities[2]).unwrap().0;
-        let edge_mesh = meshes.get(edge_handle).unwrap();
-        let edge_positions = edge_mesh
-            .attribute(Mesh::ATTRIBUTE_POSITION)
-            .unwrap()
-            .as_float3()
-            .unwrap();
-        // Cuboid half-extents: x = length/2, y = thickness/2, z = thickness/2
-        let max_x = edge_positions
-            .iter()
-            .map(|p| p[0].abs())
-            .fold(0.0f32, f32::max);
+        // The shared unit line mesh is stretched to the new length via scale.x
         assert!(
-            (max_x - expected_len / 2.0).abs() < 1e-4,
-            "edge mesh half-length: expected {}, got {max_x}",
-            expected_len / 2.0
+            (updated_tf.scale.x - expected_len).abs() < 1e-4,
+            "edge length scale: expected {expected_len}, got {}",
+            updated_tf.scale.x
         );
     }
 
@@ -0,0 +1,145 @@
+//! Optional cyberspace "glitch" flicker on random edge lines.
+//!
+//! With [`HTerrainConfig::glitch`] enabled, random [`QuadEdge`] lines occasionally get a
+//! [`Glitch`] for a few frames: their [`MeshTag`] glitch bits make `edge_line.wgsl` desaturate
+//! and channel-shift the line, and their transform is nudged by a small random jitter every
+//! frame. Both are restored when the glitch runs out.
+
+use bevy::mesh::MeshTag;
+use bevy::prelude::*;
+
+use super::HTerrainConfig;
+use super::edge_material::with_glitch;
+use super::entities::QuadEdge;
+
+/// An in-progress glitch flicker on a [`QuadEdge`].
+#[derive(Component, Reflect)]
+pub struct Glitch {
+    /// Frames left before the edge is restored.
+    pub frames_left: u32,
+    /// Glitch amount written into the edge's [`MeshTag`] (0..=1).
+    pub amount: f32,
+    /// Translation the edge had before the jitter.
+    pub origin: Vec3,
+    /// Jitter currently applied on top of `origin`.
+    pub offset: Vec3,
+}
+
+/// Random state and fractional glitch backlog.
+#[derive(Resource)]
+pub struct GlitchClock {
+    /// Xorshift state (never zero).
+    rng: u32,
+    /// Glitches owed but not yet started (accumulates `glitch_rate * dt`).
+    pending: f32,
+}
+
+impl Default for GlitchClock {
+    fn default() -> Self {
+        Self {
+            rng: 0x9e37_79b9,
+            pending: 0.0,
+        }
+    }
+}
+
+impl GlitchClock {
+    /// Next pseudo-random `u32` (xorshift32).
+    fn next_u32(&mut self) -> u32 {
+        let mut x = self.rng;
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        self.rng = x;
+        x
+    }
+
+    /// Next pseudo-random value in `-1.0..=1.0`.
+    fn next_signed(&mut self) -> f32 {
+        self.next_u32() as f32 / u32::MAX as f32 * 2.0 - 1.0
+    }
+
+    /// Random jitter offset with each axis in `-jitter..=jitter`.
+    fn jitter(&mut self, jitter: f32) -> Vec3 {
+        Vec3::new(self.next_signed(), self.next_signed(), self.next_signed()) * jitter
+    }
+}
+
+/// Starts glitches on random, currently calm edges at [`HTerrainConfig::glitch_rate`] per second.
+#[allow(clippy::type_complexity)]
+pub(super) fn start_glitches(
+    cfg: Res<HTerrainConfig>,
+    time: Res<Time>,
+    mut clock: ResMut<GlitchClock>,
+    edges: Query<(Entity, &Transform), (With<QuadEdge>, Without<Glitch>)>,
+    mut commands: Commands,
+) {
+    clock.pending += cfg.glitch_rate.max(0.0) * time.delta_secs();
+    let starts = clock.pending.floor();
+    if starts < 1.0 {
+        return;
+    }
+    clock.pending -= starts;
+
+    let count = edges.iter().len();
+    if count == 0 {
+        return;
+    }
+    for _ in 0..starts as u32 {
+        let pick = clock.next_u32() as usize % count;
+        let Some((entity, tf)) = edges.iter().nth(pick) else {
+            continue;
+        };
+        let amount = 0.6 + 0.4 * clock.next_signed().abs();
+        // A double pick in the same frame just restarts the same glitch.
+        commands.entity(entity).insert(Glitch {
+            frames_left: cfg.glitch_frames.max(1),
+            amount,
+            origin: tf.translation,
+            offset: Vec3::ZERO,
+        });
+    }
+}
+
+/// Re-jitters glitching edges each frame and restores them once their frames run out.
+///
+/// Runs after the FoV edge fade and laser extraction, which rewrite the tag and transform:
+/// the glitch bits are re-applied on top, and an edge moved by extraction adopts its new
+/// position as the origin to restore.
+pub(super) fn animate_glitches(
+    cfg: Res<HTerrainConfig>,
+    mut clock: ResMut<GlitchClock>,
+    mut edges: Query<(Entity, &mut Glitch, &mut Transform, &mut MeshTag)>,
+    mut commands: Commands,
+) {
+    for (entity, mut glitch, mut tf, mut tag) in &mut edges {
+        if tf.translation != glitch.origin + glitch.offset {
+            glitch.origin = tf.translation;
+        }
+        if glitch.frames_left == 0 {
+            tf.translation = glitch.origin;
+            *tag = with_glitch(&tag, 0.0);
+            commands.entity(entity).remove::<Glitch>();
+            continue;
+        }
+        glitch.frames_left -= 1;
+        glitch.offset = clock.jitter(cfg.glitch_jitter);
+        tf.translation = glitch.origin + glitch.offset;
+        *tag = with_glitch(&tag, glitch.amount);
+    }
+}
+
+/// Restores every glitching edge at once when [`HTerrainConfig::glitch`] is switched off, so no
+/// line is left jittered or tinted mid-flicker.
+pub(super) fn clear_glitches(
+    mut edges: Query<(Entity, &Glitch, &mut Transform, &mut MeshTag)>,
+    mut commands: Commands,
+) {
+    for (entity, glitch, mut tf, mut tag) in &mut edges {
+        if tf.translation == glitch.origin + glitch.offset {
+            tf.translation = glitch.origin;
+        }
+        *tag = with_glitch(&tag, 0.0);
+        commands.entity(entity).remove::<Glitch>();
+    }
+}
@@ -0,0 +1,107 @@
+//! Infinite neon grid plane at y=0 beneath the terrain.
+//!
+//! A single large quad follows the player on XZ; `grid_floor.wgsl` draws world-anchored
+//! anti-aliased lines on it, so the grid appears endless and stays put while the quad moves.
+
+use bevy::prelude::*;
+use bevy::render::render_resource::AsBindGroup;
+use bevy::shader::ShaderRef;
+
+use super::HTerrainConfig;
+use super::theme::{GlowLevel, Theme};
+use crate::PlayerPos;
+
+/// Asset path of the embedded grid floor shader.
+const SHADER_PATH: &str = "embedded://hex_terrain/h_terrain/grid_floor.wgsl";
+
+/// Marker on the grid floor entity.
+#[derive(Component, Reflect)]
+pub struct GridFloor;
+
+/// Additive world-space grid line material.
+#[derive(Asset, TypePath, AsBindGroup, Clone, Debug)]
+pub struct GridFloorMaterial {
+    /// Linear line color.
+    #[uniform(0)]
+    pub color: LinearRgba,
+    /// Distance between grid lines in world units.
+    #[uniform(0)]
+    pub cell_size: f32,
+    /// Camera distance where the grid is fully faded.
+    #[uniform(0)]
+    pub fade_end: f32,
+}
+
+impl GridFloorMaterial {
+    /// Builds the grid material from the theme edge color and `cfg` grid floor settings.
+    pub fn new(theme: &Theme, cfg: &HTerrainConfig) -> Self {
+        Self {
+            color: grid_floor_color(theme, cfg),
+            cell_size: cfg.grid_floor_cell_size,
+            fade_end: cfg.grid_floor_fade_end,
+        }
+    }
+}
+
+impl Material for GridFloorMaterial {
+    fn fragment_shader() -> ShaderRef {
+        SHADER_PATH.into()
+    }
+
+    fn alpha_mode(&self) -> AlphaMode {
+        AlphaMode::Add
+    }
+
+    fn enable_prepass() -> bool {
+        false
+    }
+
+    fn enable_shadows() -> bool {
+        false
+    }
+}
+
+/// Theme edge color dimmed by [`HTerrainConfig::grid_floor_intensity`].
+fn grid_floor_color(theme: &Theme, cfg: &HTerrainConfig) -> LinearRgba {
+    LinearRgba::from(theme.edge.base) * cfg.grid_floor_intensity
+}
+
+/// Spawns the grid floor plane, sized to cover the fade distance.
+pub(super) fn spawn_grid_floor(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<GridFloorMaterial>>,
+    theme: Res<Theme>,
+    cfg: Res<HTerrainConfig>,
+) {
+    let half = Vec2::splat(cfg.grid_floor_fade_end);
+    commands.spawn((
+        GridFloor,
+        Name::new("GridFloor"),
+        Mesh3d(meshes.add(Plane3d::new(Vec3::Y, half))),
+        MeshMaterial3d(materials.add(GridFloorMaterial::new(&theme, &cfg))),
+        Transform::default(),
+    ));
+}
+
+/// Keeps the grid floor centered under the player.
+pub(super) fn follow_player(
+    player: Res<PlayerPos>,
+    mut floor: Single<&mut Transform, With<GridFloor>>,
+) {
+    floor.translation.x = player.xz.x;
+    floor.translation.z = player.xz.y;
+}
+
+/// Recolors the grid floor when [`Theme`] or [`GlowLevel`] changes.
+pub(super) fn apply_grid_floor_theme(
+    theme: Res<Theme>,
+    level: Res<GlowLevel>,
+    cfg: Res<HTerrainConfig>,
+    floor: Single<&MeshMaterial3d<GridFloorMaterial>, With<GridFloor>>,
+    mut assets: ResMut<Assets<GridFloorMaterial>>,
+) {
+    if let Some(mat) = assets.get_mut(&floor.0) {
+        mat.color = grid_floor_color(&theme, &cfg) * level.0;
+    }
+}
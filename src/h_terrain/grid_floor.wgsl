@@ -0,0 +1,33 @@
+// World-anchored neon grid on the y=0 floor plane.
+//
+// Lines are drawn from the fragment's world XZ position, so the plane mesh can follow the
+// player without the grid sliding. Line width is one pixel via fwidth, and the grid fades
+// out with camera distance to hide the plane's border.
+
+#import bevy_pbr::{
+    forward_io::VertexOutput,
+    mesh_view_bindings::view,
+}
+
+struct GridFloor {
+    color: vec4<f32>,
+    cell_size: f32,
+    fade_end: f32,
+}
+
+@group(#{MATERIAL_BIND_GROUP}) @binding(0) var<uniform> material: GridFloor;
+
+@fragment
+fn fragment(in: VertexOutput) -> @location(0) vec4<f32> {
+    let coord = in.world_position.xz / material.cell_size;
+    let aa = max(fwidth(coord), vec2(1e-4));
+    let dist_to_line = abs(fract(coord - 0.5) - 0.5) / aa;
+    let line = 1.0 - min(min(dist_to_line.x, dist_to_line.y), 1.0);
+
+    let dist = distance(in.world_position.xyz, view.world_position);
+    let fade = 1.0 - smoothstep(0.4 * material.fade_end, material.fade_end, dist);
+
+    let intensity = line * fade;
+    // Additive blend: output is premultiplied.
+    return vec4(material.color.rgb * intensity, intensity);
+}
@@ -7,6 +7,15 @@ use super::math;
 
 use super::HGridSettings;
 
+/// Raw noise samples (in `-1.0..=1.0`) behind one hex's generated shape.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NoiseSample {
+    /// Height noise layer, mapped to `0..=max_height`.
+    pub height: f64,
+    /// Radius noise layer, mapped to `min_hex_radius..=max_hex_radius`.
+    pub radius: f64,
+}
+
 /// Encapsulates the hex layout, per-cell heights/radii, and vertex computation.
 ///
 /// Slimmed-down layout for the h_terrain module — owns only the data needed for
@@ -16,6 +25,7 @@ pub struct HGridLayout {
     unit_corners: [Vec2; 6],
     heights: HashMap<Hex, f32>,
     radii: HashMap<Hex, f32>,
+    noise: HashMap<Hex, NoiseSample>,
 }
 
 impl HGridLayout {
@@ -39,6 +49,7 @@ impl HGridLayout {
 
         let mut heights = HashMap::new();
         let mut radii = HashMap::new();
+        let mut noise = HashMap::new();
 
         for hex in shapes::hexagon(Hex::ZERO, g.radius) {
             let pos = layout.hex_to_world_pos(hex);
@@ -57,6 +68,13 @@ impl HGridLayout {
                 hex,
                 math::map_noise_to_range(radius_noise, g.min_hex_radius, g.max_hex_radius),
             );
+            noise.insert(
+                hex,
+                NoiseSample {
+                    height: noise_val,
+                    radius: radius_noise,
+                },
+            );
         }
 
         Self {
@@ -64,6 +82,7 @@ impl HGridLayout {
             unit_corners,
             heights,
             radii,
+            noise,
         }
     }
 
@@ -81,11 +100,30 @@ impl HGridLayout {
 
     // ── Per-hex data access ────────────────────────────────────────
 
-    /// Noise-derived terrain height for a hex.
+    /// Every hex in the grid (unordered).
+    pub fn hexes(&self) -> impl Iterator<Item = Hex> + '_ {
+        self.heights.keys().copied()
+    }
+
+    /// Terrain height for a hex (noise-derived, then edited by [`EditHexHeight`]).
+    ///
+    /// [`EditHexHeight`]: super::entities::EditHexHeight
     pub fn height(&self, hex: &Hex) -> Option<f32> {
         self.heights.get(hex).copied()
     }
 
+    /// Overrides the height of a hex already in the grid.
+    pub fn set_height(&mut self, hex: Hex, height: f32) {
+        if let Some(h) = self.heights.get_mut(&hex) {
+            *h = height;
+        }
+    }
+
+    /// Raw noise samples a hex was generated from.
+    pub fn noise_sample(&self, hex: &Hex) -> Option<NoiseSample> {
+        self.noise.get(hex).copied()
+    }
+
     /// Noise-derived visual radius for a hex.
     pub fn radius(&self, hex: &Hex) -> Option<f32> {
         self.radii.get(hex).copied()
@@ -137,6 +175,32 @@ mod tests {
         assert_eq!(layout.radii.len(), expected);
     }
 
+    #[test]
+    fn noise_samples_map_to_heights_and_edits_override() {
+        let g = default_grid_settings();
+        let mut layout = HGridLayout::from_settings(&g);
+        let hex = Hex::new(1, -1);
+        let noise = layout.noise_sample(&hex).unwrap();
+        assert_eq!(
+            layout.height(&hex),
+            Some(math::map_noise_to_range(noise.height, 0.0, g.max_height))
+        );
+        assert_eq!(
+            layout.radius(&hex),
+            Some(math::map_noise_to_range(
+                noise.radius,
+                g.min_hex_radius,
+                g.max_hex_radius
+            ))
+        );
+
+        layout.set_height(hex, 42.0);
+        assert_eq!(layout.height(&hex), Some(42.0));
+        // Hexes outside the grid stay absent.
+        layout.set_height(Hex::new(999, 0), 1.0);
+        assert_eq!(layout.height(&Hex::new(999, 0)), None);
+    }
+
     #[test]
     fn hex_to_world_and_back_roundtrip() {
         let g = default_grid_settings();
@@ -0,0 +1,184 @@
+//! Height heatmap analysis mode (G to toggle).
+//!
+//! While [`HeatmapMode`] is on, every [`HexFace`] is covered by an unlit [`HeatmapOverlay`]
+//! child colored by the cell's normalized height, quantized into a few shared band materials,
+//! and an egui legend maps the colors back to heights. The face's own material is never
+//! touched, so FoV fades, theme changes, and the aim highlight keep running underneath and
+//! the previous look is back as soon as the overlays are despawned.
+
+use bevy::prelude::*;
+use bevy_egui::egui;
+
+use super::HTerrainConfig;
+use super::entities::{HCell, HexFace};
+use super::overlay_lift;
+use super::theme::HeightGradient;
+use crate::locale::Locale;
+use crate::neon_ui::window_to_points;
+use crate::toast::Toast;
+
+/// Number of shared heatmap band materials.
+const BANDS: usize = 16;
+/// Whether the height heatmap replaces the face colors.
+#[derive(Resource, Default, Reflect)]
+pub struct HeatmapMode(pub bool);
+
+/// Marker on the false-color overlay child of a [`HexFace`] (see [`super::overlay_lift`]).
+#[derive(Component, Reflect)]
+pub struct HeatmapOverlay;
+
+/// Shared unlit band materials, lowest height first.
+#[derive(Resource)]
+pub struct HeatmapMaterials {
+    /// One material per band.
+    pub bands: Vec<Handle<StandardMaterial>>,
+}
+
+impl HeightGradient {
+    /// False-color analysis ramp: blue → cyan → green → yellow → red.
+    pub fn heatmap() -> Self {
+        Self {
+            stops: vec![
+                (0.0, LinearRgba::rgb(0.02, 0.05, 0.6)),
+                (0.25, LinearRgba::rgb(0.0, 0.6, 0.8)),
+                (0.5, LinearRgba::rgb(0.1, 0.8, 0.1)),
+                (0.75, LinearRgba::rgb(0.9, 0.8, 0.0)),
+                (1.0, LinearRgba::rgb(0.9, 0.05, 0.02)),
+            ],
+        }
+    }
+}
+
+/// Band index for normalized height `t`.
+pub fn heatmap_band(t: f32) -> usize {
+    (t.clamp(0.0, 1.0) * (BANDS - 1) as f32).round() as usize
+}
+
+/// Normalized height of the center of `band`.
+fn band_height(band: usize) -> f32 {
+    band as f32 / (BANDS - 1) as f32
+}
+
+/// Creates the shared band materials.
+pub(super) fn create_heatmap_materials(
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut commands: Commands,
+) {
+    let ramp = HeightGradient::heatmap();
+    let bands = (0..BANDS)
+        .map(|band| {
+            materials.add(StandardMaterial {
+                base_color: Color::from(ramp.sample(band_height(band))),
+                unlit: true,
+                ..default()
+            })
+        })
+        .collect();
+    commands.insert_resource(HeatmapMaterials { bands });
+}
+
+/// Toggles [`HeatmapMode`] on G.
+pub(super) fn toggle_heatmap(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut mode: ResMut<HeatmapMode>,
+    mut toasts: MessageWriter<Toast>,
+    locale: Res<Locale>,
+) {
+    if keys.just_pressed(KeyCode::KeyG) {
+        mode.0 = !mode.0;
+        let state = locale.t(if mode.0 { "on" } else { "off" });
+        toasts.write(Toast(locale.fmt("Height heatmap: {0}", &[&state])));
+    }
+}
+
+/// Spawns or despawns the face overlays when [`HeatmapMode`] changes, and covers the faces of a
+/// regenerated grid while the mode is on.
+pub(super) fn sync_heatmap(
+    mode: Res<HeatmapMode>,
+    cfg: Res<HTerrainConfig>,
+    heatmap: Res<HeatmapMaterials>,
+    cells: Query<&Transform, With<HCell>>,
+    faces: Query<(Entity, &Mesh3d, &ChildOf, Option<&Children>), With<HexFace>>,
+    overlays: Query<Entity, With<HeatmapOverlay>>,
+    mut commands: Commands,
+) {
+    if !mode.0 {
+        for overlay in &overlays {
+            commands.entity(overlay).despawn();
+        }
+        return;
+    }
+    for (face, mesh, parent, children) in &faces {
+        if children.is_some_and(|c| c.iter().any(|c| overlays.contains(c))) {
+            continue;
+        }
+        let Ok(tf) = cells.get(parent.parent()) else {
+            continue;
+        };
+        let band = heatmap_band(tf.translation.y / cfg.grid.max_height);
+        commands.entity(face).with_child((
+            HeatmapOverlay,
+            Mesh3d(mesh.0.clone()),
+            MeshMaterial3d(heatmap.bands[band].clone()),
+            Transform::from_xyz(0.0, overlay_lift::HEATMAP, 0.0),
+        ));
+    }
+}
+
+/// Re-bands the overlays of cells whose height changed (laser extraction).
+#[allow(clippy::type_complexity)]
+pub(super) fn update_heatmap(
+    cfg: Res<HTerrainConfig>,
+    heatmap: Res<HeatmapMaterials>,
+    cells: Query<(&Transform, &Children), (With<HCell>, Changed<Transform>)>,
+    faces: Query<&Children, With<HexFace>>,
+    mut overlays: Query<&mut MeshMaterial3d<StandardMaterial>, With<HeatmapOverlay>>,
+) {
+    for (tf, children) in &cells {
+        let band = &heatmap.bands[heatmap_band(tf.translation.y / cfg.grid.max_height)];
+        for face in children.iter().filter_map(|c| faces.get(c).ok()) {
+            for child in face.iter() {
+                if let Ok(mut mat) = overlays.get_mut(child) {
+                    mat.0 = band.clone();
+                }
+            }
+        }
+    }
+}
+
+/// Draws the heatmap color legend in the bottom-left corner.
+pub(super) fn draw_heatmap_legend(
+    mut egui_ctx: Single<&mut bevy_egui::EguiContext>,
+    window: Single<&Window>,
+    cfg: Res<HTerrainConfig>,
+) {
+    const SWATCH: egui::Vec2 = egui::vec2(18.0, 10.0);
+    let ramp = HeightGradient::heatmap();
+    let ctx = egui_ctx.get_mut();
+    let height = window.height() * window_to_points(ctx, &window);
+    egui::Area::new(egui::Id::new("heatmap_legend"))
+        .fixed_pos(egui::pos2(8.0, height - 24.0 - SWATCH.y * BANDS as f32))
+        .interactable(false)
+        .show(ctx, |ui| {
+            ui.spacing_mut().item_spacing.y = 0.0;
+            // Highest band on top.
+            for band in (0..BANDS).rev() {
+                let [r, g, b, _] = Color::from(ramp.sample(band_height(band)))
+                    .to_srgba()
+                    .to_u8_array();
+                ui.horizontal(|ui| {
+                    let (rect, _) = ui.allocate_exact_size(SWATCH, egui::Sense::hover());
+                    ui.painter()
+                        .rect_filled(rect, 0.0, egui::Color32::from_rgb(r, g, b));
+                    if band == BANDS - 1 || band == 0 {
+                        let height = band_height(band) * cfg.grid.max_height;
+                        ui.label(
+                            egui::RichText::new(format!("{height:.1}"))
+                                .color(egui::Color32::WHITE)
+                                .small(),
+                        );
+                    }
+                });
+            }
+        });
+}
@@ -0,0 +1,83 @@
+//! Hex inspection panel: the noise behind one hex, its neighbors, and height nudges.
+//!
+//! While `Inspecting`, the clicked [`SelectedHex`] (or the [`ActiveHex`] until one is picked)
+//! gets an egui window with its raw height/radius noise samples and the values they map to,
+//! its neighbors' heights, buttons that write [`EditHexHeight`] by
+//! [`HTerrainConfig::hex_edit_step`], and a text field for the hex's note in [`HexNotes`].
+
+use bevy::prelude::*;
+use bevy_egui::egui;
+
+use super::entities::{EditHexHeight, HGrid};
+use super::notes::{HexNotes, MAX_NOTE_LEN};
+use super::picking::SelectedHex;
+use super::{ActiveHex, HTerrainConfig};
+
+/// Draws the inspection panel for the [`SelectedHex`], falling back to the [`ActiveHex`].
+pub(super) fn draw_hex_panel(
+    mut egui_ctx: Single<&mut bevy_egui::EguiContext>,
+    cfg: Res<HTerrainConfig>,
+    active: Res<ActiveHex>,
+    selected: Res<SelectedHex>,
+    grid: Single<&HGrid>,
+    mut edits: MessageWriter<EditHexHeight>,
+    mut notes: ResMut<HexNotes>,
+) {
+    let Some(hex) = selected.0.or(active.current) else {
+        return;
+    };
+    let terrain = &grid.terrain;
+    let (Some(height), Some(radius), Some(noise)) = (
+        terrain.height(&hex),
+        terrain.radius(&hex),
+        terrain.noise_sample(&hex),
+    ) else {
+        return;
+    };
+
+    // Stack above the minimap, which shares the bottom-right corner.
+    let bottom = if cfg.minimap {
+        -16.0 - cfg.minimap_size
+    } else {
+        -8.0
+    };
+    egui::Window::new("Hex")
+        .anchor(egui::Align2::RIGHT_BOTTOM, [-8.0, bottom])
+        .resizable(false)
+        .show(egui_ctx.get_mut(), |ui| {
+            ui.monospace(format!("hex     {},{}", hex.x, hex.y));
+            ui.separator();
+            ui.monospace(format!("noise   height {:+.3}", noise.height));
+            ui.monospace(format!("        radius {:+.3}", noise.radius));
+            ui.monospace(format!("height  {height:.2}"));
+            ui.monospace(format!("radius  {radius:.2}"));
+            ui.separator();
+            ui.label("Neighbors");
+            for n in hex.all_neighbors() {
+                let text = match terrain.height(&n) {
+                    Some(h) => format!("{:>3},{:<3} {h:>6.2}  {:+.2}", n.x, n.y, h - height),
+                    None => format!("{:>3},{:<3}      -", n.x, n.y),
+                };
+                ui.monospace(text);
+            }
+            ui.separator();
+            ui.horizontal(|ui| {
+                let step = cfg.hex_edit_step;
+                if ui.button(format!("▼ {step:.2}")).clicked() {
+                    edits.write(EditHexHeight { hex, delta: -step });
+                }
+                if ui.button(format!("▲ {step:.2}")).clicked() {
+                    edits.write(EditHexHeight { hex, delta: step });
+                }
+            });
+            ui.separator();
+            ui.label("Note");
+            let mut text = notes.get(hex).unwrap_or_default().to_owned();
+            let field = egui::TextEdit::singleline(&mut text)
+                .char_limit(MAX_NOTE_LEN)
+                .hint_text("annotate this hex");
+            if ui.add(field).changed() {
+                notes.set(hex, &text);
+            }
+        });
+}
@@ -0,0 +1,130 @@
+//! Floating per-hex labels: coordinates (axial / offset / cube), height, or radius.
+//!
+//! Drawn with egui at each [`Revealed`] hex center within
+//! [`HTerrainConfig::hex_label_distance`] of the camera, in the mode picked by
+//! [`HTerrainConfig::hex_labels`] (selectable in the settings panel). Unrevealed hexes stay
+//! unlabeled, so the labels don't give away terrain not yet seen.
+
+use bevy::prelude::*;
+use bevy_egui::egui;
+use hexx::{Hex, HexOrientation, OffsetHexMode};
+
+use super::HTerrainConfig;
+use super::entities::{HCell, HGrid, Revealed};
+use crate::drone::Player;
+use crate::neon_ui::window_to_points;
+
+/// What each hex label shows.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+pub enum HexLabelMode {
+    /// No labels.
+    #[default]
+    Off,
+    /// Axial `q,r`.
+    Axial,
+    /// Odd-q offset `col,row` (flat-topped, the layout default).
+    Offset,
+    /// Cube `x,y,z`.
+    Cube,
+    /// Terrain height.
+    Height,
+    /// Visual hex radius.
+    Radius,
+}
+
+impl HexLabelMode {
+    /// All modes in picker order.
+    pub const ALL: [HexLabelMode; 6] = [
+        HexLabelMode::Off,
+        HexLabelMode::Axial,
+        HexLabelMode::Offset,
+        HexLabelMode::Cube,
+        HexLabelMode::Height,
+        HexLabelMode::Radius,
+    ];
+
+    /// Short name for the picker.
+    pub fn label(self) -> &'static str {
+        match self {
+            HexLabelMode::Off => "off",
+            HexLabelMode::Axial => "axial",
+            HexLabelMode::Offset => "offset",
+            HexLabelMode::Cube => "cube",
+            HexLabelMode::Height => "height",
+            HexLabelMode::Radius => "radius",
+        }
+    }
+}
+
+/// Label text for `hex` in `mode` (`None` when labels are off).
+pub fn hex_label_text(mode: HexLabelMode, hex: Hex, height: f32, radius: f32) -> Option<String> {
+    Some(match mode {
+        HexLabelMode::Off => return None,
+        HexLabelMode::Axial => format!("{},{}", hex.x, hex.y),
+        HexLabelMode::Offset => {
+            let [col, row] =
+                hex.to_offset_coordinates(OffsetHexMode::Odd, HexOrientation::default());
+            format!("{col},{row}")
+        }
+        HexLabelMode::Cube => format!("{},{},{}", hex.x, hex.y, hex.z()),
+        HexLabelMode::Height => format!("{height:.1}"),
+        HexLabelMode::Radius => format!("{radius:.2}"),
+    })
+}
+
+/// Draws a label over every revealed hex center within the distance cutoff.
+pub(super) fn draw_hex_labels(
+    mut egui_ctx: Single<&mut bevy_egui::EguiContext>,
+    cfg: Res<HTerrainConfig>,
+    window: Single<&Window>,
+    camera: Single<(&Camera, &GlobalTransform), With<Player>>,
+    grid: Single<&HGrid>,
+    revealed: Query<(), (With<HCell>, With<Revealed>)>,
+) {
+    let (camera, cam_gt) = *camera;
+    let Some(viewport) = camera.logical_viewport_size() else {
+        return;
+    };
+    let ctx = egui_ctx.get_mut();
+    // The camera may render to a scaled offscreen target: map viewport → window pixels → points.
+    let to_points = window.size() / viewport * window_to_points(ctx, &window);
+    let cam_pos = cam_gt.translation();
+    let font = egui::FontId::monospace(cfg.hex_label_font_size);
+
+    egui::Area::new(egui::Id::new("hex_labels"))
+        .fixed_pos(egui::pos2(0.0, 0.0))
+        .interactable(false)
+        .order(egui::Order::Background)
+        .show(ctx, |ui| {
+            let painter = ui.painter();
+            for (&hex, &cell) in &grid.hex_entities {
+                if !revealed.contains(cell) {
+                    continue;
+                }
+                let (Some(height), Some(radius)) =
+                    (grid.terrain.height(&hex), grid.terrain.radius(&hex))
+                else {
+                    continue;
+                };
+                let xz = grid.terrain.hex_to_world_pos(hex);
+                let pos = Vec3::new(xz.x, height, xz.y);
+                if pos.distance(cam_pos) > cfg.hex_label_distance {
+                    continue;
+                }
+                let Some(text) = hex_label_text(cfg.hex_labels, hex, height, radius) else {
+                    return;
+                };
+                let Ok(vp) = camera.world_to_viewport(cam_gt, pos) else {
+                    continue;
+                };
+                let p = vp * to_points;
+                painter.text(
+                    egui::pos2(p.x, p.y),
+                    egui::Align2::CENTER_CENTER,
+                    text,
+                    font.clone(),
+                    egui::Color32::WHITE,
+                );
+            }
+        });
+}
@@ -0,0 +1,181 @@
+//! Procedural landmark structures on the grid's most notable hexes.
+//!
+//! After each grid generation [`spawn_landmarks`] picks up to one site per [`LandmarkKind`] with
+//! [`landmark_sites`]: the highest peak gets a neon obelisk, the deepest basin a ring tower, and
+//! the largest-radius cell an antenna array. Each structure is a [`LandmarkMarker`] child of its
+//! [`HCell`](super::HCell) built from unit-cuboid beams ([`landmark_beams`], placed with
+//! [`edge_cuboid_transform`] like the path segments), so it follows height edits and goes away with
+//! the grid. The minimap marks landmarks on revealed cells, and the HUD names the nearest one.
+
+use bevy::prelude::*;
+use hexx::Hex;
+
+use super::HTerrainConfig;
+use super::entities::HGrid;
+use super::materials::TerrainMaterials;
+use super::math::edge_cuboid_transform;
+
+/// Kind of landmark structure, one per notable site.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+pub enum LandmarkKind {
+    /// Tapered spire on the highest peak.
+    Obelisk,
+    /// Mast with stacked hexagonal rings in the deepest basin.
+    RingTower,
+    /// Three braced masts on the largest-radius cell.
+    AntennaArray,
+}
+
+impl LandmarkKind {
+    /// All kinds, in placement priority order.
+    pub const ALL: [Self; 3] = [Self::Obelisk, Self::RingTower, Self::AntennaArray];
+
+    /// Display name (a [`Locale`](crate::locale::Locale) key).
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Obelisk => "Summit Obelisk",
+            Self::RingTower => "Basin Ring Tower",
+            Self::AntennaArray => "Wide Antenna Array",
+        }
+    }
+}
+
+/// A landmark structure, child of the [`HCell`](super::HCell) it stands on.
+#[derive(Component, Clone, Debug, Reflect)]
+pub struct LandmarkMarker {
+    /// Structure kind.
+    pub kind: LandmarkKind,
+    /// The hex it stands on.
+    #[reflect(ignore)]
+    pub hex: Hex,
+}
+
+impl LandmarkMarker {
+    /// Display name (a [`Locale`](crate::locale::Locale) key).
+    pub fn name(&self) -> &'static str {
+        self.kind.name()
+    }
+}
+
+/// Picks a distinct hex per [`LandmarkKind`] from `(hex, height, radius)` cells: the highest,
+/// the lowest, and the widest. Ties go to the smaller coordinate, so a seed always yields the
+/// same sites. Kinds whose candidates are all taken (tiny grids) are skipped.
+pub fn landmark_sites(
+    cells: impl IntoIterator<Item = (Hex, f32, f32)>,
+) -> Vec<(Hex, LandmarkKind)> {
+    let mut cells: Vec<(Hex, f32, f32)> = cells.into_iter().collect();
+    cells.sort_unstable_by_key(|(hex, _, _)| (hex.x, hex.y));
+    let mut sites: Vec<(Hex, LandmarkKind)> = Vec::new();
+    for kind in LandmarkKind::ALL {
+        let score = |&(_, height, radius): &(Hex, f32, f32)| match kind {
+            LandmarkKind::Obelisk => height,
+            LandmarkKind::RingTower => -height,
+            LandmarkKind::AntennaArray => radius,
+        };
+        // `max_by` keeps the last of equal elements; scan in reverse to favor the first.
+        let best = cells
+            .iter()
+            .rev()
+            .filter(|(hex, _, _)| sites.iter().all(|(taken, _)| taken != hex))
+            .max_by(|a, b| score(a).total_cmp(&score(b)));
+        if let Some(&(hex, _, _)) = best {
+            sites.push((hex, kind));
+        }
+    }
+    sites
+}
+
+/// Beam segments for a `kind` structure in cell-local space: standing on the face (y = 0)
+/// inside a hex of `radius` with unit `corners`, `height` tall.
+pub fn landmark_beams(
+    kind: LandmarkKind,
+    corners: [Vec2; 6],
+    radius: f32,
+    height: f32,
+) -> Vec<(Vec3, Vec3)> {
+    let at = |corner: usize, scale: f32, y: f32| {
+        let c = corners[corner % 6] * radius * scale;
+        Vec3::new(c.x, y, c.y)
+    };
+    let mut beams = Vec::new();
+    match kind {
+        LandmarkKind::Obelisk => {
+            let tip = Vec3::Y * height;
+            for i in [0, 2, 4] {
+                beams.push((at(i, 0.4, 0.0), tip));
+                beams.push((at(i, 0.4, 0.0), at(i + 2, 0.4, 0.0)));
+                beams.push((at(i, 0.2, height * 0.6), at(i + 2, 0.2, height * 0.6)));
+            }
+            beams.push((tip, tip + Vec3::Y * height * 0.15));
+        }
+        LandmarkKind::RingTower => {
+            beams.push((Vec3::ZERO, Vec3::Y * height));
+            for (y, scale) in [(0.4, 0.6), (0.7, 0.45), (1.0, 0.3)] {
+                for i in 0..6 {
+                    beams.push((at(i, scale, height * y), at(i + 1, scale, height * y)));
+                }
+            }
+        }
+        LandmarkKind::AntennaArray => {
+            let masts = [(0, 0.6), (2, 0.8), (4, 1.0)];
+            for (i, share) in masts {
+                beams.push((at(i, 0.6, 0.0), at(i, 0.6, height * share)));
+            }
+            for (k, &(i, _)) in masts.iter().enumerate() {
+                let (j, _) = masts[(k + 1) % masts.len()];
+                beams.push((at(i, 0.6, height * 0.5), at(j, 0.6, height * 0.5)));
+            }
+        }
+    }
+    beams
+}
+
+/// Places a landmark structure on each notable hex of the freshly generated grid.
+pub(super) fn spawn_landmarks(
+    cfg: Res<HTerrainConfig>,
+    grid: Single<&HGrid>,
+    mats: Res<TerrainMaterials>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut commands: Commands,
+) {
+    let terrain = &grid.terrain;
+    let sites = landmark_sites(
+        grid.hex_entities
+            .keys()
+            .filter_map(|&hex| Some((hex, terrain.height(&hex)?, terrain.radius(&hex)?))),
+    );
+    let material = materials.add(StandardMaterial {
+        base_color: cfg.landmark_color,
+        emissive: LinearRgba::from(cfg.landmark_color) * cfg.landmark_glow,
+        ..default()
+    });
+    let corners = std::array::from_fn(|i| terrain.unit_corner(i));
+    for (hex, kind) in sites {
+        let (Some(&cell), Some(radius)) = (grid.hex_entities.get(&hex), terrain.radius(&hex))
+        else {
+            continue;
+        };
+        let landmark = commands
+            .spawn((
+                Name::new(kind.name()),
+                LandmarkMarker { kind, hex },
+                Transform::default(),
+                Visibility::default(),
+                ChildOf(cell),
+            ))
+            .id();
+        for (from, to) in landmark_beams(kind, corners, radius, cfg.landmark_height) {
+            let (midpoint, length, rotation) = edge_cuboid_transform(from, to);
+            commands.spawn((
+                Mesh3d(mats.path_mesh.clone()),
+                MeshMaterial3d(material.clone()),
+                Transform {
+                    translation: midpoint,
+                    rotation,
+                    scale: Vec3::new(length, cfg.landmark_width, cfg.landmark_width),
+                },
+                ChildOf(landmark),
+            ));
+        }
+    }
+}
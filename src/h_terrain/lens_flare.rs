@@ -0,0 +1,163 @@
+//! Anamorphic lens flares over the highest visible peaks.
+//!
+//! With [`HTerrainConfig::lens_flare`] enabled, the [`HTerrainConfig::lens_flare_count`]
+//! highest in-FoV cells on screen each get a horizontal streak drawn over their projected
+//! position (egui painter, like the crosshair). A flare fades out as its peak drifts away
+//! from the view direction or drops behind nearer terrain (raycast occlusion), easing
+//! between frames so flares don't pop.
+
+use bevy::picking::mesh_picking::ray_cast::{MeshRayCast, MeshRayCastSettings};
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+use bevy_egui::egui;
+
+use super::HTerrainConfig;
+use super::entities::{HCell, HexFace, InFov};
+use super::theme::Theme;
+use crate::drone::Player;
+use crate::neon_ui::window_to_points;
+
+/// Distance short of the peak at which a face hit still counts as the peak itself.
+const OCCLUSION_SLACK: f32 = 0.1;
+
+/// A flare over one peak.
+#[derive(Clone, Copy, Debug)]
+pub struct LensFlare {
+    /// Window position (logical pixels) of the peak.
+    pub pos: Vec2,
+    /// Current opacity (0..=1).
+    pub alpha: f32,
+}
+
+/// Flares by [`HCell`], including ones still fading out after losing their peak slot.
+#[derive(Resource, Default)]
+pub struct LensFlares {
+    /// Current flares.
+    pub flares: HashMap<Entity, LensFlare>,
+}
+
+/// Flare strength for a peak in direction `to_peak` from a camera looking along `forward`:
+/// 1 straight ahead, falling to 0 at `fade_angle` (radians) off-axis.
+pub fn view_fade(forward: Vec3, to_peak: Vec3, fade_angle: f32) -> f32 {
+    let angle = forward.angle_between(to_peak);
+    (1.0 - angle / fade_angle.max(1e-3)).clamp(0.0, 1.0)
+}
+
+/// Picks the peaks, tests their occlusion, and eases every flare toward its target opacity.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub(super) fn update_lens_flares(
+    cfg: Res<HTerrainConfig>,
+    time: Res<Time>,
+    camera: Single<(&Camera, &GlobalTransform), With<Player>>,
+    window: Single<&Window>,
+    cells: Query<(Entity, &GlobalTransform), (With<HCell>, With<InFov>)>,
+    faces: Query<&ChildOf, With<HexFace>>,
+    mut raycast: MeshRayCast,
+    mut flares: ResMut<LensFlares>,
+) {
+    let (camera, cam_gt) = *camera;
+    let Some(viewport) = camera.logical_viewport_size() else {
+        return;
+    };
+    // The camera may render to a scaled offscreen target; egui draws in window space.
+    let to_window = window.size() / viewport;
+    let eye = cam_gt.translation();
+
+    let mut peaks: Vec<(Entity, Vec3, Vec2)> = cells
+        .iter()
+        .filter_map(|(cell, gt)| {
+            let pos = gt.translation();
+            let vp = camera.world_to_viewport(cam_gt, pos).ok()?;
+            let on_screen = vp.cmpge(Vec2::ZERO).all() && vp.cmple(viewport).all();
+            on_screen.then_some((cell, pos, vp))
+        })
+        .collect();
+    peaks.sort_by(|a, b| b.1.y.total_cmp(&a.1.y));
+    peaks.truncate(cfg.lens_flare_count);
+
+    // Terrain faces only, so face-mesh overlays never count as the occluder.
+    let filter = |e| faces.contains(e);
+    let settings = MeshRayCastSettings::default().with_filter(&filter);
+    let mut targets: HashMap<Entity, f32> = HashMap::default();
+    for &(cell, pos, vp) in &peaks {
+        let to_peak = pos - eye;
+        let fade = view_fade(*cam_gt.forward(), to_peak, cfg.lens_flare_fade_angle);
+        let Ok(dir) = Dir3::new(to_peak) else {
+            continue;
+        };
+        let distance = to_peak.length();
+        let blocker = raycast
+            .cast_ray(Ray3d::new(eye, dir), &settings)
+            .iter()
+            .find_map(|(hit, data)| faces.get(*hit).ok().map(|p| (p.parent(), data.distance)));
+        let visible = match blocker {
+            Some((hit_cell, d)) => hit_cell == cell || d >= distance - OCCLUSION_SLACK,
+            None => true,
+        };
+        targets.insert(cell, if visible { fade } else { 0.0 });
+        flares
+            .flares
+            .entry(cell)
+            .or_insert(LensFlare {
+                pos: Vec2::ZERO,
+                alpha: 0.0,
+            })
+            .pos = vp * to_window;
+    }
+
+    let k = 1.0 - (-cfg.lens_flare_fade_speed * time.delta_secs()).exp();
+    flares.flares.retain(|cell, flare| {
+        let target = targets.get(cell).copied().unwrap_or(0.0);
+        flare.alpha += (target - flare.alpha) * k;
+        targets.contains_key(cell) || flare.alpha > 0.01
+    });
+}
+
+/// Draws each flare as a long horizontal streak, a softer halo, and a bright core.
+pub(super) fn draw_lens_flares(
+    mut egui_ctx: Single<&mut bevy_egui::EguiContext>,
+    window: Single<&Window>,
+    cfg: Res<HTerrainConfig>,
+    theme: Res<Theme>,
+    flares: Res<LensFlares>,
+) {
+    let tint = LinearRgba::from(theme.edge.base);
+    let [r, g, b, _] = Color::from(LinearRgba::rgb(
+        tint.red.min(1.0),
+        tint.green.min(1.0),
+        tint.blue.min(1.0),
+    ))
+    .to_srgba()
+    .to_u8_array();
+    let color = |alpha: f32| egui::Color32::from_rgba_unmultiplied(r, g, b, (alpha * 255.0) as u8);
+    let half = cfg.lens_flare_width / 2.0;
+    let ctx = egui_ctx.get_mut();
+    let to_points = window_to_points(ctx, &window);
+
+    egui::Area::new(egui::Id::new("lens_flares"))
+        .fixed_pos(egui::pos2(0.0, 0.0))
+        .interactable(false)
+        .order(egui::Order::Background)
+        .show(ctx, |ui| {
+            let painter = ui.painter();
+            for flare in flares.flares.values() {
+                let pos = flare.pos * to_points;
+                let center = egui::pos2(pos.x, pos.y);
+                painter.add(egui::Shape::ellipse_filled(
+                    center,
+                    egui::vec2(half, 1.5),
+                    color(flare.alpha * 0.6),
+                ));
+                painter.add(egui::Shape::ellipse_filled(
+                    center,
+                    egui::vec2(half * 0.3, 5.0),
+                    color(flare.alpha * 0.25),
+                ));
+                painter.circle_filled(
+                    center,
+                    3.0,
+                    egui::Color32::from_white_alpha((flare.alpha * 220.0) as u8),
+                );
+            }
+        });
+}
@@ -5,79 +5,35 @@ use bevy::ecs::relationship::Relationship;
 use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 
-use super::HTerrainConfig;
+use super::{ActiveHex, HTerrainConfig};
+use bevy::mesh::MeshTag;
+
+use super::edge_material::{EdgeMaterial, edge_line_mesh, edge_tag};
 use super::entities::{
-    AimStar, FovTransition, HCell, HexFace, InFov, InSight, PreSightMaterial, Quad, QuadEdge, Tri,
+    AimStar, EdgeRing, FovTransition, HCell, HeightGlow, HexFace, InFov, InSight, PreSightMaterial,
+    Quad, QuadEdge, RevealAnim, Revealed, Tri, Visited,
 };
+use super::reveal_fx::reveal_progress;
+use super::theme::{FaceFinish, GlowLevel, Theme, ThemeColor};
 use crate::drone::Player;
 
-/// Base/default terrain color palette.
-#[derive(Clone, Copy)]
-pub enum OrigPalette {
-    Hex,
-    Gap,
-    Edge,
-    Debug,
-    ClearColor,
-}
-
-impl From<OrigPalette> for Color {
-    fn from(p: OrigPalette) -> Self {
-        match p {
-            OrigPalette::Hex => Color::srgb(0.4, 0.5, 0.1), // olive green
-            OrigPalette::Gap => Color::srgb(0.1, 0.1, 0.04), // near-black brown
-            OrigPalette::Edge => Color::srgb(0.0, 0.5, 1.0), // azure blue
-            OrigPalette::Debug => Color::srgb(1.0, 0.2, 0.8), // hot pink
-            OrigPalette::ClearColor => Color::srgb(0.01, 0.01, 0.02), // near-black navy
-        }
-    }
-}
-
-impl From<OrigPalette> for LinearRgba {
-    fn from(p: OrigPalette) -> Self {
-        match p {
-            OrigPalette::Gap => LinearRgba::rgb(0.0255, 0.051, 0.085), // dim navy glow
-            OrigPalette::Edge => LinearRgba::rgb(0.0, 20.0, 40.0),     // intense cyan bloom
-            OrigPalette::Debug => LinearRgba::rgb(4.0, 0.8, 3.2),      // bright magenta bloom
-            _ => LinearRgba::BLACK,
-        }
-    }
-}
-
-/// FoV highlight + aim color palette.
-#[derive(Clone, Copy)]
-pub enum FovPalette {
-    Hex,
-    Gap,
-    Edge,
-    Aim,
-}
-
-impl From<FovPalette> for Color {
-    fn from(p: FovPalette) -> Self {
-        match p {
-            FovPalette::Hex | FovPalette::Edge => Color::srgb(0.2, 0.9, 0.3), // bright green
-            FovPalette::Gap => Color::srgb(0.165, 0.745, 0.25),               // muted lime green
-            FovPalette::Aim => Color::srgb(0.6, 0.1, 0.8),                    // purple
-        }
-    }
-}
-
-impl From<FovPalette> for LinearRgba {
-    fn from(p: FovPalette) -> Self {
-        match p {
-            FovPalette::Hex | FovPalette::Edge => LinearRgba::rgb(0.04, 0.18, 0.06), // green-tinted glow
-            FovPalette::Gap => LinearRgba::rgb(0.022, 0.10, 0.034), // soft green glow
-            FovPalette::Aim => LinearRgba::rgb(0.92, 0.32, 2.56),   // vivid violet bloom
-        }
-    }
-}
+/// Debug marker color (hot pink), independent of the active [`Theme`].
+const DEBUG_COLOR: Color = Color::srgb(1.0, 0.2, 0.8);
+/// Debug marker glow (bright magenta bloom).
+const DEBUG_EMISSIVE: LinearRgba = LinearRgba::rgb(4.0, 0.8, 3.2);
+/// `(metallic, perceptual_roughness, reflectance)` of hologram surfaces: a glossy dielectric
+/// whose Fresnel reflection of the neon environment map rims faces seen at grazing angles.
+const HOLOGRAM_PBR: (f32, f32, f32) = (0.0, 0.1, 1.0);
 
 /// Material handles for terrain rendering: hex faces, gaps, aim highlight, and edges.
 #[derive(Resource)]
 pub struct TerrainMaterials {
     /// Original hex face material.
     pub hex_original: Handle<StandardMaterial>,
+    /// Hex face material for [`Visited`] cells outside the FoV.
+    pub hex_visited: Handle<StandardMaterial>,
+    /// Dimmed hex face material for cells not yet [`Revealed`] (fog of war).
+    pub hex_fogged: Handle<StandardMaterial>,
     /// Highlight hex face material (emissive warm glow).
     pub hex_highlight: Handle<StandardMaterial>,
     /// Original gap (Quad/Tri) material.
@@ -90,74 +46,176 @@ pub struct TerrainMaterials {
     pub aim_star: Handle<StandardMaterial>,
     /// Pre-built aim-star cuboid mesh handle.
     pub aim_star_mesh: Handle<Mesh>,
-    /// Bright emissive edge-line material for quad edges.
-    pub edge: Handle<StandardMaterial>,
-    /// Highlight edge-line material for quad edges within FoV.
-    pub edge_highlight: Handle<StandardMaterial>,
+    /// Unit cuboid for path segments (scaled to length × width × width, drawn with `aim_star`)
+    /// and landmark beams.
+    pub path_mesh: Handle<Mesh>,
+    /// Shared unit line mesh for all quad edges (see [`edge_line_mesh`]).
+    pub edge_mesh: Handle<Mesh>,
+    /// Single instanced glow line material shared by every quad edge.
+    pub edge: Handle<EdgeMaterial>,
+    /// Glowing reveal particle material (edge color).
+    pub reveal_particle: Handle<StandardMaterial>,
+    /// Shared reveal particle cube mesh.
+    pub reveal_particle_mesh: Handle<Mesh>,
+    /// Unit-radius flat ring for scanner pulses (scaled to the wavefront, drawn with `aim_star`).
+    pub scanner_ring_mesh: Handle<Mesh>,
 }
 
 impl TerrainMaterials {
-    pub fn new(materials: &mut Assets<StandardMaterial>, meshes: &mut Assets<Mesh>) -> Self {
-        Self {
-            hex_original: materials.add(StandardMaterial {
-                base_color: OrigPalette::Hex.into(),
-                ..default()
-            }),
-            hex_highlight: materials.add(StandardMaterial {
-                base_color: FovPalette::Hex.into(),
-                emissive: FovPalette::Hex.into(),
-                ..default()
-            }),
-            gap_original: materials.add(StandardMaterial {
-                base_color: OrigPalette::Gap.into(),
-                emissive: OrigPalette::Gap.into(),
-                cull_mode: None,
-                ..default()
-            }),
-            gap_highlight: materials.add(StandardMaterial {
-                base_color: FovPalette::Gap.into(),
-                emissive: FovPalette::Gap.into(),
+    pub fn new(
+        materials: &mut Assets<StandardMaterial>,
+        edge_materials: &mut Assets<EdgeMaterial>,
+        meshes: &mut Assets<Mesh>,
+        theme: &Theme,
+        cfg: &HTerrainConfig,
+    ) -> Self {
+        let holo = hologram(theme, cfg);
+        let face = |c| face_material(c, theme.face_finish, holo, theme.flat);
+        let gap = |c: ThemeColor| {
+            let mut mat = StandardMaterial {
+                base_color: c.base,
+                emissive: c.emissive,
                 cull_mode: None,
                 ..default()
-            }),
-            hex_in_aim: materials.add(StandardMaterial {
-                base_color: FovPalette::Hex.into(),
-                emissive: LinearRgba::rgb(0.06, 0.27, 0.09),
-                ..default()
-            }),
+            };
+            set_surface(&mut mat, FaceFinish::Matte, holo, theme.flat);
+            mat
+        };
+        Self {
+            hex_original: materials.add(face(theme.face)),
+            hex_visited: materials.add(face(theme.face_visited)),
+            hex_fogged: materials.add(face(fogged(theme.face, cfg))),
+            hex_highlight: materials.add(face(theme.face_fov)),
+            gap_original: materials.add(gap(theme.gap)),
+            gap_highlight: materials.add(gap(theme.gap_fov)),
+            hex_in_aim: materials.add(face(theme.aim)),
             aim_star: materials.add(StandardMaterial {
-                base_color: FovPalette::Edge.into(),
-                emissive: FovPalette::Edge.into(),
+                base_color: theme.edge_fov.base,
+                emissive: theme.edge_fov.emissive,
                 unlit: true,
                 ..default()
             }),
             aim_star_mesh: meshes.add(Cuboid::new(1.6, 0.03, 0.03)),
-            edge: materials.add(StandardMaterial {
-                base_color: OrigPalette::Edge.into(),
-                emissive: OrigPalette::Edge.into(),
-                unlit: true,
-                ..default()
-            }),
-            edge_highlight: materials.add(StandardMaterial {
-                base_color: FovPalette::Edge.into(),
-                emissive: FovPalette::Edge.into(),
-                unlit: true,
+            path_mesh: meshes.add(Cuboid::new(1.0, 1.0, 1.0)),
+            edge_mesh: meshes.add(edge_line_mesh()),
+            edge: edge_materials.add(EdgeMaterial::new(theme, cfg)),
+            reveal_particle: materials.add(StandardMaterial {
+                base_color: theme.edge.base,
+                emissive: theme.edge.emissive,
+                unlit: theme.flat,
                 ..default()
             }),
+            reveal_particle_mesh: meshes.add(Cuboid::from_length(0.08)),
+            scanner_ring_mesh: meshes.add(Annulus::new(0.97, 1.0)),
+        }
+    }
+
+    /// Shared material handles paired with the theme slot that colors them.
+    fn themed_slots<'a>(
+        &'a self,
+        theme: &'a Theme,
+        cfg: &HTerrainConfig,
+    ) -> [(&'a Handle<StandardMaterial>, ThemeColor); 9] {
+        [
+            (&self.hex_original, theme.face),
+            (&self.hex_visited, theme.face_visited),
+            (&self.hex_fogged, fogged(theme.face, cfg)),
+            (&self.hex_highlight, theme.face_fov),
+            (&self.gap_original, theme.gap),
+            (&self.gap_highlight, theme.gap_fov),
+            (&self.hex_in_aim, theme.aim),
+            (&self.aim_star, theme.edge_fov),
+            (&self.reveal_particle, theme.edge),
+        ]
+    }
+
+    /// Shared hex face material handles (original, visited, fogged, highlight, aim).
+    fn face_slots(&self) -> [&Handle<StandardMaterial>; 5] {
+        [
+            &self.hex_original,
+            &self.hex_visited,
+            &self.hex_fogged,
+            &self.hex_highlight,
+            &self.hex_in_aim,
+        ]
+    }
+
+    /// Shared gap material handles (original, highlight).
+    fn gap_slots(&self) -> [&Handle<StandardMaterial>; 2] {
+        [&self.gap_original, &self.gap_highlight]
+    }
+
+    /// Resting (out-of-FoV) hex face material for a cell; `fogged` cells are not yet revealed.
+    pub fn hex_rest(&self, visited: bool, fogged: bool) -> &Handle<StandardMaterial> {
+        if visited {
+            &self.hex_visited
+        } else if fogged {
+            &self.hex_fogged
+        } else {
+            &self.hex_original
         }
     }
 
     pub fn debug_material(materials: &mut Assets<StandardMaterial>) -> Handle<StandardMaterial> {
         materials.add(StandardMaterial {
-            base_color: OrigPalette::Debug.into(),
-            emissive: OrigPalette::Debug.into(),
+            base_color: DEBUG_COLOR,
+            emissive: DEBUG_EMISSIVE,
             unlit: true,
             ..default()
         })
     }
 }
 
-/// Bundles InFov change-detection queries and cell→HexFace navigation.
+/// Hex face material with the theme color and surface finish.
+fn face_material(
+    c: ThemeColor,
+    finish: FaceFinish,
+    hologram: Option<f32>,
+    flat: bool,
+) -> StandardMaterial {
+    let mut mat = StandardMaterial {
+        base_color: c.base,
+        emissive: c.emissive,
+        ..default()
+    };
+    set_surface(&mut mat, finish, hologram, flat);
+    mat
+}
+
+/// `c` dimmed to [`HTerrainConfig::fog_of_war_brightness`] (alpha untouched), or unchanged with
+/// fog of war off.
+fn fogged(c: ThemeColor, cfg: &HTerrainConfig) -> ThemeColor {
+    if !cfg.fog_of_war {
+        return c;
+    }
+    let base = LinearRgba::from(c.base);
+    ThemeColor {
+        base: Color::from((base * cfg.fog_of_war_brightness).with_alpha(base.alpha)),
+        emissive: c.emissive * cfg.fog_of_war_brightness,
+    }
+}
+
+/// Face/gap opacity when the theme is in hologram mode, `None` for solid surfaces.
+fn hologram(theme: &Theme, cfg: &HTerrainConfig) -> Option<f32> {
+    theme.hologram.then_some(cfg.hologram_opacity)
+}
+
+/// `emissive` plus the face's height-gradient glow scaled by `level`, if any (alpha untouched).
+pub(super) fn with_glow(emissive: LinearRgba, glow: Option<&HeightGlow>, level: f32) -> LinearRgba {
+    glow.map_or(emissive, |g| emissive + g.0.with_alpha(0.0) * level)
+}
+
+/// Applies the PBR parameters of `finish` to `mat`, or makes it a translucent additive
+/// hologram surface with the given opacity. `flat` surfaces are unlit ([`Theme::flat`]).
+fn set_surface(mat: &mut StandardMaterial, finish: FaceFinish, hologram: Option<f32>, flat: bool) {
+    mat.unlit = flat;
+    (mat.metallic, mat.perceptual_roughness, mat.reflectance) =
+        hologram.map_or(finish.pbr(), |_| HOLOGRAM_PBR);
+    mat.alpha_mode = hologram.map_or(AlphaMode::Opaque, |_| AlphaMode::Add);
+    mat.base_color.set_alpha(hologram.unwrap_or(1.0));
+}
+
+/// Bundles InFov change-detection queries and cell↔HexFace navigation.
 #[derive(SystemParam)]
 #[allow(clippy::type_complexity)]
 pub(super) struct FovChanges<'w, 's> {
@@ -167,24 +225,53 @@ pub(super) struct FovChanges<'w, 's> {
     cells: Query<'w, 's, &'static Children, With<HCell>>,
     hex_faces: Query<'w, 's, (), With<HexFace>>,
     in_sight: Query<'w, 's, (), With<InSight>>,
+    face_parents: Query<'w, 's, &'static ChildOf, With<HexFace>>,
+    visited: Query<'w, 's, (), With<Visited>>,
+    revealed: Query<'w, 's, (), With<Revealed>>,
     gap_children: Query<'w, 's, &'static Children, Or<(With<Quad>, With<Tri>)>>,
     quad_edges: Query<'w, 's, (), With<QuadEdge>>,
 }
 
+/// Bundles face/gap material access with the instanced edge lookup.
+#[derive(SystemParam)]
+pub(super) struct FovMaterials<'w, 's> {
+    standard: Query<'w, 's, &'static mut MeshMaterial3d<StandardMaterial>>,
+    standard_assets: ResMut<'w, Assets<StandardMaterial>>,
+    edges: Query<'w, 's, (), With<QuadEdge>>,
+}
+
+impl FovMaterials<'_, '_> {
+    /// Prepares `entity` for a FoV fade.
+    ///
+    /// Faces and gaps get a private copy of their material so they can be recolored alone;
+    /// instanced edges keep the shared material and fade through their [`MeshTag`].
+    /// Returns `false` when the entity has no terrain material.
+    fn make_unique(&mut self, entity: Entity) -> bool {
+        if let Ok(mut mat) = self.standard.get_mut(entity) {
+            if let Some(current) = self.standard_assets.get(&mat.0).cloned() {
+                mat.0 = self.standard_assets.add(current);
+            }
+            true
+        } else {
+            self.edges.contains(entity)
+        }
+    }
+}
+
 /// Starts or reverses [`FovTransition`] on material entities when [`InFov`] changes.
 pub(super) fn start_fov_transitions(
     mut fov: FovChanges,
     mats: Res<TerrainMaterials>,
-    mut materials: Query<&mut MeshMaterial3d<StandardMaterial>>,
+    cfg: Res<HTerrainConfig>,
+    mut materials: FovMaterials,
     mut transitions: Query<&mut FovTransition>,
-    mut mat_assets: ResMut<Assets<StandardMaterial>>,
     mut commands: Commands,
 ) {
     // Collect (material_entity, fade_in) pairs, then process.
     let mut targets: Vec<(Entity, bool)> = Vec::new();
 
     for entity in fov.removed.read() {
-        if materials.contains(entity) {
+        if materials.standard.contains(entity) {
             targets.push((entity, false));
             // Propagate to QuadEdge children of removed gap entities.
             if let Ok(children) = fov.gap_children.get(entity) {
@@ -222,12 +309,17 @@ pub(super) fn start_fov_transitions(
     }
 
     for (entity, fade_in) in targets {
+        let parent = fov.face_parents.get(entity).ok().map(|p| p.parent());
+        // Newly revealed cells are tagged later this frame, so fade in from the fog.
+        let from_fog =
+            cfg.fog_of_war && fade_in && parent.is_some_and(|p| !fov.revealed.contains(p));
         // InSight entities can't transition — update the stashed target instead.
         if fov.in_sight.contains(entity) {
             let target = if fade_in {
                 &mats.hex_highlight
             } else {
-                &mats.hex_original
+                let visited = parent.is_some_and(|p| fov.visited.contains(p));
+                mats.hex_rest(visited, false)
             };
             commands
                 .entity(entity)
@@ -239,38 +331,46 @@ pub(super) fn start_fov_transitions(
         let direction = if fade_in { 1.0 } else { -1.0 };
         if let Ok(mut existing) = transitions.get_mut(entity) {
             existing.direction = direction;
+            existing.from_fog &= fade_in;
         } else {
-            let Ok(mut mat) = materials.get_mut(entity) else {
+            if !materials.make_unique(entity) {
                 continue;
-            };
-            if let Some(current) = mat_assets.get(&mat.0).cloned() {
-                mat.0 = mat_assets.add(current);
             }
             let progress = if fade_in { 0.0 } else { 1.0 };
             commands.entity(entity).insert(FovTransition {
                 progress,
                 direction,
+                from_fog,
             });
         }
     }
 }
 
-/// Ticks [`FovTransition`] progress and lerps material colors each frame.
-#[allow(clippy::type_complexity)]
+/// Ticks [`FovTransition`] progress and lerps face/gap material colors each frame.
+///
+/// Faces of [`Visited`] cells fade back to the visited material instead of the original, and
+/// a first reveal fades in from the fog-of-war material. While a face grows in
+/// ([`RevealAnim`]) its emissive is scaled by the reveal progress, so it ignites with the
+/// wavefront; the transition is kept until the ignition completes.
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
 pub(super) fn animate_fov_transitions(
     mut query: Query<
         (
             Entity,
             &mut FovTransition,
-            &mut MeshMaterial3d<StandardMaterial>,
+            &MeshMaterial3d<StandardMaterial>,
             Has<HexFace>,
-            Has<QuadEdge>,
+            Option<&HeightGlow>,
+            Option<&ChildOf>,
+            Option<&RevealAnim>,
         ),
         Without<InSight>,
     >,
+    visited: Query<(), With<Visited>>,
     mats: Res<TerrainMaterials>,
     mut mat_assets: ResMut<Assets<StandardMaterial>>,
     cfg: Res<HTerrainConfig>,
+    level: Res<GlowLevel>,
     time: Res<Time>,
     mut commands: Commands,
 ) {
@@ -281,6 +381,12 @@ pub(super) fn animate_fov_transitions(
     let hex_orig = mat_assets
         .get(&mats.hex_original)
         .map(|m| (m.base_color, m.emissive));
+    let hex_visited = mat_assets
+        .get(&mats.hex_visited)
+        .map(|m| (m.base_color, m.emissive));
+    let hex_fogged = mat_assets
+        .get(&mats.hex_fogged)
+        .map(|m| (m.base_color, m.emissive));
     let hex_hi = mat_assets
         .get(&mats.hex_highlight)
         .map(|m| (m.base_color, m.emissive));
@@ -290,33 +396,37 @@ pub(super) fn animate_fov_transitions(
     let gap_hi = mat_assets
         .get(&mats.gap_highlight)
         .map(|m| (m.base_color, m.emissive));
-    let edge_orig = mat_assets
-        .get(&mats.edge)
-        .map(|m| (m.base_color, m.emissive));
-    let edge_hi = mat_assets
-        .get(&mats.edge_highlight)
-        .map(|m| (m.base_color, m.emissive));
 
     let (
         Some(hex_orig),
+        Some(hex_visited),
+        Some(hex_fogged),
         Some(hex_hi),
         Some(gap_orig),
         Some(gap_hi),
-        Some(edge_orig),
-        Some(edge_hi),
-    ) = (hex_orig, hex_hi, gap_orig, gap_hi, edge_orig, edge_hi)
+    ) = (hex_orig, hex_visited, hex_fogged, hex_hi, gap_orig, gap_hi)
     else {
         return;
     };
+    // Private copies follow hologram mode toggled mid-fade.
+    let alpha_mode = mat_assets
+        .get(&mats.hex_original)
+        .map_or(AlphaMode::Opaque, |m| m.alpha_mode);
 
-    for (entity, mut tr, mat_handle, is_hex, is_edge) in &mut query {
+    for (entity, mut tr, mat_handle, is_hex, glow, parent, reveal) in &mut query {
         tr.progress = (tr.progress + tr.direction * dt / duration).clamp(0.0, 1.0);
         let t = tr.progress;
+        let ignition = reveal.map_or(1.0, |anim| reveal_progress(anim, cfg.reveal_anim_secs));
 
         let ((orig_base, orig_emissive), (hi_base, hi_emissive)) = if is_hex {
-            (hex_orig, hex_hi)
-        } else if is_edge {
-            (edge_orig, edge_hi)
+            let rest = if tr.from_fog {
+                hex_fogged
+            } else if parent.is_some_and(|p| visited.contains(p.parent())) {
+                hex_visited
+            } else {
+                hex_orig
+            };
+            (rest, hex_hi)
         } else {
             (gap_orig, gap_hi)
         };
@@ -329,24 +439,214 @@ pub(super) fn animate_fov_transitions(
                     (orig_base, orig_emissive)
                 };
                 mat.base_color = base;
-                mat.emissive = emissive;
+                mat.emissive = with_glow(emissive, glow, level.0) * ignition;
+                mat.alpha_mode = alpha_mode;
+            }
+            if ignition >= 1.0 {
+                commands.entity(entity).remove::<FovTransition>();
             }
-            commands.entity(entity).remove::<FovTransition>();
         } else if let Some(mat) = mat_assets.get_mut(&mat_handle.0) {
             let orig_lin = LinearRgba::from(orig_base);
             let hi_lin = LinearRgba::from(hi_base);
             mat.base_color = Color::from(orig_lin.mix(&hi_lin, t));
-            mat.emissive = orig_emissive.mix(&hi_emissive, t);
+            mat.emissive = with_glow(orig_emissive.mix(&hi_emissive, t), glow, level.0) * ignition;
+            mat.alpha_mode = alpha_mode;
         }
     }
 }
 
+/// Ticks [`FovTransition`] progress on [`QuadEdge`] lines and writes it into their [`MeshTag`].
+pub(super) fn animate_edge_transitions(
+    mut query: Query<(Entity, &mut FovTransition, &mut MeshTag, &EdgeRing)>,
+    cfg: Res<HTerrainConfig>,
+    time: Res<Time>,
+    mut commands: Commands,
+) {
+    let dt = time.delta_secs();
+    let duration = cfg.fov_transition_secs;
+
+    for (entity, mut tr, mut tag, ring) in &mut query {
+        tr.progress = (tr.progress + tr.direction * dt / duration).clamp(0.0, 1.0);
+        *tag = edge_tag(ring.0, tr.progress);
+        // Finish only at the end being faded toward; a zero-dt first tick must not drop a fade-in.
+        let target = if tr.direction > 0.0 { 1.0 } else { 0.0 };
+        if tr.progress == target {
+            commands.entity(entity).remove::<FovTransition>();
+        }
+    }
+}
+
+/// Recolors face/gap materials and the clear color when [`Theme`] or [`GlowLevel`] changes.
+///
+/// Shared handles are rewritten in place. Entities that own a per-entity clone (left behind
+/// by a finished [`FovTransition`]) are recolored from their own or their parent's [`InFov`];
+/// in-flight transitions pick up the new targets on their next tick.
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+pub(super) fn apply_theme(
+    theme: Res<Theme>,
+    level: Res<GlowLevel>,
+    cfg: Res<HTerrainConfig>,
+    mats: Res<TerrainMaterials>,
+    mut mat_assets: ResMut<Assets<StandardMaterial>>,
+    mut clear: ResMut<ClearColor>,
+    owners: Query<
+        (
+            &MeshMaterial3d<StandardMaterial>,
+            Option<&PreSightMaterial>,
+            Has<InFov>,
+            Option<&ChildOf>,
+            Has<HexFace>,
+            Option<&HeightGlow>,
+        ),
+        (
+            Or<(With<HexFace>, With<Quad>, With<Tri>)>,
+            Without<FovTransition>,
+        ),
+    >,
+    in_fov: Query<(), With<InFov>>,
+    visited: Query<(), With<Visited>>,
+    revealed: Query<(), With<Revealed>>,
+) {
+    clear.0 = theme.clear_color;
+    let theme = theme.glowing(level.0);
+
+    let slots = mats.themed_slots(&theme, &cfg);
+    let paint = |assets: &mut Assets<StandardMaterial>,
+                 handle: &Handle<StandardMaterial>,
+                 c: ThemeColor| {
+        if let Some(mat) = assets.get_mut(handle) {
+            mat.base_color = c.base;
+            mat.emissive = c.emissive;
+        }
+    };
+    for (handle, color) in slots {
+        paint(&mut mat_assets, handle, color);
+    }
+    let holo = hologram(&theme, &cfg);
+    for handle in mats.face_slots() {
+        if let Some(mat) = mat_assets.get_mut(handle) {
+            set_surface(mat, theme.face_finish, holo, theme.flat);
+        }
+    }
+    for handle in mats.gap_slots() {
+        if let Some(mat) = mat_assets.get_mut(handle) {
+            set_surface(mat, FaceFinish::Matte, holo, theme.flat);
+        }
+    }
+
+    for (mat, stashed, self_fov, parent, is_hex, glow) in &owners {
+        let handle = stashed.map_or(&mat.0, |s| &s.0);
+        if slots.iter().any(|(shared, _)| *shared == handle) {
+            continue;
+        }
+        let lit = self_fov || parent.is_some_and(|p| in_fov.contains(p.get()));
+        let color = match (is_hex, lit) {
+            (true, false) if parent.is_some_and(|p| visited.contains(p.get())) => {
+                theme.face_visited
+            }
+            (true, false) if parent.is_some_and(|p| !revealed.contains(p.get())) => {
+                fogged(theme.face, &cfg)
+            }
+            (true, false) => theme.face,
+            (true, true) => theme.face_fov,
+            (false, false) => theme.gap,
+            (false, true) => theme.gap_fov,
+        };
+        paint(&mut mat_assets, handle, color);
+        if let Some(mat) = mat_assets.get_mut(handle) {
+            if is_hex {
+                mat.emissive = with_glow(color.emissive, glow, level.0);
+                set_surface(mat, theme.face_finish, holo, theme.flat);
+            } else {
+                set_surface(mat, FaceFinish::Matte, holo, theme.flat);
+            }
+        }
+    }
+}
+
+/// Derives [`HeightGlow`] from each moved cell's normalized height and repaints its face.
+///
+/// Runs for every cell at startup and again whenever a cell's height changes (laser
+/// extraction). A face gets a private material copy the first time it glows; faces under the
+/// crosshair update their stashed material, and in-flight [`FovTransition`]s pick up the glow
+/// on their next tick.
+#[allow(clippy::type_complexity)]
+pub(super) fn apply_height_glow(
+    cfg: Res<HTerrainConfig>,
+    theme: Res<Theme>,
+    level: Res<GlowLevel>,
+    cells: Query<
+        (
+            &Transform,
+            &Children,
+            Has<InFov>,
+            Has<Visited>,
+            Has<Revealed>,
+        ),
+        (With<HCell>, Changed<Transform>),
+    >,
+    mut faces: Query<
+        (
+            &mut MeshMaterial3d<StandardMaterial>,
+            Option<&mut PreSightMaterial>,
+            Has<HeightGlow>,
+            Has<FovTransition>,
+        ),
+        With<HexFace>,
+    >,
+    mut mat_assets: ResMut<Assets<StandardMaterial>>,
+    mut commands: Commands,
+) {
+    let Some(gradient) = &cfg.face_height_gradient else {
+        return;
+    };
+    for (tf, children, lit, visited, revealed) in &cells {
+        let t = (tf.translation.y / cfg.grid.max_height).clamp(0.0, 1.0);
+        let color = gradient.sample(t);
+        for child in children.iter() {
+            let Ok((mut mat, stashed, has_glow, fading)) = faces.get_mut(child) else {
+                continue;
+            };
+            let glow = HeightGlow(color);
+            let handle = match stashed {
+                Some(stash) => &mut stash.into_inner().0,
+                None => &mut mat.0,
+            };
+            if !has_glow && let Some(current) = mat_assets.get(&*handle).cloned() {
+                *handle = mat_assets.add(current);
+            }
+            if !fading && let Some(m) = mat_assets.get_mut(&*handle) {
+                let base = match (lit, visited, revealed) {
+                    (true, _, _) => theme.face_fov,
+                    (false, true, _) => theme.face_visited,
+                    (false, false, false) => fogged(theme.face, &cfg),
+                    (false, false, true) => theme.face,
+                };
+                m.emissive = with_glow(base.emissive * level.0, Some(&glow), level.0);
+            }
+            commands.entity(child).insert(glow);
+        }
+    }
+}
+
+/// Recolors the shared edge material when [`Theme`] or [`GlowLevel`] changes (see [`apply_theme`]).
+pub(super) fn apply_edge_theme(
+    theme: Res<Theme>,
+    level: Res<GlowLevel>,
+    mats: Res<TerrainMaterials>,
+    mut edge_assets: ResMut<Assets<EdgeMaterial>>,
+) {
+    if let Some(mat) = edge_assets.get_mut(&mats.edge) {
+        mat.color = LinearRgba::from(theme.edge.base) * level.0;
+        mat.highlight = LinearRgba::from(theme.edge_fov.base) * level.0;
+    }
+}
+
 /// Bundles queries for the [`track_in_sight`] system.
 #[derive(SystemParam)]
 #[allow(clippy::type_complexity)]
 pub(super) struct SightParams<'w, 's> {
     camera: Single<'w, 's, (&'static Camera, &'static GlobalTransform), With<Player>>,
-    windows: Single<'w, 's, &'static Window>,
     raycast: MeshRayCast<'w, 's>,
     hex_faces: Query<'w, 's, (), With<HexFace>>,
     current_sight: Query<'w, 's, (Entity, &'static PreSightMaterial), With<InSight>>,
@@ -409,11 +709,18 @@ pub(super) fn track_in_sight(mut sight: SightParams, mut commands: Commands) {
 }
 
 /// Raycasts screen center and returns the first in-FoV [`HexFace`] entity hit.
+///
+/// Hits are filtered to faces and their aim stars, skipping the overlays above the faces (see
+/// [`overlay_lift`](super::overlay_lift)).
 fn find_aimed_hex_face(sight: &mut SightParams) -> Option<Entity> {
-    let center = Vec2::new(sight.windows.width() / 2.0, sight.windows.height() / 2.0);
     let (camera, cam_gt) = *sight.camera;
+    // Viewport (not window) center: the camera may render to a scaled offscreen target.
+    let center = camera.logical_viewport_size()? / 2.0;
     let ray = camera.viewport_to_world(cam_gt, center).ok()?;
-    let hits = sight.raycast.cast_ray(ray, &default());
+    // Only faces and aim stars: the face-mesh overlays lifted above them would win otherwise.
+    let filter = |e| sight.hex_faces.contains(e) || sight.aim_stars.contains(e);
+    let settings = MeshRayCastSettings::default().with_filter(&filter);
+    let hits = sight.raycast.cast_ray(ray, &settings);
     for &(entity, _) in hits {
         // Resolve AimStar hits to their parent HexFace.
         let face = if sight.hex_faces.contains(entity) {
@@ -435,3 +742,94 @@ fn find_aimed_hex_face(sight: &mut SightParams) -> Option<Entity> {
     }
     None
 }
+
+/// Pulses the face emissive of the [`ActiveHex`] cell around its FoV highlight.
+///
+/// A face still on a shared material gets a private copy first, so only the active face
+/// pulses; the shared handle is restored when the player moves on (a face that already had
+/// its own copy is reset to the plain highlight instead). Faces mid-fade or under the
+/// crosshair are left to their own systems.
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+pub(super) fn pulse_active_hex(
+    active: Res<ActiveHex>,
+    cells: Query<&Children, With<HCell>>,
+    mut faces: Query<
+        (
+            &mut MeshMaterial3d<StandardMaterial>,
+            Option<&mut PreSightMaterial>,
+            Option<&HeightGlow>,
+            Has<InSight>,
+            Has<FovTransition>,
+        ),
+        With<HexFace>,
+    >,
+    mats: Res<TerrainMaterials>,
+    theme: Res<Theme>,
+    level: Res<GlowLevel>,
+    cfg: Res<HTerrainConfig>,
+    time: Res<Time>,
+    mut mat_assets: ResMut<Assets<StandardMaterial>>,
+    mut previous: Local<Option<(Entity, Option<ActiveCopy>)>>,
+) {
+    let face = active
+        .cell
+        .and_then(|cell| cells.get(cell).ok())
+        .and_then(|children| children.iter().find(|&c| faces.contains(c)));
+    let base = theme.face_fov.emissive * level.0;
+
+    if previous.as_ref().map(|(old, _)| *old) != face
+        && let Some((old, copy)) = previous.take()
+        && let Ok((mut mat, stash, glow, in_sight, fading)) = faces.get_mut(old)
+    {
+        match copy {
+            // Hand the shared material back, wherever the private copy went.
+            Some(ActiveCopy { shared, private }) => {
+                if let Some(mut stash) = stash.filter(|s| s.0 == private) {
+                    stash.0 = shared;
+                } else if mat.0 == private {
+                    mat.0 = shared;
+                }
+            }
+            None if !in_sight && !fading => {
+                if let Some(m) = mat_assets.get_mut(&mat.0) {
+                    m.emissive = with_glow(base, glow, level.0);
+                }
+            }
+            None => {}
+        }
+    }
+
+    let Some((mut mat, _, glow, false, false)) = face.and_then(|f| faces.get_mut(f).ok()) else {
+        return;
+    };
+    if previous.is_none() {
+        let copy = mats.face_slots().contains(&&mat.0).then(|| {
+            let shared = mat.0.clone();
+            let private = mat_assets
+                .get(&shared)
+                .cloned()
+                .map_or_else(|| shared.clone(), |m| mat_assets.add(m));
+            mat.0 = private.clone();
+            ActiveCopy { shared, private }
+        });
+        *previous = face.map(|f| (f, copy));
+    }
+    if cfg.active_pulse_amplitude <= 0.0 {
+        return;
+    }
+    let wave = 0.5 + 0.5 * (time.elapsed_secs() * cfg.active_pulse_speed).sin();
+    if let Some(m) = mat_assets.get_mut(&mat.0) {
+        m.emissive = with_glow(
+            base * (1.0 + cfg.active_pulse_amplitude * wave),
+            glow,
+            level.0,
+        );
+    }
+}
+
+/// Private material given to the active face by [`pulse_active_hex`], with the shared one
+/// it replaced.
+pub(super) struct ActiveCopy {
+    shared: Handle<StandardMaterial>,
+    private: Handle<StandardMaterial>,
+}
@@ -0,0 +1,139 @@
+//! Corner minimap of revealed hexes.
+//!
+//! Every [`Revealed`] cell is plotted as a small dot tinted by height (the heatmap ramp),
+//! north (-Z) up, with the player as an arrow along the camera heading. [`LandmarkMarker`]s on
+//! revealed cells show as diamonds in [`HTerrainConfig::landmark_color`]. While the cursor is
+//! free (`Inspecting`), clicking a revealed spot on the map teleports the player there; the
+//! drone picks up the new [`PlayerPos`] when flight resumes.
+
+use bevy::prelude::*;
+use bevy_egui::egui;
+
+use super::HTerrainConfig;
+use super::entities::{HCell, HGrid, Revealed};
+use super::landmarks::LandmarkMarker;
+use super::theme::HeightGradient;
+use crate::drone::Player;
+use crate::{PlayerMoved, PlayerPos};
+
+/// Maps world xz to pixels in a square minimap.
+#[derive(Clone, Copy, Debug)]
+pub struct MinimapFrame {
+    /// Top-left corner of the map in window pixels.
+    pub origin: Vec2,
+    /// Side length of the map in pixels.
+    pub size: f32,
+    /// World distance from the grid center to the map edge.
+    pub extent: f32,
+}
+
+impl MinimapFrame {
+    /// Pixels per world unit.
+    fn scale(self) -> f32 {
+        self.size / (2.0 * self.extent.max(1e-3))
+    }
+
+    /// Window pixel position of world `xz` (+Z points down the map).
+    pub fn map_pos(self, xz: Vec2) -> Vec2 {
+        self.origin + Vec2::splat(self.size / 2.0) + xz * self.scale()
+    }
+
+    /// World xz under window pixel `p`.
+    pub fn world_pos(self, p: Vec2) -> Vec2 {
+        (p - self.origin - Vec2::splat(self.size / 2.0)) / self.scale()
+    }
+}
+
+/// Converts a linear color to an opaque egui color.
+fn color32(c: LinearRgba) -> egui::Color32 {
+    let [r, g, b, _] = Color::from(c).to_srgba().to_u8_array();
+    egui::Color32::from_rgb(r, g, b)
+}
+
+/// Draws the minimap in the bottom-right corner and handles teleport clicks.
+#[allow(clippy::too_many_arguments)]
+pub(super) fn draw_minimap(
+    mut egui_ctx: Single<&mut bevy_egui::EguiContext>,
+    cfg: Res<HTerrainConfig>,
+    grid: Single<&HGrid>,
+    cells: Query<&GlobalTransform, (With<HCell>, With<Revealed>)>,
+    landmarks: Query<(&LandmarkMarker, &GlobalTransform)>,
+    camera: Single<&GlobalTransform, With<Player>>,
+    mut player: ResMut<PlayerPos>,
+    mut moved: ResMut<PlayerMoved>,
+) {
+    let size = cfg.minimap_size;
+    let extent = grid
+        .hex_entities
+        .keys()
+        .map(|&hex| grid.terrain.hex_to_world_pos(hex).abs().max_element())
+        .fold(0.0, f32::max)
+        + cfg.grid.max_hex_radius;
+    let ramp = HeightGradient::heatmap();
+    let forward = camera.forward().xz().normalize_or(Vec2::NEG_Y);
+
+    egui::Area::new(egui::Id::new("minimap"))
+        .anchor(egui::Align2::RIGHT_BOTTOM, [-8.0, -8.0])
+        .show(egui_ctx.get_mut(), |ui| {
+            let (rect, response) =
+                ui.allocate_exact_size(egui::vec2(size, size), egui::Sense::click());
+            let frame = MinimapFrame {
+                origin: Vec2::new(rect.min.x, rect.min.y),
+                size,
+                extent,
+            };
+            let pos = |p: Vec2| egui::pos2(p.x, p.y);
+            let painter = ui.painter_at(rect);
+            painter.rect_filled(rect, 4.0, egui::Color32::from_black_alpha(160));
+
+            let dot = (size / (2.0 * extent) * cfg.grid.point_spacing * 0.35).clamp(1.0, 4.0);
+            for tf in &cells {
+                let p = tf.translation();
+                let t = p.y / cfg.grid.max_height;
+                painter.circle_filled(pos(frame.map_pos(p.xz())), dot, color32(ramp.sample(t)));
+            }
+
+            let marker = color32(LinearRgba::from(cfg.landmark_color));
+            for (landmark, tf) in &landmarks {
+                let revealed = grid
+                    .hex_entities
+                    .get(&landmark.hex)
+                    .is_some_and(|&cell| cells.contains(cell));
+                if !revealed {
+                    continue;
+                }
+                let c = frame.map_pos(tf.translation().xz());
+                painter.add(egui::Shape::convex_polygon(
+                    [Vec2::NEG_Y, Vec2::X, Vec2::Y, Vec2::NEG_X]
+                        .map(|d| pos(c + d * 5.0))
+                        .to_vec(),
+                    marker,
+                    egui::Stroke::new(1.0, egui::Color32::BLACK),
+                ));
+            }
+
+            let tip = frame.map_pos(player.xz) + forward * 9.0;
+            let side = forward.perp() * 4.5;
+            let back = frame.map_pos(player.xz) - forward * 4.0;
+            painter.add(egui::Shape::convex_polygon(
+                vec![pos(tip), pos(back + side), pos(back - side)],
+                egui::Color32::WHITE,
+                egui::Stroke::new(1.0, egui::Color32::BLACK),
+            ));
+
+            if response.clicked()
+                && let Some(click) = response.interact_pointer_pos()
+            {
+                let target = frame.world_pos(Vec2::new(click.x, click.y));
+                let hex = grid.terrain.world_pos_to_hex(target);
+                let revealed = grid
+                    .hex_entities
+                    .get(&hex)
+                    .is_some_and(|&cell| cells.contains(cell));
+                if revealed {
+                    player.xz = target;
+                    moved.0 = true;
+                }
+            }
+        });
+}
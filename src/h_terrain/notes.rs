@@ -0,0 +1,91 @@
+//! Hex annotations: short user notes pinned to hexes.
+//!
+//! Notes are typed into the inspection panel for the selected hex, kept in [`HexNotes`], and
+//! drawn as floating labels above their hex when it is within
+//! [`HTerrainConfig::hex_note_distance`] of the camera. The session file saves them, so
+//! they survive across runs.
+
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+use bevy_egui::egui;
+use hexx::Hex;
+
+use super::HTerrainConfig;
+use super::entities::HGrid;
+use crate::drone::Player;
+use crate::neon_ui::window_to_points;
+
+/// Longest note, in characters.
+pub const MAX_NOTE_LEN: usize = 64;
+
+/// Note text per annotated hex.
+#[derive(Resource, Default, Debug, PartialEq)]
+pub struct HexNotes(pub HashMap<Hex, String>);
+
+impl HexNotes {
+    /// Sets the note for `hex` (cut to [`MAX_NOTE_LEN`]); a blank `text` removes it.
+    ///
+    /// The text is kept untrimmed so the panel can type spaces between words.
+    pub fn set(&mut self, hex: Hex, text: &str) {
+        let text: String = text.chars().take(MAX_NOTE_LEN).collect();
+        if text.trim().is_empty() {
+            self.0.remove(&hex);
+        } else {
+            self.0.insert(hex, text);
+        }
+    }
+
+    /// The note on `hex`, if any.
+    pub fn get(&self, hex: Hex) -> Option<&str> {
+        self.0.get(&hex).map(String::as_str)
+    }
+}
+
+/// Draws every note whose hex is within the distance cutoff, just above the hex top.
+pub(super) fn draw_hex_notes(
+    mut egui_ctx: Single<&mut bevy_egui::EguiContext>,
+    cfg: Res<HTerrainConfig>,
+    notes: Res<HexNotes>,
+    window: Single<&Window>,
+    camera: Single<(&Camera, &GlobalTransform), With<Player>>,
+    grid: Single<&HGrid>,
+) {
+    let (camera, cam_gt) = *camera;
+    let Some(viewport) = camera.logical_viewport_size() else {
+        return;
+    };
+    let ctx = egui_ctx.get_mut();
+    let to_points = window.size() / viewport * window_to_points(ctx, &window);
+    let cam_pos = cam_gt.translation();
+    let font = egui::FontId::proportional(cfg.hex_label_font_size + 2.0);
+    let color = egui::Color32::from_rgb(255, 214, 110);
+
+    egui::Area::new(egui::Id::new("hex_notes"))
+        .fixed_pos(egui::pos2(0.0, 0.0))
+        .interactable(false)
+        .order(egui::Order::Background)
+        .show(ctx, |ui| {
+            let painter = ui.painter();
+            for (&hex, text) in &notes.0 {
+                let Some(height) = grid.terrain.height(&hex) else {
+                    continue;
+                };
+                let xz = grid.terrain.hex_to_world_pos(hex);
+                let pos = Vec3::new(xz.x, height + 1.0, xz.y);
+                if pos.distance(cam_pos) > cfg.hex_note_distance {
+                    continue;
+                }
+                let Ok(vp) = camera.world_to_viewport(cam_gt, pos) else {
+                    continue;
+                };
+                let p = vp * to_points;
+                painter.text(
+                    egui::pos2(p.x, p.y),
+                    egui::Align2::CENTER_BOTTOM,
+                    text.trim(),
+                    font.clone(),
+                    color,
+                );
+            }
+        });
+}
@@ -0,0 +1,168 @@
+//! Selection outline around the picked hex face.
+//!
+//! A single [`SelectionOutline`] entity follows the selected face: the [`InSight`] face while
+//! flying, or in inspect mode the clicked [`SelectedHex`] (the [`ActiveHex`] until one is
+//! picked). Its mesh is a hexagonal band just outside
+//! the face edge, built in world units for the cell's radius: a bright inner line plus a black
+//! outer halo (vertex colors on one unlit material), so the selection stays readable next to
+//! blooming edges and bright FoV faces.
+
+use bevy::asset::RenderAssetUsages;
+use bevy::mesh::{Indices, PrimitiveTopology};
+use bevy::prelude::*;
+
+use super::entities::{HCell, HGrid, HexFace, InSight};
+use super::picking::SelectedHex;
+use super::theme::Theme;
+use super::{ActiveHex, HTerrainConfig};
+use crate::GameState;
+
+/// Height of the outline above its face.
+const LIFT: f32 = 0.02;
+
+/// The outline entity and the face it currently surrounds.
+#[derive(Component, Reflect)]
+pub struct SelectionOutline {
+    /// Outlined [`HexFace`], `None` while hidden.
+    pub target: Option<Entity>,
+    /// Hex radius the current mesh was built for.
+    pub radius: f32,
+}
+
+/// Hexagonal outline band around a hex of `radius` with the given `corners` (unit offsets).
+///
+/// Three vertex rings at `radius`, `radius + width`, and `radius + 2 * width`: the inner band
+/// is white (tinted by the material), the outer band black.
+pub fn outline_ring_mesh(corners: [Vec2; 6], radius: f32, width: f32) -> Mesh {
+    let rings = [radius, radius + width, radius + 2.0 * width];
+    let mut positions = Vec::with_capacity(18);
+    let mut colors = Vec::with_capacity(18);
+    for (ring, r) in rings.iter().enumerate() {
+        let color = if ring < 2 {
+            [1.0; 4]
+        } else {
+            [0.0, 0.0, 0.0, 1.0]
+        };
+        for c in corners {
+            positions.push([c.x * r, 0.0, c.y * r]);
+            colors.push(color);
+        }
+    }
+    // The middle ring carries both colors in the outer band: duplicate it in black.
+    for c in corners {
+        positions.push([c.x * rings[1], 0.0, c.y * rings[1]]);
+        colors.push([0.0, 0.0, 0.0, 1.0]);
+    }
+    let mut indices = Vec::with_capacity(6 * 2 * 6);
+    for (inner, outer) in [(0u16, 6u16), (18, 12)] {
+        for i in 0..6u16 {
+            let j = (i + 1) % 6;
+            let (a, b, c, d) = (inner + i, inner + j, outer + j, outer + i);
+            indices.extend([a, b, c, a, c, d]);
+        }
+    }
+    let normals = vec![[0.0, 1.0, 0.0]; positions.len()];
+    Mesh::new(
+        PrimitiveTopology::TriangleList,
+        RenderAssetUsages::RENDER_WORLD | RenderAssetUsages::MAIN_WORLD,
+    )
+    .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+    .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, normals)
+    .with_inserted_attribute(Mesh::ATTRIBUTE_COLOR, colors)
+    .with_inserted_indices(Indices::U16(indices))
+}
+
+/// Outline line color: the theme aim color clamped to display range (no bloom spill).
+fn outline_color(theme: &Theme) -> Color {
+    let c = LinearRgba::from(theme.aim.base);
+    Color::from(LinearRgba::rgb(
+        c.red.min(1.0),
+        c.green.min(1.0),
+        c.blue.min(1.0),
+    ))
+}
+
+/// Spawns the hidden outline entity.
+pub(super) fn spawn_selection_outline(
+    theme: Res<Theme>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut commands: Commands,
+) {
+    commands.spawn((
+        Name::new("SelectionOutline"),
+        SelectionOutline {
+            target: None,
+            radius: 0.0,
+        },
+        Mesh3d(meshes.add(Mesh::from(Triangle3d::default()))),
+        MeshMaterial3d(materials.add(StandardMaterial {
+            base_color: outline_color(&theme),
+            unlit: true,
+            cull_mode: None,
+            ..default()
+        })),
+        Transform::default(),
+        Visibility::Hidden,
+    ));
+}
+
+/// Moves the outline to the selected face, rebuilding its band for the cell radius.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub(super) fn update_selection_outline(
+    cfg: Res<HTerrainConfig>,
+    theme: Res<Theme>,
+    state: Res<State<GameState>>,
+    (active, selected): (Res<ActiveHex>, Res<SelectedHex>),
+    grid: Single<&HGrid>,
+    in_sight: Query<Entity, (With<HexFace>, With<InSight>)>,
+    faces: Query<&ChildOf, With<HexFace>>,
+    cells: Query<(&HCell, &GlobalTransform, &Children)>,
+    outline: Single<(
+        &mut SelectionOutline,
+        &mut Transform,
+        &mut Visibility,
+        &Mesh3d,
+        &MeshMaterial3d<StandardMaterial>,
+    )>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let (mut outline, mut tf, mut vis, mesh, mat) = outline.into_inner();
+    if theme.is_changed()
+        && let Some(mat) = materials.get_mut(&mat.0)
+    {
+        mat.base_color = outline_color(&theme);
+    }
+
+    let target = if *state.get() == GameState::Inspecting {
+        selected
+            .0
+            .and_then(|hex| grid.hex_entities.get(&hex).copied())
+            .or(active.cell)
+            .and_then(|cell| cells.get(cell).ok())
+            .and_then(|(_, _, children)| children.iter().find(|c| faces.contains(*c)))
+    } else {
+        in_sight.iter().next()
+    };
+    let cell = target
+        .and_then(|face| faces.get(face).ok())
+        .and_then(|parent| cells.get(parent.parent()).ok());
+    let (Some(face), Some((cell, cell_tf, _))) = (target, cell) else {
+        outline.target = None;
+        vis.set_if_neq(Visibility::Hidden);
+        return;
+    };
+
+    tf.translation = cell_tf.translation() + Vec3::Y * LIFT;
+    let radius = grid.terrain.radius(&cell.hex).unwrap_or(0.0);
+    if outline.target != Some(face) || outline.radius != radius {
+        let corners = std::array::from_fn(|i| grid.terrain.unit_corner(i));
+        if let Some(mesh) = meshes.get_mut(&mesh.0) {
+            *mesh = outline_ring_mesh(corners, radius, cfg.selection_outline_width);
+        }
+        outline.target = Some(face);
+        outline.radius = radius;
+    }
+    vis.set_if_neq(Visibility::Visible);
+}
@@ -0,0 +1,184 @@
+//! A* paths between two picked hexes, drawn as glowing segments over faces and gaps.
+//!
+//! In inspect mode a click ([`HexPicked`]) sets the path start and a Shift+click the end
+//! ([`PathEnds`]). [`find_hex_path`] runs [`hexx::algorithms::a_star`] over the
+//! [`HGridLayout`]. Each step costs 1 plus [`HTerrainConfig::path_climb_cost`] per unit of
+//! height difference, and steps taller than [`HTerrainConfig::path_max_climb`] are
+//! impassable. The result lands in [`HexPath`], and [`draw_hex_path`] renders it as a chain of
+//! [`PathSegment`] cuboids: across each face from its center to the rim, then over the gap to
+//! the next face. The path is re-solved after height edits and regenerations.
+
+use bevy::prelude::*;
+use hexx::Hex;
+use hexx::algorithms::a_star;
+
+use super::HTerrainConfig;
+use super::entities::{HGrid, HexPicked};
+use super::h_grid_layout::HGridLayout;
+use super::materials::TerrainMaterials;
+use super::math::edge_cuboid_transform;
+use crate::locale::Locale;
+use crate::toast::Toast;
+
+/// Height of the path above the faces.
+const LIFT: f32 = 0.05;
+
+/// Fixed-point scale for the integer step costs `a_star` works with.
+const COST_SCALE: f32 = 100.0;
+
+/// Path endpoints picked in inspect mode.
+#[derive(Resource, Default, Reflect)]
+pub struct PathEnds {
+    /// Set by a plain click (clears `end`).
+    #[reflect(ignore)]
+    pub start: Option<Hex>,
+    /// Set by a Shift+click.
+    #[reflect(ignore)]
+    pub end: Option<Hex>,
+}
+
+/// The solved path, start to end (empty without one).
+#[derive(Resource, Default, Reflect)]
+pub struct HexPath {
+    /// Hexes along the path, including both ends.
+    #[reflect(ignore)]
+    pub hexes: Vec<Hex>,
+    /// Summed step costs.
+    pub cost: f32,
+}
+
+/// One drawn piece of the [`HexPath`].
+#[derive(Component, Reflect)]
+pub struct PathSegment;
+
+/// Cost of stepping between hexes at heights `from` and `to` (`None` when too steep).
+pub fn step_cost(from: f32, to: f32, climb_cost: f32, max_climb: f32) -> Option<f32> {
+    let climb = (to - from).abs();
+    (climb <= max_climb).then_some(1.0 + climb_cost * climb)
+}
+
+/// Cheapest path from `start` to `end` over the grid, with its total cost.
+pub fn find_hex_path(
+    terrain: &HGridLayout,
+    start: Hex,
+    end: Hex,
+    climb_cost: f32,
+    max_climb: f32,
+) -> Option<(Vec<Hex>, f32)> {
+    let cost = |a: Hex, b: Hex| {
+        step_cost(
+            terrain.height(&a)?,
+            terrain.height(&b)?,
+            climb_cost,
+            max_climb,
+        )
+    };
+    let hexes = a_star(start, end, |a, b| {
+        cost(a, b).map(|c| (c * COST_SCALE).round() as u32)
+    })?;
+    let total = hexes.windows(2).filter_map(|w| cost(w[0], w[1])).sum();
+    Some((hexes, total))
+}
+
+/// Polyline through `hexes`: each face center, then the rims where the path leaves one face
+/// and enters the next (edge midpoints, at the faces' heights).
+pub fn path_points(terrain: &HGridLayout, hexes: &[Hex]) -> Vec<Vec3> {
+    let at = |hex: Hex, xz: Vec2| {
+        let y = terrain.height(&hex).unwrap_or(0.0) + LIFT;
+        Vec3::new(xz.x, y, xz.y)
+    };
+    let apothem = |hex: Hex| terrain.radius(&hex).unwrap_or(0.0) * 3f32.sqrt() / 2.0;
+    let mut points: Vec<Vec3> = hexes
+        .first()
+        .map(|&h| at(h, terrain.hex_to_world_pos(h)))
+        .into_iter()
+        .collect();
+    for pair in hexes.windows(2) {
+        let (a, b) = (pair[0], pair[1]);
+        let (ca, cb) = (terrain.hex_to_world_pos(a), terrain.hex_to_world_pos(b));
+        let dir = (cb - ca).normalize_or_zero();
+        points.push(at(a, ca + dir * apothem(a)));
+        points.push(at(b, cb - dir * apothem(b)));
+        points.push(at(b, cb));
+    }
+    points
+}
+
+/// Plain click sets the start, Shift+click the end.
+pub(super) fn set_path_ends(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut picked: MessageReader<HexPicked>,
+    mut ends: ResMut<PathEnds>,
+) {
+    let Some(&HexPicked(hex)) = picked.read().last() else {
+        return;
+    };
+    if keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]) {
+        ends.end = Some(hex);
+    } else {
+        *ends = PathEnds {
+            start: Some(hex),
+            end: None,
+        };
+    }
+}
+
+/// Re-solves [`HexPath`] from [`PathEnds`]; toasts the result when the ends changed.
+pub(super) fn solve_hex_path(
+    cfg: Res<HTerrainConfig>,
+    ends: Res<PathEnds>,
+    grid: Single<&HGrid>,
+    mut path: ResMut<HexPath>,
+    mut toasts: MessageWriter<Toast>,
+    locale: Res<Locale>,
+) {
+    let (Some(start), Some(end)) = (ends.start, ends.end) else {
+        *path = HexPath::default();
+        return;
+    };
+    let found = find_hex_path(
+        &grid.terrain,
+        start,
+        end,
+        cfg.path_climb_cost,
+        cfg.path_max_climb,
+    );
+    if ends.is_changed() {
+        toasts.write(Toast(match &found {
+            Some((hexes, cost)) => locale.fmt(
+                "Path: {0} hexes, cost {1}",
+                &[&hexes.len(), &format!("{cost:.1}")],
+            ),
+            None => locale.t("No path between the picked hexes").into(),
+        }));
+    }
+    *path = found.map_or_else(HexPath::default, |(hexes, cost)| HexPath { hexes, cost });
+}
+
+/// Respawns the [`PathSegment`]s for the current [`HexPath`].
+pub(super) fn draw_hex_path(
+    cfg: Res<HTerrainConfig>,
+    path: Res<HexPath>,
+    grid: Single<&HGrid>,
+    mats: Res<TerrainMaterials>,
+    segments: Query<Entity, With<PathSegment>>,
+    mut commands: Commands,
+) {
+    for segment in &segments {
+        commands.entity(segment).despawn();
+    }
+    for pair in path_points(&grid.terrain, &path.hexes).windows(2) {
+        let (midpoint, length, rotation) = edge_cuboid_transform(pair[0], pair[1]);
+        commands.spawn((
+            Name::new("PathSegment"),
+            PathSegment,
+            Mesh3d(mats.path_mesh.clone()),
+            MeshMaterial3d(mats.aim_star.clone()),
+            Transform {
+                translation: midpoint,
+                rotation,
+                scale: Vec3::new(length, cfg.path_width, cfg.path_width),
+            },
+        ));
+    }
+}
@@ -0,0 +1,136 @@
+//! Click-to-pick hexes: a cursor ray intersected with the hex face planes.
+//!
+//! [`pick_hex`] works on the [`HGridLayout`] alone. Each hex face is a horizontal hexagon at
+//! the cell height with its noise radius, so the ray is intersected with every face plane and
+//! the nearest hit inside a hexagon wins. This needs no mesh colliders and ignores reveal
+//! animations and hidden layers. While `Inspecting`, [`pick_hex_on_click`] casts from the
+//! cursor on a left click outside egui and writes [`HexPicked`]. [`select_picked_hex`] stores
+//! it in [`SelectedHex`], which the selection outline and the hex panel (and its height edits)
+//! follow instead of the [`ActiveHex`](super::ActiveHex). [`track_hovered_hex`] keeps
+//! [`HoveredHex`] on the hex under the cursor every frame for tools that follow the pointer
+//! (the territory brush).
+
+use bevy::prelude::*;
+use hexx::Hex;
+
+use super::entities::{HGrid, HexPicked};
+use super::h_grid_layout::HGridLayout;
+use crate::drone::Player;
+
+/// Hex picked by clicking in inspect mode (cleared when leaving `Inspecting`).
+#[derive(Resource, Default, Reflect)]
+pub struct SelectedHex(#[reflect(ignore)] pub Option<Hex>);
+
+/// Hex under the cursor in inspect mode (`None` over egui, off the grid, or while flying).
+#[derive(Resource, Default, PartialEq, Reflect)]
+pub struct HoveredHex(#[reflect(ignore)] pub Option<Hex>);
+
+/// Whether `p` lies inside the convex polygon `corners × radius` (either winding).
+fn in_hexagon(p: Vec2, corners: [Vec2; 6], radius: f32) -> bool {
+    let mut sign = 0.0;
+    for i in 0..6 {
+        let a = corners[i] * radius;
+        let b = corners[(i + 1) % 6] * radius;
+        let cross = (b - a).perp_dot(p - a);
+        if cross * sign < 0.0 {
+            return false;
+        }
+        if cross != 0.0 {
+            sign = cross;
+        }
+    }
+    true
+}
+
+/// Nearest hex face hit by `ray`, with the distance along the ray.
+pub fn pick_hex(terrain: &HGridLayout, ray: Ray3d) -> Option<(Hex, f32)> {
+    let dir = *ray.direction;
+    if dir.y.abs() < f32::EPSILON {
+        return None;
+    }
+    let corners = std::array::from_fn(|i| terrain.unit_corner(i));
+    terrain
+        .hexes()
+        .filter_map(|hex| {
+            let t = (terrain.height(&hex)? - ray.origin.y) / dir.y;
+            let local = (ray.origin + dir * t).xz() - terrain.hex_to_world_pos(hex);
+            (t >= 0.0 && in_hexagon(local, corners, terrain.radius(&hex)?)).then_some((hex, t))
+        })
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+}
+
+/// World ray through `pointer`, a position in logical window pixels.
+pub(super) fn window_ray(
+    window: &Window,
+    camera: &Camera,
+    cam_gt: &GlobalTransform,
+    pointer: Vec2,
+) -> Option<Ray3d> {
+    let viewport = camera.logical_viewport_size()?;
+    // The camera may render to a scaled offscreen target: map window → viewport pixels.
+    camera
+        .viewport_to_world(cam_gt, pointer * viewport / window.size())
+        .ok()
+}
+
+/// World ray through the cursor, if it is over the window.
+fn cursor_ray(window: &Window, camera: &Camera, cam_gt: &GlobalTransform) -> Option<Ray3d> {
+    window_ray(window, camera, cam_gt, window.cursor_position()?)
+}
+
+/// Writes [`HexPicked`] for the hex face under the cursor on a left click.
+pub(super) fn pick_hex_on_click(
+    mut egui_ctx: Single<&mut bevy_egui::EguiContext>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    window: Single<&Window>,
+    camera: Single<(&Camera, &GlobalTransform), With<Player>>,
+    grid: Single<&HGrid>,
+    mut picked: MessageWriter<HexPicked>,
+) {
+    if !mouse.just_pressed(MouseButton::Left) || egui_ctx.get_mut().is_pointer_over_area() {
+        return;
+    }
+    let (camera, cam_gt) = *camera;
+    let Some(ray) = cursor_ray(&window, camera, cam_gt) else {
+        return;
+    };
+    if let Some((hex, _)) = pick_hex(&grid.terrain, ray) {
+        picked.write(HexPicked(hex));
+    }
+}
+
+/// Points [`HoveredHex`] at the hex face under the cursor.
+pub(super) fn track_hovered_hex(
+    mut egui_ctx: Single<&mut bevy_egui::EguiContext>,
+    window: Single<&Window>,
+    camera: Single<(&Camera, &GlobalTransform), With<Player>>,
+    grid: Single<&HGrid>,
+    mut hovered: ResMut<HoveredHex>,
+) {
+    let (camera, cam_gt) = *camera;
+    let hex = (!egui_ctx.get_mut().is_pointer_over_area())
+        .then(|| cursor_ray(&window, camera, cam_gt))
+        .flatten()
+        .and_then(|ray| pick_hex(&grid.terrain, ray))
+        .map(|(hex, _)| hex);
+    hovered.set_if_neq(HoveredHex(hex));
+}
+
+/// Selects the most recently picked hex.
+pub(super) fn select_picked_hex(
+    mut picked: MessageReader<HexPicked>,
+    mut selected: ResMut<SelectedHex>,
+) {
+    if let Some(&HexPicked(hex)) = picked.read().last() {
+        selected.0 = Some(hex);
+    }
+}
+
+/// Drops the selection and the hover when leaving inspect mode.
+pub(super) fn clear_selected_hex(
+    mut selected: ResMut<SelectedHex>,
+    mut hovered: ResMut<HoveredHex>,
+) {
+    selected.0 = None;
+    hovered.0 = None;
+}
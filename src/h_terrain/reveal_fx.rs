@@ -0,0 +1,185 @@
+//! Reveal effects for hexes entering the FoV.
+//!
+//! Each [`HCell`] that gains [`InFov`] emits a ring of small glowing cubes from its perimeter.
+//! Particles share one mesh and material from [`TerrainMaterials`], rise while shrinking, and
+//! despawn after [`HTerrainConfig::reveal_particle_lifetime`].
+//!
+//! Hex faces spawn at zero scale (full size when [`HTerrainConfig::reveal_anim_secs`] is 0).
+//! The first time a cell is revealed it is tagged [`Revealed`] and its [`HexFace`] grows in,
+//! staggered by ring distance from the player's hex. The reveal is announced
+//! with a [`HexRevealed`] message so any effect can follow the same wavefront delay. The
+//! face's emissive "ignites" along with it: `animate_fov_transitions` scales it by
+//! [`reveal_progress`], so faces stay dark until the wavefront reaches them.
+
+use bevy::prelude::*;
+
+use super::HTerrainConfig;
+use super::entities::{HCell, HGrid, HexFace, HexRevealed, InFov, RevealAnim, Revealed};
+use super::materials::TerrainMaterials;
+use crate::{PlayerPos, math};
+
+/// Vertical speed of a reveal particle (world units per second).
+const RISE_SPEED: f32 = 2.5;
+/// Horizontal drift away from the hex center (world units per second).
+const DRIFT_SPEED: f32 = 0.6;
+
+/// A short-lived reveal particle.
+#[derive(Component, Reflect)]
+pub struct RevealParticle {
+    /// World-space velocity.
+    pub velocity: Vec3,
+    /// Seconds since spawn.
+    pub age: f32,
+}
+
+/// Spawns a perimeter burst for every hex that newly entered the FoV.
+pub(super) fn emit_reveal_particles(
+    grid: Single<&HGrid>,
+    revealed: Query<(&HCell, &Transform), Added<InFov>>,
+    mats: Res<TerrainMaterials>,
+    cfg: Res<HTerrainConfig>,
+    mut commands: Commands,
+) {
+    let count = cfg.reveal_particle_count;
+    if count == 0 {
+        return;
+    }
+    for (cell, tf) in &revealed {
+        let Some(radius) = grid.terrain.radius(&cell.hex) else {
+            continue;
+        };
+        for i in 0..count {
+            // Walk the six edges evenly; `frac` is the position along the current edge.
+            let along = (i as f32 + 0.5) / count as f32 * 6.0;
+            let (edge, frac) = (along.floor() as usize % 6, along.fract());
+            let corner = grid
+                .terrain
+                .unit_corner(edge)
+                .lerp(grid.terrain.unit_corner((edge + 1) % 6), frac);
+            let outward = corner.extend(0.0).xzy();
+            let jitter = 0.6 + 0.8 * (i as f32 * 0.618).fract();
+            commands.spawn((
+                RevealParticle {
+                    velocity: Vec3::Y * RISE_SPEED * jitter + outward * DRIFT_SPEED,
+                    age: 0.0,
+                },
+                Mesh3d(mats.reveal_particle_mesh.clone()),
+                MeshMaterial3d(mats.reveal_particle.clone()),
+                Transform::from_translation(tf.translation + outward * radius),
+            ));
+        }
+    }
+}
+
+/// Moves and shrinks reveal particles, despawning them at the end of their lifetime.
+pub(super) fn animate_reveal_particles(
+    mut particles: Query<(Entity, &mut RevealParticle, &mut Transform)>,
+    cfg: Res<HTerrainConfig>,
+    time: Res<Time>,
+    mut commands: Commands,
+) {
+    let dt = time.delta_secs();
+    for (entity, mut p, mut tf) in &mut particles {
+        p.age += dt;
+        if p.age >= cfg.reveal_particle_lifetime {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        tf.translation += p.velocity * dt;
+        tf.scale = Vec3::splat(1.0 - p.age / cfg.reveal_particle_lifetime);
+    }
+}
+
+/// Tags first-time revealed cells and announces them with [`HexRevealed`].
+#[allow(clippy::type_complexity)]
+pub(super) fn announce_reveals(
+    grid: Single<&HGrid>,
+    player: Res<PlayerPos>,
+    cfg: Res<HTerrainConfig>,
+    revealed: Query<(Entity, &HCell), (Added<InFov>, Without<Revealed>)>,
+    mut reveals: MessageWriter<HexRevealed>,
+    mut commands: Commands,
+) {
+    let center = grid.terrain.world_pos_to_hex(player.xz);
+    for (entity, cell) in &revealed {
+        commands.entity(entity).insert(Revealed);
+        reveals.write(HexRevealed {
+            cell: entity,
+            delay: cell.hex.unsigned_distance_to(center) as f32 * cfg.reveal_stagger_secs,
+        });
+    }
+}
+
+/// Full-size scale of a hex face with noise-derived `radius` (XZ only).
+pub(super) fn face_full_scale(radius: f32) -> Vec3 {
+    Vec3::new(radius, 1.0, radius)
+}
+
+/// Spawn scale of a hex face: hidden until its reveal grows it in, or full size when the
+/// reveal animation is off.
+pub(super) fn face_scale(radius: f32, reveal_anim_secs: f32) -> Vec3 {
+    if reveal_anim_secs > 0.0 {
+        Vec3::ZERO
+    } else {
+        face_full_scale(radius)
+    }
+}
+
+/// Starts the scale-in tween on the hex face of every [`HexRevealed`] cell.
+///
+/// With the animation off, the face is set straight to full size instead.
+pub(super) fn start_reveal_anims(
+    mut reveals: MessageReader<HexRevealed>,
+    grid: Single<&HGrid>,
+    cfg: Res<HTerrainConfig>,
+    cells: Query<(&HCell, &Children)>,
+    mut faces: Query<&mut Transform, With<HexFace>>,
+    mut commands: Commands,
+) {
+    for msg in reveals.read() {
+        let Ok((cell, children)) = cells.get(msg.cell) else {
+            continue;
+        };
+        let Some(radius) = grid.terrain.radius(&cell.hex) else {
+            continue;
+        };
+        let target = face_full_scale(radius);
+        for child in children.iter() {
+            if let Ok(mut tf) = faces.get_mut(child) {
+                if cfg.reveal_anim_secs > 0.0 {
+                    commands.entity(child).insert(RevealAnim {
+                        delay: msg.delay,
+                        elapsed: 0.0,
+                        target,
+                    });
+                    tf.scale = Vec3::ZERO;
+                } else {
+                    tf.scale = target;
+                }
+            }
+        }
+    }
+}
+
+/// Eased `0..=1` progress of `anim`, shared by the face scale-in and emissive ignition.
+pub(super) fn reveal_progress(anim: &RevealAnim, reveal_anim_secs: f32) -> f32 {
+    let t = ((anim.elapsed - anim.delay) / reveal_anim_secs).clamp(0.0, 1.0);
+    math::ease_out_cubic(t)
+}
+
+/// Grows revealed hex faces to full size with an ease-out curve.
+pub(super) fn animate_reveal(
+    mut faces: Query<(Entity, &mut RevealAnim, &mut Transform)>,
+    cfg: Res<HTerrainConfig>,
+    time: Res<Time>,
+    mut commands: Commands,
+) {
+    for (entity, mut anim, mut tf) in &mut faces {
+        anim.elapsed += time.delta_secs();
+        let t = reveal_progress(&anim, cfg.reveal_anim_secs);
+        tf.scale = anim.target * t;
+        if t >= 1.0 {
+            commands.entity(entity).remove::<RevealAnim>();
+        }
+    }
+}
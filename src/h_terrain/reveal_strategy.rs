@@ -0,0 +1,87 @@
+//! Which hexes around the player are in the FoV.
+//!
+//! [`RevealStrategy::Radial`] is the classic ring of [`HGridSettings::fov_reach`] hexes
+//! around the player's hex. [`RevealStrategy::Cone`] only takes hexes whose centers lie in
+//! the camera's horizontal forward cone, so the map opens up where the player looks.
+//! [`track_player_fov`](super::systems::track_player_fov) tags the result with [`InFov`],
+//! which drives passive reveals.
+//!
+//! [`HGridSettings::fov_reach`]: super::HGridSettings::fov_reach
+//! [`InFov`]: super::InFov
+
+use bevy::platform::collections::HashSet;
+use bevy::prelude::*;
+use hexx::{Hex, shapes};
+
+use super::h_grid_layout::HGridLayout;
+
+/// Shape of the FoV that tags hexes with [`InFov`](super::InFov).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Reflect)]
+pub enum RevealStrategy {
+    /// Every hex within [`fov_reach`](super::HGridSettings::fov_reach) rings of the player's.
+    #[default]
+    Radial,
+    /// Hexes ahead of the camera: within `angle` degrees (full width) of its horizontal
+    /// forward and `range` world units of the player. The player's own hex always counts.
+    Cone {
+        /// Full cone width in degrees.
+        angle: f32,
+        /// Farthest hex center, in world units.
+        range: f32,
+    },
+}
+
+impl RevealStrategy {
+    /// All strategies in picker order (the cone with its default shape).
+    pub const ALL: [Self; 2] = [
+        Self::Radial,
+        Self::Cone {
+            angle: 90.0,
+            range: 24.0,
+        },
+    ];
+
+    /// Short name for the picker.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Radial => "radial",
+            Self::Cone { .. } => "cone",
+        }
+    }
+
+    /// Whether the FoV depends on where the camera looks (not only on the player's hex).
+    pub fn follows_view(self) -> bool {
+        matches!(self, Self::Cone { .. })
+    }
+
+    /// Hexes in the FoV of an eye at `eye` (world xz) looking along `forward` (world xz; zero
+    /// when looking straight down, which counts every direction). `reach` is the radial ring
+    /// count and `spacing` the distance between hex centers.
+    pub fn fov_hexes(
+        self,
+        terrain: &HGridLayout,
+        eye: Vec2,
+        forward: Vec2,
+        reach: u32,
+        spacing: f32,
+    ) -> HashSet<Hex> {
+        let center = terrain.world_pos_to_hex(eye);
+        match self {
+            Self::Radial => shapes::hexagon(center, reach).collect(),
+            Self::Cone { angle, range } => {
+                let rings = (range / spacing.max(f32::EPSILON)).ceil() as u32 + 1;
+                let min_dot = (angle.clamp(0.0, 360.0) / 2.0).to_radians().cos();
+                let forward = forward.normalize_or_zero();
+                shapes::hexagon(center, rings)
+                    .filter(|&hex| {
+                        let offset = terrain.hex_to_world_pos(hex) - eye;
+                        hex == center
+                            || (offset.length() <= range
+                                && (forward == Vec2::ZERO
+                                    || offset.normalize_or_zero().dot(forward) >= min_dot))
+                    })
+                    .collect()
+            }
+        }
+    }
+}
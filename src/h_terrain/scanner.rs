@@ -0,0 +1,173 @@
+//! Scanner pulse: an alternative to the passive FoV reveal.
+//!
+//! With [`HTerrainConfig::reveal_mode`] set to [`RevealMode::Scanner`], entering the FoV still
+//! highlights cells, but no longer reveals them. Instead, F ([`emit_scanner_pulse`]) sends a
+//! [`ScannerPulse`] ring out from the player's hex at [`HTerrainConfig::scanner_speed`].
+//! [`advance_scanner_pulses`] tags every cell the wavefront passes with [`Revealed`] and
+//! announces it with [`HexRevealed`] (no extra stagger: the wave is the stagger), until the
+//! ring reaches [`HTerrainConfig::scanner_range`]. [`ScannerCooldown`] gates the next pulse for
+//! [`HTerrainConfig::scanner_cooldown_secs`]. Revealed faces outside the FoV lose their fog in
+//! [`lift_fog`].
+
+use std::collections::HashSet;
+use std::f32::consts::FRAC_PI_2;
+
+use bevy::prelude::*;
+
+use super::entities::{
+    FovTransition, HCell, HGrid, HeightGlow, HexFace, HexRevealed, InFov, InSight,
+    PreSightMaterial, Revealed, Visited,
+};
+use super::materials::{TerrainMaterials, with_glow};
+use super::{GlowLevel, HTerrainConfig};
+use crate::{GroundLevel, PlayerPos};
+
+/// Height of the pulse ring above the ground it was emitted over.
+const RING_LIFT: f32 = 0.1;
+
+/// How hexes get [`Revealed`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+pub enum RevealMode {
+    /// Cells are revealed as they enter the FoV.
+    #[default]
+    Passive,
+    /// Cells are revealed by scanner pulses (F).
+    Scanner,
+}
+
+impl RevealMode {
+    /// All modes in picker order.
+    pub const ALL: [RevealMode; 2] = [RevealMode::Passive, RevealMode::Scanner];
+
+    /// Short name for the picker.
+    pub fn label(self) -> &'static str {
+        match self {
+            RevealMode::Passive => "passive",
+            RevealMode::Scanner => "scanner",
+        }
+    }
+}
+
+/// Run condition: cells are revealed as they enter the FoV.
+pub(super) fn passive_reveal(cfg: Res<HTerrainConfig>) -> bool {
+    cfg.reveal_mode == RevealMode::Passive
+}
+
+/// Seconds until the next scanner pulse can fire (0 when ready).
+#[derive(Resource, Default, Reflect)]
+pub struct ScannerCooldown(pub f32);
+
+/// An expanding scanner ring.
+#[derive(Component, Reflect)]
+pub struct ScannerPulse {
+    /// World xz the pulse started from.
+    pub origin: Vec2,
+    /// Current wavefront radius.
+    pub radius: f32,
+}
+
+/// Fires a pulse on F when the cooldown has run out; ticks the cooldown.
+#[allow(clippy::too_many_arguments)]
+pub(super) fn emit_scanner_pulse(
+    keys: Res<ButtonInput<KeyCode>>,
+    time: Res<Time>,
+    cfg: Res<HTerrainConfig>,
+    player: Res<PlayerPos>,
+    ground: Res<GroundLevel>,
+    mats: Res<TerrainMaterials>,
+    mut cooldown: ResMut<ScannerCooldown>,
+    mut commands: Commands,
+) {
+    cooldown.0 = (cooldown.0 - time.delta_secs()).max(0.0);
+    if cfg.reveal_mode != RevealMode::Scanner
+        || cooldown.0 > 0.0
+        || !keys.just_pressed(KeyCode::KeyF)
+    {
+        return;
+    }
+    cooldown.0 = cfg.scanner_cooldown_secs;
+    let y = ground.0.unwrap_or(0.0) + RING_LIFT;
+    commands.spawn((
+        Name::new("ScannerPulse"),
+        ScannerPulse {
+            origin: player.xz,
+            radius: 0.0,
+        },
+        Mesh3d(mats.scanner_ring_mesh.clone()),
+        MeshMaterial3d(mats.aim_star.clone()),
+        Transform::from_xyz(player.xz.x, y, player.xz.y)
+            .with_rotation(Quat::from_rotation_x(-FRAC_PI_2))
+            .with_scale(Vec3::ZERO),
+    ));
+}
+
+/// Grows each pulse, revealing the cells its wavefront passes, and despawns it at full range.
+pub(super) fn advance_scanner_pulses(
+    time: Res<Time>,
+    cfg: Res<HTerrainConfig>,
+    grid: Single<&HGrid>,
+    revealed: Query<(), With<Revealed>>,
+    mut pulses: Query<(Entity, &mut ScannerPulse, &mut Transform)>,
+    mut reveals: MessageWriter<HexRevealed>,
+    mut commands: Commands,
+) {
+    let mut tagged = HashSet::new();
+    for (entity, mut pulse, mut tf) in &mut pulses {
+        let inner = pulse.radius;
+        pulse.radius = (inner + cfg.scanner_speed * time.delta_secs()).min(cfg.scanner_range);
+        tf.scale = Vec3::splat(pulse.radius);
+        for (&hex, &cell) in &grid.hex_entities {
+            let d = grid.terrain.hex_to_world_pos(hex).distance(pulse.origin);
+            let passed = d <= pulse.radius && (d > inner || inner == 0.0);
+            if passed && !revealed.contains(cell) && tagged.insert(cell) {
+                commands.entity(cell).insert(Revealed);
+                reveals.write(HexRevealed { cell, delay: 0.0 });
+            }
+        }
+        if pulse.radius >= cfg.scanner_range {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Repaints the faces of newly revealed cells outside the FoV from fog to their rest material.
+#[allow(clippy::type_complexity)]
+pub(super) fn lift_fog(
+    mats: Res<TerrainMaterials>,
+    level: Res<GlowLevel>,
+    cells: Query<(&Children, Has<Visited>), (With<HCell>, Added<Revealed>, Without<InFov>)>,
+    mut faces: Query<
+        (
+            &mut MeshMaterial3d<StandardMaterial>,
+            Option<&mut FovTransition>,
+            Option<&HeightGlow>,
+        ),
+        (With<HexFace>, Without<InSight>),
+    >,
+    mut sighted: Query<&mut PreSightMaterial, (With<HexFace>, With<InSight>)>,
+    mut mat_assets: ResMut<Assets<StandardMaterial>>,
+) {
+    for (children, visited) in &cells {
+        let rest = mats.hex_rest(visited, false);
+        for child in children.iter() {
+            if let Ok(mut stash) = sighted.get_mut(child) {
+                stash.0 = rest.clone();
+            }
+            let Ok((mut mat, transition, glow)) = faces.get_mut(child) else {
+                continue;
+            };
+            if let Some(mut transition) = transition {
+                // A fade back out now ends at the rest material instead of the fog.
+                transition.from_fog = false;
+            } else if [&mats.hex_fogged, &mats.hex_original, &mats.hex_visited].contains(&&mat.0) {
+                mat.0 = rest.clone();
+            } else if let Some(src) = mat_assets.get(rest).map(|m| (m.base_color, m.emissive))
+                && let Some(own) = mat_assets.get_mut(&mat.0)
+            {
+                // Private copy left by an earlier fade: recolor it in place.
+                own.base_color = src.0;
+                own.emissive = with_glow(src.1, glow, level.0);
+            }
+        }
+    }
+}
@@ -0,0 +1,162 @@
+//! Line of sight between hexes, and an inspect-mode view of what the selected hex can see.
+//!
+//! [`line_of_sight`] raymarches the straight segment from one hex center to another (raised
+//! by an eye and a target height) over [`HGridLayout::interpolate_height`], so the smoothed
+//! surface between cells blocks the view, not only the cell tops. [`visible_hexes`] runs it
+//! from one hex to every other. With the debug view's `line_of_sight` toggle on and a
+//! [`SelectedHex`], [`update_sightlines`] fills [`Sightlines`] and [`draw_sightlines`] covers
+//! every face with a [`SightlineOverlay`]: bright where the selected hex can see the face, dark
+//! where it can't. Both re-run after height edits and regenerations.
+
+use bevy::platform::collections::HashSet;
+use bevy::prelude::*;
+use hexx::Hex;
+
+use super::HTerrainConfig;
+use super::debug_view::DebugView;
+use super::entities::{HCell, HGrid, HexFace};
+use super::h_grid_layout::HGridLayout;
+use super::overlay_lift;
+use super::picking::SelectedHex;
+
+/// Hexes visible from an origin hex (empty without one).
+#[derive(Resource, Default, Reflect)]
+pub struct Sightlines {
+    /// Hex the view is computed from.
+    #[reflect(ignore)]
+    pub origin: Option<Hex>,
+    /// Hexes the origin can see, the origin included.
+    #[reflect(ignore)]
+    pub visible: HashSet<Hex>,
+}
+
+/// Marker on the visibility overlay child of a [`HexFace`]; every face gets one while a view
+/// is shown (see [`overlay_lift`]).
+#[derive(Component, Reflect)]
+pub struct SightlineOverlay;
+
+/// Shared overlay materials for visible and hidden faces.
+#[derive(Resource)]
+pub struct SightlineMaterials {
+    /// Faces the origin can see.
+    pub visible: Handle<StandardMaterial>,
+    /// Faces hidden from the origin.
+    pub hidden: Handle<StandardMaterial>,
+}
+
+/// Whether an eye `eye_height` above the center of `from` sees the point `target_height`
+/// above the center of `to`, sampling the interpolated surface every `step` world units
+/// along the way. Hexes outside the grid are never visible.
+pub fn line_of_sight(
+    terrain: &HGridLayout,
+    from: Hex,
+    to: Hex,
+    eye_height: f32,
+    target_height: f32,
+    step: f32,
+) -> bool {
+    let (Some(h0), Some(h1)) = (terrain.height(&from), terrain.height(&to)) else {
+        return false;
+    };
+    let a = terrain.hex_to_world_pos(from).extend(h0 + eye_height).xzy();
+    let b = terrain
+        .hex_to_world_pos(to)
+        .extend(h1 + target_height)
+        .xzy();
+    let samples = (a.xz().distance(b.xz()) / step.max(f32::EPSILON)).ceil() as usize;
+    (1..samples).all(|i| {
+        let p = a.lerp(b, i as f32 / samples as f32);
+        p.y >= terrain.interpolate_height(p.xz())
+    })
+}
+
+/// Every grid hex visible from `from` under [`line_of_sight`], `from` included.
+pub fn visible_hexes(
+    terrain: &HGridLayout,
+    from: Hex,
+    eye_height: f32,
+    target_height: f32,
+    step: f32,
+) -> HashSet<Hex> {
+    terrain
+        .hexes()
+        .filter(|&to| {
+            to == from || line_of_sight(terrain, from, to, eye_height, target_height, step)
+        })
+        .collect()
+}
+
+/// Creates the shared overlay materials.
+pub(super) fn create_sightline_materials(
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut commands: Commands,
+) {
+    let mut overlay = |color: Color| {
+        materials.add(StandardMaterial {
+            base_color: color,
+            alpha_mode: AlphaMode::Blend,
+            unlit: true,
+            ..default()
+        })
+    };
+    commands.insert_resource(SightlineMaterials {
+        visible: overlay(Color::srgba(0.2, 1.0, 0.6, 0.45)),
+        hidden: overlay(Color::srgba(0.02, 0.0, 0.08, 0.75)),
+    });
+}
+
+/// Recomputes [`Sightlines`] from the [`SelectedHex`] while the view is on.
+pub(super) fn update_sightlines(
+    cfg: Res<HTerrainConfig>,
+    view: Res<DebugView>,
+    selected: Res<SelectedHex>,
+    grid: Single<&HGrid>,
+    mut sightlines: ResMut<Sightlines>,
+) {
+    let origin = selected.0.filter(|_| view.line_of_sight);
+    *sightlines = Sightlines {
+        origin,
+        visible: origin.map_or_else(HashSet::default, |from| {
+            visible_hexes(
+                &grid.terrain,
+                from,
+                cfg.sight_eye_height,
+                cfg.sight_target_height,
+                cfg.grid.point_spacing / 4.0,
+            )
+        }),
+    };
+}
+
+/// Respawns the face overlays for the current [`Sightlines`].
+pub(super) fn draw_sightlines(
+    sightlines: Res<Sightlines>,
+    mats: Res<SightlineMaterials>,
+    cells: Query<&HCell>,
+    faces: Query<(Entity, &Mesh3d, &ChildOf), With<HexFace>>,
+    overlays: Query<Entity, With<SightlineOverlay>>,
+    mut commands: Commands,
+) {
+    for overlay in &overlays {
+        commands.entity(overlay).despawn();
+    }
+    if sightlines.origin.is_none() {
+        return;
+    }
+    for (face, mesh, parent) in &faces {
+        let Ok(cell) = cells.get(parent.parent()) else {
+            continue;
+        };
+        let material = if sightlines.visible.contains(&cell.hex) {
+            &mats.visible
+        } else {
+            &mats.hidden
+        };
+        commands.entity(face).with_child((
+            SightlineOverlay,
+            Mesh3d(mesh.0.clone()),
+            MeshMaterial3d(material.clone()),
+            Transform::from_xyz(0.0, overlay_lift::SIGHTLINE, 0.0),
+        ));
+    }
+}
@@ -7,13 +7,17 @@ use bevy::prelude::*;
 use bevy::render::render_resource::PrimitiveTopology;
 use hexx::{Hex, HexLayout, PlaneMeshBuilder, shapes};
 
+use super::ActiveHex;
 use super::HTerrainConfig;
-use super::entities::{Corner, HCell, HGrid, HexFace, Quad, Tri};
+use super::edge_material::EdgeMaterial;
+use super::entities::{Corner, CornerSphere, HCell, HGrid, HexFace, Quad, Tri};
 use super::gaps;
 use super::h_grid_layout::HGridLayout;
 use super::materials::TerrainMaterials;
 use super::math;
-use crate::DebugFlag;
+use super::reveal_fx::face_scale;
+use super::theme::Theme;
+use crate::{DebugFlag, PlayerPos};
 
 /// Spawns the [`HGrid`] entity with [`HCell`] children, [`Corner`] grandchildren,
 /// and Quad/Tri gap geometry with distributed emitter markers.
@@ -21,14 +25,22 @@ pub fn generate_h_grid(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
+    mut edge_materials: ResMut<Assets<EdgeMaterial>>,
     cfg: Res<HTerrainConfig>,
+    theme: Res<Theme>,
     debug: Res<DebugFlag>,
 ) {
     let g = &cfg.grid;
     let terrain = HGridLayout::from_settings(g);
 
     let edge_thickness = 0.02;
-    let fov = TerrainMaterials::new(&mut materials, &mut meshes);
+    let fov = TerrainMaterials::new(
+        &mut materials,
+        &mut edge_materials,
+        &mut meshes,
+        &theme,
+        &cfg,
+    );
     let debug_assets = debug.0.then(|| {
         let sphere_mesh = meshes.add(Sphere::new(0.08));
         let material = TerrainMaterials::debug_material(&mut materials);
@@ -79,8 +91,8 @@ pub fn generate_h_grid(
             .with_child((
                 HexFace,
                 Mesh3d(hex_mesh.clone()),
-                MeshMaterial3d(fov.hex_original.clone()),
-                Transform::from_scale(Vec3::new(radius, 1.0, radius)),
+                MeshMaterial3d(fov.hex_rest(false, cfg.fog_of_war).clone()),
+                Transform::from_scale(face_scale(radius, cfg.reveal_anim_secs)),
             ))
             .id();
         hex_entities.insert(hex, cell_entity);
@@ -95,11 +107,6 @@ pub fn generate_h_grid(
                 Visibility::default(),
             ));
             if let Some((ref sphere_mesh, ref material)) = debug_assets {
-                corner.insert((
-                    Mesh3d(sphere_mesh.clone()),
-                    MeshMaterial3d(material.clone()),
-                ));
-
                 let uc_next = terrain.unit_corner((i + 1) % 6);
                 let next_offset = Vec3::new(uc_next.x * radius, 0.0, uc_next.y * radius);
                 let edge_vec = next_offset - local_offset;
@@ -108,9 +115,14 @@ pub fn generate_h_grid(
                 let edge_mesh = meshes.add(Cuboid::new(length, edge_thickness, edge_thickness));
 
                 corner.with_child((
-                    Mesh3d(edge_mesh),
+                    CornerSphere,
+                    Mesh3d(sphere_mesh.clone()),
                     MeshMaterial3d(material.clone()),
-                    Transform::from_translation(midpoint).with_rotation(rotation),
+                    children![(
+                        Mesh3d(edge_mesh),
+                        MeshMaterial3d(material.clone()),
+                        Transform::from_translation(midpoint).with_rotation(rotation),
+                    )],
                 ));
             }
             let corner_entity = corner.id();
@@ -129,6 +141,7 @@ pub fn generate_h_grid(
                 &mut commands,
                 &mut meshes,
                 &fov.gap_original,
+                &fov.edge_mesh,
                 &fov.edge,
                 &terrain,
                 &corner_entities,
@@ -160,12 +173,27 @@ pub fn generate_h_grid(
     commands.insert_resource(fov);
 }
 
-/// Seeds [`GroundLevel`](crate::GroundLevel) from terrain height at the origin.
+/// Seeds [`GroundLevel`](crate::GroundLevel) from terrain height under the player.
 ///
-/// Runs at startup (after grid generation) so that the ground level is
-/// correct before the drone spawns.
-pub fn seed_ground_level(grid: Single<&HGrid>, mut ground: ResMut<crate::GroundLevel>) {
-    ground.0 = Some(grid.terrain.interpolate_height(Vec2::ZERO));
+/// Runs at startup (after grid generation, with the player still at the origin) so that the
+/// ground level is correct before the drone spawns, and again after a regeneration.
+pub fn seed_ground_level(
+    grid: Single<&HGrid>,
+    player: Res<PlayerPos>,
+    mut ground: ResMut<crate::GroundLevel>,
+) {
+    ground.0 = Some(grid.terrain.interpolate_height(player.xz));
+}
+
+/// Despawns the whole [`HGrid`] hierarchy ahead of [`generate_h_grid`] on
+/// [`RegenerateTerrain`](super::entities::RegenerateTerrain), and forgets the active hex.
+pub fn clear_h_grid(
+    grid: Single<Entity, With<HGrid>>,
+    mut active: ResMut<ActiveHex>,
+    mut commands: Commands,
+) {
+    commands.entity(*grid).despawn();
+    *active = ActiveHex::default();
 }
 
 /// Debug-only startup check: asserts spawned Quad/Tri counts match `gap_filler` expectations.
@@ -0,0 +1,139 @@
+//! Terrain statistics: height distribution, elevation bands, and cliff count.
+//!
+//! [`TerrainStats`] is recomputed whenever a new [`HGrid`] is spawned (startup and every
+//! [`RegenerateTerrain`](super::RegenerateTerrain)) and shown in an egui window while
+//! `Inspecting`, next to the settings panel, so noise tweaks get numeric feedback.
+
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+use bevy_egui::egui;
+use hexx::Hex;
+
+use super::HTerrainConfig;
+use super::entities::HGrid;
+
+/// Number of height histogram bins between 0 and `max_height`.
+pub const HISTOGRAM_BINS: usize = 16;
+/// Neighbor height difference, as a fraction of `max_height`, that counts as a cliff.
+pub const CLIFF_FRACTION: f32 = 0.2;
+/// Elevation band names, low to high; each covers an equal slice of `max_height`.
+pub const BANDS: [&str; 3] = ["lowland", "hills", "peaks"];
+
+/// Summary of the current grid's heights.
+#[derive(Resource, Clone, Debug, Default, PartialEq, Reflect)]
+pub struct TerrainStats {
+    /// Number of hexes in the grid.
+    pub hex_count: usize,
+    /// Lowest hex height.
+    pub min_height: f32,
+    /// Mean hex height.
+    pub mean_height: f32,
+    /// Highest hex height.
+    pub max_height: f32,
+    /// Hex counts per height bin ([`HISTOGRAM_BINS`] bins over `0..=max_height` setting).
+    pub histogram: Vec<usize>,
+    /// Hex counts per elevation band ([`BANDS`]).
+    pub bands: [usize; BANDS.len()],
+    /// Adjacent hex pairs whose heights differ by more than [`CLIFF_FRACTION`] × `max_height`.
+    pub cliffs: usize,
+}
+
+impl TerrainStats {
+    /// Computes stats for `heights`, binned against the configured `max_height`.
+    pub fn from_heights(heights: &HashMap<Hex, f32>, max_height: f32) -> Self {
+        let mut stats = Self {
+            hex_count: heights.len(),
+            histogram: vec![0; HISTOGRAM_BINS],
+            ..default()
+        };
+        if heights.is_empty() {
+            return stats;
+        }
+        let bin = |h: f32, n: usize| ((h / max_height.max(f32::EPSILON)) * n as f32) as usize;
+        stats.min_height = f32::MAX;
+        stats.max_height = f32::MIN;
+        let mut sum = 0.0;
+        for (hex, &h) in heights {
+            stats.min_height = stats.min_height.min(h);
+            stats.max_height = stats.max_height.max(h);
+            sum += h;
+            stats.histogram[bin(h, HISTOGRAM_BINS).min(HISTOGRAM_BINS - 1)] += 1;
+            stats.bands[bin(h, BANDS.len()).min(BANDS.len() - 1)] += 1;
+            stats.cliffs += hex
+                .all_neighbors()
+                .iter()
+                .filter_map(|n| heights.get(n))
+                .filter(|&&n| (n - h).abs() > CLIFF_FRACTION * max_height)
+                .count();
+        }
+        // Every pair was seen from both sides.
+        stats.cliffs /= 2;
+        stats.mean_height = sum / heights.len() as f32;
+        stats
+    }
+}
+
+/// Recomputes [`TerrainStats`] for a newly spawned [`HGrid`].
+pub(super) fn compute_terrain_stats(
+    grid: Single<&HGrid, Added<HGrid>>,
+    cfg: Res<HTerrainConfig>,
+    mut stats: ResMut<TerrainStats>,
+) {
+    let heights = grid
+        .hex_entities
+        .keys()
+        .filter_map(|&hex| Some((hex, grid.terrain.height(&hex)?)))
+        .collect();
+    *stats = TerrainStats::from_heights(&heights, cfg.grid.max_height);
+}
+
+/// Draws the stats window with a height histogram in the bottom-left corner.
+pub(super) fn draw_terrain_stats(
+    mut egui_ctx: Single<&mut bevy_egui::EguiContext>,
+    stats: Res<TerrainStats>,
+    cfg: Res<HTerrainConfig>,
+) {
+    const PLOT_SIZE: egui::Vec2 = egui::vec2(240.0, 80.0);
+    egui::Window::new("Terrain stats")
+        .anchor(egui::Align2::LEFT_BOTTOM, [8.0, -8.0])
+        .resizable(false)
+        .show(egui_ctx.get_mut(), |ui| {
+            ui.monospace(format!("hexes  {}", stats.hex_count));
+            ui.monospace(format!(
+                "height {:.1} / {:.1} / {:.1}  (min/mean/max)",
+                stats.min_height, stats.mean_height, stats.max_height
+            ));
+
+            let (rect, _) = ui.allocate_exact_size(PLOT_SIZE, egui::Sense::hover());
+            let painter = ui.painter_at(rect);
+            painter.rect_filled(rect, 2.0, egui::Color32::from_black_alpha(120));
+            let peak = stats.histogram.iter().copied().max().unwrap_or(0).max(1);
+            let bar_width = rect.width() / stats.histogram.len().max(1) as f32;
+            for (i, &count) in stats.histogram.iter().enumerate() {
+                let h = rect.height() * count as f32 / peak as f32;
+                let x = rect.left() + i as f32 * bar_width;
+                painter.rect_filled(
+                    egui::Rect::from_min_max(
+                        egui::pos2(x + 1.0, rect.bottom() - h),
+                        egui::pos2(x + bar_width - 1.0, rect.bottom()),
+                    ),
+                    0.0,
+                    egui::Color32::from_rgb(0, 200, 255),
+                );
+            }
+            ui.horizontal(|ui| {
+                ui.small("0");
+                ui.add_space(PLOT_SIZE.x - 40.0);
+                ui.small(format!("{:.0}", cfg.grid.max_height));
+            });
+
+            let total = stats.hex_count.max(1) as f32;
+            for (name, &count) in BANDS.iter().zip(&stats.bands) {
+                ui.monospace(format!(
+                    "{name:<8}{count:>6}  {:>5.1}%",
+                    100.0 * count as f32 / total
+                ));
+            }
+            ui.monospace(format!("cliffs  {:>6}", stats.cliffs));
+        });
+}
@@ -0,0 +1,45 @@
+//! Optional directional "sun" light with cascaded shadow maps.
+//!
+//! Off by default: the terrain normally reads as pure neon emissive. With
+//! [`HTerrainConfig::sun`] enabled, hex faces and gaps are lit by their theme base colors and
+//! cast shadows onto lower cells; edge lines and the grid floor stay unlit.
+
+use bevy::light::CascadeShadowConfigBuilder;
+use bevy::prelude::*;
+
+use super::HTerrainConfig;
+
+/// Marker on the sun light entity.
+#[derive(Component, Reflect)]
+pub struct Sun;
+
+/// Sun orientation from [`HTerrainConfig::sun_azimuth`] and [`HTerrainConfig::sun_elevation`]
+/// (degrees). The light shines along the rotated -Z axis.
+pub fn sun_rotation(cfg: &HTerrainConfig) -> Quat {
+    Quat::from_euler(
+        EulerRot::YXZ,
+        cfg.sun_azimuth.to_radians(),
+        -cfg.sun_elevation.to_radians(),
+        0.0,
+    )
+}
+
+/// Spawns the shadow-casting sun light.
+pub(super) fn spawn_sun(mut commands: Commands, cfg: Res<HTerrainConfig>) {
+    commands.spawn((
+        Sun,
+        Name::new("Sun"),
+        DirectionalLight {
+            illuminance: cfg.sun_illuminance,
+            shadows_enabled: true,
+            ..default()
+        },
+        CascadeShadowConfigBuilder {
+            maximum_distance: cfg.sun_shadow_distance,
+            first_cascade_far_bound: cfg.sun_shadow_distance / 6.0,
+            ..default()
+        }
+        .build(),
+        Transform::from_rotation(sun_rotation(&cfg)),
+    ));
+}
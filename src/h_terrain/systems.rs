@@ -1,19 +1,23 @@
 //! Runtime systems for height-based terrain.
 
+use bevy::diagnostic::Diagnostics;
 use bevy::ecs::relationship::Relationship;
 use bevy::ecs::system::SystemParam;
 use bevy::picking::mesh_picking::ray_cast::{MeshRayCast, MeshRayCastSettings, RayCastVisibility};
 use bevy::platform::collections::HashSet;
+use bevy::platform::time::Instant;
 use bevy::prelude::*;
-use hexx::{Hex, shapes};
+use hexx::Hex;
 
 use super::entities::{
-    Corner, EmitterMark, HCell, HGrid, HexFace, InFov, InSight, Mark, Quad, QuadEdge, QuadOwner,
-    QuadPos1Emitter, QuadPos2Emitter, Tri, TriOwner, TriPos1Emitter, TriPos2Emitter,
+    Corner, EditHexHeight, EmitterMark, HCell, HGrid, HexFace, InFov, InSight, Mark, Quad,
+    QuadEdge, QuadOwner, QuadPos1Emitter, QuadPos2Emitter, TeleportToHex, Tri, TriOwner,
+    TriPos1Emitter, TriPos2Emitter, Visited,
 };
 use super::gaps::GapMeshAccess;
-use super::{HTerrainConfig, LaserStrength};
-use crate::{GroundLevel, PlayerPos};
+use super::{ActiveHex, HTerrainConfig, LaserStrength, REVEAL_TIME};
+use crate::drone::Player;
+use crate::{GroundAhead, GroundLevel, PlayerMoved, PlayerPos};
 
 /// Bundles queries for discovering gap entities (Quad/Tri) reachable from an HCell.
 #[derive(SystemParam)]
@@ -94,26 +98,98 @@ pub fn update_ground_level(
     }
 }
 
-/// Adds/removes [`InFov`] on [`HCell`] entities when the player crosses a hex boundary.
-pub fn track_player_fov(
+/// Sets [`GroundAhead`] to the highest interpolated terrain height sampled along
+/// [`PlayerPos::heading`], so contour flight can pre-climb approaching hills.
+///
+/// Runs in [`HTerrainPhase::LookAhead`](super::HTerrainPhase::LookAhead), before the player
+/// moves, so `fly` reads the value sampled this frame rather than the previous one.
+pub fn update_ground_ahead(
     grid: Single<&HGrid>,
     player: Res<PlayerPos>,
     cfg: Res<HTerrainConfig>,
+    mut ahead: ResMut<GroundAhead>,
+) {
+    if player.heading == Vec2::ZERO {
+        ahead.0 = None;
+        return;
+    }
+    let samples = cfg.lookahead_samples.max(1);
+    ahead.0 = (1..=samples)
+        .map(|i| {
+            let dist = cfg.lookahead_distance * i as f32 / samples as f32;
+            grid.terrain
+                .interpolate_height(player.xz + player.heading * dist)
+        })
+        .reduce(f32::max);
+}
+
+/// Updates [`ActiveHex`] when the player crosses into another hex.
+pub fn track_active_hex(
+    grid: Single<&HGrid>,
+    player: Res<PlayerPos>,
+    mut active: ResMut<ActiveHex>,
+) {
+    let hex = grid.terrain.world_pos_to_hex(player.xz);
+    if active.current != Some(hex) {
+        active.current = Some(hex);
+        active.cell = grid.hex_entities.get(&hex).copied();
+    }
+}
+
+/// Tags the [`ActiveHex`] cell with [`Visited`].
+pub fn track_visited(
+    active: Res<ActiveHex>,
+    visited: Query<(), With<Visited>>,
+    mut commands: Commands,
+) {
+    if let Some(entity) = active.cell
+        && !visited.contains(entity)
+    {
+        commands.entity(entity).insert(Visited);
+    }
+}
+
+/// Adds/removes [`InFov`] on [`HCell`] entities as the FoV of the [`RevealStrategy`] moves:
+/// when the player crosses a hex boundary, or for view-following strategies, looks around.
+///
+/// [`RevealStrategy`]: super::RevealStrategy
+#[allow(clippy::too_many_arguments)]
+pub fn track_player_fov(
+    grid: Single<Ref<HGrid>>,
+    player: Res<PlayerPos>,
+    cfg: Res<HTerrainConfig>,
+    camera: Option<Single<&GlobalTransform, With<Player>>>,
     mut commands: Commands,
-    mut prev_hex: Local<Option<Hex>>,
+    mut prev: Local<Option<(Hex, HashSet<Hex>)>>,
     gap: GapLookup,
+    mut diagnostics: Diagnostics,
 ) {
     let current_hex = grid.terrain.world_pos_to_hex(player.xz);
 
-    if *prev_hex == Some(current_hex) {
+    // A regenerated grid has no InFov cells yet: tag its FoV from scratch.
+    if grid.is_added() {
+        *prev = None;
+    }
+    let strategy = cfg.reveal_strategy;
+    if !strategy.follows_view()
+        && !cfg.is_changed()
+        && prev.as_ref().is_some_and(|(hex, _)| *hex == current_hex)
+    {
         return;
     }
-
-    let reach = cfg.grid.fov_reach;
-    let new_ring: HashSet<Hex> = shapes::hexagon(current_hex, reach).collect();
-    let old_ring: HashSet<Hex> = prev_hex
-        .map(|old| shapes::hexagon(old, reach).collect())
-        .unwrap_or_default();
+    let forward = camera.map_or(Vec2::ZERO, |tf| tf.forward().xz());
+    let new_ring = strategy.fov_hexes(
+        &grid.terrain,
+        player.xz,
+        forward,
+        cfg.grid.fov_reach,
+        cfg.grid.point_spacing,
+    );
+    if prev.as_ref().is_some_and(|(_, ring)| *ring == new_ring) {
+        return;
+    }
+    let start = Instant::now();
+    let old_ring = prev.take().map(|(_, ring)| ring).unwrap_or_default();
 
     // Remove InFov only from cells that left the FoV
     for hex in old_ring.difference(&new_ring) {
@@ -149,34 +225,24 @@ pub fn track_player_fov(
         commands.entity(entity).insert(InFov);
     }
 
-    *prev_hex = Some(current_hex);
+    *prev = Some((current_hex, new_ring));
+    diagnostics.add_measurement(&REVEAL_TIME, || start.elapsed().as_secs_f64() * 1000.0);
 }
 
 /// Lowers an [`HCell`] when the player fires the laser at its [`HexFace`].
 ///
 /// Tick-based: a [`Local`] timer advances only while firing at a target and
-/// resets when not. Each tick lowers the cell by [`LaserStrength::extract_height`],
-/// then realigns neighboring gap vertices via [`GapMeshAccess`].
-#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+/// resets when not. Each tick writes an [`EditHexHeight`] lowering the cell by
+/// [`LaserStrength::extract_height`], applied by [`apply_height_edits`].
+#[allow(clippy::too_many_arguments)]
 pub fn extract_ore(
     time: Res<Time>,
     keys: Res<ButtonInput<KeyCode>>,
     mouse: Res<ButtonInput<MouseButton>>,
     strength: Res<LaserStrength>,
     sight_face: Query<&ChildOf, With<InSight>>,
-    mut cells: Query<&mut Transform, (With<HCell>, Without<QuadEdge>)>,
-    children: Query<&Children>,
-    emitters: Query<
-        (
-            Option<&QuadPos1Emitter>,
-            Option<&QuadPos2Emitter>,
-            Option<&TriPos1Emitter>,
-            Option<&TriPos2Emitter>,
-        ),
-        With<Corner>,
-    >,
-    owners: Query<(Option<&QuadOwner>, Option<&TriOwner>), With<Corner>>,
-    mut gap_mesh: GapMeshAccess,
+    cells: Query<&HCell>,
+    mut edits: MessageWriter<EditHexHeight>,
     mut timer: Local<Option<Timer>>,
 ) {
     let firing = keys.pressed(KeyCode::Space) || mouse.pressed(MouseButton::Left);
@@ -189,7 +255,9 @@ pub fn extract_ore(
     let Some(face_parent) = sight_face.iter().next() else {
         return;
     };
-    let cell = face_parent.get();
+    let Ok(cell) = cells.get(face_parent.get()) else {
+        return;
+    };
 
     let t = timer
         .get_or_insert_with(|| Timer::from_seconds(strength.extraction_time, TimerMode::Repeating));
@@ -198,45 +266,111 @@ pub fn extract_ore(
         return;
     }
 
-    let Ok(mut tf) = cells.get_mut(cell) else {
-        return;
-    };
-    tf.translation.y -= strength.extract_height;
-    let new_y = tf.translation.y;
+    edits.write(EditHexHeight {
+        hex: cell.hex,
+        delta: -strength.extract_height,
+    });
+}
 
-    let Ok(cell_children) = children.get(cell) else {
-        return;
-    };
-    for corner in cell_children.iter() {
-        let Ok((qp1, qp2, tp1, tp2)) = emitters.get(corner) else {
+/// Applies [`EditHexHeight`] requests: moves the [`HCell`], updates the [`HGrid`] height,
+/// and realigns the surrounding gap vertices via [`GapMeshAccess`].
+#[allow(clippy::type_complexity)]
+pub fn apply_height_edits(
+    mut requests: MessageReader<EditHexHeight>,
+    mut grid: Single<&mut HGrid>,
+    mut cells: Query<&mut Transform, (With<HCell>, Without<QuadEdge>)>,
+    children: Query<&Children>,
+    emitters: Query<
+        (
+            Option<&QuadPos1Emitter>,
+            Option<&QuadPos2Emitter>,
+            Option<&TriPos1Emitter>,
+            Option<&TriPos2Emitter>,
+        ),
+        With<Corner>,
+    >,
+    owners: Query<(Option<&QuadOwner>, Option<&TriOwner>), With<Corner>>,
+    mut gap_mesh: GapMeshAccess,
+) {
+    for &EditHexHeight { hex, delta } in requests.read() {
+        let Some(&cell) = grid.hex_entities.get(&hex) else {
+            warn!("Height edit target {hex:?} is outside the grid");
             continue;
         };
-        if let Some(e) = qp1 {
-            gap_mesh.realign_neighboring_vertex(e.not_owned_by_parent(), e.vertex_index(), new_y);
-        }
-        if let Some(e) = qp2 {
-            gap_mesh.realign_neighboring_vertex(e.not_owned_by_parent(), e.vertex_index(), new_y);
-        }
-        if let Some(e) = tp1 {
-            gap_mesh.realign_neighboring_vertex(e.not_owned_by_parent(), e.vertex_index(), new_y);
+        let Ok(mut tf) = cells.get_mut(cell) else {
+            continue;
+        };
+        tf.translation.y += delta;
+        let new_y = tf.translation.y;
+        grid.terrain.set_height(hex, new_y);
+
+        let Ok(cell_children) = children.get(cell) else {
+            continue;
+        };
+        for corner in cell_children.iter() {
+            let Ok((qp1, qp2, tp1, tp2)) = emitters.get(corner) else {
+                continue;
+            };
+            if let Some(e) = qp1 {
+                gap_mesh.realign_neighboring_vertex(
+                    e.not_owned_by_parent(),
+                    e.vertex_index(),
+                    new_y,
+                );
+            }
+            if let Some(e) = qp2 {
+                gap_mesh.realign_neighboring_vertex(
+                    e.not_owned_by_parent(),
+                    e.vertex_index(),
+                    new_y,
+                );
+            }
+            if let Some(e) = tp1 {
+                gap_mesh.realign_neighboring_vertex(
+                    e.not_owned_by_parent(),
+                    e.vertex_index(),
+                    new_y,
+                );
+            }
+            if let Some(e) = tp2 {
+                gap_mesh.realign_neighboring_vertex(
+                    e.not_owned_by_parent(),
+                    e.vertex_index(),
+                    new_y,
+                );
+            }
         }
-        if let Some(e) = tp2 {
-            gap_mesh.realign_neighboring_vertex(e.not_owned_by_parent(), e.vertex_index(), new_y);
+
+        // Owner-side: owned gap meshes' neighbor vertices shift against the cell in local space
+        for corner in cell_children.iter() {
+            let Ok((qo, to)) = owners.get(corner) else {
+                continue;
+            };
+            if let Some(o) = qo {
+                gap_mesh.shift_vertex_y(o.not_owned_by_parent(), 1, -delta);
+                gap_mesh.shift_vertex_y(o.not_owned_by_parent(), 2, -delta);
+            }
+            if let Some(o) = to {
+                gap_mesh.shift_vertex_y(o.not_owned_by_parent(), 1, -delta);
+                gap_mesh.shift_vertex_y(o.not_owned_by_parent(), 2, -delta);
+            }
         }
     }
+}
 
-    // Owner-side: owned gap meshes' neighbor vertices shift up in local space
-    for corner in cell_children.iter() {
-        let Ok((qo, to)) = owners.get(corner) else {
+/// Applies [`TeleportToHex`] requests to [`PlayerPos`]; the last valid one wins.
+pub fn teleport_to_hex(
+    mut requests: MessageReader<TeleportToHex>,
+    grid: Single<&HGrid>,
+    mut player: ResMut<PlayerPos>,
+    mut moved: ResMut<PlayerMoved>,
+) {
+    for &TeleportToHex(hex) in requests.read() {
+        if !grid.hex_entities.contains_key(&hex) {
+            warn!("Teleport target {hex:?} is outside the grid");
             continue;
-        };
-        if let Some(o) = qo {
-            gap_mesh.shift_vertex_y(o.not_owned_by_parent(), 1, strength.extract_height);
-            gap_mesh.shift_vertex_y(o.not_owned_by_parent(), 2, strength.extract_height);
-        }
-        if let Some(o) = to {
-            gap_mesh.shift_vertex_y(o.not_owned_by_parent(), 1, strength.extract_height);
-            gap_mesh.shift_vertex_y(o.not_owned_by_parent(), 2, strength.extract_height);
         }
+        player.xz = grid.terrain.hex_to_world_pos(hex);
+        moved.0 = true;
     }
 }
@@ -0,0 +1,86 @@
+//! The hex under the crosshair: coordinate, height, and whether it is a cliff or landmark.
+//!
+//! [`track_target_hex`] follows the [`InSight`] face picked in [`HTerrainPhase::Sight`] and
+//! classifies its cell with [`classify_target`]; the drone crosshair reads [`TargetHex`] for
+//! its readout and color.
+//!
+//! [`HTerrainPhase::Sight`]: super::HTerrainPhase::Sight
+
+use bevy::prelude::*;
+use hexx::Hex;
+
+use super::HTerrainConfig;
+use super::entities::{HCell, HGrid, InSight};
+use super::stats::{BANDS, CLIFF_FRACTION};
+
+/// What kind of hex the crosshair is on.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+pub enum TargetKind {
+    /// Ordinary terrain.
+    #[default]
+    Plain,
+    /// A neighbor differs in height by more than [`CLIFF_FRACTION`] × `max_height`.
+    Cliff,
+    /// A local peak in the top elevation band, higher than every neighbor.
+    Landmark,
+}
+
+/// The aimed hex, updated after [`HTerrainPhase::Sight`](super::HTerrainPhase::Sight).
+#[derive(Resource, Default, Reflect)]
+pub struct TargetHex {
+    /// Aimed hex coordinate (`None` when nothing is in sight).
+    #[reflect(ignore)]
+    pub hex: Option<Hex>,
+    /// Terrain height of the aimed hex.
+    pub height: f32,
+    /// Classification of the aimed hex.
+    pub kind: TargetKind,
+}
+
+/// Classifies `hex` at `height` against its neighbors' heights (`None` off the grid).
+pub fn classify_target(
+    hex: Hex,
+    height: f32,
+    max_height: f32,
+    neighbor_height: impl Fn(Hex) -> Option<f32>,
+) -> TargetKind {
+    let neighbors: Vec<f32> = hex
+        .all_neighbors()
+        .into_iter()
+        .filter_map(neighbor_height)
+        .collect();
+    let top_band = max_height * (BANDS.len() - 1) as f32 / BANDS.len() as f32;
+    if height >= top_band && neighbors.iter().all(|&n| n < height) {
+        TargetKind::Landmark
+    } else if neighbors
+        .iter()
+        .any(|&n| (n - height).abs() > CLIFF_FRACTION * max_height)
+    {
+        TargetKind::Cliff
+    } else {
+        TargetKind::Plain
+    }
+}
+
+/// Updates [`TargetHex`] from the face tagged with [`InSight`].
+pub(super) fn track_target_hex(
+    cfg: Res<HTerrainConfig>,
+    sight: Query<&ChildOf, With<InSight>>,
+    cells: Query<&HCell>,
+    grid: Single<&HGrid>,
+    mut target: ResMut<TargetHex>,
+) {
+    let hex = sight
+        .iter()
+        .next()
+        .and_then(|child_of| cells.get(child_of.parent()).ok())
+        .map(|cell| cell.hex);
+    if hex == target.hex {
+        return;
+    }
+    let height = hex.and_then(|h| grid.terrain.height(&h)).unwrap_or(0.0);
+    let kind = hex.map_or(TargetKind::Plain, |h| {
+        classify_target(h, height, cfg.grid.max_height, |n| grid.terrain.height(&n))
+    });
+    *target = TargetHex { hex, height, kind };
+}
@@ -2,15 +2,50 @@
 
 use std::time::Duration;
 
+use bevy::diagnostic::{Diagnostic, DiagnosticsStore, RegisterDiagnostic};
+use bevy::ecs::system::RunSystemOnce;
+use bevy::light::EnvironmentMapLight;
+use bevy::mesh::MeshTag;
 use bevy::prelude::*;
 use bevy::state::app::StatesPlugin;
 use bevy::time::TimeUpdateStrategy;
 use hexx::{Hex, shapes};
 
-use super::entities::{Corner, FovTransition, HCell, HGrid, HexFace, InFov, Quad, QuadEdge, Tri};
+use super::debug_view::{DebugView, edge_visibility};
+use super::edge_material::{EdgeMaterial, edge_tag_glitch, edge_tag_highlight};
+use super::entities::{
+    Corner, EditHexHeight, FovTransition, HCell, HGrid, HeightGlow, HexFace, HexPicked,
+    HexRevealed, InFov, InSight, Quad, QuadEdge, RevealAnim, Revealed, TeleportToHex, Tri, Visited,
+};
+use super::face_grid::{FaceGrid, FaceGridMaterial, face_grid_tag};
+use super::glitch::{Glitch, GlitchClock};
+use super::grid_floor::{GridFloor, GridFloorMaterial};
+use super::h_grid_layout::HGridLayout;
+use super::heatmap::{HeatmapMaterials, HeatmapMode, HeatmapOverlay, heatmap_band};
+use super::labels::{HexLabelMode, hex_label_text};
+use super::landmarks::{LandmarkKind, LandmarkMarker, landmark_beams, landmark_sites};
+use super::lens_flare::view_fade;
 use super::materials::TerrainMaterials;
-use super::{HTerrainConfig, HTerrainPhase, materials, math, startup_systems, systems};
-use crate::{DebugFlag, GameState, GroundLevel, PlayerMoved, PlayerPos};
+use super::minimap::MinimapFrame;
+use super::notes::{HexNotes, MAX_NOTE_LEN};
+use super::pathfinding::{HexPath, PathEnds, PathSegment, find_hex_path, path_points, step_cost};
+use super::picking::{HoveredHex, SelectedHex, pick_hex};
+use super::reveal_fx::RevealParticle;
+use super::reveal_strategy::RevealStrategy;
+use super::scanner::{RevealMode, ScannerCooldown, ScannerPulse};
+use super::sightline::{
+    SightlineMaterials, SightlineOverlay, Sightlines, line_of_sight, visible_hexes,
+};
+use super::stats::{HISTOGRAM_BINS, TerrainStats};
+use super::target::{TargetKind, classify_target};
+use super::tooltip::hex_tooltip_text;
+use super::{
+    ActiveHex, GlowLevel, HTerrainConfig, HTerrainPhase, HeightGradient, RegenerateTerrain,
+    environment, face_grid, glitch, grid_floor, heatmap, landmarks, materials, math, outline,
+    pathfinding, picking, reveal_fx, scanner, sightline, startup_systems, sun, systems,
+};
+use crate::drone::Player;
+use crate::{DebugFlag, GameState, GroundAhead, GroundLevel, PlayerMoved, PlayerPos};
 
 fn test_config() -> HTerrainConfig {
     HTerrainConfig {
@@ -28,8 +63,76 @@ fn test_config() -> HTerrainConfig {
             min_hex_radius: 0.2,
             max_hex_radius: 2.6,
         },
-        clear_color: Color::BLACK,
+        theme: super::ThemeKind::default(),
+        palette: super::Palette::default(),
         fov_transition_secs: 0.3,
+        lookahead_distance: 6.0,
+        lookahead_samples: 3,
+        edge_width: 0.03,
+        edge_angular_width: 0.002,
+        edge_fade_start: 40.0,
+        edge_fade_end: 110.0,
+        edge_pulse_speed: 3.0,
+        edge_pulse_amplitude: 0.6,
+        edge_pulse_ring_phase: 0.5,
+        grid_floor: true,
+        grid_floor_cell_size: 4.0,
+        grid_floor_intensity: 0.15,
+        grid_floor_fade_end: 150.0,
+        face_grid: true,
+        face_grid_ring_density: 1.5,
+        face_grid_intensity: 0.2,
+        reflection_intensity: 600.0,
+        selection_outline: true,
+        selection_outline_width: 0.06,
+        low_gfx: false,
+        hologram_opacity: 0.2,
+        fog_of_war: false,
+        fog_of_war_brightness: 0.25,
+        glitch: false,
+        glitch_rate: 4.0,
+        glitch_frames: 4,
+        glitch_jitter: 0.02,
+        lens_flare: false,
+        lens_flare_count: 3,
+        lens_flare_width: 240.0,
+        lens_flare_fade_angle: 0.6,
+        lens_flare_fade_speed: 6.0,
+        hex_tooltip: false,
+        hex_labels: HexLabelMode::Off,
+        hex_label_distance: 30.0,
+        hex_label_font_size: 12.0,
+        hex_edit_step: 0.25,
+        hex_note_distance: 60.0,
+        path_climb_cost: 0.5,
+        path_max_climb: 6.0,
+        path_width: 0.06,
+        sight_eye_height: 1.5,
+        sight_target_height: 0.5,
+        minimap: false,
+        minimap_size: 180.0,
+        reveal_particle_count: 12,
+        reveal_particle_lifetime: 0.8,
+        reveal_anim_secs: 0.4,
+        reveal_stagger_secs: 0.08,
+        reveal_mode: RevealMode::Passive,
+        reveal_strategy: RevealStrategy::Radial,
+        scanner_speed: 25.0,
+        scanner_range: 60.0,
+        scanner_cooldown_secs: 4.0,
+        face_height_gradient: None,
+        sun: false,
+        sun_illuminance: 8_000.0,
+        sun_elevation: 35.0,
+        sun_azimuth: 30.0,
+        sun_shadow_distance: 120.0,
+        active_pulse_speed: 4.0,
+        active_pulse_amplitude: 1.5,
+        landmarks: true,
+        landmark_height: 12.0,
+        landmark_width: 0.12,
+        landmark_color: Color::srgb(1.0, 0.2, 0.8),
+        landmark_glow: 5.0,
     }
 }
 
@@ -44,10 +147,38 @@ fn test_app_with_config(cfg: HTerrainConfig) -> App {
         .add_plugins(StatesPlugin)
         .init_asset::<Mesh>()
         .init_asset::<StandardMaterial>()
+        .init_asset::<EdgeMaterial>()
+        .init_asset::<GridFloorMaterial>()
+        .init_asset::<FaceGridMaterial>()
+        .init_asset::<Image>()
+        .add_message::<HexRevealed>()
+        .add_message::<RegenerateTerrain>()
+        .add_message::<TeleportToHex>()
+        .add_message::<EditHexHeight>()
+        .add_message::<HexPicked>()
+        .init_resource::<SelectedHex>()
+        .init_resource::<HoveredHex>()
+        .register_diagnostic(Diagnostic::new(super::REVEAL_TIME))
         .insert_resource(cfg.clone())
+        .insert_resource({
+            let theme = super::Theme::from(cfg.theme).with_palette(cfg.palette);
+            if cfg.low_gfx {
+                theme.flattened()
+            } else {
+                theme
+            }
+        })
+        .init_resource::<GlowLevel>()
+        .init_resource::<ActiveHex>()
+        .init_resource::<ButtonInput<KeyCode>>()
+        .init_resource::<ScannerCooldown>()
+        .init_resource::<HeatmapMode>()
+        .init_resource::<GlitchClock>()
+        .insert_resource(ClearColor::default())
         .insert_resource(DebugFlag(false))
         .init_resource::<PlayerPos>()
         .init_resource::<GroundLevel>()
+        .init_resource::<GroundAhead>()
         .init_resource::<PlayerMoved>()
         .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
             100,
@@ -62,6 +193,7 @@ fn test_app_with_config(cfg: HTerrainConfig) -> App {
     app.configure_sets(
         Update,
         (
+            HTerrainPhase::LookAhead.before(HTerrainPhase::UpdateGround),
             HTerrainPhase::UpdateGround,
             HTerrainPhase::TrackFov.after(HTerrainPhase::UpdateGround),
             HTerrainPhase::Highlight.after(HTerrainPhase::TrackFov),
@@ -73,18 +205,132 @@ fn test_app_with_config(cfg: HTerrainConfig) -> App {
         startup_systems::seed_ground_level.after(startup_systems::generate_h_grid),
     );
     app.add_systems(Startup, startup_systems::generate_h_grid);
+    app.add_systems(
+        Startup,
+        materials::apply_height_glow.after(startup_systems::generate_h_grid),
+    );
+    app.add_systems(
+        Startup,
+        grid_floor::spawn_grid_floor.run_if(|cfg: Res<HTerrainConfig>| cfg.grid_floor),
+    );
+    app.add_systems(
+        Startup,
+        face_grid::spawn_face_grids
+            .after(startup_systems::generate_h_grid)
+            .run_if(|cfg: Res<HTerrainConfig>| cfg.face_grid),
+    );
+    app.add_systems(
+        Startup,
+        sun::spawn_sun.run_if(|cfg: Res<HTerrainConfig>| cfg.sun),
+    );
+    app.add_systems(
+        Startup,
+        landmarks::spawn_landmarks
+            .after(startup_systems::generate_h_grid)
+            .run_if(|cfg: Res<HTerrainConfig>| cfg.landmarks),
+    );
+    app.add_systems(Startup, heatmap::create_heatmap_materials);
+    app.add_systems(Startup, outline::spawn_selection_outline);
 
     // Register Update systems (omit track_in_sight — requires camera/window).
+    app.add_systems(
+        Update,
+        (
+            startup_systems::clear_h_grid,
+            startup_systems::generate_h_grid,
+            (
+                startup_systems::seed_ground_level,
+                face_grid::spawn_face_grids.run_if(|cfg: Res<HTerrainConfig>| cfg.face_grid),
+                landmarks::spawn_landmarks.run_if(|cfg: Res<HTerrainConfig>| cfg.landmarks),
+            ),
+        )
+            .chain()
+            .before(HTerrainPhase::UpdateGround)
+            .run_if(on_message::<RegenerateTerrain>),
+    );
+    app.add_systems(
+        Update,
+        systems::teleport_to_hex
+            .before(HTerrainPhase::UpdateGround)
+            .run_if(on_message::<TeleportToHex>),
+    );
+    app.add_systems(
+        Update,
+        systems::apply_height_edits.run_if(on_message::<EditHexHeight>),
+    );
     app.add_systems(
         Update,
         (
             systems::update_ground_level.in_set(HTerrainPhase::UpdateGround),
+            systems::update_ground_ahead.in_set(HTerrainPhase::LookAhead),
+            grid_floor::follow_player.after(HTerrainPhase::UpdateGround),
             systems::track_player_fov.in_set(HTerrainPhase::TrackFov),
+            (systems::track_active_hex, systems::track_visited)
+                .chain()
+                .in_set(HTerrainPhase::TrackFov),
             materials::start_fov_transitions.in_set(HTerrainPhase::Highlight),
+            reveal_fx::emit_reveal_particles.in_set(HTerrainPhase::Highlight),
+            reveal_fx::animate_reveal_particles.after(HTerrainPhase::Highlight),
+            (
+                reveal_fx::announce_reveals.run_if(scanner::passive_reveal),
+                reveal_fx::start_reveal_anims,
+            )
+                .chain()
+                .in_set(HTerrainPhase::Highlight),
+            (
+                scanner::emit_scanner_pulse,
+                scanner::advance_scanner_pulses,
+                scanner::lift_fog,
+            )
+                .chain()
+                .after(HTerrainPhase::TrackFov)
+                .before(reveal_fx::start_reveal_anims),
+            reveal_fx::animate_reveal.after(HTerrainPhase::Highlight),
             materials::animate_fov_transitions.after(HTerrainPhase::Highlight),
+            materials::animate_edge_transitions.after(HTerrainPhase::Highlight),
+            materials::apply_height_glow.after(HTerrainPhase::Highlight),
+            materials::pulse_active_hex
+                .after(materials::animate_fov_transitions)
+                .after(materials::apply_height_glow),
+            (glitch::start_glitches, glitch::animate_glitches)
+                .chain()
+                .after(materials::animate_edge_transitions)
+                .run_if(|cfg: Res<HTerrainConfig>| cfg.glitch),
+            glitch::clear_glitches
+                .after(materials::animate_edge_transitions)
+                .run_if(|cfg: Res<HTerrainConfig>| !cfg.glitch),
+            (
+                heatmap::sync_heatmap
+                    .run_if(resource_changed::<HeatmapMode>.or(any_match_filter::<Added<HGrid>>))
+                    .after(startup_systems::generate_h_grid),
+                heatmap::update_heatmap.run_if(|m: Res<HeatmapMode>| m.0),
+            )
+                .chain(),
         )
             .run_if(in_state(GameState::Running)),
     );
+    app.add_systems(
+        Update,
+        outline::update_selection_outline.after(HTerrainPhase::Highlight),
+    );
+    app.add_systems(
+        Update,
+        picking::select_picked_hex
+            .before(outline::update_selection_outline)
+            .run_if(in_state(GameState::Inspecting)),
+    );
+    app.add_systems(OnExit(GameState::Inspecting), picking::clear_selected_hex);
+    app.add_systems(
+        Update,
+        (
+            materials::apply_theme,
+            materials::apply_edge_theme,
+            face_grid::apply_face_grid_theme,
+            environment::sync_environment_map.run_if(resource_changed::<super::Theme>),
+        )
+            .run_if(super::look_changed)
+            .before(HTerrainPhase::Highlight),
+    );
 
     // First update: runs Startup + one Update frame.
     app.update();
@@ -200,6 +446,85 @@ fn ground_level_updates_on_player_move() {
     );
 }
 
+// ── update_ground_ahead ────────────────────────────────────────
+
+#[test]
+fn ground_ahead_none_while_hovering() {
+    let mut app = test_app();
+    app.update();
+    assert_eq!(app.world().resource::<GroundAhead>().0, None);
+}
+
+#[test]
+fn ground_ahead_samples_along_heading() {
+    let mut app = test_app();
+
+    app.world_mut().resource_mut::<PlayerPos>().heading = Vec2::X;
+    app.update();
+
+    let ahead = app.world().resource::<GroundAhead>().0;
+    let w = app.world_mut();
+    let terrain = &w.query::<&HGrid>().iter(w).next().unwrap().terrain;
+    let expected = (1..=3)
+        .map(|i| terrain.interpolate_height(Vec2::new(2.0 * i as f32, 0.0)))
+        .fold(f32::MIN, f32::max);
+    assert!(
+        ahead.is_some_and(|a| (a - expected).abs() < 1e-4),
+        "GroundAhead {ahead:?} should be the max look-ahead sample {expected}"
+    );
+}
+
+// ── teleport_to_hex ─────────────────────────────────────────────
+
+#[test]
+fn teleport_moves_player_to_hex_center() {
+    let mut app = test_app();
+    let target = Hex::new(1, -1);
+    let expected = {
+        let w = app.world_mut();
+        w.query::<&HGrid>()
+            .single(w)
+            .unwrap()
+            .terrain
+            .hex_to_world_pos(target)
+    };
+
+    app.world_mut().write_message(TeleportToHex(target));
+    app.update();
+    assert_eq!(app.world().resource::<PlayerPos>().xz, expected);
+    assert_eq!(
+        app.world().resource::<ActiveHex>().current,
+        Some(target),
+        "active hex should follow the teleport"
+    );
+
+    // Outside the radius-2 grid: ignored.
+    app.world_mut().write_message(TeleportToHex(Hex::new(9, 0)));
+    app.update();
+    assert_eq!(app.world().resource::<PlayerPos>().xz, expected);
+}
+
+#[test]
+fn height_edit_moves_cell_and_grid_height() {
+    let mut app = test_app();
+    let hex = Hex::new(1, 0);
+    let (cell, before) = {
+        let w = app.world_mut();
+        let grid = w.query::<&HGrid>().single(w).unwrap();
+        (grid.hex_entities[&hex], grid.terrain.height(&hex).unwrap())
+    };
+
+    app.world_mut()
+        .write_message(EditHexHeight { hex, delta: 0.5 });
+    app.update();
+
+    let cell_y = app.world().get::<Transform>(cell).unwrap().translation.y;
+    assert!((cell_y - (before + 0.5)).abs() < 1e-5, "cell y {cell_y}");
+    let w = app.world_mut();
+    let grid = w.query::<&HGrid>().single(w).unwrap();
+    assert_eq!(grid.terrain.height(&hex), Some(cell_y));
+}
+
 // ── track_player_fov ────────────────────────────────────────────
 
 #[test]
@@ -216,6 +541,17 @@ fn initial_fov_marks_center_ring() {
     );
 }
 
+#[test]
+fn fov_pass_records_reveal_time() {
+    let app = test_app();
+    let store = app.world().resource::<DiagnosticsStore>();
+    let measured = store.get_measurement(&super::REVEAL_TIME).map(|m| m.value);
+    assert!(
+        measured.is_some_and(|ms| ms >= 0.0),
+        "initial FoV pass should record REVEAL_TIME, got {measured:?}"
+    );
+}
+
 #[test]
 fn fov_follows_player_across_hex_boundary() {
     let mut cfg = test_config();
@@ -231,10 +567,18 @@ fn fov_follows_player_across_hex_boundary() {
     };
     assert_eq!(before_fov.len(), 7);
 
-    // Complete any initial transitions so we start clean.
+    // Clear the initial fade-ins (kept alive by the reveal ignition) so we start clean.
     for _ in 0..5 {
         app.update();
     }
+    let w = app.world_mut();
+    let fading: Vec<Entity> = w
+        .query_filtered::<Entity, With<FovTransition>>()
+        .iter(w)
+        .collect();
+    for entity in fading {
+        w.entity_mut(entity).remove::<FovTransition>();
+    }
 
     let target_pos = {
         let w = app.world_mut();
@@ -283,6 +627,76 @@ fn fov_follows_player_across_hex_boundary() {
     }
 }
 
+#[test]
+fn cone_strategy_keeps_hexes_ahead_and_in_range() {
+    let mut settings = test_config().grid;
+    settings.radius = 6;
+    let terrain = HGridLayout::from_settings(&settings);
+    let spacing = settings.point_spacing;
+    let cone = RevealStrategy::Cone {
+        angle: 90.0,
+        range: 20.0,
+    };
+    let ahead = cone.fov_hexes(&terrain, Vec2::ZERO, Vec2::NEG_Y, 1, spacing);
+    assert!(ahead.contains(&Hex::ZERO));
+    assert!(ahead.len() > 1);
+    for hex in &ahead {
+        let offset = terrain.hex_to_world_pos(*hex);
+        assert!(offset.length() <= 20.0);
+        if *hex != Hex::ZERO {
+            assert!(offset.normalize().dot(Vec2::NEG_Y) >= 45f32.to_radians().cos() - 1e-5);
+        }
+    }
+    // Turning around swaps the cone for the opposite one.
+    let behind = cone.fov_hexes(&terrain, Vec2::ZERO, Vec2::Y, 1, spacing);
+    assert_eq!(ahead.intersection(&behind).count(), 1);
+    // Looking straight down counts every direction.
+    let down = cone.fov_hexes(&terrain, Vec2::ZERO, Vec2::ZERO, 1, spacing);
+    assert!(down.is_superset(&ahead) && down.is_superset(&behind));
+    // The radial strategy ignores the view.
+    let ring = RevealStrategy::Radial.fov_hexes(&terrain, Vec2::ZERO, Vec2::Y, 1, spacing);
+    assert_eq!(ring, shapes::hexagon(Hex::ZERO, 1).collect());
+}
+
+#[test]
+fn cone_fov_follows_the_camera_without_moving() {
+    let mut cfg = test_config();
+    cfg.reveal_strategy = RevealStrategy::Cone {
+        angle: 60.0,
+        range: 20.0,
+    };
+    let mut app = test_app_with_config(cfg);
+    let look = |dir: Vec3| {
+        let tf = Transform::default().looking_to(dir, Vec3::Y);
+        (tf, GlobalTransform::from(tf))
+    };
+    let camera = app.world_mut().spawn((Player, look(Vec3::NEG_Z))).id();
+    app.update();
+    let in_fov = |app: &mut App| -> Vec<Vec2> {
+        let w = app.world_mut();
+        let hexes: Vec<Hex> = w
+            .query_filtered::<&HCell, With<InFov>>()
+            .iter(w)
+            .map(|c| c.hex)
+            .collect();
+        let grid = w.query::<&HGrid>().single(w).unwrap();
+        hexes
+            .into_iter()
+            .filter(|h| *h != Hex::ZERO)
+            .map(|h| grid.terrain.hex_to_world_pos(h))
+            .collect()
+    };
+    let north = in_fov(&mut app);
+    assert!(!north.is_empty());
+    assert!(north.iter().all(|p| p.y < 0.0), "{north:?}");
+
+    app.world_mut().entity_mut(camera).insert(look(Vec3::X));
+    app.update();
+    let east = in_fov(&mut app);
+    assert!(!east.is_empty());
+    assert!(east.iter().all(|p| p.x > 0.0), "{east:?}");
+}
+
 #[test]
 fn fov_gap_counts_stable_across_hex_move() {
     // Use radius=4, fov_reach=2 so both origin and hex(1,0) are fully interior
@@ -455,3 +869,1569 @@ fn fov_loss_reverses_transition_direction() {
         "Some FovTransitions should have direction=-1.0 after InFov loss"
     );
 }
+
+// ── apply_theme ────────────────────────────────────────────────
+
+#[test]
+fn theme_change_recolors_shared_and_cloned_materials() {
+    let mut app = test_app();
+    // Let the initial fade-ins finish so in-FoV entities own cloned materials.
+    for _ in 0..5 {
+        app.update();
+    }
+
+    let theme = super::Theme::from(super::ThemeKind::AmberCrt);
+    app.insert_resource(theme.clone());
+    app.update();
+
+    let w = app.world_mut();
+    assert_eq!(w.resource::<ClearColor>().0, theme.clear_color);
+    let hex_original = w.resource::<TerrainMaterials>().hex_original.clone();
+    let assets = w.resource::<Assets<StandardMaterial>>();
+    assert_eq!(
+        assets.get(&hex_original).unwrap().base_color,
+        theme.face.base
+    );
+
+    let mut lit_faces =
+        w.query_filtered::<(&MeshMaterial3d<StandardMaterial>, &ChildOf), With<HexFace>>();
+    let lit: Vec<_> = lit_faces
+        .iter(w)
+        .filter(|(_, p)| w.get::<InFov>(p.parent()).is_some())
+        .map(|(m, _)| m.0.clone())
+        .collect();
+    assert!(!lit.is_empty(), "expected in-FoV hex faces");
+    let assets = w.resource::<Assets<StandardMaterial>>();
+    for handle in lit {
+        assert_eq!(assets.get(&handle).unwrap().base_color, theme.face_fov.base);
+    }
+}
+
+// ── instanced edges ────────────────────────────────────────────
+
+#[test]
+fn edges_share_one_mesh_and_material() {
+    let mut app = test_app();
+    let w = app.world_mut();
+    let edge = w.resource::<TerrainMaterials>().edge.clone();
+    let edge_mesh = w.resource::<TerrainMaterials>().edge_mesh.clone();
+    let mut q = w.query_filtered::<(
+        &Mesh3d,
+        &MeshMaterial3d<EdgeMaterial>,
+        &MeshTag,
+        &super::entities::EdgeRing,
+    ), With<QuadEdge>>();
+    let mut count = 0;
+    for (mesh, mat, tag, ring) in q.iter(w) {
+        assert_eq!(mesh.0, edge_mesh);
+        assert_eq!(mat.0, edge);
+        assert_eq!(tag.0 & 0xffff, ring.0, "tag low bits carry the ring");
+        count += 1;
+    }
+    assert!(count > 0);
+}
+
+#[test]
+fn edge_tag_tracks_fov_highlight() {
+    let mut app = test_app();
+    for _ in 0..5 {
+        app.update();
+    }
+    // Move away so the center ring loses FoV and fades out fully.
+    let far = {
+        let w = app.world_mut();
+        w.query::<&HGrid>()
+            .iter(w)
+            .next()
+            .unwrap()
+            .terrain
+            .hex_to_world_pos(Hex::new(2, 0))
+    };
+    move_player(&mut app, far);
+    for _ in 0..6 {
+        app.update();
+    }
+
+    let w = app.world_mut();
+    let mut q =
+        w.query_filtered::<(&MeshTag, &ChildOf), (With<QuadEdge>, Without<FovTransition>)>();
+    let (mut lit, mut dark) = (0, 0);
+    for (tag, parent) in q.iter(w) {
+        let highlight = edge_tag_highlight(tag);
+        if w.get::<InFov>(parent.parent()).is_some() {
+            assert_eq!(highlight, 1.0);
+            lit += 1;
+        } else {
+            assert_eq!(highlight, 0.0);
+            dark += 1;
+        }
+    }
+    assert!(
+        lit > 0 && dark > 0,
+        "expected both in- and out-of-FoV edges"
+    );
+}
+
+#[test]
+fn glitches_flicker_random_edges_and_restore_them() {
+    let mut cfg = test_config();
+    cfg.glitch = true;
+    cfg.glitch_rate = 50.0;
+    cfg.glitch_frames = 3;
+    let mut app = test_app_with_config(cfg);
+
+    // Let the initial FoV fades settle so only the glitch touches tags and transforms.
+    for _ in 0..5 {
+        app.update();
+    }
+    app.world_mut().resource_mut::<HTerrainConfig>().glitch_rate = 0.0;
+    for _ in 0..5 {
+        app.update();
+    }
+    let calm = |app: &mut App| {
+        let w = app.world_mut();
+        let mut q = w.query_filtered::<(Entity, &Transform, &MeshTag), With<QuadEdge>>();
+        let mut edges: Vec<_> = q
+            .iter(w)
+            .map(|(e, tf, tag)| (e, tf.translation, tag.0))
+            .collect();
+        edges.sort_by_key(|(e, ..)| *e);
+        edges
+    };
+    let before = calm(&mut app);
+    assert!(before.iter().all(|(_, _, tag)| tag >> 24 == 0));
+
+    app.world_mut().resource_mut::<HTerrainConfig>().glitch_rate = 50.0;
+    app.update();
+    {
+        let w = app.world_mut();
+        let mut q = w.query::<(&Glitch, &Transform, &MeshTag)>();
+        let glitching: Vec<_> = q.iter(w).collect();
+        assert_eq!(
+            glitching.len(),
+            5,
+            "50/s at 100 ms frames starts 5 glitches"
+        );
+        for (glitch, tf, tag) in glitching {
+            assert!(edge_tag_glitch(tag) >= 0.6 - 1.0 / 255.0);
+            assert!((tf.translation - glitch.origin).abs().max_element() <= 0.02);
+        }
+    }
+
+    app.world_mut().resource_mut::<HTerrainConfig>().glitch_rate = 0.0;
+    for _ in 0..4 {
+        app.update();
+    }
+    let w = app.world_mut();
+    assert_eq!(w.query::<&Glitch>().iter(w).count(), 0);
+    assert_eq!(
+        calm(&mut app),
+        before,
+        "glitched edges are put back exactly"
+    );
+}
+
+#[test]
+fn disabling_glitch_mid_flicker_restores_the_edges() {
+    let mut cfg = test_config();
+    cfg.glitch = true;
+    cfg.glitch_rate = 0.0;
+    cfg.glitch_frames = 10;
+    let mut app = test_app_with_config(cfg);
+    for _ in 0..10 {
+        app.update();
+    }
+    let calm = |app: &mut App| {
+        let w = app.world_mut();
+        let mut q = w.query_filtered::<(Entity, &Transform, &MeshTag), With<QuadEdge>>();
+        let mut edges: Vec<_> = q
+            .iter(w)
+            .map(|(e, tf, tag)| (e, tf.translation, tag.0))
+            .collect();
+        edges.sort_by_key(|(e, ..)| *e);
+        edges
+    };
+    let before = calm(&mut app);
+
+    app.world_mut().resource_mut::<HTerrainConfig>().glitch_rate = 50.0;
+    app.update();
+    let w = app.world_mut();
+    assert!(w.query::<&Glitch>().iter(w).count() > 0);
+
+    app.world_mut().resource_mut::<HTerrainConfig>().glitch = false;
+    app.update();
+    let w = app.world_mut();
+    assert_eq!(w.query::<&Glitch>().iter(w).count(), 0);
+    assert_eq!(
+        calm(&mut app),
+        before,
+        "switching glitch off puts every edge back at once"
+    );
+}
+
+#[test]
+fn reflective_theme_mirrors_faces_and_attaches_env_map() {
+    let mut app = test_app();
+    let camera = app.world_mut().spawn(crate::drone::Player).id();
+    app.insert_resource(super::Theme::from(super::ThemeKind::SynthwaveMagenta));
+    app.update();
+
+    let w = app.world_mut();
+    let hex_original = w.resource::<TerrainMaterials>().hex_original.clone();
+    let mat = w
+        .resource::<Assets<StandardMaterial>>()
+        .get(&hex_original)
+        .unwrap();
+    assert_eq!(
+        (mat.metallic, mat.perceptual_roughness, mat.reflectance),
+        super::FaceFinish::Reflective.pbr()
+    );
+    assert!(w.get::<EnvironmentMapLight>(camera).is_some());
+
+    app.insert_resource(super::Theme::from(super::ThemeKind::CyanNeon));
+    app.update();
+    assert!(app.world().get::<EnvironmentMapLight>(camera).is_none());
+}
+
+#[test]
+fn hologram_theme_makes_faces_and_gaps_translucent() {
+    let mut app = test_app();
+    let camera = app.world_mut().spawn(crate::drone::Player).id();
+    let mut theme = super::Theme::from(super::ThemeKind::CyanNeon);
+    theme.hologram = true;
+    app.insert_resource(theme);
+    app.update();
+
+    let w = app.world_mut();
+    let handles = {
+        let mats = w.resource::<TerrainMaterials>();
+        [mats.hex_original.clone(), mats.gap_original.clone()]
+    };
+    let face_mats: Vec<_> = w
+        .query_filtered::<&MeshMaterial3d<StandardMaterial>, With<HexFace>>()
+        .iter(w)
+        .map(|m| m.0.clone())
+        .collect();
+    let assets = w.resource::<Assets<StandardMaterial>>();
+    for handle in handles.iter().chain(&face_mats) {
+        let mat = assets.get(handle).unwrap();
+        assert_eq!(mat.alpha_mode, AlphaMode::Add);
+        assert_eq!(mat.base_color.alpha(), test_config().hologram_opacity);
+    }
+    assert!(w.get::<EnvironmentMapLight>(camera).is_some());
+
+    app.world_mut().resource_mut::<super::Theme>().hologram = false;
+    app.update();
+    let w = app.world();
+    let mat = w
+        .resource::<Assets<StandardMaterial>>()
+        .get(&handles[1])
+        .unwrap();
+    assert_eq!(mat.alpha_mode, AlphaMode::Opaque);
+    assert_eq!(mat.base_color.alpha(), 1.0);
+    assert!(w.get::<EnvironmentMapLight>(camera).is_none());
+}
+
+#[test]
+fn low_gfx_theme_paints_unlit_surfaces_without_emissive() {
+    let mut cfg = test_config();
+    cfg.low_gfx = true;
+    let mut app = test_app_with_config(cfg);
+    for _ in 0..5 {
+        app.update();
+    }
+
+    let w = app.world_mut();
+    let mut handles: Vec<_> = w
+        .query_filtered::<&MeshMaterial3d<StandardMaterial>, Or<(With<HexFace>, With<Quad>, With<Tri>)>>()
+        .iter(w)
+        .map(|m| m.0.clone())
+        .collect();
+    {
+        let mats = w.resource::<TerrainMaterials>();
+        handles.extend([mats.hex_highlight.clone(), mats.gap_original.clone()]);
+    }
+    let assets = w.resource::<Assets<StandardMaterial>>();
+    for handle in &handles {
+        let mat = assets.get(handle).unwrap();
+        assert!(mat.unlit);
+        assert_eq!(mat.emissive.to_f32_array()[..3], [0.0; 3]);
+    }
+    let lit_face = assets
+        .get(&w.resource::<TerrainMaterials>().hex_highlight)
+        .unwrap();
+    let standard = super::Theme::default().face_fov.base;
+    assert!(
+        LinearRgba::from(lit_face.base_color).green > LinearRgba::from(standard).green,
+        "the FoV highlight glow is folded into a brighter base color"
+    );
+}
+
+#[test]
+fn selection_outline_follows_sight_and_the_active_hex_in_inspect_mode() {
+    let mut app = test_app();
+    let (face_a, face_b) = {
+        let faces = unlit_cell_faces(&mut app);
+        (faces[0].1, faces[1].1)
+    };
+    let outline_state = |app: &mut App| {
+        let w = app.world_mut();
+        let (outline, tf, vis) = w
+            .query::<(&outline::SelectionOutline, &Transform, &Visibility)>()
+            .single(w)
+            .unwrap();
+        (outline.target, tf.translation, *vis)
+    };
+    assert_eq!(outline_state(&mut app).2, Visibility::Hidden);
+
+    app.world_mut().entity_mut(face_a).insert(InSight);
+    app.update();
+    let (target, pos, vis) = outline_state(&mut app);
+    assert_eq!((target, vis), (Some(face_a), Visibility::Visible));
+    let cell = app.world().get::<ChildOf>(face_a).unwrap().parent();
+    let cell_pos = app
+        .world()
+        .get::<GlobalTransform>(cell)
+        .unwrap()
+        .translation();
+    assert!((pos.xz() - cell_pos.xz()).length() < 1e-4);
+    assert!(pos.y > cell_pos.y, "lifted above the face");
+
+    app.world_mut().entity_mut(face_a).remove::<InSight>();
+    app.update();
+    assert_eq!(outline_state(&mut app).2, Visibility::Hidden);
+
+    // Inspect mode outlines the active hex instead.
+    let cell_b = app.world().get::<ChildOf>(face_b).unwrap().parent();
+    app.world_mut().resource_mut::<ActiveHex>().cell = Some(cell_b);
+    app.world_mut()
+        .resource_mut::<NextState<GameState>>()
+        .set(GameState::Inspecting);
+    app.update();
+    app.update();
+    let (target, _, vis) = outline_state(&mut app);
+    assert_eq!((target, vis), (Some(face_b), Visibility::Visible));
+
+    // A picked hex takes over from the active hex until inspect mode ends.
+    let hex_a = app.world().get::<HCell>(cell).unwrap().hex;
+    app.world_mut().write_message(HexPicked(hex_a));
+    app.update();
+    assert_eq!(outline_state(&mut app).0, Some(face_a));
+    assert_eq!(app.world().resource::<SelectedHex>().0, Some(hex_a));
+    app.world_mut()
+        .resource_mut::<NextState<GameState>>()
+        .set(GameState::Running);
+    app.update();
+    assert_eq!(app.world().resource::<SelectedHex>().0, None);
+}
+
+#[test]
+fn pick_hex_hits_the_face_under_the_ray() {
+    let terrain = super::h_grid_layout::HGridLayout::from_settings(&test_config().grid);
+    let down = |xz: Vec2| Ray3d::new(Vec3::new(xz.x, 100.0, xz.y), Dir3::NEG_Y);
+    for hex in shapes::hexagon(Hex::ZERO, 2) {
+        let center = terrain.hex_to_world_pos(hex);
+        let (picked, t) = pick_hex(&terrain, down(center)).unwrap();
+        assert_eq!(picked, hex);
+        assert!((100.0 - t - terrain.height(&hex).unwrap()).abs() < 1e-3);
+    }
+
+    // Between two faces the ray falls through the gap; level rays hit nothing.
+    let neighbor = Hex::ZERO.all_neighbors()[0];
+    let gap = (terrain.hex_to_world_pos(Hex::ZERO) + terrain.hex_to_world_pos(neighbor)) / 2.0;
+    assert_eq!(pick_hex(&terrain, down(gap)), None);
+    let level = Ray3d::new(Vec3::new(-50.0, 5.0, 0.0), Dir3::X);
+    assert_eq!(pick_hex(&terrain, level), None);
+
+    // A slanted ray from above a face hits that face before anything behind it.
+    let top = terrain.hex_to_world_pos(Hex::ZERO);
+    let height = terrain.height(&Hex::ZERO).unwrap();
+    let origin = Vec3::new(top.x, height + 30.0, top.y);
+    let slanted = Ray3d::new(origin, Dir3::new(Vec3::new(0.01, -1.0, 0.0)).unwrap());
+    assert_eq!(pick_hex(&terrain, slanted).map(|(h, _)| h), Some(Hex::ZERO));
+}
+
+#[test]
+fn outline_ring_is_a_bright_band_inside_a_dark_halo() {
+    let corners = std::array::from_fn(|i| {
+        let a = i as f32 * std::f32::consts::FRAC_PI_3;
+        Vec2::new(a.cos(), a.sin())
+    });
+    let mesh = outline::outline_ring_mesh(corners, 2.0, 0.1);
+    let Some(bevy::mesh::VertexAttributeValues::Float32x3(pos)) =
+        mesh.attribute(Mesh::ATTRIBUTE_POSITION)
+    else {
+        panic!("positions");
+    };
+    let Some(bevy::mesh::VertexAttributeValues::Float32x4(colors)) =
+        mesh.attribute(Mesh::ATTRIBUTE_COLOR)
+    else {
+        panic!("colors");
+    };
+    assert_eq!(mesh.indices().unwrap().len(), 2 * 6 * 6);
+    for (p, c) in pos.iter().zip(colors) {
+        let r = Vec2::new(p[0], p[2]).length();
+        assert!((2.0 - 1e-4..=2.2 + 1e-4).contains(&r));
+        if r > 2.15 {
+            assert_eq!(c[..3], [0.0; 3], "outermost ring is the dark halo");
+        } else if r < 2.05 {
+            assert_eq!(c[..3], [1.0; 3], "innermost ring is the bright line");
+        }
+    }
+}
+
+// ── reveal particles ───────────────────────────────────────────
+
+#[test]
+fn reveal_burst_spawns_and_expires() {
+    let mut app = test_app();
+    let w = app.world_mut();
+    let revealed = w
+        .query_filtered::<(), (With<HCell>, With<InFov>)>()
+        .iter(w)
+        .count();
+    let particles = w.query::<&RevealParticle>().iter(w).count();
+    assert!(particles > 0, "initial FoV reveal should emit particles");
+    assert!(particles <= revealed * test_config().reveal_particle_count as usize);
+
+    // Lifetime 0.8s at 100ms per frame.
+    for _ in 0..9 {
+        app.update();
+    }
+    let w = app.world_mut();
+    assert_eq!(w.query::<&RevealParticle>().iter(w).count(), 0);
+}
+
+/// `(ring, scale-in progress)` for every Revealed cell's hex face (1.0 = full radius).
+fn revealed_face_progress(app: &mut App) -> Vec<(u32, f32)> {
+    let w = app.world_mut();
+    let mut faces = w.query_filtered::<(&Transform, &ChildOf), With<HexFace>>();
+    let scales: Vec<(Hex, f32)> = faces
+        .iter(w)
+        .filter(|(_, p)| w.get::<Revealed>(p.parent()).is_some())
+        .map(|(tf, p)| (w.get::<HCell>(p.parent()).unwrap().hex, tf.scale.x))
+        .collect();
+    let grid = w.query::<&HGrid>().single(w).unwrap();
+    scales
+        .into_iter()
+        .map(|(hex, s)| (hex.ulength(), s / grid.terrain.radius(&hex).unwrap()))
+        .collect()
+}
+
+#[test]
+fn revealed_faces_scale_in_staggered_by_ring() {
+    let mut app = test_app();
+    app.update();
+    let progress = revealed_face_progress(&mut app);
+    assert_eq!(progress.len(), 7, "initial FoV cells should be Revealed");
+    let w = app.world_mut();
+    let mut faces = w.query_filtered::<(&Transform, &ChildOf), With<HexFace>>();
+    assert!(
+        faces
+            .iter(w)
+            .filter(|(_, p)| w.get::<Revealed>(p.parent()).is_none())
+            .all(|(tf, _)| tf.scale == Vec3::ZERO),
+        "unrevealed faces should stay hidden until their reveal"
+    );
+    let center = progress.iter().find(|(ring, _)| *ring == 0).unwrap().1;
+    assert!(center > 0.0, "center face should have started growing");
+    for &(ring, p) in &progress {
+        if ring > 0 {
+            assert!(p < center, "ring {ring} should lag the center");
+        }
+    }
+
+    for _ in 0..10 {
+        app.update();
+    }
+    let w = app.world_mut();
+    assert_eq!(w.query::<&RevealAnim>().iter(w).count(), 0);
+    for (_, p) in revealed_face_progress(&mut app) {
+        assert!((p - 1.0).abs() < 1e-5, "faces should settle at full radius");
+    }
+}
+
+/// Emissive brightness (`r + g + b`) of each revealed face.
+fn revealed_face_emissive(app: &mut App) -> bevy::platform::collections::HashMap<Hex, f32> {
+    let w = app.world_mut();
+    let mut faces =
+        w.query_filtered::<(&MeshMaterial3d<StandardMaterial>, &ChildOf), With<HexFace>>();
+    let handles: Vec<(Hex, Handle<StandardMaterial>)> = faces
+        .iter(w)
+        .filter(|(_, p)| w.get::<Revealed>(p.parent()).is_some())
+        .map(|(m, p)| (w.get::<HCell>(p.parent()).unwrap().hex, m.0.clone()))
+        .collect();
+    let standard = w.resource::<Assets<StandardMaterial>>();
+    handles
+        .into_iter()
+        .map(|(hex, h)| {
+            let e = standard.get(&h).unwrap().emissive;
+            (hex, e.red + e.green + e.blue)
+        })
+        .collect()
+}
+
+#[test]
+fn revealed_faces_ignite_with_the_wavefront() {
+    let mut app = test_app();
+    app.update();
+    let first = revealed_face_emissive(&mut app);
+    let center = first[&Hex::ZERO];
+    for (hex, &e) in &first {
+        if *hex != Hex::ZERO {
+            assert!(e < center, "{hex:?} should ignite after the center");
+        }
+    }
+
+    for _ in 0..12 {
+        app.update();
+    }
+    let w = app.world_mut();
+    assert_eq!(
+        w.query_filtered::<(), (With<HexFace>, With<FovTransition>)>()
+            .iter(w)
+            .count(),
+        0,
+        "transitions should end once the faces are lit"
+    );
+    for (hex, e) in revealed_face_emissive(&mut app) {
+        assert!(
+            e > first[&hex],
+            "{hex:?} should end brighter than it started"
+        );
+    }
+}
+
+#[test]
+fn reveal_message_written_once_per_cell() {
+    let mut app = test_app();
+    let cfg = app.world().resource::<HTerrainConfig>().clone();
+    let msgs = app.world().resource::<Messages<HexRevealed>>();
+    let mut cursor = msgs.get_cursor();
+    let first: Vec<HexRevealed> = cursor.read(msgs).copied().collect();
+    assert_eq!(first.len(), 7, "initial FoV cells should be announced");
+    for msg in &first {
+        let hex = app.world().get::<HCell>(msg.cell).unwrap().hex;
+        assert_eq!(msg.delay, hex.ulength() as f32 * cfg.reveal_stagger_secs);
+    }
+
+    // Moving one hex east reveals only the new ring-2 cells on that side.
+    move_player(&mut app, Vec2::new(8.0, 0.0));
+    app.update();
+    let msgs = app.world().resource::<Messages<HexRevealed>>();
+    let next: Vec<Hex> = cursor
+        .read(msgs)
+        .map(|m| app.world().get::<HCell>(m.cell).unwrap().hex)
+        .collect();
+    assert!(!next.is_empty(), "newly visible cells should be announced");
+    assert!(
+        next.iter().all(|h| h.ulength() == 2),
+        "already revealed cells must not be announced again"
+    );
+}
+
+#[test]
+fn glow_level_scales_shared_emissive_and_edges() {
+    let mut app = test_app();
+    app.insert_resource(GlowLevel(2.0));
+    app.update();
+
+    let theme = app.world().resource::<super::Theme>().clone();
+    let mats = app.world().resource::<TerrainMaterials>();
+    let standard = app.world().resource::<Assets<StandardMaterial>>();
+    let highlight = standard.get(&mats.hex_highlight).unwrap();
+    assert_eq!(highlight.emissive, theme.face_fov.emissive * 2.0);
+    let edge = app
+        .world()
+        .resource::<Assets<EdgeMaterial>>()
+        .get(&mats.edge)
+        .unwrap();
+    assert_eq!(edge.color, LinearRgba::from(theme.edge.base) * 2.0);
+}
+
+// ── visited cells ──────────────────────────────────────────────
+
+#[test]
+fn visited_faces_rest_on_visited_material() {
+    let mut app = test_app();
+    let (origin, far, far_pos) = {
+        let w = app.world_mut();
+        let grid = w.query::<&HGrid>().single(w).unwrap();
+        let far = Hex::new(2, 0);
+        (
+            grid.hex_entities[&Hex::ZERO],
+            grid.hex_entities[&Hex::new(-2, 0)],
+            grid.terrain.hex_to_world_pos(far),
+        )
+    };
+    assert!(app.world().get::<Visited>(origin).is_some());
+    assert!(app.world().get::<Visited>(far).is_none());
+
+    // Fly two hexes east: the origin leaves the FoV and fades to the visited tint.
+    move_player(&mut app, far_pos);
+    for _ in 0..10 {
+        app.update();
+    }
+    let theme = app.world().resource::<super::Theme>().clone();
+    let emissive = |cell| face_emissive(&app, face_of(&app, cell));
+    assert_eq!(emissive(origin), theme.face_visited.emissive);
+    assert_eq!(emissive(far), theme.face.emissive);
+}
+
+// ── fog of war ─────────────────────────────────────────────────
+
+/// Linear base color of `face`'s current material.
+fn face_base(app: &App, face: Entity) -> LinearRgba {
+    let handle = &app
+        .world()
+        .get::<MeshMaterial3d<StandardMaterial>>(face)
+        .unwrap()
+        .0;
+    let assets = app.world().resource::<Assets<StandardMaterial>>();
+    LinearRgba::from(assets.get(handle).unwrap().base_color)
+}
+
+#[test]
+fn fog_of_war_dims_unrevealed_faces_and_fades_them_in() {
+    let cfg = HTerrainConfig {
+        fog_of_war: true,
+        ..test_config()
+    };
+    let dim = cfg.fog_of_war_brightness;
+    let mut app = test_app_with_config(cfg);
+    let (west, far_west, far, far_pos) = {
+        let w = app.world_mut();
+        let grid = w.query::<&HGrid>().single(w).unwrap();
+        let far = Hex::new(2, 0);
+        (
+            grid.hex_entities[&Hex::new(-1, 0)],
+            grid.hex_entities[&Hex::new(-2, 0)],
+            grid.hex_entities[&far],
+            grid.terrain.hex_to_world_pos(far),
+        )
+    };
+    let theme = app.world().resource::<super::Theme>().clone();
+    let face = LinearRgba::from(theme.face.base);
+    let fogged = (face * dim).with_alpha(face.alpha);
+    assert!(app.world().get::<Revealed>(far).is_none());
+    assert_eq!(face_base(&app, face_of(&app, far)), fogged);
+
+    // First reveal fades from the fogged color toward the highlight.
+    move_player(&mut app, far_pos);
+    app.update();
+    let far_face = face_of(&app, far);
+    let t = app.world().get::<FovTransition>(far_face).unwrap().progress;
+    assert!(t > 0.0 && t < 1.0);
+    let hi = LinearRgba::from(theme.face_fov.base);
+    let got = face_base(&app, far_face);
+    let want = fogged.mix(&hi, t);
+    assert!((got.red - want.red).abs() < 1e-4 && (got.green - want.green).abs() < 1e-4);
+
+    for _ in 0..10 {
+        app.update();
+    }
+    // Revealed cells that left the FoV rest at full brightness; never-seen cells stay fogged.
+    assert_eq!(face_base(&app, face_of(&app, west)), face);
+    assert_eq!(face_base(&app, face_of(&app, far_west)), fogged);
+}
+
+#[test]
+fn scanner_pulse_reveals_cells_as_the_wavefront_passes() {
+    let cfg = HTerrainConfig {
+        fog_of_war: true,
+        reveal_mode: RevealMode::Scanner,
+        scanner_speed: 40.0,
+        scanner_range: 20.0,
+        scanner_cooldown_secs: 1.0,
+        ..test_config()
+    };
+    let mut app = test_app_with_config(cfg);
+    let revealed = |app: &mut App| {
+        let w = app.world_mut();
+        w.query_filtered::<&HCell, With<Revealed>>()
+            .iter(w)
+            .map(|c| c.hex)
+            .collect::<Vec<_>>()
+    };
+    let press_f = |app: &mut App| {
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::KeyF);
+        app.update();
+        let mut keys = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        keys.release(KeyCode::KeyF);
+        keys.clear();
+    };
+    let pulses = |app: &mut App| {
+        let w = app.world_mut();
+        w.query::<&ScannerPulse>().iter(w).count()
+    };
+    // Entering the FoV no longer reveals.
+    assert!(revealed(&mut app).is_empty());
+
+    // 0.1 s frames at 40 units/s: the first frame reaches only the center hex.
+    press_f(&mut app);
+    assert_eq!(pulses(&mut app), 1);
+    assert_eq!(revealed(&mut app), [Hex::ZERO]);
+
+    // Still cooling down: no second pulse.
+    press_f(&mut app);
+    assert_eq!(pulses(&mut app), 1);
+    let after_two = revealed(&mut app).len();
+    assert!(after_two > 1 && after_two < 19, "{after_two}");
+
+    for _ in 0..4 {
+        app.update();
+    }
+    assert_eq!(revealed(&mut app).len(), 19);
+    assert_eq!(pulses(&mut app), 0, "pulse despawns at full range");
+
+    // A ring-2 hex outside the FoV lost its fog.
+    let far = {
+        let w = app.world_mut();
+        w.query::<&HGrid>().single(w).unwrap().hex_entities[&Hex::new(2, 0)]
+    };
+    assert!(app.world().get::<InFov>(far).is_none());
+    let face = LinearRgba::from(app.world().resource::<super::Theme>().face.base);
+    assert_eq!(face_base(&app, face_of(&app, far)), face);
+}
+
+// ── active hex ─────────────────────────────────────────────────
+
+/// Hex face entity of `cell`.
+fn face_of(app: &App, cell: Entity) -> Entity {
+    let children = app.world().get::<Children>(cell).unwrap();
+    children
+        .iter()
+        .find(|&c| app.world().get::<HexFace>(c).is_some())
+        .unwrap()
+}
+
+#[test]
+fn active_hex_pulses_and_resets_on_leave() {
+    let mut app = test_app();
+    let origin = app.world().resource::<ActiveHex>().cell.unwrap();
+    assert_eq!(app.world().resource::<ActiveHex>().current, Some(Hex::ZERO));
+    let base = app.world().resource::<super::Theme>().face_fov.emissive;
+
+    // Let the FoV fade-in finish, then sample the pulse over a few frames.
+    let face = face_of(&app, origin);
+    let mut samples = Vec::new();
+    for _ in 0..12 {
+        app.update();
+        samples.push(face_emissive(&app, face).green);
+    }
+    let (lo, hi) = samples[6..]
+        .iter()
+        .fold((f32::MAX, f32::MIN), |(lo, hi), &g| (lo.min(g), hi.max(g)));
+    assert!(
+        lo >= base.green - 1e-6,
+        "pulse never dims below the highlight"
+    );
+    assert!(hi > lo + 1e-3, "active face emissive should pulse");
+
+    let next = {
+        let w = app.world_mut();
+        let grid = w.query::<&HGrid>().single(w).unwrap();
+        grid.terrain.hex_to_world_pos(Hex::new(1, 0))
+    };
+    move_player(&mut app, next);
+    app.update();
+    assert_eq!(
+        app.world().resource::<ActiveHex>().current,
+        Some(Hex::new(1, 0))
+    );
+    assert_eq!(
+        face_emissive(&app, face),
+        base,
+        "left face returns to the plain highlight"
+    );
+}
+
+#[test]
+fn active_hex_pulses_a_private_copy_of_a_shared_material() {
+    let mut app = test_app();
+    for _ in 0..8 {
+        app.update();
+    }
+    let origin = app.world().resource::<ActiveHex>().cell.unwrap();
+    let neighbour = {
+        let w = app.world_mut();
+        let grid = w.query::<&HGrid>().single(w).unwrap();
+        grid.hex_entities[&Hex::new(0, 1)]
+    };
+    let (face, other) = (face_of(&app, origin), face_of(&app, neighbour));
+    // Put both faces back on the shared highlight, as a finished aim restore would.
+    let shared = app
+        .world()
+        .resource::<TerrainMaterials>()
+        .hex_highlight
+        .clone();
+    for f in [face, other] {
+        app.world_mut()
+            .entity_mut(f)
+            .insert(MeshMaterial3d(shared.clone()));
+    }
+    app.update();
+    // Leave the current active hex and come back, so the pulse picks up the shared face.
+    let east = {
+        let w = app.world_mut();
+        let grid = w.query::<&HGrid>().single(w).unwrap();
+        grid.terrain.hex_to_world_pos(Hex::new(1, 0))
+    };
+    move_player(&mut app, east);
+    app.update();
+    move_player(&mut app, Vec2::ZERO);
+
+    let base = face_emissive(&app, other);
+    let mut samples = Vec::new();
+    for _ in 0..6 {
+        app.update();
+        samples.push(face_emissive(&app, face).green);
+        assert_eq!(
+            face_emissive(&app, other),
+            base,
+            "only the active face pulses"
+        );
+    }
+    let handle = |app: &App, f| {
+        app.world()
+            .get::<MeshMaterial3d<StandardMaterial>>(f)
+            .unwrap()
+            .0
+            .clone()
+    };
+    assert_ne!(
+        handle(&app, face),
+        shared,
+        "active face pulses its own copy"
+    );
+    assert!(samples.windows(2).any(|w| w[0] != w[1]));
+
+    move_player(&mut app, east);
+    app.update();
+    assert_eq!(
+        handle(&app, face),
+        shared,
+        "shared material restored on leave"
+    );
+}
+
+// ── face grid ──────────────────────────────────────────────────
+
+#[test]
+fn every_face_gets_a_radius_tagged_grid_overlay() {
+    let mut app = test_app();
+    let w = app.world_mut();
+    let grid = w.query::<&HGrid>().single(w).unwrap();
+    let expected: Vec<MeshTag> = shapes::hexagon(Hex::ZERO, 2)
+        .map(|hex| face_grid_tag(grid.terrain.radius(&hex).unwrap()))
+        .collect();
+
+    let mut overlays = w.query_filtered::<(&MeshTag, &ChildOf), With<FaceGrid>>();
+    let mut tags = Vec::new();
+    for (tag, parent) in overlays.iter(w) {
+        assert!(w.get::<HexFace>(parent.parent()).is_some());
+        tags.push(tag.0);
+    }
+    let mut expected: Vec<u32> = expected.into_iter().map(|t| t.0).collect();
+    tags.sort_unstable();
+    expected.sort_unstable();
+    assert_eq!(tags, expected);
+
+    let mut app = test_app_with_config(HTerrainConfig {
+        face_grid: false,
+        ..test_config()
+    });
+    let w = app.world_mut();
+    assert_eq!(w.query::<&FaceGrid>().iter(w).count(), 0);
+}
+
+// ── sun ────────────────────────────────────────────────────────
+
+#[test]
+fn sun_spawns_only_when_enabled() {
+    let mut app = test_app();
+    let w = app.world_mut();
+    assert_eq!(w.query::<&sun::Sun>().iter(w).count(), 0);
+
+    let cfg = HTerrainConfig {
+        sun: true,
+        ..test_config()
+    };
+    let mut app = test_app_with_config(cfg.clone());
+    let w = app.world_mut();
+    let (light, tf) = w
+        .query_filtered::<(&DirectionalLight, &Transform), With<sun::Sun>>()
+        .single(w)
+        .unwrap();
+    assert!(light.shadows_enabled);
+    assert_eq!(light.illuminance, cfg.sun_illuminance);
+    // Shines downward at the configured elevation.
+    let dir = tf.forward();
+    assert!((dir.y + cfg.sun_elevation.to_radians().sin()).abs() < 1e-5);
+}
+
+// ── height gradient ────────────────────────────────────────────
+
+/// `(cell, face)` pairs for cells outside the FoV.
+fn unlit_cell_faces(app: &mut App) -> Vec<(Entity, Entity)> {
+    let w = app.world_mut();
+    let mut cells = w.query_filtered::<(Entity, &Children), (With<HCell>, Without<InFov>)>();
+    let cells: Vec<(Entity, Vec<Entity>)> = cells
+        .iter(w)
+        .map(|(e, c)| (e, c.iter().collect()))
+        .collect();
+    cells
+        .into_iter()
+        .filter_map(|(cell, children)| {
+            let face = children
+                .into_iter()
+                .find(|&c| w.get::<HexFace>(c).is_some())?;
+            Some((cell, face))
+        })
+        .collect()
+}
+
+/// Emissive of `face`'s current material.
+fn face_emissive(app: &App, face: Entity) -> LinearRgba {
+    let handle = &app
+        .world()
+        .get::<MeshMaterial3d<StandardMaterial>>(face)
+        .unwrap()
+        .0;
+    let assets = app.world().resource::<Assets<StandardMaterial>>();
+    assets.get(handle).unwrap().emissive
+}
+
+#[test]
+fn height_gradient_tints_faces_and_follows_height() {
+    let gradient = HeightGradient::glacier();
+    let cfg = HTerrainConfig {
+        face_height_gradient: Some(gradient.clone()),
+        ..test_config()
+    };
+    let max_height = cfg.grid.max_height;
+    let mut app = test_app_with_config(cfg);
+    let theme = app.world().resource::<super::Theme>().clone();
+
+    let pairs = unlit_cell_faces(&mut app);
+    assert!(!pairs.is_empty());
+    let mut handles = std::collections::HashSet::new();
+    for &(cell, face) in &pairs {
+        let y = app.world().get::<Transform>(cell).unwrap().translation.y;
+        let glow = gradient.sample(y / max_height);
+        assert_eq!(app.world().get::<HeightGlow>(face).unwrap().0, glow);
+        assert_eq!(
+            face_emissive(&app, face),
+            theme.face.emissive + glow.with_alpha(0.0)
+        );
+        let mat = app.world().get::<MeshMaterial3d<StandardMaterial>>(face);
+        handles.insert(mat.unwrap().0.id());
+    }
+    assert_eq!(handles.len(), pairs.len(), "each face owns its material");
+
+    // Lowering a cell to the valley floor re-derives its glow.
+    let (cell, face) = pairs[0];
+    app.world_mut()
+        .get_mut::<Transform>(cell)
+        .unwrap()
+        .translation
+        .y = 0.0;
+    app.update();
+    let valley = gradient.sample(0.0);
+    assert_eq!(app.world().get::<HeightGlow>(face).unwrap().0, valley);
+    assert_eq!(
+        face_emissive(&app, face),
+        theme.face.emissive + valley.with_alpha(0.0)
+    );
+}
+
+// ── height heatmap ─────────────────────────────────────────────
+
+#[test]
+fn heatmap_overlays_faces_by_height_and_leaves_face_materials_alone() {
+    let mut app = test_app();
+    let face_mats = |app: &mut App| {
+        let w = app.world_mut();
+        let mut mats: Vec<_> = w
+            .query_filtered::<(Entity, &MeshMaterial3d<StandardMaterial>), With<HexFace>>()
+            .iter(w)
+            .map(|(e, m)| (e, m.0.id()))
+            .collect();
+        mats.sort();
+        mats
+    };
+    let before = face_mats(&mut app);
+
+    app.world_mut().resource_mut::<HeatmapMode>().0 = true;
+    app.update();
+    let max_height = test_config().grid.max_height;
+    let bands = app.world().resource::<HeatmapMaterials>().bands.clone();
+    let pairs = unlit_cell_faces(&mut app);
+    let band_of = |app: &mut App, face: Entity| {
+        let w = app.world_mut();
+        let children = w.get::<Children>(face).unwrap().to_vec();
+        let overlay = children
+            .into_iter()
+            .find(|&c| w.get::<HeatmapOverlay>(c).is_some())
+            .expect("face should have a heatmap overlay");
+        w.get::<MeshMaterial3d<StandardMaterial>>(overlay)
+            .unwrap()
+            .0
+            .id()
+    };
+    for &(cell, face) in &pairs {
+        let y = app.world().get::<Transform>(cell).unwrap().translation.y;
+        let expected = bands[heatmap_band(y / max_height)].id();
+        assert_eq!(band_of(&mut app, face), expected);
+    }
+    let w = app.world_mut();
+    assert_eq!(w.query::<&HeatmapOverlay>().iter(w).count(), 19);
+
+    // Height changes re-band the overlay.
+    let (cell, face) = pairs[0];
+    app.world_mut()
+        .get_mut::<Transform>(cell)
+        .unwrap()
+        .translation
+        .y = max_height;
+    app.update();
+    assert_eq!(band_of(&mut app, face), bands[bands.len() - 1].id());
+
+    app.world_mut().resource_mut::<HeatmapMode>().0 = false;
+    app.update();
+    let w = app.world_mut();
+    assert_eq!(w.query::<&HeatmapOverlay>().iter(w).count(), 0);
+    assert_eq!(face_mats(&mut app), before);
+}
+
+#[test]
+fn heatmap_overlays_return_after_regenerate() {
+    let mut app = test_app();
+    app.world_mut().resource_mut::<HeatmapMode>().0 = true;
+    app.update();
+
+    app.world_mut().resource_mut::<HTerrainConfig>().grid.radius = 3;
+    app.world_mut().write_message(RegenerateTerrain);
+    app.update();
+
+    let w = app.world_mut();
+    let faces = w.query::<&HexFace>().iter(w).count();
+    assert_eq!(faces, 37);
+    let parents: Vec<Entity> = w
+        .query_filtered::<&ChildOf, With<HeatmapOverlay>>()
+        .iter(w)
+        .map(ChildOf::parent)
+        .collect();
+    assert_eq!(parents.len(), faces);
+    assert!(parents.iter().all(|&p| w.get::<HexFace>(p).is_some()));
+}
+
+#[test]
+fn faces_share_material_without_height_gradient() {
+    let mut app = test_app();
+    let shared = app.world().resource::<TerrainMaterials>().hex_original.id();
+    for (_, face) in unlit_cell_faces(&mut app) {
+        assert!(app.world().get::<HeightGlow>(face).is_none());
+        let mat = app.world().get::<MeshMaterial3d<StandardMaterial>>(face);
+        assert_eq!(mat.unwrap().0.id(), shared);
+    }
+}
+
+// ── terrain stats ──────────────────────────────────────────────
+
+#[test]
+fn terrain_stats_bins_heights_and_counts_cliffs() {
+    let max = 10.0;
+    let heights = [
+        (Hex::ZERO, 0.0),
+        (Hex::new(1, 0), 0.5),
+        (Hex::new(0, 1), 9.9),
+        (Hex::new(5, 5), 5.0),
+    ]
+    .into_iter()
+    .collect();
+    let stats = TerrainStats::from_heights(&heights, max);
+
+    assert_eq!(stats.hex_count, 4);
+    assert_eq!((stats.min_height, stats.max_height), (0.0, 9.9));
+    assert!((stats.mean_height - 3.85).abs() < 1e-5);
+    assert_eq!(stats.histogram.len(), HISTOGRAM_BINS);
+    assert_eq!(stats.histogram[0], 2);
+    assert_eq!(stats.histogram[HISTOGRAM_BINS - 1], 1);
+    assert_eq!(stats.bands, [2, 1, 1]);
+    // (0,0)–(0,1) and (1,0)–(0,1) differ by more than 2.0 (CLIFF_FRACTION × max); (5,5) is isolated.
+    assert_eq!(stats.cliffs, 2);
+}
+
+// ── grid floor ─────────────────────────────────────────────────
+
+#[test]
+fn grid_floor_follows_player() {
+    let mut app = test_app();
+    move_player(&mut app, Vec2::new(7.5, -3.0));
+    app.update();
+
+    let w = app.world_mut();
+    let tf = w
+        .query_filtered::<&Transform, With<GridFloor>>()
+        .single(w)
+        .unwrap();
+    assert_eq!(tf.translation, Vec3::new(7.5, 0.0, -3.0));
+}
+
+#[test]
+fn grid_floor_can_be_disabled() {
+    let mut cfg = test_config();
+    cfg.grid_floor = false;
+    let mut app = test_app_with_config(cfg);
+    app.update();
+
+    let w = app.world_mut();
+    assert_eq!(w.query::<&GridFloor>().iter(w).count(), 0);
+}
+
+#[test]
+fn lens_flare_fades_with_view_angle() {
+    let forward = Vec3::NEG_Z;
+    assert_eq!(view_fade(forward, Vec3::new(0.0, 0.0, -10.0), 0.6), 1.0);
+    let half = view_fade(forward, Quat::from_rotation_y(0.3) * Vec3::NEG_Z, 0.6);
+    assert!((half - 0.5).abs() < 1e-4);
+    assert_eq!(view_fade(forward, Vec3::X, 0.6), 0.0);
+    assert_eq!(view_fade(forward, Vec3::Z, 0.6), 0.0);
+}
+
+#[test]
+fn regenerate_rebuilds_the_grid_from_new_settings() {
+    let mut app = test_app();
+    let old_grid = {
+        let w = app.world_mut();
+        w.query_filtered::<Entity, With<HGrid>>().single(w).unwrap()
+    };
+
+    {
+        let mut cfg = app.world_mut().resource_mut::<HTerrainConfig>();
+        cfg.grid.radius = 3;
+        cfg.grid.height_noise_seed += 1;
+    }
+    app.world_mut().write_message(RegenerateTerrain);
+    app.update();
+
+    let w = app.world_mut();
+    let grids: Vec<Entity> = w.query_filtered::<Entity, With<HGrid>>().iter(w).collect();
+    assert_eq!(grids.len(), 1);
+    assert_ne!(grids[0], old_grid);
+    assert_eq!(w.query::<&HCell>().iter(w).count(), 37);
+    assert_eq!(w.query::<&HexFace>().iter(w).count(), 37);
+
+    // The FoV and active hex are tracked again on the new cells.
+    let reach = w.resource::<HTerrainConfig>().grid.fov_reach;
+    let in_fov = w
+        .query_filtered::<(), (With<HCell>, With<InFov>)>()
+        .iter(w)
+        .count();
+    assert_eq!(in_fov, shapes::hexagon(Hex::ZERO, reach).count());
+    let active = w.resource::<ActiveHex>().cell.unwrap();
+    assert!(w.get::<HCell>(active).is_some());
+
+    let expected = w
+        .query::<&HGrid>()
+        .single(w)
+        .unwrap()
+        .terrain
+        .interpolate_height(Vec2::ZERO);
+    assert_eq!(w.resource::<GroundLevel>().0, Some(expected));
+}
+
+#[test]
+fn minimap_frame_maps_north_up_and_round_trips() {
+    let frame = MinimapFrame {
+        origin: Vec2::new(100.0, 50.0),
+        size: 200.0,
+        extent: 50.0,
+    };
+    assert_eq!(frame.map_pos(Vec2::ZERO), Vec2::new(200.0, 150.0));
+    // North (-Z) is up, east (+X) is right, and the extent reaches the map edge.
+    assert_eq!(frame.map_pos(Vec2::new(0.0, -50.0)), Vec2::new(200.0, 50.0));
+    assert_eq!(frame.map_pos(Vec2::new(50.0, 0.0)), Vec2::new(300.0, 150.0));
+
+    let xz = Vec2::new(-12.5, 31.0);
+    assert!((frame.world_pos(frame.map_pos(xz)) - xz).length() < 1e-4);
+}
+
+#[test]
+fn hex_tooltip_lists_coordinate_height_radius_and_entity() {
+    let mut app = test_app();
+    let w = app.world_mut();
+    let (cell, hcell, tf) = w
+        .query::<(Entity, &HCell, &Transform)>()
+        .iter(w)
+        .find(|(_, c, _)| c.hex == Hex::new(1, -1))
+        .map(|(e, c, tf)| (e, c.hex, tf.translation.y))
+        .unwrap();
+    let text = hex_tooltip_text(hcell, tf, 1.25, cell);
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(lines[0], "hex +1,-1");
+    assert_eq!(lines[1], format!("height {tf:.2}"));
+    assert_eq!(lines[2], "radius 1.25");
+    assert_eq!(lines[3], format!("entity {cell}"));
+}
+
+#[test]
+fn hex_label_text_per_mode() {
+    let hex = Hex::new(2, -3);
+    let text = |mode| hex_label_text(mode, hex, 4.567, 0.8);
+    assert_eq!(text(HexLabelMode::Off), None);
+    assert_eq!(text(HexLabelMode::Axial).as_deref(), Some("2,-3"));
+    assert_eq!(text(HexLabelMode::Cube).as_deref(), Some("2,-3,1"));
+    assert_eq!(text(HexLabelMode::Height).as_deref(), Some("4.6"));
+    assert_eq!(text(HexLabelMode::Radius).as_deref(), Some("0.80"));
+    // Offset coordinates keep the column and shift the row by half the column.
+    assert_eq!(text(HexLabelMode::Offset).as_deref(), Some("2,-2"));
+}
+
+#[test]
+fn target_classifies_cliffs_and_landmark_peaks() {
+    // max_height 10: cliffs need a neighbor step above 2, landmarks sit above 6.67.
+    let flat = |_: Hex| Some(5.0);
+    assert_eq!(
+        classify_target(Hex::ZERO, 5.5, 10.0, flat),
+        TargetKind::Plain
+    );
+    assert_eq!(
+        classify_target(Hex::ZERO, 7.5, 10.0, flat),
+        TargetKind::Landmark
+    );
+    assert_eq!(
+        classify_target(Hex::ZERO, 2.5, 10.0, flat),
+        TargetKind::Cliff
+    );
+    // A high hex with a taller neighbor is not a peak, but the drop still makes a cliff.
+    let ridge = |h: Hex| Some(if h == Hex::new(1, 0) { 9.0 } else { 4.0 });
+    assert_eq!(
+        classify_target(Hex::ZERO, 8.0, 10.0, ridge),
+        TargetKind::Cliff
+    );
+    // Missing neighbors (grid edge) are ignored.
+    assert_eq!(
+        classify_target(Hex::ZERO, 8.0, 10.0, |_| None),
+        TargetKind::Landmark
+    );
+}
+
+#[test]
+fn debug_view_hides_gaps_but_keeps_edges_visible() {
+    let mut app = test_app();
+    app.init_resource::<DebugView>()
+        .add_systems(Update, super::debug_view::apply_debug_view);
+    app.world_mut().resource_mut::<DebugView>().gap_faces = false;
+    app.update();
+
+    let w = app.world_mut();
+    assert!(
+        w.query_filtered::<&Visibility, Or<(With<Quad>, With<Tri>)>>()
+            .iter(w)
+            .all(|v| *v == Visibility::Hidden)
+    );
+    assert!(
+        w.query_filtered::<&Visibility, With<QuadEdge>>()
+            .iter(w)
+            .all(|v| *v == Visibility::Visible),
+        "edges are forced visible under hidden quads"
+    );
+
+    let view = DebugView {
+        edge_lines: false,
+        ..default()
+    };
+    assert_eq!(edge_visibility(&view), Visibility::Hidden);
+    assert_eq!(
+        edge_visibility(&DebugView::default()),
+        Visibility::Inherited
+    );
+}
+
+#[test]
+fn step_cost_grows_with_climb_and_caps_at_max() {
+    assert_eq!(step_cost(2.0, 2.0, 0.5, 6.0), Some(1.0));
+    assert_eq!(step_cost(2.0, 6.0, 0.5, 6.0), Some(3.0));
+    assert_eq!(step_cost(6.0, 2.0, 0.5, 6.0), Some(3.0));
+    assert_eq!(step_cost(0.0, 6.5, 0.5, 6.0), None);
+}
+
+#[test]
+fn hex_path_connects_neighbors_and_respects_the_climb_limit() {
+    let terrain = super::h_grid_layout::HGridLayout::from_settings(&test_config().grid);
+    let (start, end) = (Hex::new(-2, 0), Hex::new(2, 0));
+    let (hexes, cost) = find_hex_path(&terrain, start, end, 0.5, f32::INFINITY).unwrap();
+    assert_eq!((hexes[0], *hexes.last().unwrap()), (start, end));
+    assert!(hexes.len() > start.unsigned_distance_to(end) as usize);
+    assert!(
+        hexes
+            .windows(2)
+            .all(|w| w[0].unsigned_distance_to(w[1]) == 1)
+    );
+    let expected: f32 = hexes
+        .windows(2)
+        .map(|w| {
+            let (a, b) = (
+                terrain.height(&w[0]).unwrap(),
+                terrain.height(&w[1]).unwrap(),
+            );
+            1.0 + 0.5 * (a - b).abs()
+        })
+        .sum();
+    assert!((cost - expected).abs() < 1e-3);
+
+    assert_eq!(
+        find_hex_path(&terrain, start, start, 0.5, 0.0).unwrap().0,
+        [start]
+    );
+    assert_eq!(
+        find_hex_path(&terrain, start, Hex::new(9, 9), 0.5, 6.0),
+        None
+    );
+    // Without any climb allowance only equal-height steps remain.
+    assert_eq!(find_hex_path(&terrain, start, end, 0.5, 0.0), None);
+
+    // Center, then rim → rim → center per step, at the faces' heights.
+    let points = path_points(&terrain, &hexes);
+    assert_eq!(points.len(), 1 + 3 * (hexes.len() - 1));
+    let center = terrain.hex_to_world_pos(start);
+    assert!((points[0].xz() - center).length() < 1e-4);
+    assert_eq!(points[1].y, points[0].y, "rim on the start face");
+}
+
+#[test]
+fn picked_ends_draw_a_segment_chain() {
+    let mut app = test_app();
+    app.add_message::<crate::toast::Toast>()
+        .init_resource::<crate::locale::Locale>()
+        .init_resource::<ButtonInput<KeyCode>>()
+        .init_resource::<PathEnds>()
+        .init_resource::<HexPath>()
+        .add_systems(
+            Update,
+            (
+                pathfinding::set_path_ends,
+                pathfinding::solve_hex_path.run_if(resource_changed::<PathEnds>),
+                pathfinding::draw_hex_path.run_if(resource_changed::<HexPath>),
+            )
+                .chain(),
+        );
+    let segments = |app: &mut App| {
+        let w = app.world_mut();
+        w.query_filtered::<(), With<PathSegment>>().iter(w).count()
+    };
+
+    app.world_mut().write_message(HexPicked(Hex::new(0, 0)));
+    app.update();
+    assert!(app.world().resource::<HexPath>().hexes.is_empty());
+    assert_eq!(segments(&mut app), 0);
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(KeyCode::ShiftLeft);
+    app.world_mut().write_message(HexPicked(Hex::new(1, 0)));
+    app.update();
+    app.update();
+    let hexes = app.world().resource::<HexPath>().hexes.clone();
+    assert_eq!(hexes, [Hex::new(0, 0), Hex::new(1, 0)]);
+    assert_eq!(segments(&mut app), 3);
+    let toasts = app.world().resource::<Messages<crate::toast::Toast>>();
+    assert!(!toasts.is_empty());
+}
+
+// ── Line of sight ───────────────────────────────────────────────
+
+#[test]
+fn line_of_sight_sees_across_flat_ground_and_not_through_a_ridge() {
+    let mut terrain = super::h_grid_layout::HGridLayout::from_settings(&test_config().grid);
+    for hex in terrain.hexes().collect::<Vec<_>>() {
+        terrain.set_height(hex, 0.0);
+    }
+    let all = terrain.hexes().count();
+    assert_eq!(
+        visible_hexes(&terrain, Hex::new(-2, 0), 1.5, 0.5, 1.0).len(),
+        all
+    );
+
+    terrain.set_height(Hex::ZERO, 20.0);
+    let (west, east) = (Hex::new(-2, 0), Hex::new(2, 0));
+    assert!(!line_of_sight(&terrain, west, east, 1.5, 0.5, 1.0));
+    assert!(!line_of_sight(&terrain, east, west, 1.5, 0.5, 1.0));
+    // Beside the ridge, and from high enough above it, the view is clear.
+    assert!(line_of_sight(
+        &terrain,
+        west,
+        Hex::new(-2, 1),
+        1.5,
+        0.5,
+        1.0
+    ));
+    assert!(line_of_sight(&terrain, west, east, 40.0, 0.5, 1.0));
+    assert!(line_of_sight(&terrain, Hex::ZERO, east, 1.5, 0.5, 1.0));
+    assert!(!line_of_sight(
+        &terrain,
+        west,
+        Hex::new(9, 9),
+        1.5,
+        0.5,
+        1.0
+    ));
+    let visible = visible_hexes(&terrain, west, 1.5, 0.5, 1.0);
+    assert!(visible.contains(&west) && !visible.contains(&east));
+}
+
+#[test]
+fn sightline_overlays_follow_the_selected_hex() {
+    let mut app = test_app();
+    app.world_mut()
+        .run_system_once(sightline::create_sightline_materials)
+        .unwrap();
+    app.init_resource::<DebugView>()
+        .init_resource::<Sightlines>()
+        .add_systems(
+            Update,
+            (sightline::update_sightlines, sightline::draw_sightlines).chain(),
+        );
+    let overlays = |app: &mut App| {
+        let w = app.world_mut();
+        w.query::<(
+            &SightlineOverlay,
+            &MeshMaterial3d<StandardMaterial>,
+            &ChildOf,
+        )>()
+        .iter(w)
+        .map(|(_, m, parent)| (m.0.id(), parent.parent()))
+        .collect::<Vec<_>>()
+    };
+
+    app.update();
+    assert!(overlays(&mut app).is_empty(), "off until toggled");
+    app.world_mut().resource_mut::<DebugView>().line_of_sight = true;
+    app.world_mut().resource_mut::<SelectedHex>().0 = Some(Hex::new(1, -1));
+    app.update();
+    let expected = {
+        let w = app.world_mut();
+        let grid = w.query::<&HGrid>().single(w).unwrap();
+        visible_hexes(&grid.terrain, Hex::new(1, -1), 1.5, 0.5, 1.0)
+    };
+    let w = app.world();
+    let sightlines = w.resource::<Sightlines>();
+    assert_eq!(sightlines.origin, Some(Hex::new(1, -1)));
+    assert_eq!(sightlines.visible, expected);
+    let mats = w.resource::<SightlineMaterials>();
+    let (visible, hidden) = (mats.visible.id(), mats.hidden.id());
+    let shaded = overlays(&mut app);
+    assert_eq!(shaded.len(), 19);
+    for (material, face) in shaded {
+        let w = app.world();
+        let cell = w.get::<ChildOf>(face).unwrap().parent();
+        let hex = w.get::<HCell>(cell).unwrap().hex;
+        let want = if expected.contains(&hex) {
+            visible
+        } else {
+            hidden
+        };
+        assert_eq!(material, want, "{hex:?}");
+    }
+
+    app.world_mut().resource_mut::<SelectedHex>().0 = None;
+    app.update();
+    assert!(overlays(&mut app).is_empty());
+}
+
+// ── Landmarks ───────────────────────────────────────────────────
+
+#[test]
+fn landmark_sites_pick_peak_basin_and_widest_distinct_hexes() {
+    let cells = [
+        (Hex::new(0, 0), 5.0, 1.0),
+        (Hex::new(1, 0), 9.0, 2.5),
+        (Hex::new(0, 1), 1.0, 0.5),
+        (Hex::new(-1, 0), 4.0, 2.0),
+    ];
+    assert_eq!(
+        landmark_sites(cells),
+        [
+            (Hex::new(1, 0), LandmarkKind::Obelisk),
+            (Hex::new(0, 1), LandmarkKind::RingTower),
+            // The widest cell is the peak: the next widest gets the array.
+            (Hex::new(-1, 0), LandmarkKind::AntennaArray),
+        ]
+    );
+    // Ties go to the smaller coordinate regardless of input order.
+    let flat = [(Hex::new(2, 0), 3.0, 1.0), (Hex::new(-2, 0), 3.0, 1.0)];
+    assert_eq!(landmark_sites(flat)[0].0, Hex::new(-2, 0));
+    // One cell can only host one landmark.
+    assert_eq!(landmark_sites([(Hex::ZERO, 1.0, 1.0)]).len(), 1);
+}
+
+#[test]
+fn landmark_beams_stand_on_the_face_within_the_hex() {
+    let corners = std::array::from_fn(|i| {
+        let a = std::f32::consts::FRAC_PI_3 * i as f32;
+        Vec2::new(a.cos(), a.sin())
+    });
+    for kind in LandmarkKind::ALL {
+        let beams = landmark_beams(kind, corners, 2.0, 10.0);
+        assert!(!beams.is_empty());
+        let points = || beams.iter().flat_map(|&(a, b)| [a, b]);
+        assert!(points().all(|p| p.y >= 0.0 && p.xz().length() <= 2.0 + 1e-4));
+        assert!(points().any(|p| p.y == 0.0), "{kind:?} touches the face");
+        let top = points().map(|p| p.y).fold(0.0, f32::max);
+        assert!(top >= 10.0 - 1e-4, "{kind:?} reaches its height");
+    }
+}
+
+#[test]
+fn landmarks_spawn_on_cells_and_respawn_with_the_grid() {
+    let mut app = test_app();
+    let landmarks = |app: &mut App| {
+        let w = app.world_mut();
+        w.query::<(&LandmarkMarker, &ChildOf)>()
+            .iter(w)
+            .map(|(l, c)| (l.kind, l.hex, c.parent()))
+            .collect::<Vec<_>>()
+    };
+    let before = landmarks(&mut app);
+    assert_eq!(before.len(), 3);
+    let w = app.world_mut();
+    let grid = w.query::<&HGrid>().single(w).unwrap();
+    for (_, hex, parent) in &before {
+        assert_eq!(grid.hex_entities[hex], *parent);
+    }
+    let peak = before
+        .iter()
+        .find(|(kind, ..)| *kind == LandmarkKind::Obelisk)
+        .unwrap()
+        .1;
+    let top = grid.terrain.height(&peak).unwrap();
+    assert!(
+        grid.hex_entities
+            .keys()
+            .all(|h| grid.terrain.height(h).unwrap() <= top)
+    );
+
+    app.world_mut().write_message(RegenerateTerrain);
+    app.update();
+    app.update();
+    let after = landmarks(&mut app);
+    assert_eq!(after.len(), 3);
+    assert!(after.iter().all(|a| !before.iter().any(|b| b.2 == a.2)));
+}
+
+#[test]
+fn hex_notes_are_capped_and_cleared_by_blank_text() {
+    let mut notes = HexNotes::default();
+    let hex = Hex::new(1, -2);
+    notes.set(hex, "twin ");
+    assert_eq!(notes.get(hex), Some("twin "));
+    notes.set(hex, &"x".repeat(MAX_NOTE_LEN + 10));
+    assert_eq!(notes.get(hex).unwrap().len(), MAX_NOTE_LEN);
+    notes.set(hex, "   ");
+    assert_eq!(notes.get(hex), None);
+    assert!(notes.0.is_empty());
+}
@@ -0,0 +1,590 @@
+//! Color themes for terrain materials and the clear color.
+//!
+//! [`Theme`] is the single source of terrain colors.
+//! [`TerrainMaterials`](super::materials::TerrainMaterials) is built from it at startup, and
+//! [`apply_theme`](super::materials::apply_theme) rewrites the material assets whenever the
+//! resource changes at runtime.
+
+use bevy::color::Mix;
+use bevy::prelude::*;
+
+use crate::locale::Locale;
+use crate::toast::Toast;
+
+/// Built-in theme presets, selectable via `--theme` or cycled at runtime with T.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[cfg_attr(not(target_arch = "wasm32"), derive(clap::ValueEnum))]
+pub enum ThemeKind {
+    /// Olive faces, azure edges, green FoV highlight (the original look).
+    #[default]
+    CyanNeon,
+    /// Mirror-finish violet faces, hot magenta edges, orange FoV highlight.
+    SynthwaveMagenta,
+    /// Dark green faces, phosphor green edges.
+    MatrixGreen,
+    /// Brown faces, amber edges, like an old monochrome monitor.
+    AmberCrt,
+}
+
+impl ThemeKind {
+    /// All presets in cycling order.
+    pub const ALL: [ThemeKind; 4] = [
+        ThemeKind::CyanNeon,
+        ThemeKind::SynthwaveMagenta,
+        ThemeKind::MatrixGreen,
+        ThemeKind::AmberCrt,
+    ];
+
+    /// The preset after this one, wrapping around.
+    pub fn next(self) -> Self {
+        let i = Self::ALL.iter().position(|&k| k == self).unwrap_or(0);
+        Self::ALL[(i + 1) % Self::ALL.len()]
+    }
+
+    /// Human-readable name.
+    pub fn label(self) -> &'static str {
+        match self {
+            ThemeKind::CyanNeon => "Cyan Neon",
+            ThemeKind::SynthwaveMagenta => "Synthwave Magenta",
+            ThemeKind::MatrixGreen => "Matrix Green",
+            ThemeKind::AmberCrt => "Amber CRT",
+        }
+    }
+}
+
+/// Color-vision palette applied on top of any [`ThemeKind`], selectable via `--palette`.
+///
+/// The safe variants replace the hue-coded slots (edges, FoV highlight, aim) with colors that
+/// stay apart under the matching color-vision deficiency; dark rest colors are kept.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[cfg_attr(not(target_arch = "wasm32"), derive(clap::ValueEnum))]
+pub enum Palette {
+    /// The theme's own colors.
+    #[default]
+    Standard,
+    /// Red-green safe (green-weak): blue edges, orange highlight.
+    Deuteranopia,
+    /// Red-green safe (red-weak): blue edges, yellow highlight.
+    Protanopia,
+    /// Blue-yellow safe: rose edges, cyan highlight.
+    Tritanopia,
+}
+
+impl Palette {
+    /// `(edge, highlight)` hues in sRGB, or `None` for [`Palette::Standard`].
+    /// The aim highlight is always white, which differs from both by lightness.
+    fn hues(self) -> Option<(Color, Color)> {
+        let c = |r, g, b| Color::srgb(r, g, b);
+        match self {
+            Palette::Standard => None,
+            Palette::Deuteranopia => Some((c(0.0, 0.45, 0.7), c(0.9, 0.6, 0.0))),
+            Palette::Protanopia => Some((c(0.0, 0.45, 0.7), c(0.95, 0.9, 0.25))),
+            Palette::Tritanopia => Some((c(0.9, 0.2, 0.35), c(0.0, 0.75, 0.8))),
+        }
+    }
+}
+
+/// Surface finish of the hex face materials.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+pub enum FaceFinish {
+    /// Rough dielectric (the original look).
+    #[default]
+    Matte,
+    /// Near-mirror metal reflecting the neon environment map.
+    Reflective,
+}
+
+impl FaceFinish {
+    /// `(metallic, perceptual_roughness, reflectance)` for `StandardMaterial`.
+    pub fn pbr(self) -> (f32, f32, f32) {
+        match self {
+            FaceFinish::Matte => (0.0, 0.5, 0.5),
+            FaceFinish::Reflective => (0.9, 0.08, 0.9),
+        }
+    }
+}
+
+/// Multi-stop color ramp mapping normalized terrain height (0..=1) to extra face emissive.
+#[derive(Clone, Debug, Reflect)]
+pub struct HeightGradient {
+    /// `(height, emissive)` stops, sorted by ascending height.
+    pub stops: Vec<(f32, LinearRgba)>,
+}
+
+impl HeightGradient {
+    /// Deep blue valleys → cyan slopes → white peaks.
+    pub fn glacier() -> Self {
+        Self {
+            stops: vec![
+                (0.0, LinearRgba::rgb(0.0, 0.02, 0.25)),
+                (0.6, LinearRgba::rgb(0.0, 0.6, 0.9)),
+                (1.0, LinearRgba::rgb(1.5, 1.5, 1.6)),
+            ],
+        }
+    }
+
+    /// Emissive at normalized height `t`, linearly interpolated and clamped to the end stops.
+    pub fn sample(&self, t: f32) -> LinearRgba {
+        let Some(&(first_h, first_c)) = self.stops.first() else {
+            return LinearRgba::BLACK;
+        };
+        if t <= first_h {
+            return first_c;
+        }
+        for pair in self.stops.windows(2) {
+            let ((h0, c0), (h1, c1)) = (pair[0], pair[1]);
+            if t <= h1 {
+                return c0.mix(&c1, (t - h0) / (h1 - h0).max(f32::EPSILON));
+            }
+        }
+        self.stops.last().map_or(first_c, |&(_, c)| c)
+    }
+}
+
+/// Base color + emissive pair for one material slot.
+#[derive(Clone, Copy, Debug, Reflect)]
+pub struct ThemeColor {
+    /// `StandardMaterial::base_color`.
+    pub base: Color,
+    /// `StandardMaterial::emissive`.
+    pub emissive: LinearRgba,
+}
+
+impl ThemeColor {
+    const fn new(base: Color, emissive: LinearRgba) -> Self {
+        Self { base, emissive }
+    }
+}
+
+/// Active terrain color theme.
+#[derive(Resource, Clone, Debug, Reflect)]
+pub struct Theme {
+    /// Preset this theme was built from.
+    pub kind: ThemeKind,
+    /// Color-vision palette applied on top of the preset.
+    pub palette: Palette,
+    /// Surface finish of the hex faces.
+    pub face_finish: FaceFinish,
+    /// Translucent additive faces and gaps with a Fresnel rim, showing the structure behind
+    /// them (toggled with H).
+    pub hologram: bool,
+    /// Unlit surfaces with the emissive glow folded into the base colors (see
+    /// [`Theme::flattened`]).
+    pub flat: bool,
+    /// Hex face outside the FoV.
+    pub face: ThemeColor,
+    /// Hex face outside the FoV on a cell the player has flown over (warmer tint). Only the
+    /// face is tinted; edges and gaps of visited cells keep their usual colors.
+    pub face_visited: ThemeColor,
+    /// Quad/Tri gap outside the FoV.
+    pub gap: ThemeColor,
+    /// Quad edge lines outside the FoV. The line material draws the base color; the emissive
+    /// only shows through [`Theme::flattened`], which folds it into the base.
+    pub edge: ThemeColor,
+    /// Hex face inside the FoV.
+    pub face_fov: ThemeColor,
+    /// Gap inside the FoV.
+    pub gap_fov: ThemeColor,
+    /// Edge lines (and aim-star lines) inside the FoV.
+    pub edge_fov: ThemeColor,
+    /// Hex face under the crosshair.
+    pub aim: ThemeColor,
+    /// Background clear color.
+    pub clear_color: Color,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        ThemeKind::default().into()
+    }
+}
+
+impl From<ThemeKind> for Theme {
+    fn from(kind: ThemeKind) -> Self {
+        let c = |r, g, b| Color::srgb(r, g, b);
+        let e = LinearRgba::rgb;
+        match kind {
+            ThemeKind::CyanNeon => Self {
+                kind,
+                palette: Palette::Standard,
+                face_finish: FaceFinish::Matte,
+                hologram: false,
+                flat: false,
+                face: ThemeColor::new(c(0.4, 0.5, 0.1), LinearRgba::BLACK), // olive green
+                face_visited: ThemeColor::new(c(0.5, 0.45, 0.1), e(0.12, 0.05, 0.0)), // warm olive
+                gap: ThemeColor::new(c(0.1, 0.1, 0.04), e(0.0255, 0.051, 0.085)), // dim navy glow
+                edge: ThemeColor::new(c(0.0, 0.5, 1.0), e(0.0, 20.0, 40.0)), // cyan
+                face_fov: ThemeColor::new(c(0.2, 0.9, 0.3), e(0.04, 0.18, 0.06)), // bright green
+                gap_fov: ThemeColor::new(c(0.165, 0.745, 0.25), e(0.022, 0.10, 0.034)), // muted lime
+                edge_fov: ThemeColor::new(c(0.2, 0.9, 0.3), e(0.04, 0.18, 0.06)),
+                aim: ThemeColor::new(c(0.2, 0.9, 0.3), e(0.06, 0.27, 0.09)),
+                clear_color: c(0.01, 0.01, 0.02), // near-black navy
+            },
+            ThemeKind::SynthwaveMagenta => Self {
+                kind,
+                palette: Palette::Standard,
+                face_finish: FaceFinish::Reflective,
+                hologram: false,
+                flat: false,
+                face: ThemeColor::new(c(0.25, 0.05, 0.3), LinearRgba::BLACK),
+                face_visited: ThemeColor::new(c(0.35, 0.08, 0.25), e(0.12, 0.03, 0.04)),
+                gap: ThemeColor::new(c(0.08, 0.02, 0.1), e(0.06, 0.01, 0.08)),
+                edge: ThemeColor::new(c(1.0, 0.1, 0.8), e(30.0, 2.0, 24.0)),
+                face_fov: ThemeColor::new(c(1.0, 0.5, 0.2), e(0.2, 0.08, 0.03)),
+                gap_fov: ThemeColor::new(c(0.8, 0.35, 0.2), e(0.1, 0.04, 0.02)),
+                edge_fov: ThemeColor::new(c(1.0, 0.6, 0.1), e(0.2, 0.12, 0.02)),
+                aim: ThemeColor::new(c(0.3, 0.9, 1.0), e(0.1, 0.5, 0.6)),
+                clear_color: c(0.03, 0.0, 0.05),
+            },
+            ThemeKind::MatrixGreen => Self {
+                kind,
+                palette: Palette::Standard,
+                face_finish: FaceFinish::Matte,
+                hologram: false,
+                flat: false,
+                face: ThemeColor::new(c(0.02, 0.15, 0.03), LinearRgba::BLACK),
+                face_visited: ThemeColor::new(c(0.08, 0.2, 0.03), e(0.05, 0.06, 0.0)),
+                gap: ThemeColor::new(c(0.0, 0.05, 0.0), e(0.0, 0.03, 0.0)),
+                edge: ThemeColor::new(c(0.1, 1.0, 0.2), e(2.0, 30.0, 4.0)),
+                face_fov: ThemeColor::new(c(0.4, 1.0, 0.4), e(0.08, 0.3, 0.08)),
+                gap_fov: ThemeColor::new(c(0.2, 0.7, 0.2), e(0.03, 0.15, 0.03)),
+                edge_fov: ThemeColor::new(c(0.7, 1.0, 0.7), e(0.1, 0.3, 0.1)),
+                aim: ThemeColor::new(c(1.0, 1.0, 1.0), e(0.5, 0.9, 0.5)),
+                clear_color: c(0.0, 0.02, 0.0),
+            },
+            ThemeKind::AmberCrt => Self {
+                kind,
+                palette: Palette::Standard,
+                face_finish: FaceFinish::Matte,
+                hologram: false,
+                flat: false,
+                face: ThemeColor::new(c(0.3, 0.18, 0.02), LinearRgba::BLACK),
+                face_visited: ThemeColor::new(c(0.4, 0.2, 0.02), e(0.15, 0.05, 0.0)),
+                gap: ThemeColor::new(c(0.08, 0.05, 0.0), e(0.06, 0.03, 0.0)),
+                edge: ThemeColor::new(c(1.0, 0.6, 0.0), e(36.0, 16.0, 0.0)),
+                face_fov: ThemeColor::new(c(1.0, 0.8, 0.3), e(0.25, 0.15, 0.03)),
+                gap_fov: ThemeColor::new(c(0.8, 0.55, 0.15), e(0.12, 0.07, 0.01)),
+                edge_fov: ThemeColor::new(c(1.0, 0.85, 0.4), e(0.25, 0.18, 0.05)),
+                aim: ThemeColor::new(c(1.0, 0.3, 0.1), e(0.9, 0.2, 0.05)),
+                clear_color: c(0.02, 0.01, 0.0),
+            },
+        }
+    }
+}
+
+impl Theme {
+    /// The `kind` preset with this theme's palette and flat setting (hologram off).
+    pub fn switched_to(&self, kind: ThemeKind) -> Self {
+        let next = Theme::from(kind).with_palette(self.palette);
+        if self.flat { next.flattened() } else { next }
+    }
+
+    /// This theme recolored for `palette`, keeping every slot's peak emissive channel.
+    pub fn with_palette(self, palette: Palette) -> Self {
+        let Some((edge, highlight)) = palette.hues() else {
+            return Self { palette, ..self };
+        };
+        let peak = |c: LinearRgba| c.red.max(c.green).max(c.blue);
+        let hue = |c: ThemeColor, hue: Color| {
+            let lin = LinearRgba::from(hue);
+            ThemeColor::new(hue, lin * (peak(c.emissive) / peak(lin)))
+        };
+        Self {
+            palette,
+            edge: hue(self.edge, edge),
+            edge_fov: hue(self.edge_fov, highlight),
+            face_fov: hue(self.face_fov, highlight),
+            gap_fov: hue(self.gap_fov, highlight),
+            aim: hue(self.aim, Color::WHITE),
+            ..self
+        }
+    }
+
+    /// This theme for the low-graphics pipeline (no HDR, bloom, or tonemapping): every slot's
+    /// emissive is compressed into displayable range and added to its base color, emissive is
+    /// cleared, and surfaces render [`flat`](Self::flat) (unlit).
+    pub fn flattened(self) -> Self {
+        let fold = |c: ThemeColor| {
+            let glow = c.emissive;
+            let compress = |v: f32| v / (1.0 + v);
+            let lifted = LinearRgba::from(c.base)
+                + LinearRgba::rgb(
+                    compress(glow.red),
+                    compress(glow.green),
+                    compress(glow.blue),
+                );
+            let base = LinearRgba::rgb(
+                lifted.red.min(1.0),
+                lifted.green.min(1.0),
+                lifted.blue.min(1.0),
+            );
+            ThemeColor::new(Color::from(base), LinearRgba::BLACK)
+        };
+        Self {
+            flat: true,
+            face: fold(self.face),
+            face_visited: fold(self.face_visited),
+            gap: fold(self.gap),
+            edge: fold(self.edge),
+            face_fov: fold(self.face_fov),
+            gap_fov: fold(self.gap_fov),
+            edge_fov: fold(self.edge_fov),
+            aim: fold(self.aim),
+            ..self
+        }
+    }
+
+    /// Copy of this theme with every emissive color scaled by `level` (see [`GlowLevel`]).
+    pub fn glowing(&self, level: f32) -> Self {
+        let scale = |c: ThemeColor| ThemeColor::new(c.base, c.emissive * level);
+        Self {
+            face: scale(self.face),
+            face_visited: scale(self.face_visited),
+            gap: scale(self.gap),
+            edge: scale(self.edge),
+            face_fov: scale(self.face_fov),
+            gap_fov: scale(self.gap_fov),
+            edge_fov: scale(self.edge_fov),
+            aim: scale(self.aim),
+            ..self.clone()
+        }
+    }
+}
+
+/// Global glow dial: scales every emissive color and the camera bloom intensity.
+///
+/// Adjusted at runtime with +/- (including the numpad keys).
+#[derive(Resource, Clone, Copy, Debug, PartialEq, Reflect)]
+pub struct GlowLevel(pub f32);
+
+impl GlowLevel {
+    /// Change per key press.
+    pub const STEP: f32 = 0.1;
+    /// Upper bound (0 is the lower bound).
+    pub const MAX: f32 = 3.0;
+}
+
+impl Default for GlowLevel {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+/// Run condition: [`Theme`] or [`GlowLevel`] changed (false while either is absent).
+pub fn look_changed(theme: Option<Res<Theme>>, glow: Option<Res<GlowLevel>>) -> bool {
+    matches!((theme, glow), (Some(t), Some(g)) if t.is_changed() || g.is_changed())
+}
+
+/// Cycles to the next built-in theme on T.
+pub(super) fn cycle_theme(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut theme: ResMut<Theme>,
+    mut toasts: MessageWriter<Toast>,
+    locale: Res<Locale>,
+) {
+    if keys.just_pressed(KeyCode::KeyT) {
+        *theme = theme.switched_to(theme.kind.next());
+        toasts.write(Toast(locale.fmt("Theme: {0}", &[&theme.kind.label()])));
+    }
+}
+
+/// Toggles hologram mode on the active theme with H (cycling themes restores each preset's look).
+pub(super) fn toggle_hologram(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut theme: ResMut<Theme>,
+    mut toasts: MessageWriter<Toast>,
+    locale: Res<Locale>,
+) {
+    if keys.just_pressed(KeyCode::KeyH) {
+        theme.hologram = !theme.hologram;
+        let state = locale.t(if theme.hologram { "on" } else { "off" });
+        toasts.write(Toast(locale.fmt("Hologram: {0}", &[&state])));
+    }
+}
+
+/// Steps [`GlowLevel`] up on +/= and down on -.
+pub(super) fn adjust_glow_level(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut glow: ResMut<GlowLevel>,
+    mut toasts: MessageWriter<Toast>,
+    locale: Res<Locale>,
+) {
+    let up = keys.any_just_pressed([KeyCode::Equal, KeyCode::NumpadAdd]);
+    let down = keys.any_just_pressed([KeyCode::Minus, KeyCode::NumpadSubtract]);
+    let delta = match (up, down) {
+        (true, false) => GlowLevel::STEP,
+        (false, true) => -GlowLevel::STEP,
+        _ => return,
+    };
+    glow.0 = (glow.0 + delta).clamp(0.0, GlowLevel::MAX);
+    toasts.write(Toast(
+        locale.fmt("Glow level: {0}", &[&format_args!("{:.1}", glow.0)]),
+    ));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn next_cycles_through_all_presets() {
+        let mut kind = ThemeKind::default();
+        for _ in 0..ThemeKind::ALL.len() {
+            kind = kind.next();
+        }
+        assert_eq!(kind, ThemeKind::default());
+    }
+
+    #[test]
+    fn height_gradient_interpolates_and_clamps() {
+        let g = HeightGradient {
+            stops: vec![
+                (0.0, LinearRgba::BLACK),
+                (0.5, LinearRgba::rgb(1.0, 0.0, 0.0)),
+            ],
+        };
+        assert_eq!(g.sample(-1.0), LinearRgba::BLACK);
+        assert_eq!(g.sample(0.25), LinearRgba::rgb(0.5, 0.0, 0.0));
+        assert_eq!(g.sample(2.0), LinearRgba::rgb(1.0, 0.0, 0.0));
+        assert_eq!(
+            HeightGradient { stops: vec![] }.sample(0.5),
+            LinearRgba::BLACK
+        );
+    }
+
+    #[test]
+    fn glowing_scales_only_emissive() {
+        let theme = Theme::default();
+        let bright = theme.glowing(2.0);
+        assert_eq!(bright.edge.emissive, theme.edge.emissive * 2.0);
+        assert_eq!(bright.edge.base, theme.edge.base);
+        assert_eq!(bright.clear_color, theme.clear_color);
+    }
+
+    /// Machado et al. (2009) full-severity simulation matrices, linear RGB.
+    fn simulate(palette: Palette, color: Color) -> Oklaba {
+        let m = match palette {
+            Palette::Standard => Mat3::IDENTITY,
+            Palette::Protanopia => Mat3::from_cols_array(&[
+                0.152286, 0.114503, -0.003882, 1.052583, 0.786281, -0.048116, -0.204868, 0.099216,
+                1.051998,
+            ]),
+            Palette::Deuteranopia => Mat3::from_cols_array(&[
+                0.367322, 0.280085, -0.011820, 0.860646, 0.672501, 0.042940, -0.227968, 0.047413,
+                0.968881,
+            ]),
+            Palette::Tritanopia => Mat3::from_cols_array(&[
+                1.255528, -0.078411, 0.004733, -0.076749, 0.930809, 0.691367, -0.178779, 0.147602,
+                0.303900,
+            ]),
+        };
+        let c = LinearRgba::from(color);
+        let v = (m * Vec3::new(c.red, c.green, c.blue)).clamp(Vec3::ZERO, Vec3::ONE);
+        Oklaba::from(LinearRgba::rgb(v.x, v.y, v.z))
+    }
+
+    #[test]
+    fn safe_palettes_stay_distinguishable_under_their_deficiency() {
+        for palette in [
+            Palette::Deuteranopia,
+            Palette::Protanopia,
+            Palette::Tritanopia,
+        ] {
+            for kind in ThemeKind::ALL {
+                let theme = Theme::from(kind).with_palette(palette);
+                let slots = [theme.edge.base, theme.face_fov.base, theme.aim.base]
+                    .map(|c| simulate(palette, c));
+                for (i, a) in slots.iter().enumerate() {
+                    for b in &slots[i + 1..] {
+                        let d = Vec3::new(a.lightness - b.lightness, a.a - b.a, a.b - b.b);
+                        assert!(d.length() > 0.15, "{palette:?}/{kind:?}: {a:?} vs {b:?}");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn palette_survives_theme_cycling_and_keeps_glow_strength() {
+        let theme = Theme::default().with_palette(Palette::Tritanopia);
+        assert_eq!(theme.palette, Palette::Tritanopia);
+        let edge = Theme::default().edge.emissive;
+        let strength = |c: LinearRgba| c.red.max(c.green).max(c.blue);
+        assert_eq!(strength(theme.edge.emissive), strength(edge));
+
+        let mut app = App::new();
+        app.insert_resource(theme)
+            .insert_resource(ButtonInput::<KeyCode>::default())
+            .init_resource::<Locale>()
+            .add_message::<Toast>()
+            .add_systems(Update, cycle_theme);
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::KeyT);
+        app.update();
+        let theme = app.world().resource::<Theme>();
+        assert_eq!(theme.kind, ThemeKind::default().next());
+        assert_eq!(theme.palette, Palette::Tritanopia);
+    }
+
+    #[test]
+    fn flattened_theme_folds_glow_into_displayable_base_colors() {
+        for kind in ThemeKind::ALL {
+            let theme = Theme::from(kind);
+            let flat = theme.clone().flattened();
+            assert!(flat.flat);
+            let pairs = [
+                (theme.face, flat.face),
+                (theme.gap, flat.gap),
+                (theme.edge, flat.edge),
+                (theme.face_fov, flat.face_fov),
+                (theme.edge_fov, flat.edge_fov),
+                (theme.aim, flat.aim),
+            ];
+            for (orig, folded) in pairs {
+                assert_eq!(folded.emissive, LinearRgba::BLACK);
+                let (o, f) = (LinearRgba::from(orig.base), LinearRgba::from(folded.base));
+                for (o, f) in [(o.red, f.red), (o.green, f.green), (o.blue, f.blue)] {
+                    assert!(
+                        f <= 1.0 + 1e-5 && f >= o.min(1.0) - 1e-5,
+                        "{kind:?}: {o} → {f}"
+                    );
+                }
+            }
+        }
+
+        let mut app = App::new();
+        app.insert_resource(Theme::default().flattened())
+            .insert_resource(ButtonInput::<KeyCode>::default())
+            .init_resource::<Locale>()
+            .add_message::<Toast>()
+            .add_systems(Update, cycle_theme);
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::KeyT);
+        app.update();
+        let theme = app.world().resource::<Theme>();
+        assert!(theme.flat, "cycling keeps the low-graphics look");
+        assert_eq!(theme.edge.emissive, LinearRgba::BLACK);
+    }
+
+    #[test]
+    fn switched_to_keeps_palette_and_flat_but_drops_hologram() {
+        let mut theme = Theme::default()
+            .with_palette(Palette::Deuteranopia)
+            .flattened();
+        theme.hologram = true;
+        let picked = theme.switched_to(ThemeKind::AmberCrt);
+        assert_eq!(picked.kind, ThemeKind::AmberCrt);
+        assert_eq!(picked.palette, Palette::Deuteranopia);
+        assert!(picked.flat);
+        assert!(!picked.hologram);
+    }
+
+    #[test]
+    fn presets_keep_their_kind() {
+        for kind in ThemeKind::ALL {
+            assert_eq!(Theme::from(kind).kind, kind);
+        }
+    }
+}
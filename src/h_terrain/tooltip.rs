@@ -0,0 +1,82 @@
+//! Hover tooltip with info about the hex under the cursor.
+//!
+//! Raycasts from the cursor while `Inspecting` (free cursor) or from the screen center while
+//! `Running`, resolves the hit [`HexFace`] to its [`HCell`], and shows the hex coordinate,
+//! height, radius, and entity ID in a small egui popup next to the pointer.
+
+use bevy::picking::mesh_picking::ray_cast::{MeshRayCast, MeshRayCastSettings};
+use bevy::prelude::*;
+use bevy_egui::egui;
+use hexx::Hex;
+
+use super::entities::{HCell, HGrid, HexFace};
+use super::picking::window_ray;
+use crate::GameState;
+use crate::drone::Player;
+use crate::neon_ui::window_to_points;
+
+/// Tooltip lines for a hex.
+pub fn hex_tooltip_text(hex: Hex, height: f32, radius: f32, cell: Entity) -> String {
+    format!(
+        "hex {:+},{:+}\nheight {height:.2}\nradius {radius:.2}\nentity {cell}",
+        hex.x, hex.y
+    )
+}
+
+/// Shows the tooltip for the hex face under the cursor (or crosshair).
+#[allow(clippy::too_many_arguments)]
+pub(super) fn draw_hex_tooltip(
+    mut egui_ctx: Single<&mut bevy_egui::EguiContext>,
+    state: Res<State<GameState>>,
+    window: Single<&Window>,
+    camera: Single<(&Camera, &GlobalTransform), With<Player>>,
+    grid: Single<&HGrid>,
+    faces: Query<&ChildOf, With<HexFace>>,
+    cells: Query<(&HCell, &Transform)>,
+    mut raycast: MeshRayCast,
+) {
+    let (camera, cam_gt) = *camera;
+    let inspecting = *state.get() == GameState::Inspecting;
+    let pointer = if inspecting {
+        let Some(cursor) = window.cursor_position() else {
+            return;
+        };
+        cursor
+    } else {
+        window.size() / 2.0
+    };
+    let ctx = egui_ctx.get_mut();
+    if inspecting && ctx.is_pointer_over_area() {
+        return;
+    }
+
+    let Some(ray) = window_ray(&window, camera, cam_gt, pointer) else {
+        return;
+    };
+    let filter = |e| faces.contains(e);
+    let settings = MeshRayCastSettings::default().with_filter(&filter);
+    let Some(cell) = raycast
+        .cast_ray(ray, &settings)
+        .first()
+        .and_then(|(face, _)| faces.get(*face).ok())
+        .map(|parent| parent.parent())
+    else {
+        return;
+    };
+    let Ok((hcell, tf)) = cells.get(cell) else {
+        return;
+    };
+    let radius = grid.terrain.radius(&hcell.hex).unwrap_or(0.0);
+    let text = hex_tooltip_text(hcell.hex, tf.translation.y, radius, cell);
+
+    let at = pointer * window_to_points(ctx, &window);
+    egui::Area::new(egui::Id::new("hex_tooltip"))
+        .fixed_pos(egui::pos2(at.x + 16.0, at.y + 16.0))
+        .order(egui::Order::Tooltip)
+        .interactable(false)
+        .show(ctx, |ui| {
+            egui::Frame::popup(ui.style()).show(ui, |ui| {
+                ui.label(egui::RichText::new(text).monospace());
+            });
+        });
+}
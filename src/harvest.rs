@@ -0,0 +1,435 @@
+//! Resource nodes and harvesting.
+//!
+//! Whenever the grid is (re)generated, [`scatter_nodes`] gives a seeded share of the new
+//! [`HCell`]s a [`ResourceNode`] child (picked with [`hash_roll`] under the grid's height
+//! seed). Its [`ResourceKind`] follows the cell's noise height: biomass in the lowlands, ore
+//! on the slopes, crystal on the peaks. Holding X over a node ([`harvest_nodes`]) drains it
+//! into the [`Inventory`] at [`HarvestConfig::rate`] units per second while a beam
+//! ([`draw_harvest_beam`]) links the drone to it. The node shrinks as it empties and is
+//! removed when depleted. The HUD strip shows the inventory.
+
+use bevy::prelude::*;
+
+use crate::controls::{Binding, BindingCategory, RegisterBinding, Trigger};
+use crate::drone::Player;
+use crate::h_terrain::{HCell, HTerrainConfig, HTerrainPhase};
+use crate::locale::Locale;
+use crate::math::hash_roll;
+use crate::toast::Toast;
+use crate::{GameState, PlayerPos};
+
+/// Per-plugin configuration for resource nodes.
+#[derive(Resource, Clone, Debug, Reflect)]
+pub struct HarvestConfig {
+    /// Share of hexes that get a node (0 disables).
+    pub density: f32,
+    /// Mixed into the grid's height seed to pick node hexes.
+    pub seed: u64,
+    /// Units held by a fresh node.
+    pub amount: f32,
+    /// Units harvested per second while the key is held.
+    pub rate: f32,
+    /// Horizontal distance from the drone within which a node can be harvested.
+    pub reach: f32,
+    /// Maximum drone height above a node for harvesting.
+    pub max_height: f32,
+    /// Emissive multiplier on the kind colors (drives bloom).
+    pub glow: f32,
+}
+
+impl Default for HarvestConfig {
+    fn default() -> Self {
+        Self {
+            density: 0.03,
+            seed: 0x4a27_e57a,
+            amount: 10.0,
+            rate: 2.0,
+            reach: 2.5,
+            max_height: 14.0,
+            glow: 4.0,
+        }
+    }
+}
+
+/// What a node yields.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+pub enum ResourceKind {
+    /// Low ground.
+    Biomass,
+    /// Middle heights.
+    Ore,
+    /// High ground.
+    Crystal,
+}
+
+impl ResourceKind {
+    /// All kinds in inventory order.
+    pub const ALL: [Self; 3] = [Self::Biomass, Self::Ore, Self::Crystal];
+
+    /// Kind found at `share` of the grid's maximum height.
+    pub fn for_height(share: f32) -> Self {
+        if share < 0.35 {
+            Self::Biomass
+        } else if share < 0.7 {
+            Self::Ore
+        } else {
+            Self::Crystal
+        }
+    }
+
+    /// Position in [`ResourceKind::ALL`] and [`Inventory::amounts`].
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    /// English name (a [`Locale`] key).
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Biomass => "Biomass",
+            Self::Ore => "Ore",
+            Self::Crystal => "Crystal",
+        }
+    }
+
+    /// Three-letter HUD tag (a [`Locale`] key).
+    pub fn tag(self) -> &'static str {
+        match self {
+            Self::Biomass => "BIO",
+            Self::Ore => "ORE",
+            Self::Crystal => "XTL",
+        }
+    }
+
+    /// Node and beam color.
+    pub fn color(self) -> Color {
+        match self {
+            Self::Biomass => Color::srgb(0.3, 1.0, 0.2),
+            Self::Ore => Color::srgb(1.0, 0.55, 0.1),
+            Self::Crystal => Color::srgb(0.4, 0.7, 1.0),
+        }
+    }
+
+    /// Node mesh standing on the origin.
+    fn mesh(self) -> Mesh {
+        match self {
+            Self::Biomass => Mesh::from(Cylinder::new(0.5, 0.3)).translated_by(Vec3::Y * 0.15),
+            Self::Ore => Mesh::from(Sphere::new(0.45)).translated_by(Vec3::Y * 0.45),
+            Self::Crystal => Mesh::from(Cuboid::new(0.35, 1.4, 0.35))
+                .rotated_by(Quat::from_rotation_y(std::f32::consts::FRAC_PI_4))
+                .translated_by(Vec3::Y * 0.7),
+        }
+    }
+}
+
+/// A harvestable node (child of its [`HCell`]).
+#[derive(Component, Clone, Copy, Debug, Reflect)]
+pub struct ResourceNode {
+    /// What it yields.
+    pub kind: ResourceKind,
+    /// Units left.
+    pub amount: f32,
+}
+
+/// Harvested units per [`ResourceKind`].
+#[derive(Resource, Default, Debug, Reflect)]
+pub struct Inventory {
+    /// Units held, indexed by [`ResourceKind::index`].
+    pub amounts: [f32; 3],
+}
+
+impl Inventory {
+    /// Units of `kind` held.
+    pub fn get(&self, kind: ResourceKind) -> f32 {
+        self.amounts[kind.index()]
+    }
+
+    /// Whether anything has been harvested.
+    pub fn is_empty(&self) -> bool {
+        self.amounts.iter().all(|&a| a <= 0.0)
+    }
+}
+
+/// Node being harvested this frame, for the beam.
+#[derive(Resource, Default, Reflect)]
+pub struct Harvesting(pub Option<Entity>);
+
+/// Shared node mesh and material per kind, in [`ResourceKind::ALL`] order.
+#[derive(Resource)]
+struct NodeAssets {
+    meshes: Vec<Handle<Mesh>>,
+    materials: Vec<Handle<StandardMaterial>>,
+}
+
+/// Resource nodes plugin.
+pub struct HarvestPlugin {
+    /// Per-plugin configuration.
+    pub config: HarvestConfig,
+}
+
+impl Plugin for HarvestPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<HarvestConfig>()
+            .register_type::<ResourceNode>()
+            .register_type::<Inventory>()
+            .register_type::<Harvesting>()
+            .insert_resource(self.config.clone())
+            .init_resource::<Inventory>()
+            .init_resource::<Harvesting>()
+            .add_systems(Startup, create_node_assets)
+            .add_systems(
+                Update,
+                scatter_nodes.run_if(any_match_filter::<Added<HCell>>),
+            )
+            .add_systems(
+                Update,
+                (
+                    harvest_nodes,
+                    draw_harvest_beam.run_if(|h: Res<Harvesting>| h.0.is_some()),
+                )
+                    .chain()
+                    .after(HTerrainPhase::UpdateGround)
+                    .run_if(in_state(GameState::Running)),
+            )
+            .register_binding(Binding::new(
+                BindingCategory::Drone,
+                "Harvest the resource node below (hold)",
+                [Trigger::Key(KeyCode::KeyX)],
+            ));
+    }
+}
+
+/// Builds one mesh and emissive material per kind.
+fn create_node_assets(
+    cfg: Res<HarvestConfig>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut commands: Commands,
+) {
+    commands.insert_resource(NodeAssets {
+        meshes: ResourceKind::ALL
+            .iter()
+            .map(|kind| meshes.add(kind.mesh()))
+            .collect(),
+        materials: ResourceKind::ALL
+            .iter()
+            .map(|kind| {
+                materials.add(StandardMaterial {
+                    base_color: kind.color(),
+                    emissive: LinearRgba::from(kind.color()) * cfg.glow,
+                    ..default()
+                })
+            })
+            .collect(),
+    });
+}
+
+/// Gives freshly generated cells their resource nodes.
+fn scatter_nodes(
+    cfg: Res<HarvestConfig>,
+    terrain: Res<HTerrainConfig>,
+    assets: Res<NodeAssets>,
+    cells: Query<(Entity, &HCell, &Transform), Added<HCell>>,
+    mut commands: Commands,
+) {
+    let seed = cfg.seed ^ u64::from(terrain.grid.height_noise_seed);
+    let max_height = terrain.grid.max_height.max(f32::EPSILON);
+    for (entity, cell, tf) in &cells {
+        if hash_roll(cell.hex, seed) >= cfg.density {
+            continue;
+        }
+        let kind = ResourceKind::for_height(tf.translation.y / max_height);
+        commands.entity(entity).with_child((
+            Name::new("ResourceNode"),
+            ResourceNode {
+                kind,
+                amount: cfg.amount,
+            },
+            Mesh3d(assets.meshes[kind.index()].clone()),
+            MeshMaterial3d(assets.materials[kind.index()].clone()),
+            Transform::default(),
+        ));
+    }
+}
+
+/// While X is held, drains the nearest node under the drone into the [`Inventory`].
+#[allow(clippy::too_many_arguments)]
+fn harvest_nodes(
+    time: Res<Time>,
+    keys: Res<ButtonInput<KeyCode>>,
+    cfg: Res<HarvestConfig>,
+    player: Res<PlayerPos>,
+    camera: Single<&GlobalTransform, With<Player>>,
+    mut nodes: Query<(Entity, &mut ResourceNode, &mut Transform, &GlobalTransform)>,
+    mut inventory: ResMut<Inventory>,
+    mut harvesting: ResMut<Harvesting>,
+    mut toasts: MessageWriter<Toast>,
+    locale: Res<Locale>,
+    mut commands: Commands,
+) {
+    harvesting.0 = None;
+    if !keys.pressed(KeyCode::KeyX) {
+        return;
+    }
+    let eye = camera.translation().y;
+    let Some((entity, mut node, mut tf, _)) = nodes
+        .iter_mut()
+        .filter(|(.., gt)| {
+            let at = gt.translation();
+            at.xz().distance(player.xz) <= cfg.reach
+                && (0.0..=cfg.max_height).contains(&(eye - at.y))
+        })
+        .min_by(|a, b| {
+            let d = |gt: &GlobalTransform| gt.translation().xz().distance(player.xz);
+            d(a.3).total_cmp(&d(b.3))
+        })
+    else {
+        return;
+    };
+    let taken = (cfg.rate * time.delta_secs()).min(node.amount);
+    node.amount -= taken;
+    inventory.amounts[node.kind.index()] += taken;
+    if node.amount <= 0.0 {
+        commands.entity(entity).despawn();
+        toasts.write(Toast(
+            locale.fmt("{0} node depleted", &[&locale.t(node.kind.label())]),
+        ));
+        return;
+    }
+    tf.scale = Vec3::splat(0.4 + 0.6 * node.amount / cfg.amount.max(f32::EPSILON));
+    harvesting.0 = Some(entity);
+}
+
+/// Flickering beam from just under the drone to the node being harvested.
+fn draw_harvest_beam(
+    time: Res<Time>,
+    harvesting: Res<Harvesting>,
+    camera: Single<&GlobalTransform, With<Player>>,
+    nodes: Query<(&ResourceNode, &GlobalTransform)>,
+    mut gizmos: Gizmos,
+) {
+    let Some((node, gt)) = harvesting.0.and_then(|e| nodes.get(e).ok()) else {
+        return;
+    };
+    let from = camera.translation() - Vec3::Y * 0.5;
+    let to = gt.translation() + Vec3::Y * 0.3;
+    let flicker = 0.7 + 0.3 * (time.elapsed_secs() * 40.0).sin();
+    let color = node.kind.color().with_alpha(flicker);
+    gizmos.line(from, to, color);
+    for offset in [Vec3::X, Vec3::Z] {
+        gizmos.line(from + offset * 0.05, to - offset * 0.05, color);
+    }
+    gizmos.circle(
+        Isometry3d::new(to, Quat::from_rotation_arc(Vec3::Z, Vec3::Y)),
+        0.6 * flicker,
+        color,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::state::app::StatesPlugin;
+    use bevy::time::TimeUpdateStrategy;
+    use hexx::Hex;
+
+    use super::*;
+
+    #[test]
+    fn kinds_follow_height() {
+        assert_eq!(ResourceKind::for_height(0.1), ResourceKind::Biomass);
+        assert_eq!(ResourceKind::for_height(0.5), ResourceKind::Ore);
+        assert_eq!(ResourceKind::for_height(0.9), ResourceKind::Crystal);
+        for (i, kind) in ResourceKind::ALL.into_iter().enumerate() {
+            assert_eq!(kind.index(), i);
+        }
+    }
+
+    #[test]
+    fn holding_the_key_drains_the_node_below_into_the_inventory() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin, TransformPlugin))
+            .add_plugins(AssetPlugin::default())
+            .init_asset::<Mesh>()
+            .init_asset::<StandardMaterial>()
+            .add_message::<Toast>()
+            .insert_state(GameState::Running)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                200,
+            )))
+            .insert_resource(HarvestConfig {
+                density: 1.0,
+                amount: 2.0,
+                rate: 5.0,
+                ..default()
+            })
+            .init_resource::<HTerrainConfig>()
+            .init_resource::<Inventory>()
+            .init_resource::<Harvesting>()
+            .init_resource::<PlayerPos>()
+            .init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<Locale>()
+            .add_systems(Startup, create_node_assets)
+            .add_systems(
+                Update,
+                (
+                    scatter_nodes.run_if(any_match_filter::<Added<HCell>>),
+                    harvest_nodes,
+                )
+                    .chain(),
+            );
+        let max_height = app.world().resource::<HTerrainConfig>().grid.max_height;
+        for (q, x, y) in [(0, 0.0, 0.1), (1, 20.0, 0.9)] {
+            app.world_mut().spawn((
+                HCell {
+                    hex: Hex::new(q, 0),
+                },
+                Transform::from_xyz(x, y * max_height, 0.0),
+            ));
+        }
+        app.world_mut().spawn((
+            Player,
+            GlobalTransform::from(Transform::from_xyz(0.0, 0.1 * max_height + 5.0, 0.0)),
+        ));
+        app.update();
+        let kinds = |app: &mut App| -> Vec<ResourceKind> {
+            let w = app.world_mut();
+            let mut kinds: Vec<_> = w.query::<&ResourceNode>().iter(w).map(|n| n.kind).collect();
+            kinds.sort_by_key(|k| k.index());
+            kinds
+        };
+        assert_eq!(
+            kinds(&mut app),
+            [ResourceKind::Biomass, ResourceKind::Crystal]
+        );
+
+        // Not holding the key: nothing happens.
+        app.update();
+        assert!(app.world().resource::<Inventory>().is_empty());
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::KeyX);
+        app.update();
+        let w = app.world();
+        assert_eq!(w.resource::<Inventory>().get(ResourceKind::Biomass), 1.0);
+        assert!(w.resource::<Harvesting>().0.is_some());
+        app.update();
+        // Depleted: removed, and only the far crystal is left.
+        assert_eq!(
+            app.world()
+                .resource::<Inventory>()
+                .get(ResourceKind::Biomass),
+            2.0
+        );
+        assert_eq!(kinds(&mut app), [ResourceKind::Crystal]);
+        app.update();
+        assert_eq!(
+            app.world()
+                .resource::<Inventory>()
+                .get(ResourceKind::Crystal),
+            0.0
+        );
+        assert!(app.world().resource::<Harvesting>().0.is_none());
+    }
+}
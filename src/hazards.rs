@@ -0,0 +1,248 @@
+//! Hazard hexes: a seeded subset of cells with red pulsing faces and a repulsion field.
+//!
+//! Whenever the grid is (re)generated, [`scatter_hazards`] tags each new [`HCell`] whose
+//! [`hash_roll`] under [`HazardsConfig::seed`] (mixed with the grid's height seed) falls under
+//! [`HazardsConfig::density`] with [`Hazard`], and covers its [`HexFace`] with a
+//! [`HazardOverlay`]. All overlays share one material whose glow [`pulse_hazards`] animates.
+//! While flying, [`repel_drone`] pushes the drone out of a hazard hex it hovers over, at up to
+//! [`HazardsConfig::strength`] units per second. There is no boost energy in this tree, so
+//! the field only pushes.
+
+use bevy::prelude::*;
+
+use crate::h_terrain::{ActiveHex, HCell, HTerrainConfig, HTerrainPhase, HexFace, overlay_lift};
+use crate::math::hash_roll;
+use crate::{GameState, PlayerMoved, PlayerPos};
+
+/// Per-plugin configuration for hazard hexes.
+#[derive(Resource, Clone, Debug, Reflect)]
+pub struct HazardsConfig {
+    /// Share of hexes that are hazards (0 disables).
+    pub density: f32,
+    /// Mixed into the grid's height seed to pick hazard hexes.
+    pub seed: u64,
+    /// Push speed at the hex center, in world units per second (0 disables the field).
+    pub strength: f32,
+    /// Face pulses per second.
+    pub pulse_hz: f32,
+    /// Overlay color.
+    pub color: Color,
+    /// Peak emissive multiplier on `color` (drives bloom).
+    pub glow: f32,
+}
+
+impl Default for HazardsConfig {
+    fn default() -> Self {
+        Self {
+            density: 0.03,
+            seed: 0x4a2a_4d5f,
+            strength: 25.0,
+            pulse_hz: 1.2,
+            color: Color::srgb(1.0, 0.05, 0.05),
+            glow: 4.0,
+        }
+    }
+}
+
+/// Marker on hazard [`HCell`]s.
+#[derive(Component, Reflect)]
+pub struct Hazard;
+
+/// Marker on the pulsing overlay child of a hazard's [`HexFace`] (see [`overlay_lift`]).
+#[derive(Component, Reflect)]
+pub struct HazardOverlay;
+
+/// Shared pulsing overlay material.
+#[derive(Resource)]
+struct HazardMaterial(Handle<StandardMaterial>);
+
+/// Push velocity for a drone at `offset` from the hazard's center: away from the center,
+/// strongest there and fading to a quarter of `strength` at `radius`.
+pub fn repulsion(offset: Vec2, radius: f32, strength: f32) -> Vec2 {
+    let falloff = (1.0 - offset.length() / radius.max(f32::EPSILON)).clamp(0.0, 1.0);
+    // Dead center: push along +x rather than not at all.
+    offset.try_normalize().unwrap_or(Vec2::X) * strength * (0.25 + 0.75 * falloff)
+}
+
+/// Hazard hexes plugin.
+pub struct HazardsPlugin {
+    /// Per-plugin configuration.
+    pub config: HazardsConfig,
+}
+
+impl Plugin for HazardsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<HazardsConfig>()
+            .register_type::<Hazard>()
+            .register_type::<HazardOverlay>()
+            .insert_resource(self.config.clone())
+            .add_systems(Startup, create_hazard_material)
+            .add_systems(
+                Update,
+                scatter_hazards.run_if(any_match_filter::<Added<HCell>>),
+            )
+            .add_systems(Update, pulse_hazards)
+            .add_systems(
+                Update,
+                repel_drone
+                    .after(crate::drone::systems::fly)
+                    .before(HTerrainPhase::UpdateGround)
+                    .run_if(in_state(GameState::Running)),
+            );
+    }
+}
+
+/// Creates the shared translucent overlay material.
+fn create_hazard_material(
+    cfg: Res<HazardsConfig>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut commands: Commands,
+) {
+    commands.insert_resource(HazardMaterial(materials.add(StandardMaterial {
+        base_color: cfg.color.with_alpha(0.5),
+        emissive: LinearRgba::from(cfg.color) * cfg.glow,
+        alpha_mode: AlphaMode::Blend,
+        unlit: true,
+        ..default()
+    })));
+}
+
+/// Tags freshly generated hazard cells and overlays their faces.
+fn scatter_hazards(
+    cfg: Res<HazardsConfig>,
+    terrain: Res<HTerrainConfig>,
+    material: Res<HazardMaterial>,
+    cells: Query<(Entity, &HCell, &Children), Added<HCell>>,
+    faces: Query<&Mesh3d, With<HexFace>>,
+    mut commands: Commands,
+) {
+    let seed = cfg.seed ^ u64::from(terrain.grid.height_noise_seed);
+    for (entity, cell, children) in &cells {
+        if hash_roll(cell.hex, seed) >= cfg.density {
+            continue;
+        }
+        commands.entity(entity).insert(Hazard);
+        for face in children.iter() {
+            let Ok(mesh) = faces.get(face) else {
+                continue;
+            };
+            commands.entity(face).with_child((
+                HazardOverlay,
+                Mesh3d(mesh.0.clone()),
+                MeshMaterial3d(material.0.clone()),
+                Transform::from_xyz(0.0, overlay_lift::HAZARD, 0.0),
+            ));
+        }
+    }
+}
+
+/// Pulses the shared overlay glow.
+fn pulse_hazards(
+    time: Res<Time>,
+    cfg: Res<HazardsConfig>,
+    material: Res<HazardMaterial>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let Some(mat) = materials.get_mut(&material.0) else {
+        return;
+    };
+    let wave = 0.5 + 0.5 * (time.elapsed_secs() * cfg.pulse_hz * std::f32::consts::TAU).sin();
+    mat.emissive = LinearRgba::from(cfg.color) * cfg.glow * (0.2 + 0.8 * wave);
+}
+
+/// Pushes the drone out of the hazard hex under it.
+fn repel_drone(
+    time: Res<Time>,
+    cfg: Res<HazardsConfig>,
+    terrain: Res<HTerrainConfig>,
+    active: Res<ActiveHex>,
+    hazards: Query<&GlobalTransform, With<Hazard>>,
+    mut player: ResMut<PlayerPos>,
+    mut moved: ResMut<PlayerMoved>,
+) {
+    let Some(center) = active.cell.and_then(|cell| hazards.get(cell).ok()) else {
+        return;
+    };
+    if cfg.strength <= 0.0 {
+        return;
+    }
+    let offset = player.xz - center.translation().xz();
+    // Inner radius of the cell: half the spacing between hex centers.
+    let radius = terrain.grid.point_spacing / 2.0;
+    player.xz += repulsion(offset, radius, cfg.strength) * time.delta_secs();
+    moved.0 = true;
+}
+
+#[cfg(test)]
+mod tests {
+    use hexx::Hex;
+
+    use super::*;
+
+    #[test]
+    fn repulsion_points_away_and_fades_toward_the_edge() {
+        let center = repulsion(Vec2::ZERO, 4.0, 10.0);
+        assert_eq!(center, Vec2::new(10.0, 0.0));
+        let near = repulsion(Vec2::new(0.0, -1.0), 4.0, 10.0);
+        let far = repulsion(Vec2::new(0.0, -3.0), 4.0, 10.0);
+        assert!(near.y < 0.0 && near.x == 0.0);
+        assert!(near.length() > far.length());
+        // Past the edge the push bottoms out instead of vanishing.
+        assert_eq!(
+            repulsion(Vec2::new(8.0, 0.0), 4.0, 10.0),
+            Vec2::new(2.5, 0.0)
+        );
+    }
+
+    #[test]
+    fn hazards_scatter_on_new_cells_and_push_the_drone_out() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_plugins(AssetPlugin::default())
+            .init_asset::<Mesh>()
+            .init_asset::<StandardMaterial>()
+            .insert_resource(HazardsConfig {
+                density: 1.0,
+                ..default()
+            })
+            .init_resource::<HTerrainConfig>()
+            .init_resource::<ActiveHex>()
+            .init_resource::<PlayerPos>()
+            .init_resource::<PlayerMoved>()
+            .add_systems(Startup, create_hazard_material)
+            .add_systems(
+                Update,
+                (
+                    scatter_hazards.run_if(any_match_filter::<Added<HCell>>),
+                    repel_drone,
+                )
+                    .chain(),
+            );
+        let mesh = app
+            .world_mut()
+            .resource_mut::<Assets<Mesh>>()
+            .add(Cuboid::default());
+        let cell = app
+            .world_mut()
+            .spawn((
+                HCell { hex: Hex::ZERO },
+                GlobalTransform::from_xyz(0.0, 2.0, 0.0),
+            ))
+            .id();
+        app.world_mut()
+            .spawn((HexFace, Mesh3d(mesh), ChildOf(cell)));
+        app.update();
+        let w = app.world_mut();
+        assert!(w.get::<Hazard>(cell).is_some());
+        assert_eq!(w.query::<&HazardOverlay>().iter(w).count(), 1);
+
+        w.resource_mut::<ActiveHex>().cell = Some(cell);
+        w.resource_mut::<PlayerPos>().xz = Vec2::new(-1.0, 0.0);
+        app.update();
+        app.update();
+        let player = app.world().resource::<PlayerPos>();
+        assert!(player.xz.x < -1.0, "{:?}", player.xz);
+        assert_eq!(player.xz.y, 0.0);
+        assert!(app.world().resource::<PlayerMoved>().0);
+    }
+}
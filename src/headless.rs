@@ -0,0 +1,343 @@
+//! Headless generation: build a grid without a window or renderer, report it, and exit.
+//!
+//! `--headless` runs the app on `MinimalPlugins` with only [`HeadlessPlugin`]. Its Startup
+//! system ([`generate_headless`]) samples an [`HGridLayout`] from [`HeadlessConfig::grid`],
+//! computes [`TerrainStats`], prints a summary, writes the requested exports, and sends
+//! [`AppExit`] (an error exit when a write fails). Exports:
+//!
+//! - [`HeadlessConfig::ron`]: a [`HeightExport`] with every hex height, as RON.
+//! - [`HeadlessConfig::heightmap`]: a 16-bit binary PGM over the axial `q`/`r` square
+//!   ([`heightmap_pgm`]); cells outside the hexagon are black.
+//! - [`HeadlessConfig::gltf`]: the hex faces as a binary glTF mesh ([`terrain_glb`]).
+//!
+//! The same writers back the console's `export` command.
+//!
+//! Handy for CI checks of generation and for producing worlds in batches.
+
+use bevy::app::AppExit;
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+use hexx::Hex;
+use serde::{Deserialize, Serialize};
+
+use crate::h_terrain::{HGridLayout, HGridSettings, HTerrainConfig, TerrainStats};
+
+/// Per-plugin configuration for headless generation.
+#[derive(Resource, Clone, Debug)]
+pub struct HeadlessConfig {
+    /// Grid layout and noise parameters to generate from.
+    pub grid: HGridSettings,
+    /// Write a [`HeightExport`] as RON to this path.
+    pub ron: Option<String>,
+    /// Write a 16-bit PGM heightmap to this path.
+    pub heightmap: Option<String>,
+    /// Write the hex faces as a binary glTF (`.glb`) to this path.
+    pub gltf: Option<String>,
+}
+
+impl Default for HeadlessConfig {
+    fn default() -> Self {
+        Self {
+            grid: HTerrainConfig::default().grid,
+            ron: None,
+            heightmap: None,
+            gltf: None,
+        }
+    }
+}
+
+/// Generated heights, as written by the RON export.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct HeightExport {
+    /// [`HGridSettings::radius`].
+    pub radius: u32,
+    /// [`HGridSettings::point_spacing`].
+    pub point_spacing: f32,
+    /// [`HGridSettings::max_height`].
+    pub max_height: f32,
+    /// [`HGridSettings::height_noise_seed`].
+    pub height_seed: u32,
+    /// [`HGridSettings::radius_noise_seed`].
+    pub radius_seed: u32,
+    /// Axial coordinates and height of every hex, sorted by `(q, r)`.
+    pub hexes: Vec<([i32; 2], f32)>,
+}
+
+impl HeightExport {
+    /// Collects the heights of `layout`, generated from `grid`.
+    pub fn new(grid: &HGridSettings, layout: &HGridLayout) -> Self {
+        let mut hexes: Vec<_> = layout
+            .hexes()
+            .filter_map(|hex| Some(([hex.x, hex.y], layout.height(&hex)?)))
+            .collect();
+        hexes.sort_by_key(|&(c, _)| c);
+        Self {
+            radius: grid.radius,
+            point_spacing: grid.point_spacing,
+            max_height: grid.max_height,
+            height_seed: grid.height_noise_seed,
+            radius_seed: grid.radius_noise_seed,
+            hexes,
+        }
+    }
+
+    /// Serializes the export to pretty-printed RON.
+    pub fn to_ron(&self) -> Result<String, ron::Error> {
+        ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+    }
+}
+
+/// Encodes `heights` as a 16-bit binary PGM (`P5`, big-endian samples).
+///
+/// The image covers axial `q` (columns) and `r` (rows) in `-radius..=radius`; heights map
+/// linearly from `0..=max_height` to `0..=65535`, missing cells are 0.
+pub fn heightmap_pgm(heights: &HashMap<Hex, f32>, radius: u32, max_height: f32) -> Vec<u8> {
+    let r = radius as i32;
+    let side = 2 * radius + 1;
+    let mut out = format!("P5\n{side} {side}\n65535\n").into_bytes();
+    for row in -r..=r {
+        for col in -r..=r {
+            let h = heights.get(&Hex::new(col, row)).copied().unwrap_or(0.0);
+            let v = (h / max_height.max(f32::EPSILON)).clamp(0.0, 1.0) * 65535.0;
+            out.extend_from_slice(&(v.round() as u16).to_be_bytes());
+        }
+    }
+    out
+}
+
+/// Encodes the hex faces of `layout` as a binary glTF (`.glb`) with a single mesh.
+///
+/// Each face is a flat hexagon at its height and noise radius (six triangles around the
+/// center, wound counter-clockwise seen from above); gaps between faces are not exported.
+pub fn terrain_glb(layout: &HGridLayout) -> Vec<u8> {
+    let mut positions: Vec<Vec3> = Vec::new();
+    let mut indices: Vec<u32> = Vec::new();
+    for hex in layout.hexes() {
+        let Some(corners) = (0..6)
+            .map(|i| layout.vertex(hex, i))
+            .collect::<Option<Vec<Vec3>>>()
+        else {
+            continue;
+        };
+        let center = corners.iter().sum::<Vec3>() / 6.0;
+        let base = positions.len() as u32;
+        positions.push(center);
+        positions.extend(&corners);
+        let upward = (corners[0] - center).cross(corners[1] - center).y > 0.0;
+        for i in 0..6u32 {
+            let (a, b) = (base + 1 + i, base + 1 + (i + 1) % 6);
+            indices.extend(if upward { [base, a, b] } else { [base, b, a] });
+        }
+    }
+
+    let min = positions.iter().copied().fold(Vec3::INFINITY, Vec3::min);
+    let max = positions
+        .iter()
+        .copied()
+        .fold(Vec3::NEG_INFINITY, Vec3::max);
+    let mut bin: Vec<u8> = positions
+        .iter()
+        .flat_map(|p| p.to_array())
+        .flat_map(f32::to_le_bytes)
+        .collect();
+    let positions_len = bin.len();
+    bin.extend(indices.iter().flat_map(|i| i.to_le_bytes()));
+    let json = format!(
+        concat!(
+            r#"{{"asset":{{"version":"2.0","generator":"hex-terrain"}},"scene":0,"#,
+            r#""scenes":[{{"nodes":[0]}}],"nodes":[{{"mesh":0,"name":"terrain"}}],"#,
+            r#""meshes":[{{"primitives":[{{"attributes":{{"POSITION":0}},"indices":1}}]}}],"#,
+            r#""buffers":[{{"byteLength":{}}}],"bufferViews":["#,
+            r#"{{"buffer":0,"byteOffset":0,"byteLength":{},"target":34962}},"#,
+            r#"{{"buffer":0,"byteOffset":{},"byteLength":{},"target":34963}}],"accessors":["#,
+            r#"{{"bufferView":0,"componentType":5126,"count":{},"type":"VEC3","#,
+            r#""min":[{},{},{}],"max":[{},{},{}]}},"#,
+            r#"{{"bufferView":1,"componentType":5125,"count":{},"type":"SCALAR"}}]}}"#
+        ),
+        bin.len(),
+        positions_len,
+        positions_len,
+        bin.len() - positions_len,
+        positions.len(),
+        min.x,
+        min.y,
+        min.z,
+        max.x,
+        max.y,
+        max.z,
+        indices.len(),
+    );
+
+    // Chunks are 4-byte aligned: JSON padded with spaces, binary with zeros.
+    let mut json = json.into_bytes();
+    json.resize(json.len().next_multiple_of(4), b' ');
+    bin.resize(bin.len().next_multiple_of(4), 0);
+    let total = 12 + 8 + json.len() + 8 + bin.len();
+    let mut out = Vec::with_capacity(total);
+    out.extend_from_slice(b"glTF");
+    out.extend_from_slice(&2u32.to_le_bytes());
+    out.extend_from_slice(&(total as u32).to_le_bytes());
+    out.extend_from_slice(&(json.len() as u32).to_le_bytes());
+    out.extend_from_slice(b"JSON");
+    out.extend_from_slice(&json);
+    out.extend_from_slice(&(bin.len() as u32).to_le_bytes());
+    out.extend_from_slice(b"BIN\0");
+    out.extend_from_slice(&bin);
+    out
+}
+
+/// Headless generation plugin (add to an app with `MinimalPlugins`).
+pub struct HeadlessPlugin {
+    /// Per-plugin configuration.
+    pub config: HeadlessConfig,
+}
+
+impl Plugin for HeadlessPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(self.config.clone())
+            .init_resource::<TerrainStats>()
+            .add_systems(Startup, generate_headless);
+    }
+}
+
+/// Generates the grid, stores and prints its [`TerrainStats`], writes the exports, and exits.
+pub fn generate_headless(
+    cfg: Res<HeadlessConfig>,
+    mut stats: ResMut<TerrainStats>,
+    mut exit: MessageWriter<AppExit>,
+) {
+    let layout = HGridLayout::from_settings(&cfg.grid);
+    let heights: HashMap<Hex, f32> = layout
+        .hexes()
+        .filter_map(|hex| Some((hex, layout.height(&hex)?)))
+        .collect();
+    *stats = TerrainStats::from_heights(&heights, cfg.grid.max_height);
+
+    println!(
+        "seeds {}/{}  hexes {}  height {:.2} / {:.2} / {:.2} (min/mean/max)  cliffs {}",
+        cfg.grid.height_noise_seed,
+        cfg.grid.radius_noise_seed,
+        stats.hex_count,
+        stats.min_height,
+        stats.mean_height,
+        stats.max_height,
+        stats.cliffs
+    );
+
+    let mut result = Ok(());
+    if let Some(path) = &cfg.ron {
+        result = HeightExport::new(&cfg.grid, &layout)
+            .to_ron()
+            .map_err(|e| e.to_string())
+            .and_then(|text| std::fs::write(path, text).map_err(|e| e.to_string()))
+            .map_err(|e| format!("{path}: {e}"));
+    }
+    if let (Ok(()), Some(path)) = (&result, &cfg.heightmap) {
+        let pgm = heightmap_pgm(&heights, cfg.grid.radius, cfg.grid.max_height);
+        result = std::fs::write(path, pgm).map_err(|e| format!("{path}: {e}"));
+    }
+    if let (Ok(()), Some(path)) = (&result, &cfg.gltf) {
+        result = std::fs::write(path, terrain_glb(&layout)).map_err(|e| format!("{path}: {e}"));
+    }
+
+    match result {
+        Ok(()) => {
+            exit.write(AppExit::Success);
+        }
+        Err(e) => {
+            eprintln!("Failed to write export {e}");
+            exit.write(AppExit::error());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn small_grid() -> HGridSettings {
+        HGridSettings {
+            radius: 3,
+            ..HTerrainConfig::default().grid
+        }
+    }
+
+    #[test]
+    fn height_export_lists_every_hex_in_order() {
+        let grid = small_grid();
+        let layout = HGridLayout::from_settings(&grid);
+        let export = HeightExport::new(&grid, &layout);
+        assert_eq!(export.hexes.len(), layout.hexes().count());
+        assert!(export.hexes.windows(2).all(|w| w[0].0 < w[1].0));
+        let text = export.to_ron().unwrap();
+        assert_eq!(ron::from_str::<HeightExport>(&text).unwrap(), export);
+    }
+
+    #[test]
+    fn heightmap_scales_heights_and_blanks_missing_cells() {
+        let heights = HashMap::from_iter([(Hex::ZERO, 10.0), (Hex::new(1, 0), 20.0)]);
+        let pgm = heightmap_pgm(&heights, 1, 20.0);
+        let header = b"P5\n3 3\n65535\n";
+        assert_eq!(&pgm[..header.len()], header);
+        let px: Vec<u16> = pgm[header.len()..]
+            .chunks(2)
+            .map(|b| u16::from_be_bytes([b[0], b[1]]))
+            .collect();
+        assert_eq!(px.len(), 9);
+        // Row r = 0 is the middle row; columns q = -1, 0, 1.
+        assert_eq!(&px[3..6], &[0, 32768, 65535]);
+        assert_eq!(px.iter().filter(|&&v| v != 0).count(), 2);
+    }
+
+    #[test]
+    fn glb_holds_one_fan_per_hex() {
+        let layout = HGridLayout::from_settings(&small_grid());
+        let glb = terrain_glb(&layout);
+        assert_eq!(&glb[..4], b"glTF");
+        let word = |at: usize| u32::from_le_bytes(glb[at..at + 4].try_into().unwrap()) as usize;
+        assert_eq!(word(8), glb.len());
+        let json_len = word(12);
+        assert_eq!(&glb[16..20], b"JSON");
+        let json = std::str::from_utf8(&glb[20..20 + json_len]).unwrap();
+        let hexes = layout.hexes().count();
+        assert!(json.contains(&format!(r#""count":{},"type":"VEC3""#, hexes * 7)));
+        assert!(json.contains(&format!(r#""count":{},"type":"SCALAR""#, hexes * 18)));
+        let bin_at = 20 + json_len;
+        assert_eq!(&glb[bin_at + 4..bin_at + 8], b"BIN\0");
+        assert_eq!(word(bin_at), hexes * (7 * 12 + 18 * 4));
+    }
+
+    #[test]
+    fn headless_app_generates_and_exits() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins).add_plugins(HeadlessPlugin {
+            config: HeadlessConfig {
+                grid: small_grid(),
+                ..default()
+            },
+        });
+        app.update();
+        assert_eq!(app.world().resource::<TerrainStats>().hex_count, 37);
+        assert_eq!(app.should_exit(), Some(AppExit::Success));
+    }
+
+    #[test]
+    fn headless_app_writes_the_glb() {
+        let path =
+            std::env::temp_dir().join(format!("hex-terrain-headless-{}.glb", std::process::id()));
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins).add_plugins(HeadlessPlugin {
+            config: HeadlessConfig {
+                grid: small_grid(),
+                gltf: Some(path.to_str().unwrap().into()),
+                ..default()
+            },
+        });
+        app.update();
+        assert_eq!(app.should_exit(), Some(AppExit::Success));
+        let glb = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(&glb[..4], b"glTF");
+        assert!(glb.len() > 20);
+    }
+}
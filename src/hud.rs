@@ -0,0 +1,566 @@
+//! Exploration HUD strip: hex coordinates, altitude, compass heading, ground speed, and
+//! progress counters (revealed share, orbs, claimed hexes; energy in survival mode), the
+//! harvested [`Inventory`], the
+//! [`Wind`] speed and direction, and the nearest [`LandmarkMarker`] with its distance. The strip
+//! takes the [`BandTint`] of the current elevation band.
+//!
+//! Drawn with egui along the top edge while [`GameState::Running`], when
+//! [`HudConfig::enabled`] is set. A thin bar under the strip shows the share of hexes
+//! [`Revealed`] so far and pulses for [`HudConfig::celebrate_secs`] once the whole grid is.
+//!
+//! An altitude control along the bottom edge binds a slider to [`PlayerPos::offset`] with
+//! [`AltitudePreset`] buttons (F2–F4 while flying, since the cursor is locked), shown while
+//! `Running` or `Inspecting`.
+
+use bevy::prelude::*;
+use bevy_egui::egui;
+
+use crate::budget::BudgetStatus;
+use crate::claims::{Owner, PLAYER, claimed_count};
+use crate::controls::{Binding, BindingCategory, RegisterBinding, Trigger};
+use crate::drone::{DroneConfig, Player};
+use crate::elevation::BandTint;
+use crate::energy::Energy;
+use crate::h_terrain::{ActiveHex, HCell, LandmarkMarker, Revealed};
+use crate::harvest::{Inventory, ResourceKind};
+use crate::locale::Locale;
+use crate::orbs::OrbScore;
+use crate::photo::PhotoMode;
+use crate::wind::Wind;
+use crate::{GameState, GroundLevel, PlayerMoved, PlayerPos};
+
+/// Per-plugin configuration for the HUD.
+#[derive(Resource, Clone, Debug, Reflect)]
+pub struct HudConfig {
+    /// Show the HUD strip.
+    pub enabled: bool,
+    /// Ground speed easing rate (per second); higher follows speed changes faster.
+    pub speed_smoothing: f32,
+    /// Duration of the progress-bar pulse after the whole grid is revealed.
+    pub celebrate_secs: f32,
+    /// Show the altitude slider and preset buttons.
+    pub altitude_control: bool,
+    /// Top of the altitude slider (offset above ground).
+    pub max_offset: f32,
+    /// Offset above ground for [`AltitudePreset::Skim`].
+    pub skim_offset: f32,
+    /// Offset above ground for [`AltitudePreset::Cruise`].
+    pub cruise_offset: f32,
+    /// Offset above ground for [`AltitudePreset::Survey`].
+    pub survey_offset: f32,
+}
+
+impl Default for HudConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            speed_smoothing: 6.0,
+            celebrate_secs: 3.0,
+            altitude_control: true,
+            max_offset: 60.0,
+            skim_offset: 2.5,
+            cruise_offset: 10.0,
+            survey_offset: 40.0,
+        }
+    }
+}
+
+/// Smoothed horizontal speed of the player, derived from [`PlayerPos`] deltas.
+#[derive(Resource, Default, Reflect)]
+pub struct GroundSpeed {
+    /// Player xz on the previous frame.
+    last_xz: Option<Vec2>,
+    /// Smoothed ground speed in world units per second.
+    pub speed: f32,
+}
+
+/// Revealed vs total hex count, for the HUD progress bar.
+#[derive(Resource, Default, Reflect)]
+pub struct RevealProgress {
+    /// [`HCell`]s tagged [`Revealed`].
+    pub revealed: usize,
+    /// All [`HCell`]s in the grid.
+    pub total: usize,
+    /// Elapsed seconds when the grid became fully revealed (cleared on regeneration).
+    pub completed_at: Option<f32>,
+}
+
+impl RevealProgress {
+    /// Revealed share in `0.0..=1.0` (0 for an empty grid).
+    pub fn fraction(&self) -> f32 {
+        if self.total == 0 {
+            0.0
+        } else {
+            self.revealed as f32 / self.total as f32
+        }
+    }
+}
+
+/// Named inspection height, jumped to from the altitude control.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+pub enum AltitudePreset {
+    /// Just above the hex tops.
+    Skim,
+    /// Default flying height.
+    Cruise,
+    /// High overview.
+    Survey,
+}
+
+impl AltitudePreset {
+    /// All presets, low to high.
+    pub const ALL: [AltitudePreset; 3] = [Self::Skim, Self::Cruise, Self::Survey];
+
+    /// Button label.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Skim => "skim",
+            Self::Cruise => "cruise",
+            Self::Survey => "survey",
+        }
+    }
+
+    /// Hotkey that jumps to the preset.
+    pub fn key(self) -> KeyCode {
+        match self {
+            Self::Skim => KeyCode::F2,
+            Self::Cruise => KeyCode::F3,
+            Self::Survey => KeyCode::F4,
+        }
+    }
+
+    /// Offset above ground configured for the preset.
+    pub fn offset(self, cfg: &HudConfig) -> f32 {
+        match self {
+            Self::Skim => cfg.skim_offset,
+            Self::Cruise => cfg.cruise_offset,
+            Self::Survey => cfg.survey_offset,
+        }
+    }
+}
+
+/// Celebration brightness in `0.0..=1.0`, `secs` after completion: three fading pulses over
+/// `duration`, then 0.
+pub fn celebration_pulse(secs: f32, duration: f32) -> f32 {
+    if !(0.0..duration).contains(&secs) {
+        return 0.0;
+    }
+    let t = secs / duration;
+    (t * 3.0 * std::f32::consts::TAU).cos().mul_add(0.5, 0.5) * (1.0 - t)
+}
+
+/// Compass heading in degrees for a camera `forward` vector: 0 = -Z (north), 90 = +X (east).
+pub fn compass_heading(forward: Vec3) -> f32 {
+    forward.x.atan2(-forward.z).to_degrees().rem_euclid(360.0)
+}
+
+/// HUD plugin.
+pub struct HudPlugin {
+    /// Per-plugin configuration.
+    pub config: HudConfig,
+}
+
+impl Plugin for HudPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<HudConfig>()
+            .register_type::<GroundSpeed>()
+            .register_type::<RevealProgress>()
+            .register_type::<AltitudePreset>()
+            .insert_resource(self.config.clone())
+            .init_resource::<GroundSpeed>()
+            .init_resource::<RevealProgress>()
+            .register_binding(Binding::new(
+                BindingCategory::Flight,
+                "Altitude preset: skim / cruise / survey",
+                AltitudePreset::ALL.map(|p| Trigger::Key(p.key())),
+            ))
+            .add_systems(
+                Update,
+                (
+                    track_ground_speed,
+                    track_reveal_progress,
+                    // Photo mode clears the frame.
+                    draw_hud.run_if(|photo: Option<Res<PhotoMode>>| !photo.is_some_and(|p| p.0)),
+                )
+                    .chain()
+                    .after(crate::drone::systems::fly)
+                    .run_if(in_state(GameState::Running))
+                    .run_if(|cfg: Res<HudConfig>| cfg.enabled),
+            )
+            .add_systems(
+                Update,
+                (
+                    altitude_preset_keys
+                        .before(crate::drone::systems::fly)
+                        .run_if(in_state(GameState::Running)),
+                    draw_altitude_control
+                        .run_if(in_state(GameState::Running).or(in_state(GameState::Inspecting))),
+                )
+                    // Nothing to steer without the drone.
+                    .run_if(resource_exists::<DroneConfig>)
+                    .run_if(|cfg: Res<HudConfig>| cfg.altitude_control),
+            );
+    }
+}
+
+/// Eases [`GroundSpeed`] toward the distance the player moved this frame over `dt`.
+pub fn track_ground_speed(
+    cfg: Res<HudConfig>,
+    time: Res<Time>,
+    player: Res<PlayerPos>,
+    mut ground_speed: ResMut<GroundSpeed>,
+) {
+    let dt = time.delta_secs();
+    if dt <= 0.0 {
+        return;
+    }
+    let instant = ground_speed
+        .last_xz
+        .map_or(0.0, |last| last.distance(player.xz) / dt);
+    let k = 1.0 - (-cfg.speed_smoothing * dt).exp();
+    ground_speed.speed += (instant - ground_speed.speed) * k;
+    ground_speed.last_xz = Some(player.xz);
+}
+
+/// Recounts [`RevealProgress`] and stamps the moment the grid becomes fully revealed.
+pub fn track_reveal_progress(
+    time: Res<Time>,
+    cells: Query<(), With<HCell>>,
+    revealed: Query<(), (With<HCell>, With<Revealed>)>,
+    mut progress: ResMut<RevealProgress>,
+) {
+    progress.total = cells.iter().count();
+    progress.revealed = revealed.iter().count();
+    let complete = progress.total > 0 && progress.revealed == progress.total;
+    match (complete, progress.completed_at) {
+        (true, None) => progress.completed_at = Some(time.elapsed_secs()),
+        (false, Some(_)) => progress.completed_at = None,
+        _ => {}
+    }
+}
+
+/// Draws the HUD strip centered along the top of the window.
+#[allow(clippy::too_many_arguments)]
+fn draw_hud(
+    mut egui_ctx: Single<&mut bevy_egui::EguiContext>,
+    camera: Single<&GlobalTransform, With<Player>>,
+    cfg: Res<HudConfig>,
+    time: Res<Time>,
+    active: Res<ActiveHex>,
+    ground: Res<GroundLevel>,
+    ground_speed: Res<GroundSpeed>,
+    progress: Res<RevealProgress>,
+    budget: Option<Res<BudgetStatus>>,
+    (orbs, inventory): (Option<Res<OrbScore>>, Option<Res<Inventory>>),
+    owners: Query<&Owner>,
+    energy: Option<Res<Energy>>,
+    wind: Option<Res<Wind>>,
+    tint: Option<Res<BandTint>>,
+    landmarks: Query<(&LandmarkMarker, &GlobalTransform)>,
+    locale: Res<Locale>,
+) {
+    let y = camera.translation().y;
+    let hex = active
+        .current
+        .map_or_else(|| "--".to_string(), |h| format!("{:+},{:+}", h.x, h.y));
+    let altitude = ground
+        .0
+        .map_or_else(|| "--".to_string(), |g| format!("{:.1}", y - g));
+    let fraction = progress.fraction();
+    let t = |key| locale.t(key);
+    let mut text = format!(
+        "{} {hex}   {} {altitude}   Y {y:.1}   {} {:03.0}°   {} {:.1}   {} {:.0}%",
+        t("HEX"),
+        t("ALT"),
+        t("HDG"),
+        compass_heading(*camera.forward()),
+        t("SPD"),
+        ground_speed.speed,
+        t("REV"),
+        fraction * 100.0,
+    );
+    if let Some(orbs) = orbs {
+        text += &format!("   {} {}/{}", t("ORB"), orbs.collected, orbs.total);
+    }
+    text += &format!("   {} {}", t("CLM"), claimed_count(owners, PLAYER));
+    if let Some(inventory) = inventory.filter(|i| !i.is_empty()) {
+        for kind in ResourceKind::ALL {
+            text += &format!("   {} {:.0}", t(kind.tag()), inventory.get(kind).floor());
+        }
+    }
+    if let Some(energy) = energy {
+        text += &format!("   {} {:.0}%", t("NRG"), energy.fraction() * 100.0);
+    }
+    if let Some(wind) = wind.filter(|w| w.velocity != Vec2::ZERO) {
+        let toward = compass_heading(wind.velocity.extend(0.0).xzy());
+        text += &format!(
+            "   {} {:.1} {toward:03.0}°",
+            t("WND"),
+            wind.velocity.length()
+        );
+    }
+    let here = camera.translation().xz();
+    if let Some((landmark, distance)) = landmarks
+        .iter()
+        .map(|(l, tf)| (l, tf.translation().xz().distance(here)))
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+    {
+        text += &format!("   {} {} {distance:.0}m", t("LMK"), t(landmark.name()));
+    }
+    let pulse = progress.completed_at.map_or(0.0, |at| {
+        celebration_pulse(time.elapsed_secs() - at, cfg.celebrate_secs)
+    });
+    let accent = tint.map_or(egui::Color32::from_rgb(0, 255, 200), |tint| {
+        let [r, g, b, _] = tint.0.to_srgba().to_u8_array();
+        egui::Color32::from_rgb(r, g, b)
+    });
+    let warning = budget.filter(|b| !b.overruns.is_empty()).map(|budget| {
+        let list = budget
+            .overruns
+            .iter()
+            .map(|o| format!("{} {}/{}", t(o.kind.label()), o.count, o.budget))
+            .collect::<Vec<_>>()
+            .join(", ");
+        locale.fmt("Entity budget exceeded: {0}", &[&list])
+    });
+
+    egui::Area::new(egui::Id::new("hud_strip"))
+        .anchor(egui::Align2::CENTER_TOP, [0.0, 8.0])
+        .interactable(false)
+        .show(egui_ctx.get_mut(), |ui| {
+            egui::Frame::new()
+                .fill(egui::Color32::from_black_alpha(140))
+                .inner_margin(egui::Margin::symmetric(10, 4))
+                .corner_radius(4.0)
+                .show(ui, |ui| {
+                    let label = ui.label(
+                        egui::RichText::new(text)
+                            .color(accent)
+                            .font(egui::FontId::monospace(14.0)),
+                    );
+                    let (bar, _) = ui.allocate_exact_size(
+                        egui::vec2(label.rect.width(), 3.0),
+                        egui::Sense::hover(),
+                    );
+                    let painter = ui.painter();
+                    painter.rect_filled(bar, 1.0, egui::Color32::from_white_alpha(24));
+                    let mut filled = bar;
+                    filled.set_width(bar.width() * fraction);
+                    painter.rect_filled(
+                        filled,
+                        1.0,
+                        accent.lerp_to_gamma(egui::Color32::WHITE, pulse),
+                    );
+                    if pulse > 0.0 {
+                        painter.rect_filled(
+                            bar.expand(2.0 * pulse),
+                            2.0,
+                            egui::Color32::from_white_alpha((60.0 * pulse) as u8),
+                        );
+                    }
+                    if let Some(warning) = warning {
+                        ui.label(
+                            egui::RichText::new(warning)
+                                .color(egui::Color32::from_rgb(255, 80, 60))
+                                .font(egui::FontId::monospace(12.0)),
+                        );
+                    }
+                });
+        });
+}
+
+/// Sets [`PlayerPos::offset`] (floored at [`DroneConfig::lowest_offset`]) and flags the move.
+pub fn set_offset(
+    player: &mut PlayerPos,
+    moved: &mut PlayerMoved,
+    drone: &DroneConfig,
+    offset: f32,
+) {
+    player.offset = offset.max(drone.lowest_offset);
+    moved.0 = true;
+}
+
+/// Jumps to an [`AltitudePreset`] on F2–F4.
+pub fn altitude_preset_keys(
+    keys: Res<ButtonInput<KeyCode>>,
+    cfg: Res<HudConfig>,
+    drone: Res<DroneConfig>,
+    mut player: ResMut<PlayerPos>,
+    mut moved: ResMut<PlayerMoved>,
+) {
+    if let Some(preset) = AltitudePreset::ALL
+        .into_iter()
+        .find(|p| keys.just_pressed(p.key()))
+    {
+        set_offset(&mut player, &mut moved, &drone, preset.offset(&cfg));
+    }
+}
+
+/// Draws the altitude slider and preset buttons centered along the bottom edge.
+///
+/// Clickable while `Inspecting` (free cursor); the new offset is flown to when flight resumes.
+fn draw_altitude_control(
+    mut egui_ctx: Single<&mut bevy_egui::EguiContext>,
+    cfg: Res<HudConfig>,
+    drone: Res<DroneConfig>,
+    mut player: ResMut<PlayerPos>,
+    mut moved: ResMut<PlayerMoved>,
+    locale: Res<Locale>,
+) {
+    let mut offset = player.offset;
+    egui::Area::new(egui::Id::new("altitude_control"))
+        .anchor(egui::Align2::CENTER_BOTTOM, [0.0, -8.0])
+        .show(egui_ctx.get_mut(), |ui| {
+            egui::Frame::new()
+                .fill(egui::Color32::from_black_alpha(140))
+                .inner_margin(egui::Margin::symmetric(10, 4))
+                .corner_radius(4.0)
+                .show(ui, |ui| {
+                    ui.horizontal(|ui| {
+                        ui.monospace(locale.t("ALT"));
+                        ui.add(
+                            egui::Slider::new(
+                                &mut offset,
+                                drone.lowest_offset..=cfg.max_offset.max(drone.lowest_offset),
+                            )
+                            .fixed_decimals(1),
+                        );
+                        for preset in AltitudePreset::ALL {
+                            let selected = (player.offset - preset.offset(&cfg)).abs() < 0.05;
+                            if ui
+                                .selectable_label(selected, locale.t(preset.label()))
+                                .on_hover_text(crate::controls::key_label(preset.key()))
+                                .clicked()
+                            {
+                                offset = preset.offset(&cfg);
+                            }
+                        }
+                    });
+                });
+        });
+    if offset != player.offset {
+        set_offset(&mut player, &mut moved, &drone, offset);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compass_heading_follows_the_camera_yaw() {
+        assert_eq!(compass_heading(Vec3::NEG_Z), 0.0);
+        assert!((compass_heading(Vec3::X) - 90.0).abs() < 1e-4);
+        assert!((compass_heading(Vec3::Z) - 180.0).abs() < 1e-4);
+        assert!((compass_heading(Vec3::NEG_X) - 270.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn celebration_pulse_fades_out() {
+        assert_eq!(celebration_pulse(0.0, 3.0), 1.0);
+        assert!(celebration_pulse(1.5, 3.0) < 0.5);
+        assert_eq!(celebration_pulse(3.0, 3.0), 0.0);
+        assert_eq!(celebration_pulse(-0.1, 3.0), 0.0);
+    }
+
+    #[test]
+    fn reveal_progress_stamps_and_clears_completion() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<RevealProgress>()
+            .add_systems(Update, track_reveal_progress);
+        let a = app
+            .world_mut()
+            .spawn((
+                HCell {
+                    hex: hexx::Hex::ZERO,
+                },
+                Revealed,
+            ))
+            .id();
+        let b = app.world_mut().spawn(HCell { hex: hexx::Hex::X }).id();
+        app.update();
+        let progress = app.world().resource::<RevealProgress>();
+        assert_eq!((progress.revealed, progress.total), (1, 2));
+        assert_eq!(progress.fraction(), 0.5);
+        assert!(progress.completed_at.is_none());
+
+        app.world_mut().entity_mut(b).insert(Revealed);
+        app.update();
+        assert!(
+            app.world()
+                .resource::<RevealProgress>()
+                .completed_at
+                .is_some()
+        );
+
+        // A regenerated grid starts unrevealed again.
+        app.world_mut().entity_mut(a).remove::<Revealed>();
+        app.update();
+        assert!(
+            app.world()
+                .resource::<RevealProgress>()
+                .completed_at
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn preset_keys_set_the_offset_above_the_floor() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<ButtonInput<KeyCode>>()
+            .insert_resource(HudConfig {
+                skim_offset: 0.5,
+                ..default()
+            })
+            .init_resource::<DroneConfig>()
+            .init_resource::<PlayerPos>()
+            .init_resource::<PlayerMoved>()
+            .add_systems(Update, altitude_preset_keys);
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::F4);
+        app.update();
+        assert_eq!(
+            app.world().resource::<PlayerPos>().offset,
+            HudConfig::default().survey_offset
+        );
+        assert!(app.world().resource::<PlayerMoved>().0);
+
+        let mut keys = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        keys.clear();
+        keys.press(KeyCode::F2);
+        app.update();
+        // Skim below the drone floor clamps to `lowest_offset`.
+        assert_eq!(
+            app.world().resource::<PlayerPos>().offset,
+            DroneConfig::default().lowest_offset
+        );
+    }
+
+    #[test]
+    fn ground_speed_eases_toward_the_player_velocity() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(
+                std::time::Duration::from_millis(100),
+            ))
+            .init_resource::<HudConfig>()
+            .init_resource::<PlayerPos>()
+            .init_resource::<GroundSpeed>()
+            .add_systems(Update, track_ground_speed);
+        app.update();
+        app.update();
+        assert_eq!(app.world().resource::<GroundSpeed>().speed, 0.0);
+
+        // 1 unit per 0.1 s frame = 10 units/s.
+        for _ in 0..30 {
+            app.world_mut().resource_mut::<PlayerPos>().xz.x += 1.0;
+            app.update();
+        }
+        let speed = app.world().resource::<GroundSpeed>().speed;
+        assert!((speed - 10.0).abs() < 0.1, "speed {speed}");
+    }
+}
@@ -5,9 +5,18 @@
 //!
 //! The actual animation is built as a procedural `AnimationClip` inside
 //! [`crate::drone::systems::spawn_drone`] using Bevy's animation graph.
-//! This module only provides the config resource.
+//! This module provides the config resource plus a "Press Space to skip" prompt with a
+//! phase progress bar; skipping snaps to the final pose and hands over to `Arming`
+//! exactly like the clip's `IntroComplete` event.
 
 use bevy::prelude::*;
+use bevy_egui::egui;
+
+use crate::GameState;
+use crate::controls::{Binding, BindingCategory, RegisterBinding, Trigger};
+use crate::drone::Player;
+use crate::drone::systems::IntroAnimNode;
+use crate::locale::Locale;
 
 /// Per-plugin configuration for the intro camera animation.
 #[derive(Resource, Clone, Debug, Reflect)]
@@ -22,6 +31,26 @@ pub struct IntroConfig {
     pub tilt_down_angle: f32,
 }
 
+impl IntroConfig {
+    /// Total length of the intro clip (seconds).
+    pub fn total_duration(&self) -> f32 {
+        self.tilt_up_duration + self.highlight_delay + self.tilt_down_duration
+    }
+
+    /// Phase name and overall progress in `0.0..=1.0` at `elapsed` seconds into the clip.
+    pub fn phase_at(&self, elapsed: f32) -> (&'static str, f32) {
+        let phase = if elapsed < self.tilt_up_duration {
+            "tilt up"
+        } else if elapsed < self.tilt_up_duration + self.highlight_delay {
+            "hold"
+        } else {
+            "tilt down"
+        };
+        let progress = (elapsed / self.total_duration().max(f32::EPSILON)).clamp(0.0, 1.0);
+        (phase, progress)
+    }
+}
+
 impl Default for IntroConfig {
     fn default() -> Self {
         Self {
@@ -39,6 +68,86 @@ pub struct IntroPlugin(pub IntroConfig);
 impl Plugin for IntroPlugin {
     fn build(&self, app: &mut App) {
         app.register_type::<IntroConfig>()
-            .insert_resource(self.0.clone());
+            .insert_resource(self.0.clone())
+            .register_binding(Binding::new(
+                BindingCategory::App,
+                "Skip intro",
+                [Trigger::Key(KeyCode::Space)],
+            ))
+            .add_systems(
+                Update,
+                (skip_intro, draw_intro_overlay)
+                    .chain()
+                    .run_if(in_state(GameState::Intro)),
+            );
+    }
+}
+
+/// On Space, stops the intro clip at its final pose and moves on to `Arming`.
+pub fn skip_intro(
+    keys: Res<ButtonInput<KeyCode>>,
+    cfg: Res<IntroConfig>,
+    node: Res<IntroAnimNode>,
+    camera: Single<(&mut Transform, &mut AnimationPlayer), With<Player>>,
+    mut next: ResMut<NextState<GameState>>,
+) {
+    if !keys.just_pressed(KeyCode::Space) {
+        return;
+    }
+    let (mut transform, mut anim) = camera.into_inner();
+    anim.stop(node.0);
+    // The clip only animates pitch: keep the spawn yaw, land on the tilt-down angle.
+    let (yaw, _, _) = transform.rotation.to_euler(EulerRot::YXZ);
+    transform.rotation =
+        Quat::from_euler(EulerRot::YXZ, yaw, -cfg.tilt_down_angle.to_radians(), 0.0);
+    next.set(GameState::Arming);
+}
+
+/// Draws the skip prompt and a thin progress bar with the current phase.
+fn draw_intro_overlay(
+    mut egui_ctx: Single<&mut bevy_egui::EguiContext>,
+    cfg: Res<IntroConfig>,
+    node: Res<IntroAnimNode>,
+    anim: Single<&AnimationPlayer, With<Player>>,
+    locale: Res<Locale>,
+) {
+    const BAR_SIZE: egui::Vec2 = egui::vec2(220.0, 2.0);
+    let elapsed = anim.animation(node.0).map_or(0.0, |a| a.seek_time());
+    let (phase, progress) = cfg.phase_at(elapsed);
+    let faint = egui::Color32::from_white_alpha(140);
+
+    egui::Area::new(egui::Id::new("intro_overlay"))
+        .anchor(egui::Align2::CENTER_BOTTOM, [0.0, -32.0])
+        .interactable(false)
+        .show(egui_ctx.get_mut(), |ui| {
+            ui.vertical_centered(|ui| {
+                ui.label(
+                    egui::RichText::new(locale.t("Press Space to skip"))
+                        .color(faint)
+                        .font(egui::FontId::monospace(13.0)),
+                );
+                let (bar, _) = ui.allocate_exact_size(BAR_SIZE, egui::Sense::hover());
+                let painter = ui.painter();
+                painter.rect_filled(bar, 1.0, egui::Color32::from_white_alpha(30));
+                let mut filled = bar;
+                filled.set_width(bar.width() * progress);
+                painter.rect_filled(filled, 1.0, faint);
+                ui.small(egui::RichText::new(locale.t(phase)).color(faint));
+            });
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn phases_follow_the_configured_durations() {
+        let cfg = IntroConfig::default();
+        assert_eq!(cfg.phase_at(0.0), ("tilt up", 0.0));
+        assert_eq!(cfg.phase_at(cfg.tilt_up_duration + 0.1).0, "hold");
+        let (phase, progress) = cfg.phase_at(cfg.total_duration() + 1.0);
+        assert_eq!(phase, "tilt down");
+        assert_eq!(progress, 1.0);
     }
 }
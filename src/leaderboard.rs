@@ -0,0 +1,448 @@
+//! Local leaderboard of time-trial results.
+//!
+//! Every [`RaceFinished`] becomes a [`RaceRun`] (world seed, course id, time, date) in the
+//! [`Leaderboard`], saved to [`LeaderboardConfig::path`] as RON (native only) and loaded
+//! again at startup. Runs are ranked per course: a top-three finish earns a [`Medal`], which
+//! is toasted and kept in [`SessionMedals`] for the session summary. N toggles a panel
+//! listing the best runs per course of one world, starting with the current grid's.
+
+use bevy::prelude::*;
+use bevy_egui::{EguiContexts, egui};
+use serde::{Deserialize, Serialize};
+
+use crate::controls::{Binding, BindingCategory, RegisterBinding, Trigger};
+use crate::h_terrain::HTerrainConfig;
+use crate::locale::Locale;
+use crate::race::{RaceFinished, format_race_time};
+use crate::toast::Toast;
+use crate::{GameState, persist};
+
+/// Per-plugin configuration for the leaderboard.
+#[derive(Resource, Clone, Debug, Reflect)]
+pub struct LeaderboardConfig {
+    /// Save runs to [`LeaderboardConfig::path`] and load them at startup (native only).
+    pub persist: bool,
+    /// File the runs are persisted to.
+    pub path: String,
+    /// Runs listed per course in the panel.
+    pub rows: usize,
+}
+
+impl Default for LeaderboardConfig {
+    fn default() -> Self {
+        Self {
+            persist: true,
+            path: "leaderboard.ron".into(),
+            rows: 10,
+        }
+    }
+}
+
+/// One finished race.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RaceRun {
+    /// Height seed of the world.
+    pub seed: u32,
+    /// [`course_id`](crate::race::course_id) of the checkpoints.
+    pub course: u32,
+    /// Finish time in seconds.
+    pub secs: f32,
+    /// Finish date in seconds since the Unix epoch (0 when unknown).
+    pub date: u64,
+}
+
+/// Top-three finish on a course.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+pub enum Medal {
+    /// Fastest run.
+    Gold,
+    /// Second fastest.
+    Silver,
+    /// Third fastest.
+    Bronze,
+}
+
+impl Medal {
+    /// Medal for a 1-based rank, if it earns one.
+    pub fn for_rank(rank: usize) -> Option<Self> {
+        match rank {
+            1 => Some(Self::Gold),
+            2 => Some(Self::Silver),
+            3 => Some(Self::Bronze),
+            _ => None,
+        }
+    }
+
+    /// English name (a [`Locale`] key).
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Gold => "Gold",
+            Self::Silver => "Silver",
+            Self::Bronze => "Bronze",
+        }
+    }
+
+    /// Display color.
+    pub fn color(self) -> egui::Color32 {
+        match self {
+            Self::Gold => egui::Color32::from_rgb(255, 200, 40),
+            Self::Silver => egui::Color32::from_rgb(200, 210, 220),
+            Self::Bronze => egui::Color32::from_rgb(205, 127, 50),
+        }
+    }
+}
+
+/// Every recorded run, in finishing order.
+#[derive(Resource, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Leaderboard {
+    /// Recorded runs.
+    pub runs: Vec<RaceRun>,
+}
+
+impl Leaderboard {
+    /// Adds `run` and returns its 1-based rank on its course (ties go to the earlier run).
+    pub fn add(&mut self, run: RaceRun) -> usize {
+        let rank = 1 + self
+            .runs
+            .iter()
+            .filter(|r| r.seed == run.seed && r.course == run.course && r.secs <= run.secs)
+            .count();
+        self.runs.push(run);
+        rank
+    }
+
+    /// Fastest time on `course` of world `seed`, if it was ever raced.
+    pub fn best(&self, seed: u32, course: u32) -> Option<f32> {
+        self.runs
+            .iter()
+            .filter(|r| r.seed == seed && r.course == course)
+            .map(|r| r.secs)
+            .min_by(f32::total_cmp)
+    }
+
+    /// Worlds with at least one run, ascending.
+    pub fn worlds(&self) -> Vec<u32> {
+        let mut seeds: Vec<u32> = self.runs.iter().map(|r| r.seed).collect();
+        seeds.sort_unstable();
+        seeds.dedup();
+        seeds
+    }
+
+    /// Runs on `course` of world `seed`, fastest first (earlier first among ties).
+    pub fn course_runs(&self, seed: u32, course: u32) -> Vec<RaceRun> {
+        let mut runs: Vec<RaceRun> = self
+            .runs
+            .iter()
+            .filter(|r| r.seed == seed && r.course == course)
+            .copied()
+            .collect();
+        runs.sort_by(|a, b| a.secs.total_cmp(&b.secs));
+        runs
+    }
+
+    /// Courses raced on world `seed` with their runs, the course with the fastest run first.
+    pub fn world_courses(&self, seed: u32) -> Vec<(u32, Vec<RaceRun>)> {
+        let mut courses: Vec<u32> = self
+            .runs
+            .iter()
+            .filter(|r| r.seed == seed)
+            .map(|r| r.course)
+            .collect();
+        courses.sort_unstable();
+        courses.dedup();
+        let mut courses: Vec<_> = courses
+            .into_iter()
+            .map(|course| (course, self.course_runs(seed, course)))
+            .collect();
+        courses.sort_by(|a, b| a.1[0].secs.total_cmp(&b.1[0].secs));
+        courses
+    }
+}
+
+/// Medals earned since startup, in finishing order.
+#[derive(Resource, Default, Debug)]
+pub struct SessionMedals(pub Vec<(Medal, RaceRun)>);
+
+/// Whether the leaderboard panel is shown.
+#[derive(Resource, Default, Reflect)]
+pub struct LeaderboardVisible(pub bool);
+
+/// Seconds since the Unix epoch (0 on WASM, where the system clock isn't available).
+pub fn unix_now() -> u64 {
+    #[cfg(not(target_arch = "wasm32"))]
+    return std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    #[cfg(target_arch = "wasm32")]
+    0
+}
+
+/// `YYYY-MM-DD` (UTC) for seconds since the Unix epoch, or `-` for 0.
+pub fn format_date(unix_secs: u64) -> String {
+    if unix_secs == 0 {
+        return "-".into();
+    }
+    // Civil date from days since 1970-01-01 (Howard Hinnant's algorithm).
+    let z = (unix_secs / 86_400) as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+/// Leaderboard plugin.
+pub struct LeaderboardPlugin {
+    /// Per-plugin configuration.
+    pub config: LeaderboardConfig,
+}
+
+impl Plugin for LeaderboardPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<LeaderboardConfig>()
+            .register_type::<LeaderboardVisible>()
+            .insert_resource(self.config.clone())
+            .init_resource::<Leaderboard>()
+            .init_resource::<SessionMedals>()
+            .init_resource::<LeaderboardVisible>()
+            .register_binding(Binding::new(
+                BindingCategory::App,
+                "Race leaderboard",
+                [Trigger::Key(KeyCode::KeyN)],
+            ));
+
+        app.add_systems(
+            Startup,
+            load_leaderboard.run_if(|cfg: Res<LeaderboardConfig>| cfg.persist),
+        );
+
+        app.add_systems(
+            Update,
+            record_runs
+                .run_if(on_message::<RaceFinished>)
+                .after(crate::race::tick_race),
+        )
+        .add_systems(
+            Update,
+            (
+                toggle_leaderboard,
+                draw_leaderboard_panel.run_if(|v: Res<LeaderboardVisible>| v.0),
+            )
+                .chain()
+                .run_if(in_state(GameState::Running).or(in_state(GameState::Inspecting))),
+        );
+    }
+}
+
+/// Reads persisted runs from disk, if present.
+fn load_leaderboard(cfg: Res<LeaderboardConfig>, mut board: ResMut<Leaderboard>) {
+    *board = persist::load_ron(&cfg.path, "leaderboard");
+}
+
+/// Writes all runs to disk when [`LeaderboardConfig::persist`] is set (no-op on WASM).
+fn save_leaderboard(board: &Leaderboard, cfg: &LeaderboardConfig) {
+    if cfg.persist {
+        persist::save_ron(board, &cfg.path, "leaderboard");
+    }
+}
+
+/// Adds finished races to the [`Leaderboard`] and hands out medals.
+fn record_runs(
+    cfg: Res<LeaderboardConfig>,
+    mut finished: MessageReader<RaceFinished>,
+    mut board: ResMut<Leaderboard>,
+    mut medals: ResMut<SessionMedals>,
+    mut toasts: MessageWriter<Toast>,
+    locale: Res<Locale>,
+) {
+    for &RaceFinished { seed, course, secs } in finished.read() {
+        let run = RaceRun {
+            seed,
+            course,
+            secs,
+            date: unix_now(),
+        };
+        if let Some(medal) = Medal::for_rank(board.add(run)) {
+            medals.0.push((medal, run));
+            toasts.write(Toast(locale.fmt(
+                "{0} medal on course {1}",
+                &[&locale.t(medal.label()), &format!("{course:08x}")],
+            )));
+        }
+    }
+    save_leaderboard(&board, &cfg);
+}
+
+/// N shows or hides the leaderboard panel, unless an egui text field has keyboard focus.
+fn toggle_leaderboard(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut contexts: EguiContexts,
+    mut visible: ResMut<LeaderboardVisible>,
+) {
+    if contexts
+        .ctx_mut()
+        .is_ok_and(|ctx| ctx.wants_keyboard_input())
+    {
+        return;
+    }
+    if keys.just_pressed(KeyCode::KeyN) {
+        visible.0 = !visible.0;
+    }
+}
+
+/// Lists the best runs per course of the selected world (the current grid's by default).
+fn draw_leaderboard_panel(
+    mut egui_ctx: Single<&mut bevy_egui::EguiContext>,
+    cfg: Res<LeaderboardConfig>,
+    terrain: Res<HTerrainConfig>,
+    board: Res<Leaderboard>,
+    mut world: Local<Option<u32>>,
+    locale: Res<Locale>,
+) {
+    let current = terrain.grid.height_noise_seed;
+    let mut worlds = board.worlds();
+    if !worlds.contains(&current) {
+        worlds.insert(0, current);
+    }
+    let selected = world.get_or_insert(current);
+    let mono = egui::FontId::monospace(13.0);
+    egui::Window::new(locale.t("Leaderboard"))
+        .id(egui::Id::new("leaderboard_panel"))
+        .default_pos([8.0, 200.0])
+        .resizable(false)
+        .show(egui_ctx.get_mut(), |ui| {
+            let world_label = |seed: u32| {
+                let name = locale.fmt("World {0}", &[&seed]);
+                if seed == current {
+                    format!("{name} *")
+                } else {
+                    name
+                }
+            };
+            egui::ComboBox::from_id_salt("leaderboard_world")
+                .selected_text(world_label(*selected))
+                .show_ui(ui, |ui| {
+                    for &seed in &worlds {
+                        ui.selectable_value(selected, seed, world_label(seed));
+                    }
+                });
+            let courses = board.world_courses(*selected);
+            if courses.is_empty() {
+                ui.label(locale.t("No races finished yet"));
+                return;
+            }
+            for (course, runs) in courses {
+                ui.separator();
+                ui.label(
+                    egui::RichText::new(locale.fmt("Course {0}", &[&format!("{course:08x}")]))
+                        .strong(),
+                );
+                egui::Grid::new(("leaderboard_course", course))
+                    .num_columns(3)
+                    .spacing([16.0, 2.0])
+                    .show(ui, |ui| {
+                        for (i, run) in runs.iter().take(cfg.rows).enumerate() {
+                            let rank =
+                                egui::RichText::new(format!("{:>2}.", i + 1)).font(mono.clone());
+                            ui.label(match Medal::for_rank(i + 1) {
+                                Some(medal) => rank.color(medal.color()),
+                                None => rank,
+                            });
+                            ui.label(
+                                egui::RichText::new(format_race_time(run.secs)).font(mono.clone()),
+                            );
+                            ui.label(egui::RichText::new(format_date(run.date)).font(mono.clone()));
+                            ui.end_row();
+                        }
+                    });
+            }
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(seed: u32, course: u32, secs: f32) -> RaceRun {
+        RaceRun {
+            seed,
+            course,
+            secs,
+            date: 0,
+        }
+    }
+
+    #[test]
+    fn runs_rank_per_course() {
+        let mut board = Leaderboard::default();
+        assert_eq!(board.add(run(1, 10, 50.0)), 1);
+        assert_eq!(board.add(run(1, 10, 40.0)), 1);
+        // Ties go to the earlier run.
+        assert_eq!(board.add(run(1, 10, 50.0)), 3);
+        assert_eq!(board.add(run(1, 10, 60.0)), 4);
+        // Other courses and worlds rank on their own.
+        assert_eq!(board.add(run(1, 20, 90.0)), 1);
+        assert_eq!(board.add(run(2, 10, 70.0)), 1);
+
+        assert_eq!(board.worlds(), [1, 2]);
+        let times: Vec<f32> = board.course_runs(1, 10).iter().map(|r| r.secs).collect();
+        assert_eq!(times, [40.0, 50.0, 50.0, 60.0]);
+        assert_eq!(board.best(1, 10), Some(40.0));
+        assert_eq!(board.best(1, 30), None);
+        let courses: Vec<u32> = board.world_courses(1).iter().map(|c| c.0).collect();
+        assert_eq!(courses, [10, 20]);
+        assert_eq!(Medal::for_rank(3), Some(Medal::Bronze));
+        assert_eq!(Medal::for_rank(4), None);
+    }
+
+    #[test]
+    fn dates_format_as_utc_days() {
+        assert_eq!(format_date(0), "-");
+        assert_eq!(format_date(86_399), "1970-01-01");
+        assert_eq!(format_date(951_782_400), "2000-02-29");
+        assert_eq!(format_date(1_792_195_200), "2026-10-17");
+    }
+
+    #[test]
+    fn finishes_are_recorded_with_medals() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_message::<RaceFinished>()
+            .add_message::<Toast>()
+            .insert_resource(LeaderboardConfig {
+                persist: false,
+                ..default()
+            })
+            .init_resource::<Leaderboard>()
+            .init_resource::<SessionMedals>()
+            .init_resource::<Locale>()
+            .add_systems(Update, record_runs.run_if(on_message::<RaceFinished>));
+        for secs in [30.0, 20.0, 25.0, 40.0] {
+            app.world_mut().write_message(RaceFinished {
+                seed: 7,
+                course: 3,
+                secs,
+            });
+            app.update();
+        }
+        let w = app.world();
+        assert_eq!(w.resource::<Leaderboard>().runs.len(), 4);
+        let medals: Vec<Medal> = w
+            .resource::<SessionMedals>()
+            .0
+            .iter()
+            .map(|m| m.0)
+            .collect();
+        assert_eq!(medals, [Medal::Gold, Medal::Gold, Medal::Silver]);
+        assert!(
+            w.resource::<SessionMedals>()
+                .0
+                .iter()
+                .all(|(_, r)| r.date > 0)
+        );
+    }
+}
@@ -0,0 +1,230 @@
+#![warn(missing_docs)]
+//! Hex terrain viewer with neon edge lighting.
+//!
+//! Renders a hexagonal grid with noise-derived terrain heights, progressive
+//! edge/face reveal as the drone moves, and bloom post-processing.
+//!
+//! Every feature is a plugin with its own config resource, so other Bevy apps can embed the
+//! viewer without the `hex-terrain` binary: add [`CorePlugin`] (shared state, resources, and
+//! messages), then [`TerrainPlugin`], [`DronePlugin`], [`IntroPlugin`], and the feature plugins
+//! they want. `src/main.rs` is the reference wiring.
+//!
+//! Most feature plugins need nothing more. The ones built on another feature need it too:
+//!
+//! - [`race::RacePlugin`] needs [`gates::GatesPlugin`].
+//! - [`leaderboard::LeaderboardPlugin`] needs [`race::RacePlugin`].
+//! - [`photo::PhotoPlugin`] needs [`objectives::ObjectivesPlugin`].
+//! - [`session::SessionPlugin`] needs [`props::PropsPlugin`].
+//! - [`budget::BudgetPlugin`] needs [`settings::SettingsPlugin`].
+//! - [`console::ConsolePlugin`] needs [`quakes::QuakesPlugin`] and [`settings::SettingsPlugin`].
+//! - [`pause::PausePlugin`] needs [`race::RacePlugin`], [`scoring::ScoringPlugin`], and
+//!   [`settings::SettingsPlugin`].
+//! - [`settings::SettingsPlugin`] needs [`budget::BudgetPlugin`],
+//!   [`frame_limit::FrameLimitPlugin`], [`hud::HudPlugin`], [`post_fx::PostFxPlugin`], and
+//!   [`neon_ui::NeonUiPlugin`].
+
+pub mod beacons;
+pub mod bookmarks;
+pub mod budget;
+pub mod claims;
+pub mod console;
+pub mod controls;
+pub mod drone;
+pub mod elevation;
+pub mod energy;
+pub mod frame_limit;
+pub mod gates;
+pub mod ghost;
+pub mod h_terrain;
+pub mod harvest;
+pub mod hazards;
+pub mod headless;
+pub mod hud;
+pub mod intro;
+pub mod leaderboard;
+pub mod locale;
+pub mod math;
+pub mod neon_ui;
+pub mod objectives;
+pub mod orbs;
+pub mod pause;
+pub mod perf;
+pub mod persist;
+pub mod photo;
+pub mod post_fx;
+pub mod props;
+pub mod quakes;
+pub mod race;
+pub mod radar;
+pub mod scoring;
+pub mod session;
+pub mod settings;
+pub mod telemetry;
+pub mod teleporters;
+pub mod toast;
+pub mod wind;
+pub mod world_map;
+
+pub use drone::{DroneConfig, DronePlugin};
+pub use h_terrain::{HTerrainConfig, HTerrainPlugin as TerrainPlugin};
+pub use intro::{IntroConfig, IntroPlugin};
+
+use bevy::prelude::*;
+use bevy::window::{CursorGrabMode, CursorOptions};
+
+/// Application-wide game state, used for system scheduling.
+#[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash, Reflect)]
+pub enum GameState {
+    /// Intro camera animation playing.
+    #[default]
+    Intro,
+    /// Pipe swing-in animation after intro.
+    Arming,
+    /// Normal gameplay — drone movement + terrain reveal.
+    Running,
+    /// Inspector overlay active (Tab to toggle).
+    Inspecting,
+    /// Pause menu open (Escape to toggle); virtual time stopped.
+    Paused,
+    /// Full-screen world map open (M to toggle); virtual time stopped.
+    Map,
+    /// End-of-session score summary (grid complete or Quit); virtual time stopped.
+    Summary,
+}
+
+/// Rule set layered on top of the viewer.
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[cfg_attr(not(target_arch = "wasm32"), derive(clap::ValueEnum))]
+pub enum GameMode {
+    /// Free flight, no limits.
+    #[default]
+    Viewer,
+    /// Flight drains energy; land on charger hexes to refill.
+    Survival,
+}
+
+/// CLI debug flag exposed as a resource for verbose logging.
+#[derive(Resource)]
+pub struct DebugFlag(pub bool);
+
+/// Shared Startup set: terrain seed systems run in this set,
+/// so that [`drone::systems::spawn_drone`] can be ordered after them.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TerrainSeededPhase;
+
+/// Player world position. Drone writes xz + offset.
+#[derive(Resource, Default, Reflect)]
+pub struct PlayerPos {
+    /// Horizontal position on the terrain plane.
+    pub xz: Vec2,
+    /// User-controlled vertical offset above ground (Q/E/scroll).
+    pub offset: f32,
+    /// Unit xz direction of the current movement (zero while hovering).
+    pub heading: Vec2,
+}
+
+/// Terrain height beneath the player, written by h_terrain.
+/// `None` until terrain has been seeded at startup.
+#[derive(Resource, Default, Reflect)]
+pub struct GroundLevel(pub Option<f32>);
+
+/// Highest terrain height sampled ahead of the player along [`PlayerPos::heading`],
+/// written by h_terrain. `None` while the player is not moving.
+#[derive(Resource, Default, Reflect)]
+pub struct GroundAhead(pub Option<f32>);
+
+/// Set by drone/intro when [`PlayerPos`] xz or offset changes.
+/// Consumed (reset to `false`) by terrain height systems.
+#[derive(Resource, Default, Reflect)]
+pub struct PlayerMoved(pub bool);
+
+/// Shared state and resources the other plugins read: [`GameState`], [`GameMode`],
+/// [`DebugFlag`], [`PlayerPos`], [`PlayerMoved`], [`GroundLevel`], [`GroundAhead`], the
+/// [`Toast`](toast::Toast) message, and an English [`Locale`](locale::Locale) (replaced by
+/// [`LocalePlugin`](locale::LocalePlugin) when added).
+///
+/// Add it after `DefaultPlugins` (it initializes a state) and before the feature plugins.
+pub struct CorePlugin {
+    /// Verbose logging ([`DebugFlag`]).
+    pub debug: bool,
+    /// Rule set layered on top of the viewer.
+    pub mode: GameMode,
+}
+
+impl Plugin for CorePlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<GameState>()
+            .register_type::<GameMode>()
+            .register_type::<PlayerPos>()
+            .register_type::<PlayerMoved>()
+            .register_type::<GroundLevel>()
+            .register_type::<GroundAhead>()
+            .init_state::<GameState>()
+            .init_resource::<PlayerPos>()
+            .init_resource::<PlayerMoved>()
+            .init_resource::<GroundLevel>()
+            .init_resource::<GroundAhead>()
+            .init_resource::<locale::Locale>()
+            .add_message::<toast::Toast>()
+            .insert_resource(DebugFlag(self.debug))
+            .insert_resource(self.mode);
+    }
+}
+
+/// Frees the cursor (visible, ungrabbed) or captures it (hidden, confined, centered).
+pub fn set_cursor_free(windows: &mut Query<(&mut CursorOptions, &mut Window)>, free: bool) {
+    for (mut opts, mut window) in windows {
+        if free {
+            opts.visible = true;
+            opts.grab_mode = CursorGrabMode::None;
+        } else {
+            opts.visible = false;
+            opts.grab_mode = CursorGrabMode::Confined;
+            let center = Vec2::new(window.width() / 2.0, window.height() / 2.0);
+            window.set_cursor_position(Some(center));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::input::InputPlugin;
+    use bevy::state::app::StatesPlugin;
+
+    use super::*;
+    use crate::hud::{HudConfig, HudPlugin, RevealProgress};
+
+    /// The HUD reads feature state (orbs, budgets, energy, …) only when those plugins are added.
+    #[test]
+    fn hud_runs_on_core_and_terrain_alone() {
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            StatesPlugin,
+            AssetPlugin::default(),
+            InputPlugin,
+        ))
+        .init_asset::<Mesh>()
+        .init_asset::<StandardMaterial>()
+        .init_asset::<Image>()
+        .add_plugins(CorePlugin {
+            debug: false,
+            mode: GameMode::Viewer,
+        })
+        .add_plugins(TerrainPlugin {
+            config: HTerrainConfig::default(),
+            after_player_movement: None,
+            terrain_seeded_set: None,
+        })
+        .add_plugins(HudPlugin {
+            config: HudConfig::default(),
+        });
+        app.update();
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Running);
+        app.update();
+        app.update();
+        assert!(app.world().resource::<RevealProgress>().total > 0);
+    }
+}
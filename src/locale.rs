@@ -0,0 +1,451 @@
+//! UI localization: a key → string map for HUD, menu, and toast text.
+//!
+//! Keys are the English source strings, so English needs no table and any untranslated key
+//! falls back to English. [`Locale::t`] looks a string up; [`Locale::fmt`] also fills `{0}`,
+//! `{1}`, … placeholders, which translations may reorder. The language comes from
+//! [`LocaleConfig`], picked in the settings panel and saved with the exported settings.
+//!
+//! Developer panels (inspector, hex/debug/stats panels, perf overlay, console) and tuning
+//! slider names mirror config fields and stay English.
+
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use bevy::prelude::*;
+
+/// A shipped UI language.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect)]
+pub enum Language {
+    /// English (the source strings).
+    #[default]
+    English,
+    /// German.
+    German,
+}
+
+impl Language {
+    /// All languages, in picker order.
+    pub const ALL: [Self; 2] = [Self::English, Self::German];
+
+    /// Language name in the language itself, for the picker.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::English => "English",
+            Self::German => "Deutsch",
+        }
+    }
+
+    /// Translations from the English source strings (empty for English).
+    fn table(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Self::English => &[],
+            Self::German => GERMAN,
+        }
+    }
+}
+
+/// Per-plugin configuration for localization.
+#[derive(Resource, Clone, Debug, Default, Reflect)]
+pub struct LocaleConfig {
+    /// UI language.
+    pub language: Language,
+}
+
+/// Strings for the active [`Language`], rebuilt when [`LocaleConfig`] changes.
+#[derive(Resource, Default)]
+pub struct Locale {
+    language: Language,
+    strings: HashMap<&'static str, &'static str>,
+}
+
+impl Locale {
+    /// Loads the strings for `language`.
+    pub fn new(language: Language) -> Self {
+        Self {
+            language,
+            strings: language.table().iter().copied().collect(),
+        }
+    }
+
+    /// Translation of `key`, or `key` itself when untranslated.
+    pub fn t<'a>(&self, key: &'a str) -> &'a str {
+        self.strings.get(key).copied().unwrap_or(key)
+    }
+
+    /// Translation of `key` with `{0}`, `{1}`, … replaced by `args`.
+    pub fn fmt(&self, key: &str, args: &[&dyn Display]) -> String {
+        args.iter()
+            .enumerate()
+            .fold(self.t(key).to_string(), |text, (i, arg)| {
+                text.replace(&format!("{{{i}}}"), &arg.to_string())
+            })
+    }
+}
+
+/// Localization plugin.
+pub struct LocalePlugin {
+    /// Per-plugin configuration.
+    pub config: LocaleConfig,
+}
+
+impl Plugin for LocalePlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Language>()
+            .register_type::<LocaleConfig>()
+            .insert_resource(self.config.clone())
+            .insert_resource(Locale::new(self.config.language))
+            .add_systems(
+                PreUpdate,
+                rebuild_locale.run_if(resource_changed::<LocaleConfig>),
+            );
+    }
+}
+
+/// Reloads [`Locale`] when the configured language changes.
+fn rebuild_locale(cfg: Res<LocaleConfig>, mut locale: ResMut<Locale>) {
+    if locale.language != cfg.language {
+        *locale = Locale::new(cfg.language);
+    }
+}
+
+/// German strings, keyed by the English source.
+const GERMAN: &[(&str, &str)] = &[
+    // HUD
+    ("HEX", "HEX"),
+    ("ALT", "HÖHE"),
+    ("HDG", "KURS"),
+    ("SPD", "TEMPO"),
+    ("REV", "ENTD"),
+    ("ORB", "KUGL"),
+    ("RACE", "RENNEN"),
+    ("BEST", "BESTZEIT"),
+    ("CLM", "BES"),
+    ("ORE", "ERZ"),
+    ("XTL", "KRS"),
+    ("NRG", "ENRG"),
+    ("LMK", "WZ"),
+    ("WND", "WIND"),
+    ("skim", "Tiefflug"),
+    ("cruise", "Reise"),
+    ("survey", "Übersicht"),
+    ("cliff", "Klippe"),
+    ("landmark", "Wahrzeichen"),
+    // Intro
+    ("Press Space to skip", "Leertaste zum Überspringen"),
+    ("tilt up", "Blick hoch"),
+    ("hold", "halten"),
+    ("tilt down", "Blick runter"),
+    // Pause menu
+    ("PAUSED", "PAUSE"),
+    ("Resume", "Weiter"),
+    ("Restart World (new seed)", "Neue Welt (neuer Seed)"),
+    ("Settings", "Einstellungen"),
+    ("Quit", "Beenden"),
+    ("New world: seeds {0} / {1}", "Neue Welt: Seeds {0} / {1}"),
+    // World map
+    ("WORLD MAP", "WELTKARTE"),
+    (
+        "click: store waypoint   M / Esc: close",
+        "Klick: Wegpunkt setzen   M / Esc: schließen",
+    ),
+    ("M / Esc: close", "M / Esc: schließen"),
+    ("Waypoint {0}", "Wegpunkt {0}"),
+    ("Waypoint {0} stored", "Wegpunkt {0} gespeichert"),
+    (
+        "All bookmark slots are taken",
+        "Alle Lesezeichenplätze sind belegt",
+    ),
+    // Bookmarks
+    ("Bookmark {0}", "Lesezeichen {0}"),
+    ("{0} stored", "{0} gespeichert"),
+    ("Flying to {0}", "Flug zu {0}"),
+    ("Waypoints", "Wegpunkte"),
+    (
+        "No bookmarks yet (Ctrl+1–9 while flying).",
+        "Noch keine Lesezeichen (Strg+1–9 im Flug).",
+    ),
+    ("Delete", "Löschen"),
+    ("Fly here", "Hinfliegen"),
+    ("Store current view", "Aktuelle Ansicht speichern"),
+    // Beacons
+    ("Beacons", "Baken"),
+    ("Beacon {0}", "Bake {0}"),
+    ("{0} dropped", "{0} gesetzt"),
+    (
+        "A beacon already marks this hex",
+        "Dieses Feld ist bereits markiert",
+    ),
+    (
+        "No beacons yet (B while flying).",
+        "Noch keine Baken (B im Flug).",
+    ),
+    ("Teleport", "Teleportieren"),
+    // Pathfinding
+    ("Path: {0} hexes, cost {1}", "Pfad: {0} Felder, Kosten {1}"),
+    (
+        "No path between the picked hexes",
+        "Kein Pfad zwischen den gewählten Feldern",
+    ),
+    // Orbs
+    ("All {0} orbs collected!", "Alle {0} Kugeln eingesammelt!"),
+    // Resource nodes
+    ("Biomass", "Biomasse"),
+    ("Ore", "Erz"),
+    ("Crystal", "Kristall"),
+    ("{0} node depleted", "{0}-Vorkommen erschöpft"),
+    (
+        "Harvest the resource node below (hold)",
+        "Rohstoffvorkommen darunter abbauen (halten)",
+    ),
+    // Claims
+    ("Claimed hex {0}", "Feld {0} beansprucht"),
+    ("Hex already claimed", "Feld bereits beansprucht"),
+    (
+        "Hex held by another faction",
+        "Feld gehört einer anderen Fraktion",
+    ),
+    (
+        "Claims must border a claimed hex",
+        "Ansprüche müssen an ein beanspruchtes Feld grenzen",
+    ),
+    ("Territory brush", "Gebietspinsel"),
+    ("Paint with the mouse", "Mit der Maus malen"),
+    ("Rings", "Ringe"),
+    ("Faction {0}", "Fraktion {0}"),
+    ("Eraser", "Radierer"),
+    // Props
+    ("Props", "Requisiten"),
+    ("Pillar", "Säule"),
+    ("Arch", "Bogen"),
+    ("Sphere cluster", "Kugelhaufen"),
+    ("Rotation", "Drehung"),
+    ("Scale", "Größe"),
+    (
+        "Click a hex to place props",
+        "Hex anklicken, um Requisiten zu platzieren",
+    ),
+    ("Place on {0}", "Auf {0} setzen"),
+    ("Clear", "Leeren"),
+    // Quakes
+    ("Quake at {0}", "Beben bei {0}"),
+    // Teleporters
+    ("Teleported to {0}", "Teleportiert nach {0}"),
+    // Photo mode
+    ("Photo saved", "Foto gespeichert"),
+    ("Photo challenge complete: {0}", "Fotoaufgabe erfüllt: {0}"),
+    (
+        "PHOTO  Enter: shoot  P: exit",
+        "FOTO  Enter: auslösen  P: beenden",
+    ),
+    (
+        "Photograph the tallest peak from below {0}",
+        "Fotografiere den höchsten Gipfel von unter {0}",
+    ),
+    (
+        "Photograph the deepest basin from above {0}",
+        "Fotografiere das tiefste Becken von über {0}",
+    ),
+    (
+        "Photograph the origin from within {0}",
+        "Fotografiere den Ursprung aus höchstens {0}",
+    ),
+    ("Photo mode", "Fotomodus"),
+    ("Take photo (photo mode)", "Foto aufnehmen (Fotomodus)"),
+    // Flight recording
+    ("Recording flight", "Flug wird aufgezeichnet"),
+    ("Flight recorded: {0}", "Flug aufgezeichnet: {0}"),
+    ("Ghost replay", "Geisterflug"),
+    ("Ghost stopped", "Geisterflug beendet"),
+    ("Ghost finished", "Geist am Ziel"),
+    (
+        "No recorded flight (R to record)",
+        "Kein aufgezeichneter Flug (R zum Aufnehmen)",
+    ),
+    (
+        "Start / stop flight recording",
+        "Flugaufzeichnung starten / stoppen",
+    ),
+    (
+        "Replay the recorded flight as a ghost",
+        "Aufgezeichneten Flug als Geist abspielen",
+    ),
+    // Leaderboard
+    ("Leaderboard", "Bestenliste"),
+    ("Race leaderboard", "Rennbestenliste"),
+    ("World {0}", "Welt {0}"),
+    ("Course {0}", "Strecke {0}"),
+    ("No races finished yet", "Noch keine Rennen beendet"),
+    ("{0} medal on course {1}", "{0}medaille auf Strecke {1}"),
+    ("{0}  {1}  course {2}", "{0}  {1}  Strecke {2}"),
+    ("Silver", "Silber"),
+    // Peak radar
+    ("PEAKS", "GIPFEL"),
+    // Time trial
+    ("Time Trial", "Zeitfahren"),
+    (
+        "Time trial: {0} checkpoints",
+        "Zeitfahren: {0} Kontrollpunkte",
+    ),
+    (
+        "No room for a race course",
+        "Kein Platz für eine Rennstrecke",
+    ),
+    ("Checkpoint {0}/{1}", "Kontrollpunkt {0}/{1}"),
+    ("Missed the gate", "Tor verfehlt"),
+    ("Finished in {0}", "Ziel in {0}"),
+    ("Finished in {0}: new best!", "Ziel in {0}: neue Bestzeit!"),
+    // Energy
+    ("Out of energy: descending", "Keine Energie: Sinkflug"),
+    ("Energy recharged", "Energie aufgeladen"),
+    // Landmarks
+    ("Summit Obelisk", "Gipfelobelisk"),
+    ("Basin Ring Tower", "Beckenringturm"),
+    ("Wide Antenna Array", "Weitfeld-Antennenfeld"),
+    // Session
+    ("Session restored", "Sitzung wiederhergestellt"),
+    // Objectives
+    ("Objectives", "Ziele"),
+    ("Visit hex {0}", "Feld {0} besuchen"),
+    ("Reach altitude {0}", "Höhe {0} erreichen"),
+    ("Reveal {0}% of the grid", "{0}% des Rasters aufdecken"),
+    ("Objective complete: {0}", "Ziel erreicht: {0}"),
+    ("All objectives complete", "Alle Ziele erreicht"),
+    // Session summary
+    ("GRID COMPLETE", "RASTER KOMPLETT"),
+    ("SESSION SUMMARY", "SITZUNGSBILANZ"),
+    ("Hexes revealed", "Aufgedeckte Felder"),
+    ("Distance flown", "Geflogene Strecke"),
+    ("Peaks visited", "Besuchte Gipfel"),
+    ("Time", "Zeit"),
+    ("Score: {0}", "Punkte: {0}"),
+    ("Keep flying", "Weiterfliegen"),
+    // Toggles
+    ("Theme: {0}", "Thema: {0}"),
+    ("Hologram: {0}", "Hologramm: {0}"),
+    ("Height heatmap: {0}", "Höhenkarte: {0}"),
+    ("Glow level: {0}", "Leuchtstärke: {0}"),
+    ("on", "an"),
+    ("off", "aus"),
+    // Telemetry
+    ("Telemetry", "Telemetrie"),
+    ("altitude", "Höhe"),
+    ("ground", "Boden"),
+    ("speed", "Tempo"),
+    // Entity budgets
+    (
+        "Entity budget exceeded: {0}",
+        "Entitätsbudget überschritten: {0}",
+    ),
+    (
+        "Entity budget exceeded: reveal radius lowered to {0}",
+        "Entitätsbudget überschritten: Sichtradius auf {0} gesenkt",
+    ),
+    ("gaps", "Lücken"),
+    ("edges", "Kanten"),
+    ("FoV gaps", "Lücken im Sichtfeld"),
+    ("particles", "Partikel"),
+    // Settings panel
+    ("Grid (regenerates terrain)", "Raster (erzeugt Gelände neu)"),
+    ("Apply & Regenerate", "Anwenden & neu erzeugen"),
+    ("Revert", "Zurücksetzen"),
+    ("Terrain", "Gelände"),
+    ("Drone", "Drohne"),
+    ("Display", "Anzeige"),
+    ("Intro (next time it plays)", "Intro (beim nächsten Start)"),
+    ("Entity budgets", "Entitätsbudgets"),
+    ("language", "Sprache"),
+    ("UI scale", "UI-Skalierung"),
+    ("Export settings", "Einstellungen exportieren"),
+    ("Import settings", "Einstellungen importieren"),
+    (
+        "Settings exported to {0}",
+        "Einstellungen nach {0} exportiert",
+    ),
+    ("Export failed: {0}", "Export fehlgeschlagen: {0}"),
+    (
+        "Settings imported from {0}",
+        "Einstellungen aus {0} importiert",
+    ),
+    ("Import failed: {0}", "Import fehlgeschlagen: {0}"),
+    (
+        "Failed to load --config {0}",
+        "--config konnte nicht geladen werden: {0}",
+    ),
+    (
+        "Settings reloaded from {0}",
+        "Einstellungen aus {0} neu geladen",
+    ),
+    ("Reload failed: {0}", "Neu laden fehlgeschlagen: {0}"),
+    (
+        "seeds  height {0}  radius {1}",
+        "Seeds  Höhe {0}  Radius {1}",
+    ),
+    ("Regenerate", "Neu erzeugen"),
+    ("Randomize", "Zufällig"),
+    // Cheat sheet
+    ("Flight", "Flug"),
+    ("Look", "Blick"),
+    ("Bookmarks", "Lesezeichen"),
+    ("App", "Anwendung"),
+    ("Forward / back", "Vorwärts / rückwärts"),
+    ("Strafe left / right", "Seitwärts links / rechts"),
+    ("Climb / descend", "Steigen / sinken"),
+    (
+        "Altitude preset: skim / cruise / survey",
+        "Flughöhe: Tiefflug / Reise / Übersicht",
+    ),
+    ("Look around", "Umsehen"),
+    ("Turn left / right", "Drehen links / rechts"),
+    ("Pitch up / down", "Neigen hoch / runter"),
+    ("Fire laser", "Laser feuern"),
+    ("Survey view", "Übersichtsansicht"),
+    ("Cycle theme", "Thema wechseln"),
+    ("Toggle hologram", "Hologramm ein/aus"),
+    ("Glow up / down", "Leuchten heller / dunkler"),
+    ("Toggle height heatmap", "Höhenkarte ein/aus"),
+    ("Store viewpoint", "Ansicht speichern"),
+    ("Fly to viewpoint", "Zur Ansicht fliegen"),
+    ("Drop beacon", "Bake setzen"),
+    ("Skip intro", "Intro überspringen"),
+    ("Pause menu", "Pausenmenü"),
+    ("World map", "Weltkarte"),
+    ("Command console", "Befehlskonsole"),
+    ("Telemetry graphs", "Telemetrie-Diagramme"),
+    ("Show/hide this help", "Diese Hilfe ein/aus"),
+    ("Inspector / free cursor", "Inspektor / freier Cursor"),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lookups_translate_and_fall_back_to_english() {
+        let english = Locale::new(Language::English);
+        assert_eq!(english.t("Resume"), "Resume");
+        let german = Locale::new(Language::German);
+        assert_eq!(german.t("Resume"), "Weiter");
+        assert_eq!(german.t("not a key"), "not a key");
+        assert_eq!(
+            german.fmt("New world: seeds {0} / {1}", &[&7, &9]),
+            "Neue Welt: Seeds 7 / 9"
+        );
+        assert_eq!(english.fmt("Flying to {0}", &[&"ridge"]), "Flying to ridge");
+    }
+
+    #[test]
+    fn translations_keep_every_placeholder() {
+        let mut keys = std::collections::HashSet::new();
+        for &(key, text) in GERMAN {
+            assert!(keys.insert(key), "duplicate key `{key}`");
+            for i in 0..4 {
+                let placeholder = format!("{{{i}}}");
+                assert_eq!(
+                    key.contains(&placeholder),
+                    text.contains(&placeholder),
+                    "`{key}` → `{text}`"
+                );
+            }
+        }
+    }
+}
@@ -1,24 +1,22 @@
 #![warn(missing_docs)]
-//! Hex terrain viewer with neon edge lighting.
-//!
-//! Renders a hexagonal grid with noise-derived terrain heights, progressive
-//! edge/face reveal as the drone moves, and bloom post-processing.
+//! `hex-terrain` binary: parses the CLI and wires every plugin of the [`hex_terrain`] library
+//! into one app.
 
-mod drone;
-mod h_terrain;
-mod intro;
-pub mod math;
-
-#[cfg(not(target_arch = "wasm32"))]
-use bevy::app::AppExit;
 use bevy::prelude::*;
 #[cfg(not(target_arch = "wasm32"))]
 use bevy::remote::{RemotePlugin, http::RemoteHttpPlugin};
-use bevy::window::{CursorGrabMode, CursorOptions};
-use bevy_egui::egui;
+use bevy::window::{CursorOptions, PresentMode, WindowResolution};
 use bevy_inspector_egui::quick::WorldInspectorPlugin;
 #[cfg(not(target_arch = "wasm32"))]
 use clap::Parser;
+use hex_terrain::controls::{Binding, BindingCategory, RegisterBinding, Trigger};
+use hex_terrain::{
+    CorePlugin, GameMode, GameState, TerrainSeededPhase, beacons, bookmarks, budget, claims,
+    console, controls, drone, elevation, energy, frame_limit, gates, ghost, h_terrain, harvest,
+    hazards, headless, hud, intro, leaderboard, locale, neon_ui, objectives, orbs, pause, perf,
+    persist, photo, post_fx, props, quakes, race, radar, scoring, session, set_cursor_free,
+    settings, telemetry, teleporters, toast, wind, world_map,
+};
 
 /// Hex terrain viewer with neon edge lighting.
 #[cfg(not(target_arch = "wasm32"))]
@@ -31,59 +29,331 @@ struct Cli {
     /// Override intro tilt-up duration (seconds).
     #[arg(long)]
     intro_duration: Option<f32>,
+
+    /// Terrain-contour flight: hold altitude above ground using height look-ahead.
+    #[arg(long)]
+    contour: bool,
+
+    /// Color theme for terrain materials.
+    #[arg(long, value_enum)]
+    theme: Option<h_terrain::ThemeKind>,
+
+    /// Color-blind-safe palette applied on top of the theme.
+    #[arg(long, value_enum)]
+    palette: Option<h_terrain::Palette>,
+
+    /// Enable the CRT post-process (scanlines, barrel distortion, phosphor glow).
+    #[arg(long)]
+    crt: bool,
+
+    /// Screen-space light shafts from the brightest on-screen hexes.
+    #[arg(long)]
+    god_rays: bool,
+
+    /// Horizontal lens-flare streaks over the highest visible peaks.
+    #[arg(long)]
+    lens_flare: bool,
+
+    /// Tint hex faces by height (deep blue valleys → cyan → white peaks).
+    #[arg(long)]
+    height_gradient: bool,
+
+    /// Light the terrain with a shadow-casting directional sun.
+    #[arg(long)]
+    sun: bool,
+
+    /// Fallback pipeline without HDR, bloom, or tonemapping (flat, brighter colors).
+    #[arg(long)]
+    low_gfx: bool,
+
+    /// MSAA sample count for the drone camera (1 disables).
+    #[arg(long, value_parser = parse_msaa)]
+    msaa: Option<u32>,
+
+    /// Internal render resolution in percent of the window (50–200).
+    #[arg(long, value_parser = parse_render_scale)]
+    render_scale: Option<f32>,
+
+    /// Swap-chain present mode (vsync behavior).
+    #[arg(long, value_enum, default_value_t = PresentModeArg::AutoVsync)]
+    present_mode: PresentModeArg,
+
+    /// Initial window size in physical pixels, e.g. `1920x1080`.
+    #[arg(long, value_parser = parse_resolution)]
+    resolution: Option<UVec2>,
+
+    /// Frame-rate cap: 30, 60, 120, or 0 / uncapped.
+    #[arg(long, value_parser = frame_limit::FpsCap::parse, default_value = "uncapped")]
+    fps_cap: frame_limit::FpsCap,
+
+    /// Time-trial race: a checkpoint course on every new grid.
+    #[arg(long)]
+    race: bool,
+
+    /// Rule set: `viewer` (free flight) or `survival` (flight drains energy).
+    #[arg(long, value_enum, default_value_t = GameMode::Viewer)]
+    mode: GameMode,
+
+    /// Turn on wind with this peak drift speed in world units per second (off by default).
+    #[arg(long)]
+    wind: Option<f32>,
+
+    /// Periodic quakes: a region of hexes shifts every couple of minutes.
+    #[arg(long)]
+    quakes: bool,
+
+    /// Resume the session saved on the last exit (terrain, position, revealed and claimed hexes).
+    #[arg(long = "continue")]
+    resume: bool,
+
+    /// Settings file (RON) to load at startup; also the settings panel's export/import file.
+    #[arg(long)]
+    config: Option<String>,
+
+    /// Generate the grid without a window, print its stats, write any exports, and exit.
+    #[arg(long)]
+    headless: bool,
+
+    /// With `--headless`: write every hex height as RON to this path.
+    #[arg(long, requires = "headless")]
+    export_ron: Option<String>,
+
+    /// With `--headless`: write a 16-bit PGM heightmap to this path.
+    #[arg(long, requires = "headless")]
+    export_heightmap: Option<String>,
+
+    /// With `--headless`: write the hex faces as a binary glTF (`.glb`) to this path.
+    #[arg(long, requires = "headless")]
+    export_gltf: Option<String>,
+}
+
+/// CLI names for [`PresentMode`].
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Clone, Copy, Debug, clap::ValueEnum)]
+enum PresentModeArg {
+    /// Vsync where supported (Fifo fallback).
+    AutoVsync,
+    /// No vsync where supported (Fifo fallback).
+    AutoNoVsync,
+    /// Strict vsync.
+    Fifo,
+    /// Vsync, but late frames tear instead of waiting.
+    FifoRelaxed,
+    /// No vsync; may tear.
+    Immediate,
+    /// Latest frame wins without tearing.
+    Mailbox,
 }
-/// Application-wide game state, used for system scheduling.
-#[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash, Reflect)]
-pub enum GameState {
-    /// Intro camera animation playing.
-    #[default]
-    Intro,
-    /// Pipe swing-in animation after intro.
-    Arming,
-    /// Normal gameplay — drone movement + terrain reveal.
-    Running,
-    /// Inspector overlay active (Tab to toggle).
-    Inspecting,
+
+#[cfg(not(target_arch = "wasm32"))]
+impl From<PresentModeArg> for PresentMode {
+    fn from(arg: PresentModeArg) -> Self {
+        match arg {
+            PresentModeArg::AutoVsync => PresentMode::AutoVsync,
+            PresentModeArg::AutoNoVsync => PresentMode::AutoNoVsync,
+            PresentModeArg::Fifo => PresentMode::Fifo,
+            PresentModeArg::FifoRelaxed => PresentMode::FifoRelaxed,
+            PresentModeArg::Immediate => PresentMode::Immediate,
+            PresentModeArg::Mailbox => PresentMode::Mailbox,
+        }
+    }
 }
 
-/// CLI debug flag exposed as a resource for verbose logging.
-#[derive(Resource)]
-pub struct DebugFlag(pub bool);
-
-/// Shared Startup set: terrain seed systems run in this set,
-/// so that [`drone::systems::spawn_drone`] can be ordered after them.
-#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TerrainSeededPhase;
-
-/// Player world position. Drone writes xz + offset.
-#[derive(Resource, Default, Reflect)]
-pub struct PlayerPos {
-    /// Horizontal position on the terrain plane.
-    pub xz: Vec2,
-    /// User-controlled vertical offset above ground (Q/E/scroll).
-    pub offset: f32,
+/// Parses an MSAA sample count (1, 2, 4, or 8).
+#[cfg(not(target_arch = "wasm32"))]
+fn parse_msaa(s: &str) -> Result<u32, String> {
+    match s.parse::<u32>() {
+        Ok(n @ (1 | 2 | 4 | 8)) => Ok(n),
+        _ => Err(format!("expected 1, 2, 4, or 8, got `{s}`")),
+    }
 }
 
-/// Terrain height beneath the player, written by h_terrain.
-/// `None` until terrain has been seeded at startup.
-#[derive(Resource, Default, Reflect)]
-pub struct GroundLevel(pub Option<f32>);
+/// Parses a render scale percentage (50–200) into a fraction.
+#[cfg(not(target_arch = "wasm32"))]
+fn parse_render_scale(s: &str) -> Result<f32, String> {
+    match s.trim_end_matches('%').parse::<u32>() {
+        Ok(pct @ 50..=200) => Ok(pct as f32 / 100.0),
+        _ => Err(format!("expected a percentage from 50 to 200, got `{s}`")),
+    }
+}
 
-/// Set by drone/intro when [`PlayerPos`] xz or offset changes.
-/// Consumed (reset to `false`) by terrain height systems.
-#[derive(Resource, Default, Reflect)]
-pub struct PlayerMoved(pub bool);
+/// Parses `WIDTHxHEIGHT` (e.g. `1920x1080`).
+#[cfg(not(target_arch = "wasm32"))]
+fn parse_resolution(s: &str) -> Result<UVec2, String> {
+    let (w, h) = s
+        .split_once(['x', 'X'])
+        .ok_or_else(|| format!("expected WIDTHxHEIGHT, got `{s}`"))?;
+    let parse = |v: &str| {
+        v.trim()
+            .parse::<u32>()
+            .ok()
+            .filter(|&n| n > 0)
+            .ok_or_else(|| format!("invalid dimension `{v}`"))
+    };
+    Ok(UVec2::new(parse(w)?, parse(h)?))
+}
+
+/// Window presentation options applied when building `DefaultPlugins`.
+#[derive(Clone, Debug)]
+struct DisplayConfig {
+    /// Swap-chain present mode.
+    present_mode: PresentMode,
+    /// Initial physical window size (`None` keeps Bevy's default).
+    resolution: Option<UVec2>,
+    /// Frame-rate cap (seeds [`frame_limit::FrameLimitConfig`]; not a window setting).
+    fps_cap: frame_limit::FpsCap,
+}
+
+impl Default for DisplayConfig {
+    fn default() -> Self {
+        Self {
+            present_mode: PresentMode::AutoVsync,
+            resolution: None,
+            fps_cap: frame_limit::FpsCap::Uncapped,
+        }
+    }
+}
+
+/// Startup options from the CLI; the web build, which has no command line, uses
+/// [`Default`].
+#[derive(Default)]
+struct StartupArgs {
+    /// Start in `GameState::Inspecting`.
+    debug: bool,
+    /// Intro tilt-up duration override (seconds).
+    intro_duration: Option<f32>,
+    /// Terrain-contour flight.
+    contour: bool,
+    /// Theme preset override.
+    theme: Option<h_terrain::ThemeKind>,
+    /// Palette override.
+    palette: Option<h_terrain::Palette>,
+    /// CRT post-process.
+    crt: bool,
+    /// Screen-space light shafts.
+    god_rays: bool,
+    /// Peak lens flares.
+    lens_flare: bool,
+    /// Height-gradient face tint.
+    height_gradient: bool,
+    /// Shadow-casting sun.
+    sun: bool,
+    /// Fallback pipeline without HDR, bloom, or tonemapping.
+    low_gfx: bool,
+    /// MSAA sample count override.
+    msaa: Option<u32>,
+    /// Render scale override (fraction of the window).
+    render_scale: Option<f32>,
+    /// Window presentation options.
+    display: DisplayConfig,
+    /// Settings file to load and hot-reload.
+    config: Option<String>,
+    /// Time-trial race.
+    race: bool,
+    /// Rule set.
+    mode: GameMode,
+    /// Wind strength (off when `None`).
+    wind: Option<f32>,
+    /// Periodic quakes.
+    quakes: bool,
+    /// Resume the saved session.
+    resume: bool,
+    /// Headless generation instead of a window.
+    headless: Option<headless::HeadlessConfig>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl From<Cli> for StartupArgs {
+    fn from(cli: Cli) -> Self {
+        Self {
+            debug: cli.debug,
+            intro_duration: cli.intro_duration,
+            contour: cli.contour,
+            theme: cli.theme,
+            palette: cli.palette,
+            crt: cli.crt,
+            god_rays: cli.god_rays,
+            lens_flare: cli.lens_flare,
+            height_gradient: cli.height_gradient,
+            sun: cli.sun,
+            low_gfx: cli.low_gfx,
+            msaa: cli.msaa,
+            render_scale: cli.render_scale,
+            display: DisplayConfig {
+                present_mode: cli.present_mode.into(),
+                resolution: cli.resolution,
+                fps_cap: cli.fps_cap,
+            },
+            config: cli.config,
+            race: cli.race,
+            mode: cli.mode,
+            wind: cli.wind,
+            quakes: cli.quakes,
+            resume: cli.resume,
+            headless: cli.headless.then(|| headless::HeadlessConfig {
+                ron: cli.export_ron,
+                heightmap: cli.export_heightmap,
+                gltf: cli.export_gltf,
+                ..default()
+            }),
+        }
+    }
+}
 
 fn main() {
     #[cfg(not(target_arch = "wasm32"))]
-    let (debug, intro_duration_override) = {
-        let cli = Cli::parse();
-        (cli.debug, cli.intro_duration)
-    };
+    let args = StartupArgs::from(Cli::parse());
     #[cfg(target_arch = "wasm32")]
-    let (debug, intro_duration_override) = (false, None::<f32>);
+    let args = StartupArgs::default();
+    let StartupArgs {
+        debug,
+        intro_duration: intro_duration_override,
+        contour,
+        theme,
+        palette,
+        crt,
+        god_rays,
+        lens_flare,
+        height_gradient,
+        sun,
+        low_gfx,
+        msaa,
+        render_scale,
+        display,
+        config: config_file,
+        race,
+        mode,
+        wind,
+        quakes,
+        resume,
+        headless,
+    } = args;
+
+    // `--config` is applied first, so the individual flags below take precedence over it.
+    #[cfg_attr(target_arch = "wasm32", allow(unused_mut))]
+    let mut startup = settings::Settings::default();
+    #[cfg_attr(target_arch = "wasm32", allow(unused_mut))]
+    let mut load_error = None;
+    #[cfg(not(target_arch = "wasm32"))]
+    if let Some(path) = &config_file
+        && let Err(e) = settings::load_settings_file(path, &mut startup)
+    {
+        warn!("Failed to load --config {e}");
+        startup = settings::Settings::default();
+        load_error = Some(e);
+    }
+    let settings::Settings {
+        terrain: mut terrain_cfg,
+        drone: mut drone_cfg,
+        intro: mut intro_cfg,
+        theme: loaded_theme,
+        locale: locale_cfg,
+        ui: ui_cfg,
+    } = startup;
+    // `--theme` / `--palette` rebuild the theme from the preset instead of the file's colors.
+    let file_theme =
+        (config_file.is_some() && theme.is_none() && palette.is_none()).then_some(loaded_theme);
 
-    let mut intro_cfg = intro::IntroConfig::default();
     if let Some(d) = intro_duration_override {
         intro_cfg.tilt_up_duration = d;
     }
@@ -94,75 +364,226 @@ fn main() {
         );
     }
 
+    if contour {
+        drone_cfg.altitude_mode = drone::AltitudeMode::Contour;
+    }
+
+    if let Some(theme) = theme {
+        terrain_cfg.theme = theme;
+    }
+    if let Some(palette) = palette {
+        terrain_cfg.palette = palette;
+    }
+    if height_gradient {
+        terrain_cfg.face_height_gradient = Some(h_terrain::HeightGradient::glacier());
+    }
+    if sun {
+        terrain_cfg.sun = true;
+    }
+    if lens_flare {
+        terrain_cfg.lens_flare = true;
+    }
+    if low_gfx {
+        terrain_cfg.low_gfx = true;
+        drone_cfg.low_gfx = true;
+    }
+
+    let settings_cfg = settings::SettingsConfig {
+        hot_reload: config_file.is_some(),
+        load_error,
+        file: config_file.unwrap_or_else(|| settings::SettingsConfig::default().file),
+        ..default()
+    };
+
+    let session_cfg = session::SessionConfig::default();
+    let resume =
+        resume.then(|| persist::load_ron::<session::Session>(&session_cfg.path, "session"));
+    if let Some(session) = &resume {
+        session.apply_grid(&mut terrain_cfg);
+    }
+
+    if let Some(mut headless_cfg) = headless {
+        headless_cfg.grid = terrain_cfg.grid.clone();
+        let exit = App::new()
+            .add_plugins(MinimalPlugins)
+            .add_plugins(headless::HeadlessPlugin {
+                config: headless_cfg,
+            })
+            .run();
+        if exit.is_error() {
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    let mut post_fx_cfg = post_fx::PostFxConfig {
+        crt,
+        god_rays,
+        ..default()
+    };
+    if let Some(samples) = msaa {
+        post_fx_cfg.msaa_samples = samples;
+    }
+    if let Some(scale) = render_scale {
+        post_fx_cfg.render_scale = scale;
+    }
+
     let mut app = App::new();
 
-    app.add_plugins(DefaultPlugins.set(WindowPlugin {
-        primary_window: Some(Window {
-            title: "Hex Terrain".into(),
-            #[cfg(target_arch = "wasm32")]
-            canvas: Some("#game-canvas".into()),
-            fit_canvas_to_parent: true,
+    app.add_plugins(
+        DefaultPlugins.set(WindowPlugin {
+            primary_window: Some(Window {
+                title: "Hex Terrain".into(),
+                #[cfg(target_arch = "wasm32")]
+                canvas: Some("#game-canvas".into()),
+                fit_canvas_to_parent: true,
+                present_mode: display.present_mode,
+                resolution: display
+                    .resolution
+                    .map_or_else(WindowResolution::default, |r| {
+                        WindowResolution::new(r.x, r.y)
+                    }),
+                ..default()
+            }),
             ..default()
         }),
-        ..default()
-    }))
-    .register_type::<GameState>()
-    .register_type::<PlayerPos>()
-    .register_type::<PlayerMoved>()
-    .register_type::<GroundLevel>()
-    .init_state::<GameState>()
-    .init_resource::<PlayerPos>()
-    .init_resource::<PlayerMoved>()
-    .init_resource::<GroundLevel>()
-    .insert_resource(DebugFlag(debug))
+    )
+    .add_plugins(CorePlugin { debug, mode })
     .add_plugins(bevy_egui::EguiPlugin::default());
 
     #[cfg(not(target_arch = "wasm32"))]
     app.add_plugins((RemotePlugin::default(), RemoteHttpPlugin::default()));
 
     app.add_plugins(h_terrain::HTerrainPlugin {
-        config: h_terrain::HTerrainConfig::default(),
-        after_player_movement: Some(drone::systems::fly.into_system_set().intern()),
+        config: terrain_cfg,
+        after_player_movement: Some(drone::fly.into_system_set().intern()),
         terrain_seeded_set: Some(TerrainSeededPhase.intern()),
     });
+    if let Some(theme) = file_theme {
+        app.insert_resource(theme);
+    }
+    app.add_plugins(locale::LocalePlugin { config: locale_cfg });
 
     app.add_plugins(drone::DronePlugin {
-        config: drone::DroneConfig::default(),
+        config: drone_cfg,
         after_terrain_seed: Some(TerrainSeededPhase.intern()),
     })
+    .add_plugins(post_fx::PostFxPlugin {
+        config: post_fx_cfg,
+    })
     .add_plugins(intro::IntroPlugin(intro_cfg))
+    .add_plugins(bookmarks::BookmarksPlugin {
+        config: bookmarks::BookmarksConfig::default(),
+    })
+    .add_plugins(beacons::BeaconsPlugin {
+        config: beacons::BeaconsConfig::default(),
+    })
+    .add_plugins(harvest::HarvestPlugin {
+        config: harvest::HarvestConfig::default(),
+    })
+    .add_plugins(orbs::OrbsPlugin {
+        config: orbs::OrbsConfig::default(),
+    })
+    .add_plugins(energy::EnergyPlugin {
+        config: energy::EnergyConfig::default(),
+    })
+    .add_plugins(elevation::ElevationPlugin {
+        config: elevation::ElevationConfig::default(),
+    })
+    .add_plugins(wind::WindPlugin {
+        config: wind::WindConfig {
+            strength: wind.unwrap_or(wind::WindConfig::default().strength),
+            ..default()
+        },
+    })
+    .add_plugins(hazards::HazardsPlugin {
+        config: hazards::HazardsConfig::default(),
+    })
+    .add_plugins(quakes::QuakesPlugin {
+        config: quakes::QuakesConfig {
+            periodic: quakes,
+            ..default()
+        },
+    })
+    .add_plugins(teleporters::TeleportersPlugin {
+        config: teleporters::TeleportersConfig::default(),
+    })
+    .add_plugins(claims::ClaimsPlugin {
+        config: claims::ClaimsConfig::default(),
+    })
+    .add_plugins(props::PropsPlugin {
+        config: props::PropsConfig::default(),
+    })
+    .add_plugins(gates::GatesPlugin {
+        config: gates::GatesConfig::default(),
+    })
+    .add_plugins(race::RacePlugin {
+        config: race::RaceConfig {
+            enabled: race,
+            ..default()
+        },
+    })
+    .add_plugins(ghost::GhostPlugin {
+        config: ghost::GhostConfig::default(),
+    })
+    .add_plugins(leaderboard::LeaderboardPlugin {
+        config: leaderboard::LeaderboardConfig::default(),
+    })
+    .add_plugins(session::SessionPlugin {
+        config: session_cfg,
+        resume,
+    })
+    .add_plugins(objectives::ObjectivesPlugin {
+        config: objectives::ObjectivesConfig::default(),
+    })
+    .add_plugins(photo::PhotoPlugin {
+        config: photo::PhotoConfig::default(),
+    })
+    .add_plugins(scoring::ScoringPlugin {
+        config: scoring::ScoringConfig::default(),
+    })
+    .add_plugins(frame_limit::FrameLimitPlugin {
+        config: frame_limit::FrameLimitConfig {
+            fps_cap: display.fps_cap,
+        },
+    })
+    .add_plugins(hud::HudPlugin {
+        config: hud::HudConfig::default(),
+    })
+    .add_plugins(radar::RadarPlugin {
+        config: radar::RadarConfig::default(),
+    })
+    .add_plugins(toast::ToastPlugin {
+        config: toast::ToastConfig::default(),
+    })
+    .add_plugins(neon_ui::NeonUiPlugin { config: ui_cfg })
+    .add_plugins(settings::SettingsPlugin {
+        config: settings_cfg,
+    })
+    .add_plugins(controls::ControlsPlugin)
+    .add_plugins(console::ConsolePlugin)
+    .register_binding(Binding::new(
+        BindingCategory::App,
+        "Inspector / free cursor",
+        [Trigger::Key(KeyCode::Tab)],
+    ))
     .add_systems(Update, toggle_inspector)
-    .add_systems(Update, draw_fps.run_if(|f: Res<DebugFlag>| f.0))
+    .add_plugins(pause::PausePlugin)
+    .add_plugins(perf::PerfPlugin)
+    .add_plugins(telemetry::TelemetryPlugin {
+        config: telemetry::TelemetryConfig::default(),
+    })
+    .add_plugins(world_map::WorldMapPlugin {
+        config: world_map::WorldMapConfig::default(),
+    })
+    .add_plugins(budget::BudgetPlugin {
+        config: budget::BudgetConfig::default(),
+    })
     .add_plugins(WorldInspectorPlugin::new().run_if(in_state(GameState::Inspecting)));
 
-    #[cfg(not(target_arch = "wasm32"))]
-    app.add_systems(Update, exit_on_esc);
-
     app.run();
 }
 
-fn draw_fps(
-    mut egui_ctx: Single<&mut bevy_egui::EguiContext>,
-    time: Res<Time>,
-    mut ready: Local<bool>,
-) {
-    // Skip first frame — bevy_egui hasn't called Context::run() yet.
-    if !*ready {
-        *ready = true;
-        return;
-    }
-    let fps = 1.0 / time.delta_secs().max(f32::EPSILON);
-    egui::Area::new(egui::Id::new("fps_overlay"))
-        .fixed_pos(egui::pos2(8.0, 8.0))
-        .show(egui_ctx.get_mut(), |ui| {
-            ui.label(
-                egui::RichText::new(format!("{fps:.0} fps"))
-                    .color(egui::Color32::from_rgb(0, 255, 128))
-                    .font(egui::FontId::monospace(14.0)),
-            );
-        });
-}
-
 fn toggle_inspector(
     keys: Res<ButtonInput<KeyCode>>,
     state: Res<State<GameState>>,
@@ -175,25 +596,7 @@ fn toggle_inspector(
             GameState::Inspecting => GameState::Running,
             _ => return,
         };
-        let entering_inspect = new_state == GameState::Inspecting;
+        set_cursor_free(&mut windows, new_state == GameState::Inspecting);
         next.set(new_state);
-        for (mut opts, mut window) in &mut windows {
-            if entering_inspect {
-                opts.visible = true;
-                opts.grab_mode = CursorGrabMode::None;
-            } else {
-                opts.visible = false;
-                opts.grab_mode = CursorGrabMode::Confined;
-                let center = Vec2::new(window.width() / 2.0, window.height() / 2.0);
-                window.set_cursor_position(Some(center));
-            }
-        }
-    }
-}
-
-#[cfg(not(target_arch = "wasm32"))]
-fn exit_on_esc(keys: Res<ButtonInput<KeyCode>>, mut exit: MessageWriter<AppExit>) {
-    if keys.just_pressed(KeyCode::Escape) {
-        exit.write(AppExit::Success);
     }
 }
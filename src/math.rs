@@ -2,6 +2,8 @@
 //!
 //! Terrain-specific math lives in `h_terrain::math`.
 
+use hexx::Hex;
+
 /// Cubic ease-out curve: fast start, gentle deceleration.
 ///
 /// `t` should be in `[0, 1]`. Returns `1 - (1 - t)^3`.
@@ -23,8 +25,24 @@ pub fn clamp_pitch(current: f32, delta: f32, margin: f32) -> f32 {
     clamped - current
 }
 
+/// Deterministic value in `0.0..1.0` for `hex` under `seed` (splitmix64 of the coordinates).
+///
+/// The shared seeded per-hex roll: orb, node, hazard and charger scattering, and seeded
+/// picks among cells (quakes, teleporter pairs, race routes).
+pub fn hash_roll(hex: Hex, seed: u64) -> f32 {
+    let [q, r] = hex.to_array();
+    let key = (u64::from(q as u32) << 32 | u64::from(r as u32)) ^ seed;
+    let mut z = key.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^= z >> 31;
+    (z >> 40) as f32 / (1u64 << 24) as f32
+}
+
 #[cfg(test)]
 mod tests {
+    use hexx::shapes;
+
     use super::*;
 
     // ── ease_out_cubic ──────────────────────────────────────────────
@@ -80,4 +98,17 @@ mod tests {
         let delta = clamp_pitch(limit + 0.01, -0.1, 0.05);
         assert!((delta - (-0.01)).abs() < 1e-4);
     }
+
+    // ── hash_roll ───────────────────────────────────────────────────
+
+    #[test]
+    fn rolls_are_seeded_and_spread_over_the_unit_interval() {
+        let hexes: Vec<Hex> = shapes::hexagon(Hex::ZERO, 20).collect();
+        let rolls: Vec<f32> = hexes.iter().map(|&h| hash_roll(h, 7)).collect();
+        assert!(rolls.iter().all(|r| (0.0..1.0).contains(r)));
+        assert_eq!(rolls[5], hash_roll(hexes[5], 7));
+        assert_ne!(rolls[5], hash_roll(hexes[5], 8));
+        let share = rolls.iter().filter(|&&r| r < 0.25).count() as f32 / rolls.len() as f32;
+        assert!((share - 0.25).abs() < 0.05, "{share}");
+    }
 }
@@ -0,0 +1,212 @@
+//! Neon egui styling for the app's own panels, derived from the active [`Theme`].
+//!
+//! [`neon_style`] builds dark visuals with translucent panels tinted by the clear color,
+//! accents in the theme's (displayable) edge color, and monospace numbers. Our panels draw in
+//! `Update`, so the style is set in `PreUpdate` and swapped back to egui's stock style before
+//! bevy_egui's context pass in `PostUpdate`, where the world inspector draws; the inspector
+//! keeps its default look. The neon style is rebuilt whenever [`Theme`] changes.
+//!
+//! [`UiConfig::scale`] multiplies egui's pixels-per-point (and with it every panel, HUD, and
+//! label font) for high-DPI displays. Overlays that place shapes at projected window positions
+//! convert them with [`window_to_points`].
+
+use std::ops::RangeInclusive;
+use std::sync::Arc;
+
+use bevy::prelude::*;
+use bevy_egui::{EguiContextSettings, EguiPostUpdateSet, EguiPreUpdateSet, egui};
+
+use crate::h_terrain::Theme;
+
+/// Per-plugin configuration for the app's egui panels.
+#[derive(Resource, Clone, Debug, Reflect)]
+pub struct UiConfig {
+    /// Global UI scale on top of the window's DPI factor ([`UiConfig::SCALE_RANGE`]).
+    pub scale: f32,
+}
+
+impl UiConfig {
+    /// Allowed [`UiConfig::scale`] values.
+    pub const SCALE_RANGE: RangeInclusive<f32> = 0.75..=2.0;
+}
+
+impl Default for UiConfig {
+    fn default() -> Self {
+        Self { scale: 1.0 }
+    }
+}
+
+/// egui points per logical window pixel (below 1 when the UI is scaled up).
+///
+/// Positions from `world_to_viewport` or the cursor are logical pixels; multiply by this
+/// before painting them with egui.
+pub fn window_to_points(ctx: &egui::Context, window: &Window) -> f32 {
+    window.scale_factor() / ctx.pixels_per_point()
+}
+
+/// Panel opacity (0–255).
+const PANEL_ALPHA: u8 = 200;
+
+/// Displayable egui color for a Bevy color.
+fn color32(color: Color) -> egui::Color32 {
+    let [r, g, b, _] = color.to_srgba().to_u8_array();
+    egui::Color32::from_rgb(r, g, b)
+}
+
+/// Dark egui style with translucent panels and accents from `theme`.
+pub fn neon_style(theme: &Theme) -> egui::Style {
+    let accent = color32(theme.clone().flattened().edge.base);
+    let backdrop = color32(theme.clear_color);
+    let panel = egui::Color32::from_rgba_unmultiplied(
+        backdrop.r(),
+        backdrop.g(),
+        backdrop.b(),
+        PANEL_ALPHA,
+    );
+    let text = egui::Color32::from_gray(210);
+
+    let mut style = egui::Style {
+        visuals: egui::Visuals::dark(),
+        drag_value_text_style: egui::TextStyle::Monospace,
+        ..default()
+    };
+    let v = &mut style.visuals;
+    v.window_fill = panel;
+    v.panel_fill = panel;
+    v.extreme_bg_color = egui::Color32::from_black_alpha(160);
+    v.faint_bg_color = accent.gamma_multiply(0.05);
+    v.window_stroke = egui::Stroke::new(1.0, accent.gamma_multiply(0.6));
+    v.window_shadow = egui::Shadow::NONE;
+    v.popup_shadow = egui::Shadow::NONE;
+    v.hyperlink_color = accent;
+    v.selection.bg_fill = accent.gamma_multiply(0.35);
+    v.selection.stroke = egui::Stroke::new(1.0, accent);
+
+    let w = &mut v.widgets;
+    w.noninteractive.fg_stroke = egui::Stroke::new(1.0, text);
+    w.noninteractive.bg_stroke = egui::Stroke::new(1.0, accent.gamma_multiply(0.25));
+    for (state, fill) in [
+        (&mut w.inactive, 0.12),
+        (&mut w.hovered, 0.25),
+        (&mut w.active, 0.4),
+        (&mut w.open, 0.25),
+    ] {
+        state.bg_fill = accent.gamma_multiply(fill);
+        state.weak_bg_fill = accent.gamma_multiply(fill);
+        state.fg_stroke = egui::Stroke::new(1.0, accent);
+    }
+    w.hovered.bg_stroke = egui::Stroke::new(1.0, accent);
+    w.active.bg_stroke = egui::Stroke::new(1.0, accent);
+    style
+}
+
+/// Neon style for our panels and the stock style captured for the inspector.
+#[derive(Resource, Default)]
+struct NeonUiStyle {
+    neon: Option<Arc<egui::Style>>,
+    stock: Option<Arc<egui::Style>>,
+}
+
+/// Neon egui theme plugin.
+pub struct NeonUiPlugin {
+    /// Per-plugin configuration.
+    pub config: UiConfig,
+}
+
+impl Plugin for NeonUiPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<UiConfig>()
+            .insert_resource(self.config.clone())
+            .init_resource::<NeonUiStyle>()
+            .add_systems(
+                PreUpdate,
+                apply_ui_scale
+                    .before(EguiPreUpdateSet::ProcessInput)
+                    .run_if(
+                        resource_changed::<UiConfig>
+                            .or(any_match_filter::<Added<EguiContextSettings>>),
+                    ),
+            )
+            .add_systems(
+                PreUpdate,
+                apply_neon_style.after(EguiPreUpdateSet::BeginPass),
+            )
+            .add_systems(
+                PostUpdate,
+                restore_stock_style.before(EguiPostUpdateSet::EndPass),
+            );
+    }
+}
+
+/// Writes [`UiConfig::scale`] (clamped to [`UiConfig::SCALE_RANGE`]) to every egui context.
+fn apply_ui_scale(cfg: Res<UiConfig>, mut contexts: Query<&mut EguiContextSettings>) {
+    let scale = cfg
+        .scale
+        .clamp(*UiConfig::SCALE_RANGE.start(), *UiConfig::SCALE_RANGE.end());
+    for mut settings in &mut contexts {
+        if settings.scale_factor != scale {
+            settings.scale_factor = scale;
+        }
+    }
+}
+
+/// Sets the neon style (rebuilt when [`Theme`] changes) for the `Update` panels.
+fn apply_neon_style(
+    mut egui_ctx: Single<&mut bevy_egui::EguiContext>,
+    theme: Res<Theme>,
+    mut styles: ResMut<NeonUiStyle>,
+) {
+    let ctx = egui_ctx.get_mut();
+    if styles.stock.is_none() {
+        styles.stock = Some(ctx.style());
+    }
+    if theme.is_changed() || styles.neon.is_none() {
+        styles.neon = Some(Arc::new(neon_style(&theme)));
+    }
+    if let Some(neon) = &styles.neon {
+        ctx.set_style(neon.clone());
+    }
+}
+
+/// Restores the stock style before the inspector draws in the egui context pass.
+fn restore_stock_style(
+    mut egui_ctx: Single<&mut bevy_egui::EguiContext>,
+    styles: Res<NeonUiStyle>,
+) {
+    if let Some(stock) = &styles.stock {
+        egui_ctx.get_mut().set_style(stock.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::h_terrain::ThemeKind;
+
+    #[test]
+    fn neon_style_uses_theme_accent_and_translucent_panels() {
+        let theme = Theme::from(ThemeKind::AmberCrt);
+        let style = neon_style(&theme);
+        let accent = color32(theme.flattened().edge.base);
+        assert_eq!(style.visuals.selection.stroke.color, accent);
+        assert_eq!(style.visuals.widgets.inactive.fg_stroke.color, accent);
+        assert_eq!(style.visuals.window_fill.a(), PANEL_ALPHA);
+        assert_eq!(style.drag_value_text_style, egui::TextStyle::Monospace);
+    }
+
+    #[test]
+    fn ui_scale_is_clamped_onto_egui_contexts() {
+        let mut app = App::new();
+        app.insert_resource(UiConfig { scale: 3.0 })
+            .add_systems(Update, apply_ui_scale);
+        let context = app.world_mut().spawn(EguiContextSettings::default()).id();
+        app.update();
+        let settings = app.world().get::<EguiContextSettings>(context).unwrap();
+        assert_eq!(settings.scale_factor, 2.0);
+
+        app.world_mut().resource_mut::<UiConfig>().scale = 1.25;
+        app.update();
+        let settings = app.world().get::<EguiContextSettings>(context).unwrap();
+        assert_eq!(settings.scale_factor, 1.25);
+    }
+}
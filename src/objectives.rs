@@ -0,0 +1,332 @@
+//! Mission objectives: an ordered queue of goals for demo sessions.
+//!
+//! [`Objectives`] is filled from [`ObjectivesConfig::objectives`] at startup. While flying,
+//! [`track_objectives`] checks the head of the queue against an [`ObjectiveSnapshot`] of the player
+//! (active hex, altitude above ground, revealed share of the grid, photos taken). Met objectives
+//! move to the completed list, each with an [`ObjectiveCompleted`] message that
+//! [`announce_objectives`] turns into a toast. [`draw_objectives`] lists them in the top-right
+//! corner: completed ones checked, the current one highlighted, the rest dimmed.
+
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+use bevy_egui::egui;
+use hexx::Hex;
+
+use crate::h_terrain::{ActiveHex, HCell, Revealed};
+use crate::locale::Locale;
+use crate::photo::{PhotoAlbum, PhotoTarget};
+use crate::toast::Toast;
+use crate::{GameState, PlayerPos};
+
+/// A single goal.
+#[derive(Clone, Debug, PartialEq, Reflect)]
+pub enum Objective {
+    /// Fly over the hex at these axial coordinates.
+    VisitHex([i32; 2]),
+    /// Climb to this height above the ground.
+    ReachAltitude(f32),
+    /// Reveal this share (`0.0..=1.0`) of the grid.
+    RevealShare(f32),
+    /// Take a photo matching this composition (see [`crate::photo`]).
+    Photograph(PhotoTarget),
+}
+
+impl Objective {
+    /// Whether `snapshot` satisfies this objective.
+    pub fn is_met(&self, snapshot: &ObjectiveSnapshot) -> bool {
+        match self {
+            Self::VisitHex(hex) => snapshot.hex == Some(Hex::from(*hex)),
+            Self::ReachAltitude(altitude) => snapshot.altitude >= *altitude,
+            Self::RevealShare(share) => snapshot.revealed >= *share,
+            Self::Photograph(target) => snapshot.photos.contains(target),
+        }
+    }
+
+    /// Localized description for the HUD list and toasts.
+    pub fn label(&self, locale: &Locale) -> String {
+        match *self {
+            Self::VisitHex([q, r]) => locale.fmt("Visit hex {0}", &[&format!("{q:+},{r:+}")]),
+            Self::ReachAltitude(altitude) => {
+                locale.fmt("Reach altitude {0}", &[&format!("{altitude:.0}")])
+            }
+            Self::RevealShare(share) => locale.fmt(
+                "Reveal {0}% of the grid",
+                &[&format!("{:.0}", share * 100.0)],
+            ),
+            Self::Photograph(ref target) => target.label(locale),
+        }
+    }
+}
+
+/// Player state the objectives are checked against.
+#[derive(Clone, Debug, Default)]
+pub struct ObjectiveSnapshot {
+    /// Hex under the player.
+    pub hex: Option<Hex>,
+    /// Height above the ground ([`PlayerPos::offset`]).
+    pub altitude: f32,
+    /// Revealed share of the grid in `0.0..=1.0`.
+    pub revealed: f32,
+    /// Photo compositions captured so far.
+    pub photos: Vec<PhotoTarget>,
+}
+
+/// Per-plugin configuration for objectives.
+#[derive(Resource, Clone, Debug, Reflect)]
+pub struct ObjectivesConfig {
+    /// Objectives queued at startup, in order.
+    pub objectives: Vec<Objective>,
+    /// Show the objective list while flying.
+    pub show_list: bool,
+}
+
+impl Default for ObjectivesConfig {
+    fn default() -> Self {
+        Self {
+            objectives: vec![
+                Objective::VisitHex([4, -2]),
+                Objective::ReachAltitude(25.0),
+                Objective::RevealShare(0.1),
+            ],
+            show_list: true,
+        }
+    }
+}
+
+/// Objective queue: the head of `pending` is the current objective.
+#[derive(Resource, Default, Reflect)]
+pub struct Objectives {
+    /// Objectives still to do, current first.
+    pub pending: VecDeque<Objective>,
+    /// Objectives done, in completion order.
+    pub completed: Vec<Objective>,
+}
+
+impl Objectives {
+    /// Queue holding `objectives`, none completed.
+    pub fn new(objectives: impl IntoIterator<Item = Objective>) -> Self {
+        Self {
+            pending: objectives.into_iter().collect(),
+            completed: Vec::new(),
+        }
+    }
+
+    /// Completes objectives from the head of the queue while `snapshot` meets them.
+    pub fn advance(&mut self, snapshot: &ObjectiveSnapshot) -> Vec<Objective> {
+        let mut done = Vec::new();
+        while self.pending.front().is_some_and(|o| o.is_met(snapshot)) {
+            let objective = self.pending.pop_front().expect("checked non-empty");
+            self.completed.push(objective.clone());
+            done.push(objective);
+        }
+        done
+    }
+}
+
+/// Written once for each objective completed.
+#[derive(Message, Clone, Debug)]
+pub struct ObjectiveCompleted(pub Objective);
+
+/// Objectives plugin.
+pub struct ObjectivesPlugin {
+    /// Per-plugin configuration.
+    pub config: ObjectivesConfig,
+}
+
+impl Plugin for ObjectivesPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<ObjectivesConfig>()
+            .register_type::<Objectives>()
+            .add_message::<ObjectiveCompleted>()
+            .add_message::<Toast>()
+            .insert_resource(Objectives::new(self.config.objectives.clone()))
+            .insert_resource(self.config.clone())
+            .add_systems(
+                Update,
+                (
+                    track_objectives,
+                    announce_objectives,
+                    draw_objectives.run_if(|cfg: Res<ObjectivesConfig>| cfg.show_list),
+                )
+                    .chain()
+                    .after(crate::drone::systems::fly)
+                    .run_if(in_state(GameState::Running)),
+            );
+    }
+}
+
+/// Completes met objectives, writing an [`ObjectiveCompleted`] for each.
+#[allow(clippy::type_complexity)]
+pub fn track_objectives(
+    active: Res<ActiveHex>,
+    player: Res<PlayerPos>,
+    cells: Query<(), With<HCell>>,
+    revealed: Query<(), (With<HCell>, With<Revealed>)>,
+    album: Option<Res<PhotoAlbum>>,
+    mut objectives: ResMut<Objectives>,
+    mut completed: MessageWriter<ObjectiveCompleted>,
+) {
+    let Some(current) = objectives.pending.front() else {
+        return;
+    };
+    // Only count cells while a reveal objective is up.
+    let revealed = match current {
+        Objective::RevealShare(_) => match cells.iter().count() {
+            0 => 0.0,
+            total => revealed.iter().count() as f32 / total as f32,
+        },
+        _ => 0.0,
+    };
+    let photos = match (current, album) {
+        (Objective::Photograph(_), Some(album)) => album.0.clone(),
+        _ => Vec::new(),
+    };
+    let snapshot = ObjectiveSnapshot {
+        hex: active.current,
+        altitude: player.offset,
+        revealed,
+        photos,
+    };
+    let done = objectives.bypass_change_detection().advance(&snapshot);
+    if !done.is_empty() {
+        objectives.set_changed();
+        completed.write_batch(done.into_iter().map(ObjectiveCompleted));
+    }
+}
+
+/// Toasts each completed objective, and the end of the queue.
+fn announce_objectives(
+    mut completed: MessageReader<ObjectiveCompleted>,
+    objectives: Res<Objectives>,
+    mut toasts: MessageWriter<Toast>,
+    locale: Res<Locale>,
+) {
+    let mut any = false;
+    for ObjectiveCompleted(objective) in completed.read() {
+        toasts.write(Toast(
+            locale.fmt("Objective complete: {0}", &[&objective.label(&locale)]),
+        ));
+        any = true;
+    }
+    if any && objectives.pending.is_empty() {
+        toasts.write(Toast(locale.t("All objectives complete").into()));
+    }
+}
+
+/// Lists the objectives in the top-right corner.
+fn draw_objectives(
+    mut egui_ctx: Single<&mut bevy_egui::EguiContext>,
+    objectives: Res<Objectives>,
+    locale: Res<Locale>,
+) {
+    if objectives.pending.is_empty() && objectives.completed.is_empty() {
+        return;
+    }
+    let accent = egui::Color32::from_rgb(0, 255, 200);
+    let font = egui::FontId::monospace(13.0);
+    egui::Area::new(egui::Id::new("objectives"))
+        .anchor(egui::Align2::RIGHT_TOP, [-8.0, 8.0])
+        .interactable(false)
+        .show(egui_ctx.get_mut(), |ui| {
+            egui::Frame::new()
+                .fill(egui::Color32::from_black_alpha(140))
+                .inner_margin(egui::Margin::symmetric(10, 4))
+                .corner_radius(4.0)
+                .show(ui, |ui| {
+                    ui.label(
+                        egui::RichText::new(locale.t("Objectives"))
+                            .color(accent)
+                            .font(font.clone())
+                            .strong(),
+                    );
+                    for objective in &objectives.completed {
+                        ui.label(
+                            egui::RichText::new(format!("✔ {}", objective.label(&locale)))
+                                .color(egui::Color32::GRAY)
+                                .font(font.clone())
+                                .strikethrough(),
+                        );
+                    }
+                    for (i, objective) in objectives.pending.iter().enumerate() {
+                        let (marker, color) = if i == 0 {
+                            ("▶", accent)
+                        } else {
+                            ("·", egui::Color32::from_gray(170))
+                        };
+                        ui.label(
+                            egui::RichText::new(format!("{marker} {}", objective.label(&locale)))
+                                .color(color)
+                                .font(font.clone()),
+                        );
+                    }
+                });
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn objectives_complete_in_queue_order() {
+        let mut objectives = Objectives::new([
+            Objective::ReachAltitude(20.0),
+            Objective::VisitHex([1, -1]),
+            Objective::RevealShare(0.5),
+        ]);
+        let mut snapshot = ObjectiveSnapshot {
+            hex: Some(Hex::new(1, -1)),
+            altitude: 5.0,
+            revealed: 0.8,
+            ..default()
+        };
+        // The hex and reveal goals are met, but wait behind the altitude goal.
+        assert!(objectives.advance(&snapshot).is_empty());
+        snapshot.altitude = 20.0;
+        assert_eq!(objectives.advance(&snapshot).len(), 3);
+        assert!(objectives.pending.is_empty());
+        assert_eq!(objectives.completed[1], Objective::VisitHex([1, -1]));
+    }
+
+    #[test]
+    fn tracker_reports_completions() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_message::<ObjectiveCompleted>()
+            .add_message::<Toast>()
+            .init_resource::<ActiveHex>()
+            .init_resource::<PlayerPos>()
+            .init_resource::<Locale>()
+            .insert_resource(Objectives::new([
+                Objective::RevealShare(0.5),
+                Objective::ReachAltitude(10.0),
+            ]))
+            .add_systems(Update, (track_objectives, announce_objectives).chain());
+        app.world_mut().spawn((HCell { hex: Hex::ZERO }, Revealed));
+        app.world_mut().spawn(HCell { hex: Hex::ONE });
+        app.update();
+        let completed = app.world().resource::<Messages<ObjectiveCompleted>>();
+        let done: Vec<_> = completed.iter_current_update_messages().collect();
+        assert_eq!(done.len(), 1);
+        assert_eq!(done[0].0, Objective::RevealShare(0.5));
+
+        app.world_mut().resource_mut::<PlayerPos>().offset = 12.0;
+        app.update();
+        let objectives = app.world().resource::<Objectives>();
+        assert!(objectives.pending.is_empty());
+        let toasts = app.world().resource::<Messages<Toast>>();
+        let texts: Vec<_> = toasts
+            .iter_current_update_messages()
+            .map(|t| &t.0)
+            .collect();
+        assert_eq!(
+            texts,
+            [
+                "Objective complete: Reveal 50% of the grid",
+                "Objective complete: Reach altitude 10",
+                "All objectives complete",
+            ]
+        );
+    }
+}
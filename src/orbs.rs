@@ -0,0 +1,313 @@
+//! Collectible orbs: glowing spheres floating over a seeded subset of hexes.
+//!
+//! Whenever the grid is (re)generated, [`scatter_orbs`] gives each new [`HCell`] an [`Orb`]
+//! child when [`hash_roll`] for its hex falls under [`OrbsConfig::density`]. The roll is seeded
+//! from the grid's height seed, so the same world always gets the same orbs. While flying,
+//! [`collect_orbs`] picks up orbs the drone passes over. It bumps [`OrbScore`] and despawns
+//! the orb with a burst of sparks (animated as [`RevealParticle`]s) and a short [`OrbFlash`].
+//! The HUD strip shows the collected / total count.
+
+use bevy::prelude::*;
+
+use crate::drone::Player;
+use crate::h_terrain::{HCell, HTerrainConfig, HTerrainPhase, RevealParticle};
+use crate::locale::Locale;
+use crate::math::hash_roll;
+use crate::toast::Toast;
+use crate::{GameState, PlayerPos};
+
+/// Per-plugin configuration for collectible orbs.
+#[derive(Resource, Clone, Debug, Reflect)]
+pub struct OrbsConfig {
+    /// Share of hexes that get an orb (0 disables).
+    pub density: f32,
+    /// Mixed into the grid's height seed to pick orb hexes.
+    pub seed: u64,
+    /// Float height above the hex face.
+    pub hover: f32,
+    /// Orb sphere radius.
+    pub radius: f32,
+    /// Horizontal distance from the drone within which an orb is collected.
+    pub pickup_radius: f32,
+    /// Maximum drone height above an orb for pickup.
+    pub pickup_height: f32,
+    /// Particles in the pickup burst.
+    pub burst: u32,
+    /// Duration of the pickup flash in seconds.
+    pub flash_secs: f32,
+    /// Orb color.
+    pub color: Color,
+    /// Emissive multiplier on `color` (drives bloom).
+    pub glow: f32,
+}
+
+impl Default for OrbsConfig {
+    fn default() -> Self {
+        Self {
+            density: 0.04,
+            seed: 0,
+            hover: 1.2,
+            radius: 0.3,
+            pickup_radius: 2.0,
+            pickup_height: 12.0,
+            burst: 16,
+            flash_secs: 0.3,
+            color: Color::srgb(1.0, 0.3, 0.9),
+            glow: 8.0,
+        }
+    }
+}
+
+/// An uncollected orb (child of its [`HCell`]).
+#[derive(Component, Reflect)]
+pub struct Orb {
+    /// Bob phase offset in radians.
+    pub phase: f32,
+}
+
+/// Expanding flash left where an orb was collected.
+#[derive(Component, Reflect)]
+pub struct OrbFlash {
+    /// Seconds since pickup.
+    pub age: f32,
+}
+
+/// Orbs collected out of those scattered on the current grid.
+#[derive(Resource, Default, Reflect)]
+pub struct OrbScore {
+    /// Orbs picked up since the grid was generated.
+    pub collected: u32,
+    /// Orbs scattered on the current grid.
+    pub total: u32,
+}
+
+/// Shared orb, spark mesh and glow material.
+#[derive(Resource)]
+struct OrbAssets {
+    mesh: Handle<Mesh>,
+    spark_mesh: Handle<Mesh>,
+    material: Handle<StandardMaterial>,
+}
+
+/// Collectible orbs plugin.
+pub struct OrbsPlugin {
+    /// Per-plugin configuration.
+    pub config: OrbsConfig,
+}
+
+impl Plugin for OrbsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<OrbsConfig>()
+            .register_type::<Orb>()
+            .register_type::<OrbFlash>()
+            .register_type::<OrbScore>()
+            .insert_resource(self.config.clone())
+            .init_resource::<OrbScore>()
+            .add_systems(Startup, create_orb_assets)
+            .add_systems(
+                Update,
+                scatter_orbs.run_if(any_match_filter::<Added<HCell>>),
+            )
+            .add_systems(
+                Update,
+                (bob_orbs, collect_orbs, animate_orb_flashes)
+                    .chain()
+                    .after(HTerrainPhase::UpdateGround)
+                    .run_if(in_state(GameState::Running)),
+            );
+    }
+}
+
+/// Builds the shared orb sphere, spark cube and emissive material.
+fn create_orb_assets(
+    cfg: Res<OrbsConfig>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut commands: Commands,
+) {
+    commands.insert_resource(OrbAssets {
+        mesh: meshes.add(Sphere::new(cfg.radius)),
+        spark_mesh: meshes.add(Cuboid::from_length(cfg.radius * 0.3)),
+        material: materials.add(StandardMaterial {
+            base_color: cfg.color,
+            emissive: LinearRgba::from(cfg.color) * cfg.glow,
+            ..default()
+        }),
+    });
+}
+
+/// Gives freshly generated cells their orbs and resets the score.
+fn scatter_orbs(
+    cfg: Res<OrbsConfig>,
+    terrain: Res<HTerrainConfig>,
+    assets: Res<OrbAssets>,
+    cells: Query<(Entity, &HCell), Added<HCell>>,
+    mut score: ResMut<OrbScore>,
+    mut commands: Commands,
+) {
+    let seed = cfg.seed ^ u64::from(terrain.grid.height_noise_seed);
+    let mut total = 0;
+    for (entity, cell) in &cells {
+        let roll = hash_roll(cell.hex, seed);
+        if roll >= cfg.density {
+            continue;
+        }
+        total += 1;
+        commands.entity(entity).with_child((
+            Name::new("Orb"),
+            Orb {
+                phase: roll / cfg.density * std::f32::consts::TAU,
+            },
+            Mesh3d(assets.mesh.clone()),
+            MeshMaterial3d(assets.material.clone()),
+            Transform::from_xyz(0.0, cfg.hover, 0.0),
+        ));
+    }
+    *score = OrbScore {
+        collected: 0,
+        total,
+    };
+}
+
+/// Floats the orbs up and down.
+fn bob_orbs(time: Res<Time>, cfg: Res<OrbsConfig>, mut orbs: Query<(&Orb, &mut Transform)>) {
+    let t = time.elapsed_secs();
+    for (orb, mut tf) in &mut orbs {
+        tf.translation.y = cfg.hover + 0.2 * (t * 2.0 + orb.phase).sin();
+    }
+}
+
+/// Collects orbs under the drone: score, particle burst, and flash.
+#[allow(clippy::too_many_arguments)]
+fn collect_orbs(
+    cfg: Res<OrbsConfig>,
+    player: Res<PlayerPos>,
+    camera: Single<&GlobalTransform, With<Player>>,
+    orbs: Query<(Entity, Ref<Orb>, &GlobalTransform)>,
+    assets: Res<OrbAssets>,
+    mut score: ResMut<OrbScore>,
+    mut toasts: MessageWriter<Toast>,
+    locale: Res<Locale>,
+    mut commands: Commands,
+) {
+    let eye = camera.translation().y;
+    for (entity, orb, gt) in &orbs {
+        // Fresh orbs sit at the origin until transforms propagate.
+        if orb.is_added() {
+            continue;
+        }
+        let at = gt.translation();
+        let above = eye - at.y;
+        if at.xz().distance(player.xz) > cfg.pickup_radius
+            || !(-cfg.pickup_radius..=cfg.pickup_height).contains(&above)
+        {
+            continue;
+        }
+        commands.entity(entity).despawn();
+        score.collected += 1;
+        for i in 0..cfg.burst {
+            let angle = i as f32 / cfg.burst.max(1) as f32 * std::f32::consts::TAU;
+            let outward = Vec3::new(angle.cos(), 0.0, angle.sin());
+            commands.spawn((
+                RevealParticle {
+                    velocity: outward * 3.0 + Vec3::Y * (1.5 + (i % 3) as f32),
+                    age: 0.0,
+                },
+                Mesh3d(assets.spark_mesh.clone()),
+                MeshMaterial3d(assets.material.clone()),
+                Transform::from_translation(at),
+            ));
+        }
+        commands.spawn((
+            Name::new("OrbFlash"),
+            OrbFlash { age: 0.0 },
+            Mesh3d(assets.mesh.clone()),
+            MeshMaterial3d(assets.material.clone()),
+            Transform::from_translation(at),
+        ));
+        if score.collected == score.total {
+            toasts.write(Toast(
+                locale.fmt("All {0} orbs collected!", &[&score.total]),
+            ));
+        }
+    }
+}
+
+/// Swells each flash and shrinks it away over [`OrbsConfig::flash_secs`].
+fn animate_orb_flashes(
+    time: Res<Time>,
+    cfg: Res<OrbsConfig>,
+    mut flashes: Query<(Entity, &mut OrbFlash, &mut Transform)>,
+    mut commands: Commands,
+) {
+    for (entity, mut flash, mut tf) in &mut flashes {
+        flash.age += time.delta_secs();
+        let t = flash.age / cfg.flash_secs.max(f32::EPSILON);
+        if t >= 1.0 {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        tf.scale = Vec3::splat(1.0 + 3.0 * (t * std::f32::consts::PI).sin());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::state::app::StatesPlugin;
+    use hexx::Hex;
+
+    use super::*;
+
+    #[test]
+    fn orbs_scatter_on_new_cells_and_are_collected_underneath() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_plugins(AssetPlugin::default())
+            .add_plugins(StatesPlugin)
+            .add_plugins(TransformPlugin)
+            .init_asset::<Mesh>()
+            .init_asset::<StandardMaterial>()
+            .add_message::<Toast>()
+            .init_state::<GameState>()
+            .insert_resource(OrbsConfig {
+                density: 1.0,
+                ..default()
+            })
+            .init_resource::<HTerrainConfig>()
+            .init_resource::<OrbScore>()
+            .init_resource::<PlayerPos>()
+            .init_resource::<Locale>()
+            .add_systems(Startup, create_orb_assets)
+            .add_systems(
+                Update,
+                (
+                    scatter_orbs.run_if(any_match_filter::<Added<HCell>>),
+                    collect_orbs,
+                )
+                    .chain(),
+            );
+        for (i, x) in [0.0, 20.0].into_iter().enumerate() {
+            app.world_mut().spawn((
+                HCell {
+                    hex: Hex::new(i as i32, 0),
+                },
+                Transform::from_xyz(x, 1.0, 0.0),
+            ));
+        }
+        app.world_mut().spawn((
+            Player,
+            GlobalTransform::from(Transform::from_xyz(0.0, 5.0, 0.0)),
+        ));
+        app.update();
+        assert_eq!(app.world().resource::<OrbScore>().total, 2);
+
+        // Global transforms propagate in PostUpdate; collection sees them next frame.
+        app.update();
+        let score = app.world().resource::<OrbScore>();
+        assert_eq!((score.collected, score.total), (1, 2));
+        let w = app.world_mut();
+        assert_eq!(w.query::<&Orb>().iter(w).count(), 1);
+        assert_eq!(w.query::<&OrbFlash>().iter(w).count(), 1);
+        assert_eq!(w.query::<&RevealParticle>().iter(w).count(), 16);
+    }
+}
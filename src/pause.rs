@@ -0,0 +1,233 @@
+//! Pause menu (Escape while flying): Resume, Restart World, Time Trial, Settings, Quit.
+//!
+//! Entering [`GameState::Paused`] frees the cursor and pauses [`Time<Virtual>`], so
+//! animations freeze behind the dimmed backdrop; leaving unpauses it. Quit opens the session
+//! summary ([`GameState::Summary`]), which exits.
+
+use bevy::prelude::*;
+use bevy::window::CursorOptions;
+use bevy_egui::egui;
+
+use crate::controls::{Binding, BindingCategory, RegisterBinding, Trigger};
+use crate::h_terrain::{HTerrainConfig, RegenerateTerrain};
+use crate::locale::Locale;
+use crate::race::StartRace;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::scoring::{SessionSummary, SummaryReason};
+use crate::settings::{SettingsDraft, random_seeds};
+use crate::toast::Toast;
+use crate::{GameState, set_cursor_free};
+
+/// Backdrop opacity over the paused scene (0–255).
+const BACKDROP_ALPHA: u8 = 170;
+/// Menu button size in points.
+const BUTTON_SIZE: egui::Vec2 = egui::vec2(200.0, 32.0);
+
+/// Pause menu plugin.
+pub struct PausePlugin;
+
+impl Plugin for PausePlugin {
+    fn build(&self, app: &mut App) {
+        app.register_binding(Binding::new(
+            BindingCategory::App,
+            "Pause menu",
+            [Trigger::Key(KeyCode::Escape)],
+        ))
+        .add_systems(
+            Update,
+            toggle_pause.run_if(
+                in_state(GameState::Running)
+                    .or(in_state(GameState::Inspecting))
+                    .or(in_state(GameState::Paused)),
+            ),
+        )
+        .add_systems(Update, draw_pause_menu.run_if(in_state(GameState::Paused)))
+        .add_systems(
+            OnEnter(GameState::Paused),
+            |mut time: ResMut<Time<Virtual>>| time.pause(),
+        )
+        .add_systems(
+            OnExit(GameState::Paused),
+            |mut time: ResMut<Time<Virtual>>| time.unpause(),
+        );
+    }
+}
+
+/// Escape opens the menu from flight or the inspector and resumes flight from the menu.
+fn toggle_pause(
+    keys: Res<ButtonInput<KeyCode>>,
+    state: Res<State<GameState>>,
+    mut next: ResMut<NextState<GameState>>,
+    mut windows: Query<(&mut CursorOptions, &mut Window)>,
+) {
+    if !keys.just_pressed(KeyCode::Escape) {
+        return;
+    }
+    let paused = *state.get() == GameState::Paused;
+    next.set(if paused {
+        GameState::Running
+    } else {
+        GameState::Paused
+    });
+    set_cursor_free(&mut windows, !paused);
+}
+
+/// Menu choice made this frame.
+enum PauseAction {
+    Resume,
+    Restart,
+    Race,
+    Settings,
+    #[cfg(not(target_arch = "wasm32"))]
+    Quit,
+}
+
+/// Draws the dimmed backdrop and the centered menu, then applies the chosen action.
+#[allow(clippy::too_many_arguments)]
+fn draw_pause_menu(
+    mut egui_ctx: Single<&mut bevy_egui::EguiContext>,
+    real_time: Res<Time<Real>>,
+    mut next: ResMut<NextState<GameState>>,
+    mut windows: Query<(&mut CursorOptions, &mut Window)>,
+    mut terrain: ResMut<HTerrainConfig>,
+    mut draft: ResMut<SettingsDraft>,
+    mut regenerate: MessageWriter<RegenerateTerrain>,
+    mut races: MessageWriter<StartRace>,
+    mut toasts: MessageWriter<Toast>,
+    locale: Res<Locale>,
+    #[cfg(not(target_arch = "wasm32"))] mut summary: ResMut<SessionSummary>,
+) {
+    let ctx = egui_ctx.get_mut();
+    let mut action = None;
+
+    egui::Area::new(egui::Id::new("pause_backdrop"))
+        .fixed_pos(egui::pos2(0.0, 0.0))
+        .order(egui::Order::Background)
+        .interactable(false)
+        .show(ctx, |ui| {
+            ui.painter().rect_filled(
+                ui.ctx().content_rect(),
+                0.0,
+                egui::Color32::from_black_alpha(BACKDROP_ALPHA),
+            );
+        });
+
+    egui::Area::new(egui::Id::new("pause_menu"))
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .order(egui::Order::Foreground)
+        .show(ctx, |ui| {
+            egui::Frame::new()
+                .fill(egui::Color32::from_black_alpha(220))
+                .inner_margin(egui::Margin::same(16))
+                .corner_radius(6.0)
+                .show(ui, |ui| {
+                    ui.vertical_centered(|ui| {
+                        ui.label(
+                            egui::RichText::new(locale.t("PAUSED"))
+                                .color(egui::Color32::from_rgb(0, 255, 128))
+                                .font(egui::FontId::monospace(22.0)),
+                        );
+                        ui.add_space(8.0);
+                        let mut button = |label: &str, choice: PauseAction| {
+                            if ui
+                                .add_sized(BUTTON_SIZE, egui::Button::new(label))
+                                .clicked()
+                            {
+                                action = Some(choice);
+                            }
+                        };
+                        button(locale.t("Resume"), PauseAction::Resume);
+                        button(locale.t("Restart World (new seed)"), PauseAction::Restart);
+                        button(locale.t("Time Trial"), PauseAction::Race);
+                        button(locale.t("Settings"), PauseAction::Settings);
+                        #[cfg(not(target_arch = "wasm32"))]
+                        button(locale.t("Quit"), PauseAction::Quit);
+                    });
+                });
+        });
+
+    match action {
+        None => {}
+        Some(PauseAction::Resume) => {
+            next.set(GameState::Running);
+            set_cursor_free(&mut windows, false);
+        }
+        Some(PauseAction::Restart) => {
+            let (height, radius) = random_seeds(real_time.elapsed().as_nanos() as u64);
+            terrain.grid.height_noise_seed = height;
+            terrain.grid.radius_noise_seed = radius;
+            if let Some(grid) = draft.grid.as_mut() {
+                grid.height_noise_seed = height;
+                grid.radius_noise_seed = radius;
+            }
+            regenerate.write(RegenerateTerrain);
+            toasts.write(Toast(
+                locale.fmt("New world: seeds {0} / {1}", &[&height, &radius]),
+            ));
+            next.set(GameState::Running);
+            set_cursor_free(&mut windows, false);
+        }
+        Some(PauseAction::Race) => {
+            races.write(StartRace);
+            next.set(GameState::Running);
+            set_cursor_free(&mut windows, false);
+        }
+        // The settings panel lives in the inspector; the cursor stays free.
+        Some(PauseAction::Settings) => next.set(GameState::Inspecting),
+        #[cfg(not(target_arch = "wasm32"))]
+        Some(PauseAction::Quit) => {
+            summary.0 = SummaryReason::Quit;
+            next.set(GameState::Summary);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::state::app::StatesPlugin;
+
+    use super::*;
+
+    fn test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin))
+            .init_resource::<ButtonInput<KeyCode>>()
+            .init_state::<GameState>()
+            .add_plugins(PausePlugin);
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Running);
+        app.update();
+        app
+    }
+
+    /// Presses Escape for one frame, then runs another so the state transition applies.
+    fn press_escape(app: &mut App) {
+        let mut keys = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        keys.press(KeyCode::Escape);
+        app.update();
+        let mut keys = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        keys.release(KeyCode::Escape);
+        keys.clear();
+        app.update();
+    }
+
+    #[test]
+    fn escape_toggles_pause_and_virtual_time() {
+        let mut app = test_app();
+
+        press_escape(&mut app);
+        assert_eq!(
+            *app.world().resource::<State<GameState>>().get(),
+            GameState::Paused
+        );
+        assert!(app.world().resource::<Time<Virtual>>().is_paused());
+
+        press_escape(&mut app);
+        assert_eq!(
+            *app.world().resource::<State<GameState>>().get(),
+            GameState::Running
+        );
+        assert!(!app.world().resource::<Time<Virtual>>().is_paused());
+    }
+}
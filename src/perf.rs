@@ -0,0 +1,201 @@
+//! Debug performance overlay: frame-time graph, entity and asset counts, reveal timing.
+//!
+//! Drawn with egui on the left edge when [`DebugFlag`] is set, backed by bevy's
+//! frame-time and entity-count diagnostics plus [`h_terrain::REVEAL_TIME`].
+
+use bevy::diagnostic::{
+    DiagnosticPath, DiagnosticsStore, EntityCountDiagnosticsPlugin, FrameTimeDiagnosticsPlugin,
+};
+use bevy::prelude::*;
+use bevy_egui::egui;
+
+use crate::DebugFlag;
+use crate::budget::EntityCounts;
+use crate::h_terrain::{
+    self, HCell, HexFace, InFov, Quad, QuadEdge, RevealParticle, Revealed, Tri,
+};
+
+/// Frame-time graph size in points.
+const GRAPH_SIZE: egui::Vec2 = egui::vec2(220.0, 60.0);
+/// Frame budget marked on the graph (60 fps), in milliseconds.
+const BUDGET_MS: f64 = 1000.0 / 60.0;
+
+const TEXT: egui::Color32 = egui::Color32::from_rgb(0, 255, 128);
+
+/// Performance overlay plugin.
+pub struct PerfPlugin;
+
+impl Plugin for PerfPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins((
+            FrameTimeDiagnosticsPlugin::default(),
+            EntityCountDiagnosticsPlugin::default(),
+        ))
+        .add_systems(Update, draw_perf_panel.run_if(|f: Res<DebugFlag>| f.0));
+    }
+}
+
+/// Entity count matching filter `F`, for the per-marker table.
+type Count<'w, 's, F> = Query<'w, 's, (), F>;
+
+/// Per-marker entity counts shown in the panel and checked against the entity budgets.
+#[allow(clippy::type_complexity)]
+#[derive(bevy::ecs::system::SystemParam)]
+pub struct MarkerCounts<'w, 's> {
+    cells: Count<'w, 's, With<HCell>>,
+    faces: Count<'w, 's, With<HexFace>>,
+    quads: Count<'w, 's, With<Quad>>,
+    tris: Count<'w, 's, With<Tri>>,
+    edges: Count<'w, 's, With<QuadEdge>>,
+    revealed: Count<'w, 's, (With<HCell>, With<Revealed>)>,
+    in_fov: Count<'w, 's, (With<HCell>, With<InFov>)>,
+    fov_gaps: Count<'w, 's, (Or<(With<Quad>, With<Tri>)>, With<InFov>)>,
+    particles: Count<'w, 's, With<RevealParticle>>,
+}
+
+impl MarkerCounts<'_, '_> {
+    fn rows(&self) -> [(&'static str, usize); 7] {
+        [
+            ("HCell", self.cells.iter().count()),
+            ("HexFace", self.faces.iter().count()),
+            ("Quad", self.quads.iter().count()),
+            ("Tri", self.tris.iter().count()),
+            ("QuadEdge", self.edges.iter().count()),
+            ("Revealed", self.revealed.iter().count()),
+            ("InFov", self.in_fov.iter().count()),
+        ]
+    }
+
+    /// Counts of the budgeted groups ([`crate::budget`]).
+    pub fn entity_counts(&self) -> EntityCounts {
+        EntityCounts {
+            gaps: self.quads.iter().count() + self.tris.iter().count(),
+            edges: self.edges.iter().count(),
+            fov_gaps: self.fov_gaps.iter().count(),
+            particles: self.particles.iter().count(),
+        }
+    }
+}
+
+/// Latest smoothed value of a diagnostic, or `NaN` before its first measurement.
+fn smoothed(store: &DiagnosticsStore, path: &DiagnosticPath) -> f64 {
+    store
+        .get(path)
+        .and_then(|d| d.smoothed())
+        .unwrap_or(f64::NAN)
+}
+
+/// Y coordinate of `ms` in a graph spanning `top..bottom` with `scale_ms` at the top.
+pub fn graph_y(ms: f64, scale_ms: f64, top: f32, bottom: f32) -> f32 {
+    let t = (ms / scale_ms).clamp(0.0, 1.0) as f32;
+    bottom + (top - bottom) * t
+}
+
+/// Draws the diagnostics panel on the left edge.
+fn draw_perf_panel(
+    mut egui_ctx: Single<&mut bevy_egui::EguiContext>,
+    store: Res<DiagnosticsStore>,
+    markers: MarkerCounts,
+    meshes: Res<Assets<Mesh>>,
+    materials: Res<Assets<StandardMaterial>>,
+    images: Res<Assets<Image>>,
+    mut ready: Local<bool>,
+) {
+    // Skip first frame — bevy_egui hasn't called Context::run() yet.
+    if !*ready {
+        *ready = true;
+        return;
+    }
+    let fps = smoothed(&store, &FrameTimeDiagnosticsPlugin::FPS);
+    let frame_ms = smoothed(&store, &FrameTimeDiagnosticsPlugin::FRAME_TIME);
+    let history: Vec<f64> = store
+        .get(&FrameTimeDiagnosticsPlugin::FRAME_TIME)
+        .map(|d| d.values().copied().collect())
+        .unwrap_or_default();
+    let entities = smoothed(&store, &EntityCountDiagnosticsPlugin::ENTITY_COUNT);
+    let reveal = store.get(&h_terrain::REVEAL_TIME);
+    let reveal_last = reveal.and_then(|d| d.value()).unwrap_or(f64::NAN);
+    let reveal_avg = reveal.and_then(|d| d.average()).unwrap_or(f64::NAN);
+
+    let line = |ui: &mut egui::Ui, text: String| {
+        ui.label(
+            egui::RichText::new(text)
+                .color(TEXT)
+                .font(egui::FontId::monospace(12.0)),
+        );
+    };
+
+    egui::Area::new(egui::Id::new("perf_overlay"))
+        .anchor(egui::Align2::LEFT_CENTER, [8.0, 0.0])
+        .interactable(false)
+        .show(egui_ctx.get_mut(), |ui| {
+            egui::Frame::new()
+                .fill(egui::Color32::from_black_alpha(160))
+                .inner_margin(egui::Margin::same(6))
+                .corner_radius(4.0)
+                .show(ui, |ui| {
+                    ui.spacing_mut().item_spacing.y = 1.0;
+                    ui.label(
+                        egui::RichText::new(format!("{fps:.0} fps  {frame_ms:.2} ms"))
+                            .color(TEXT)
+                            .font(egui::FontId::monospace(14.0)),
+                    );
+
+                    let (rect, _) = ui.allocate_exact_size(GRAPH_SIZE, egui::Sense::hover());
+                    let painter = ui.painter_at(rect);
+                    painter.rect_filled(rect, 2.0, egui::Color32::from_black_alpha(120));
+                    let scale = history.iter().copied().fold(2.0 * BUDGET_MS, f64::max);
+                    let budget_y = graph_y(BUDGET_MS, scale, rect.top(), rect.bottom());
+                    painter.hline(
+                        rect.x_range(),
+                        budget_y,
+                        egui::Stroke::new(1.0, egui::Color32::from_gray(90)),
+                    );
+                    if history.len() > 1 {
+                        let dx = rect.width() / (history.len() - 1) as f32;
+                        let points = history
+                            .iter()
+                            .enumerate()
+                            .map(|(i, &ms)| {
+                                egui::pos2(
+                                    rect.left() + i as f32 * dx,
+                                    graph_y(ms, scale, rect.top(), rect.bottom()),
+                                )
+                            })
+                            .collect();
+                        painter.add(egui::Shape::line(points, egui::Stroke::new(1.0, TEXT)));
+                    }
+                    line(ui, format!("max {scale:.1} ms"));
+
+                    ui.add_space(4.0);
+                    line(ui, format!("entities {entities:>8.0}"));
+                    for (name, count) in markers.rows() {
+                        line(ui, format!("  {name:<9}{count:>6}"));
+                    }
+
+                    ui.add_space(4.0);
+                    line(ui, format!("meshes    {:>6}", meshes.len()));
+                    line(ui, format!("materials {:>6}", materials.len()));
+                    line(ui, format!("images    {:>6}", images.len()));
+
+                    ui.add_space(4.0);
+                    line(
+                        ui,
+                        format!("reveal {reveal_last:.2} ms (avg {reveal_avg:.2})"),
+                    );
+                });
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn graph_y_maps_and_clamps() {
+        assert_eq!(graph_y(0.0, 40.0, 10.0, 70.0), 70.0);
+        assert_eq!(graph_y(20.0, 40.0, 10.0, 70.0), 40.0);
+        assert_eq!(graph_y(40.0, 40.0, 10.0, 70.0), 10.0);
+        assert_eq!(graph_y(100.0, 40.0, 10.0, 70.0), 10.0);
+    }
+}
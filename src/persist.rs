@@ -0,0 +1,73 @@
+//! RON files behind the plugins that keep state between runs (bookmarks, the ghost flight,
+//! the leaderboard, the session).
+//!
+//! Both helpers log failures instead of returning them, so a missing or broken file never stops
+//! the app, and both are no-ops on WASM, where there is no file system.
+
+use bevy::prelude::*;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+/// Reads `path` as RON, falling back to `T::default()` when the file is missing or unreadable
+/// (the latter is logged with `what` naming the contents). Always the default on WASM.
+pub fn load_ron<T: DeserializeOwned + Default>(path: &str, what: &str) -> T {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let Ok(text) = std::fs::read_to_string(path) else {
+            return T::default();
+        };
+        match ron::from_str(&text) {
+            Ok(value) => return value,
+            Err(e) => warn!("Ignoring unreadable {what} file {path}: {e}"),
+        }
+    }
+    #[cfg(target_arch = "wasm32")]
+    let _ = (path, what);
+    T::default()
+}
+
+/// Writes `value` to `path` as pretty-printed RON, logging failures with `what` naming the
+/// contents (no-op on WASM).
+pub fn save_ron<T: Serialize>(value: &T, path: &str, what: &str) {
+    #[cfg(not(target_arch = "wasm32"))]
+    match ron::ser::to_string_pretty(value, ron::ser::PrettyConfig::default()) {
+        Ok(text) => {
+            if let Err(e) = std::fs::write(path, text) {
+                warn!("Failed to write {what} to {path}: {e}");
+            }
+        }
+        Err(e) => warn!("Failed to serialize {what}: {e}"),
+    }
+    #[cfg(target_arch = "wasm32")]
+    let _ = (value, path, what);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+
+    type Slots = BTreeMap<u8, (String, [f32; 2])>;
+
+    #[test]
+    fn values_round_trip_and_missing_or_broken_files_load_the_default() {
+        let path =
+            std::env::temp_dir().join(format!("hex-terrain-persist-{}.ron", std::process::id()));
+        let path = path.to_str().unwrap();
+        let slots: Slots = [
+            (1, ("ridge".into(), [0.5, -2.0])),
+            (7, (String::new(), [3.0, 4.0])),
+        ]
+        .into();
+
+        save_ron(&slots, path, "test slots");
+        assert_eq!(load_ron::<Slots>(path, "test slots"), slots);
+
+        std::fs::write(path, "not ron").unwrap();
+        assert!(load_ron::<Slots>(path, "test slots").is_empty());
+
+        std::fs::remove_file(path).unwrap();
+        assert!(load_ron::<Slots>(path, "test slots").is_empty());
+    }
+}
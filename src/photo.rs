@@ -0,0 +1,452 @@
+//! Photo mode: frame a shot, save a screenshot, and check it against photo challenges.
+//!
+//! P ([`toggle_photo_mode`]) switches [`PhotoMode`] while flying: the HUD strip gives way to a
+//! viewfinder ([`draw_viewfinder`]). Enter writes a [`PhotoTaken`]; [`save_photo`] captures
+//! the window to [`PhotoConfig::dir`] (native only) and [`judge_photo`] checks every
+//! [`PhotoConfig::targets`] composition not yet in the [`PhotoAlbum`]: the subject
+//! ([`photo_subject`]: the tallest or lowest cell, or the origin hex) must sit inside the frame
+//! and in range, unhidden by terrain (a ray from the camera that only hits hex faces), and the
+//! drone's altitude must fit the target ([`composition_met`]). Matches
+//! go into the album and are toasted. With [`PhotoConfig::objectives`] each target is also
+//! queued as an [`Objective::Photograph`], which completes from the album.
+
+use bevy::prelude::*;
+use bevy::render::view::screenshot::{Screenshot, save_to_disk};
+use bevy_egui::egui;
+use hexx::Hex;
+
+use crate::controls::{Binding, BindingCategory, RegisterBinding, Trigger};
+use crate::drone::Player;
+use crate::h_terrain::{HCell, HexFace};
+use crate::locale::Locale;
+use crate::objectives::{Objective, Objectives};
+use crate::toast::Toast;
+use crate::{GameState, PlayerPos};
+
+/// Distance short of the subject at which a face hit still counts as the subject itself.
+const OCCLUSION_SLACK: f32 = 0.1;
+
+/// A shot to take.
+#[derive(Clone, Debug, PartialEq, Reflect)]
+pub enum PhotoTarget {
+    /// The tallest cell, shot from no higher than `max_altitude` above the ground.
+    TallestPeak {
+        /// Highest allowed drone altitude.
+        max_altitude: f32,
+    },
+    /// The lowest cell, shot from at least `min_altitude` above the ground.
+    DeepestBasin {
+        /// Lowest allowed drone altitude.
+        min_altitude: f32,
+    },
+    /// The origin hex, shot from within `max_distance`.
+    Origin {
+        /// Farthest allowed camera distance.
+        max_distance: f32,
+    },
+}
+
+impl PhotoTarget {
+    /// Localized description for toasts and the objective list.
+    pub fn label(&self, locale: &Locale) -> String {
+        match *self {
+            Self::TallestPeak { max_altitude } => locale.fmt(
+                "Photograph the tallest peak from below {0}",
+                &[&format!("{max_altitude:.0}")],
+            ),
+            Self::DeepestBasin { min_altitude } => locale.fmt(
+                "Photograph the deepest basin from above {0}",
+                &[&format!("{min_altitude:.0}")],
+            ),
+            Self::Origin { max_distance } => locale.fmt(
+                "Photograph the origin from within {0}",
+                &[&format!("{max_distance:.0}")],
+            ),
+        }
+    }
+}
+
+/// Per-plugin configuration for photo mode.
+#[derive(Resource, Clone, Debug, Reflect)]
+pub struct PhotoConfig {
+    /// Compositions to shoot.
+    pub targets: Vec<PhotoTarget>,
+    /// Queue every target as an objective at startup.
+    pub objectives: bool,
+    /// Subjects must sit this share of the half-frame away from the edges (0 = anywhere on
+    /// screen).
+    pub frame_margin: f32,
+    /// Farthest a subject may be from the camera.
+    pub max_range: f32,
+    /// Screenshot directory (native only).
+    pub dir: String,
+}
+
+impl Default for PhotoConfig {
+    fn default() -> Self {
+        Self {
+            targets: vec![
+                PhotoTarget::TallestPeak { max_altitude: 5.0 },
+                PhotoTarget::DeepestBasin { min_altitude: 30.0 },
+                PhotoTarget::Origin { max_distance: 25.0 },
+            ],
+            objectives: true,
+            frame_margin: 0.2,
+            max_range: 150.0,
+            dir: "photos".into(),
+        }
+    }
+}
+
+/// Whether photo mode is on.
+#[derive(Resource, Default, Reflect)]
+pub struct PhotoMode(pub bool);
+
+/// Targets captured so far, in capture order.
+#[derive(Resource, Default, Reflect)]
+pub struct PhotoAlbum(pub Vec<PhotoTarget>);
+
+/// Written when a photo is taken.
+#[derive(Message, Clone, Copy, Debug)]
+pub struct PhotoTaken;
+
+/// Where a subject landed in a shot.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Framing {
+    /// Normalized device position: `-1.0..=1.0` on both axes inside the frame.
+    pub ndc: Vec2,
+    /// Distance from the camera.
+    pub distance: f32,
+    /// Whether the subject is in front of the camera.
+    pub in_front: bool,
+    /// Whether the line of sight to the subject is clear of terrain.
+    pub visible: bool,
+}
+
+/// World position of the subject of `target` among `cells` (hex and cell center).
+pub fn photo_subject(
+    target: &PhotoTarget,
+    cells: impl IntoIterator<Item = (Hex, Vec3)>,
+) -> Option<Vec3> {
+    let mut cells = cells.into_iter();
+    match target {
+        PhotoTarget::TallestPeak { .. } => cells.map(|(_, p)| p).max_by(|a, b| a.y.total_cmp(&b.y)),
+        PhotoTarget::DeepestBasin { .. } => {
+            cells.map(|(_, p)| p).min_by(|a, b| a.y.total_cmp(&b.y))
+        }
+        PhotoTarget::Origin { .. } => cells.find(|(hex, _)| *hex == Hex::ZERO).map(|(_, p)| p),
+    }
+}
+
+/// Whether a shot from `altitude` (above the ground) with the subject at `framing` meets
+/// `target` under `cfg`'s frame margin and range.
+pub fn composition_met(
+    target: &PhotoTarget,
+    altitude: f32,
+    framing: Framing,
+    cfg: &PhotoConfig,
+) -> bool {
+    let edge = 1.0 - cfg.frame_margin.clamp(0.0, 1.0);
+    let framed = framing.in_front
+        && framing.visible
+        && framing.ndc.abs().max_element() <= edge
+        && framing.distance <= cfg.max_range;
+    framed
+        && match *target {
+            PhotoTarget::TallestPeak { max_altitude } => altitude <= max_altitude,
+            PhotoTarget::DeepestBasin { min_altitude } => altitude >= min_altitude,
+            PhotoTarget::Origin { max_distance } => framing.distance <= max_distance,
+        }
+}
+
+/// Photo mode plugin.
+pub struct PhotoPlugin {
+    /// Per-plugin configuration.
+    pub config: PhotoConfig,
+}
+
+impl Plugin for PhotoPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<PhotoConfig>()
+            .register_type::<PhotoMode>()
+            .register_type::<PhotoAlbum>()
+            .insert_resource(self.config.clone())
+            .init_resource::<PhotoMode>()
+            .init_resource::<PhotoAlbum>()
+            .add_message::<PhotoTaken>()
+            .add_systems(
+                Startup,
+                queue_photo_objectives.run_if(|cfg: Res<PhotoConfig>| cfg.objectives),
+            )
+            .add_systems(
+                Update,
+                (
+                    toggle_photo_mode,
+                    (
+                        shoot_photo,
+                        (save_photo, judge_photo).run_if(on_message::<PhotoTaken>),
+                        draw_viewfinder,
+                    )
+                        .chain()
+                        .run_if(|mode: Res<PhotoMode>| mode.0),
+                )
+                    .chain()
+                    .run_if(in_state(GameState::Running)),
+            )
+            .add_systems(OnExit(GameState::Running), leave_photo_mode)
+            .register_binding(Binding::new(
+                BindingCategory::Drone,
+                "Photo mode",
+                [Trigger::Key(KeyCode::KeyP)],
+            ))
+            .register_binding(Binding::new(
+                BindingCategory::Drone,
+                "Take photo (photo mode)",
+                [Trigger::Key(KeyCode::Enter)],
+            ));
+    }
+}
+
+/// Appends every photo target to the objective queue.
+fn queue_photo_objectives(cfg: Res<PhotoConfig>, mut objectives: ResMut<Objectives>) {
+    objectives
+        .pending
+        .extend(cfg.targets.iter().cloned().map(Objective::Photograph));
+}
+
+/// P toggles photo mode.
+fn toggle_photo_mode(keys: Res<ButtonInput<KeyCode>>, mut mode: ResMut<PhotoMode>) {
+    if keys.just_pressed(KeyCode::KeyP) {
+        mode.0 = !mode.0;
+    }
+}
+
+/// Photo mode only lasts while flying.
+fn leave_photo_mode(mut mode: ResMut<PhotoMode>) {
+    mode.0 = false;
+}
+
+/// Enter takes a photo.
+fn shoot_photo(keys: Res<ButtonInput<KeyCode>>, mut taken: MessageWriter<PhotoTaken>) {
+    if keys.just_pressed(KeyCode::Enter) {
+        taken.write(PhotoTaken);
+    }
+}
+
+/// Captures the window to a numbered PNG in [`PhotoConfig::dir`] (no-op on WASM).
+fn save_photo(cfg: Res<PhotoConfig>, mut count: Local<u32>, mut commands: Commands) {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        if let Err(e) = std::fs::create_dir_all(&cfg.dir) {
+            warn!("Failed to create photo directory {}: {e}", cfg.dir);
+            return;
+        }
+        *count += 1;
+        let path = std::path::Path::new(&cfg.dir).join(format!(
+            "photo-{}-{:03}.png",
+            std::process::id(),
+            *count
+        ));
+        commands
+            .spawn(Screenshot::primary_window())
+            .observe(save_to_disk(path));
+    }
+    #[cfg(target_arch = "wasm32")]
+    let _ = (cfg, count, commands);
+}
+
+/// Checks the shot against the targets still missing from the album.
+#[allow(clippy::too_many_arguments)]
+fn judge_photo(
+    cfg: Res<PhotoConfig>,
+    player: Res<PlayerPos>,
+    camera: Single<(&Camera, &GlobalTransform), With<Player>>,
+    cells: Query<(&HCell, &GlobalTransform)>,
+    faces: Query<&ChildOf, With<HexFace>>,
+    mut raycast: MeshRayCast,
+    mut album: ResMut<PhotoAlbum>,
+    mut toasts: MessageWriter<Toast>,
+    locale: Res<Locale>,
+) {
+    let (camera, cam_gt) = *camera;
+    let eye = cam_gt.translation();
+    // Terrain faces only, so overlays and props never hide the subject.
+    let filter = |e| faces.contains(e);
+    let settings = MeshRayCastSettings::default().with_filter(&filter);
+    let mut matched = Vec::new();
+    for target in &cfg.targets {
+        if album.0.contains(target) {
+            continue;
+        }
+        let Some(subject) = photo_subject(
+            target,
+            cells.iter().map(|(c, tf)| (c.hex, tf.translation())),
+        ) else {
+            continue;
+        };
+        let Some(ndc) = camera.world_to_ndc(cam_gt, subject) else {
+            continue;
+        };
+        let distance = eye.distance(subject);
+        let blocker = Dir3::new(subject - eye).ok().and_then(|dir| {
+            raycast
+                .cast_ray(Ray3d::new(eye, dir), &settings)
+                .first()
+                .map(|(hit, data)| (*hit, data.distance))
+        });
+        let visible = match blocker {
+            Some((face, d)) => {
+                d >= distance - OCCLUSION_SLACK
+                    || faces
+                        .get(face)
+                        .and_then(|p| cells.get(p.parent()))
+                        .is_ok_and(|(_, tf)| tf.translation() == subject)
+            }
+            None => true,
+        };
+        let framing = Framing {
+            ndc: ndc.truncate(),
+            distance,
+            in_front: (subject - eye).dot(*cam_gt.forward()) > 0.0,
+            visible,
+        };
+        if composition_met(target, player.offset, framing, &cfg) {
+            matched.push(target.clone());
+        }
+    }
+    if matched.is_empty() {
+        toasts.write(Toast(locale.t("Photo saved").into()));
+    }
+    for target in matched {
+        toasts.write(Toast(
+            locale.fmt("Photo challenge complete: {0}", &[&target.label(&locale)]),
+        ));
+        album.0.push(target);
+    }
+}
+
+/// Corner brackets, a center mark and the shutter hint.
+fn draw_viewfinder(mut egui_ctx: Single<&mut bevy_egui::EguiContext>, locale: Res<Locale>) {
+    let ctx = egui_ctx.get_mut();
+    let color = egui::Color32::from_white_alpha(200);
+    let stroke = egui::Stroke::new(2.0, color);
+    egui::Area::new(egui::Id::new("viewfinder"))
+        .fixed_pos(egui::pos2(0.0, 0.0))
+        .order(egui::Order::Background)
+        .interactable(false)
+        .show(ctx, |ui| {
+            let rect = ui.ctx().content_rect().shrink(24.0);
+            let arm = rect.width().min(rect.height()) * 0.08;
+            let painter = ui.painter();
+            for (corner, dx, dy) in [
+                (rect.left_top(), 1.0, 1.0),
+                (rect.right_top(), -1.0, 1.0),
+                (rect.left_bottom(), 1.0, -1.0),
+                (rect.right_bottom(), -1.0, -1.0),
+            ] {
+                painter.line_segment([corner, corner + egui::vec2(arm * dx, 0.0)], stroke);
+                painter.line_segment([corner, corner + egui::vec2(0.0, arm * dy)], stroke);
+            }
+            let c = rect.center();
+            painter.circle_stroke(c, 6.0, stroke);
+            painter.text(
+                rect.center_bottom() - egui::vec2(0.0, 8.0),
+                egui::Align2::CENTER_BOTTOM,
+                locale.t("PHOTO  Enter: shoot  P: exit"),
+                egui::FontId::monospace(14.0),
+                color,
+            );
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::h_terrain::ActiveHex;
+    use crate::objectives::{ObjectiveCompleted, track_objectives};
+
+    fn framed(distance: f32) -> Framing {
+        Framing {
+            ndc: Vec2::new(0.1, -0.2),
+            distance,
+            in_front: true,
+            visible: true,
+        }
+    }
+
+    #[test]
+    fn subjects_are_the_extreme_cells_and_the_origin() {
+        let cells = [
+            (Hex::new(1, 0), Vec3::new(2.0, 7.0, 0.0)),
+            (Hex::ZERO, Vec3::new(0.0, 3.0, 0.0)),
+            (Hex::new(-1, 0), Vec3::new(-2.0, -4.0, 0.0)),
+        ];
+        let peak = PhotoTarget::TallestPeak { max_altitude: 5.0 };
+        let basin = PhotoTarget::DeepestBasin { min_altitude: 30.0 };
+        let origin = PhotoTarget::Origin { max_distance: 25.0 };
+        assert_eq!(photo_subject(&peak, cells).unwrap().y, 7.0);
+        assert_eq!(photo_subject(&basin, cells).unwrap().y, -4.0);
+        assert_eq!(photo_subject(&origin, cells).unwrap().y, 3.0);
+        assert_eq!(photo_subject(&origin, [cells[0]]), None);
+    }
+
+    #[test]
+    fn compositions_check_frame_range_and_altitude() {
+        let cfg = PhotoConfig::default();
+        let peak = PhotoTarget::TallestPeak { max_altitude: 5.0 };
+        assert!(composition_met(&peak, 4.0, framed(50.0), &cfg));
+        assert!(!composition_met(&peak, 6.0, framed(50.0), &cfg));
+        // Near the edge, behind the camera, hidden by terrain, or too far: not framed.
+        let edge = Framing {
+            ndc: Vec2::new(0.9, 0.0),
+            ..framed(50.0)
+        };
+        assert!(!composition_met(&peak, 4.0, edge, &cfg));
+        let behind = Framing {
+            in_front: false,
+            ..framed(50.0)
+        };
+        assert!(!composition_met(&peak, 4.0, behind, &cfg));
+        let hidden = Framing {
+            visible: false,
+            ..framed(50.0)
+        };
+        assert!(!composition_met(&peak, 4.0, hidden, &cfg));
+        assert!(!composition_met(&peak, 4.0, framed(200.0), &cfg));
+
+        let basin = PhotoTarget::DeepestBasin { min_altitude: 30.0 };
+        assert!(composition_met(&basin, 35.0, framed(80.0), &cfg));
+        assert!(!composition_met(&basin, 20.0, framed(80.0), &cfg));
+        let origin = PhotoTarget::Origin { max_distance: 25.0 };
+        assert!(composition_met(&origin, 10.0, framed(20.0), &cfg));
+        assert!(!composition_met(&origin, 10.0, framed(30.0), &cfg));
+    }
+
+    #[test]
+    fn album_entries_complete_photo_objectives() {
+        let peak = PhotoTarget::TallestPeak { max_altitude: 5.0 };
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_message::<ObjectiveCompleted>()
+            .init_resource::<ActiveHex>()
+            .init_resource::<PlayerPos>()
+            .init_resource::<PhotoAlbum>()
+            .insert_resource(PhotoConfig {
+                targets: vec![peak.clone()],
+                ..default()
+            })
+            .insert_resource(Objectives::new([]))
+            .add_systems(Startup, queue_photo_objectives)
+            .add_systems(Update, track_objectives);
+        app.update();
+        assert_eq!(
+            app.world().resource::<Objectives>().pending[0],
+            Objective::Photograph(peak.clone())
+        );
+        app.world_mut().resource_mut::<PhotoAlbum>().0.push(peak);
+        app.update();
+        let objectives = app.world().resource::<Objectives>();
+        assert!(objectives.pending.is_empty());
+        assert_eq!(objectives.completed.len(), 1);
+    }
+}
@@ -0,0 +1,681 @@
+//! Full-screen post-processing for the drone camera.
+//!
+//! Effects are components on the [`Player`] camera, kept in sync with [`PostFxConfig`] so
+//! they can be tweaked at runtime. The CRT and vignette passes are [`FullscreenMaterial`]s
+//! running after tonemapping; chromatic aberration, SSAO, and motion blur are Bevy's built-in
+//! effects.
+//!
+//! [`PostFxConfig::god_rays`] adds a screen-space light-shaft pass whose sources are the
+//! brightest [`HexFace`]s on screen, re-picked every frame.
+//!
+//! [`PostFxConfig::render_scale`] renders the drone camera into an offscreen image at a
+//! fraction (or multiple) of the window resolution; a [`RenderScalePresenter`] camera then
+//! stretches that image over the window with linear filtering.
+
+use bevy::asset::embedded_asset;
+use bevy::camera::visibility::RenderLayers;
+use bevy::camera::{RenderTarget, ScalingMode};
+use bevy::core_pipeline::core_3d::graph::Node3d;
+use bevy::core_pipeline::fullscreen_material::{FullscreenMaterial, FullscreenMaterialPlugin};
+use bevy::core_pipeline::prepass::{DepthPrepass, MotionVectorPrepass, NormalPrepass};
+use bevy::core_pipeline::tonemapping::Tonemapping;
+use bevy::pbr::ScreenSpaceAmbientOcclusion;
+use bevy::post_process::effect_stack::ChromaticAberration;
+use bevy::post_process::motion_blur::MotionBlur;
+use bevy::prelude::*;
+use bevy::render::extract_component::ExtractComponent;
+use bevy::render::render_graph::{InternedRenderLabel, RenderLabel};
+use bevy::render::render_resource::{Extent3d, ShaderType, TextureFormat};
+use bevy::shader::ShaderRef;
+use bevy::window::{PrimaryWindow, WindowRef};
+use bevy_egui::{EguiContext, PrimaryEguiContext};
+
+use crate::drone::Player;
+use crate::h_terrain::HexFace;
+
+/// Asset path of the embedded CRT shader.
+const CRT_SHADER_PATH: &str = "embedded://hex_terrain/post_fx/crt.wgsl";
+/// Asset path of the embedded vignette shader.
+const VIGNETTE_SHADER_PATH: &str = "embedded://hex_terrain/post_fx/vignette.wgsl";
+/// Asset path of the embedded god-ray shader.
+const GOD_RAYS_SHADER_PATH: &str = "embedded://hex_terrain/post_fx/god_rays.wgsl";
+/// Maximum number of god-ray sources (size of the shader's source array).
+pub const GOD_RAY_SOURCES: usize = 4;
+/// Render layer of the upscaling quad, never seen by the drone camera.
+const PRESENT_LAYER: usize = 31;
+
+/// Per-plugin configuration for camera post-processing.
+#[derive(Resource, Clone, Debug, Reflect)]
+pub struct PostFxConfig {
+    /// Enable the CRT pass (scanlines, barrel distortion, phosphor glow).
+    pub crt: bool,
+    /// Number of scanlines across the screen height.
+    pub crt_scanline_count: f32,
+    /// Scanline darkening (0 = none, 1 = black gaps).
+    pub crt_scanline_strength: f32,
+    /// Barrel distortion amount (0 = flat screen).
+    pub crt_curvature: f32,
+    /// Phosphor glow added from neighboring pixels.
+    pub crt_glow: f32,
+    /// Corner darkening of the vignette (0 disables).
+    pub vignette_strength: f32,
+    /// Distance from the screen center (0..1, corners ≈ 1) where the vignette begins.
+    pub vignette_radius: f32,
+    /// Width of the vignette falloff.
+    pub vignette_softness: f32,
+    /// Chromatic aberration streak size as a fraction of the window (0 disables).
+    pub chromatic_aberration: f32,
+    /// MSAA sample count for the camera (1, 2, 4, or 8; 1 disables).
+    pub msaa_samples: u32,
+    /// Internal render resolution as a fraction of the window (clamped to 0.5..=2.0;
+    /// 1.0 renders straight to the window).
+    pub render_scale: f32,
+    /// Screen-space ambient occlusion for contact shading between cells (native only;
+    /// forces MSAA off).
+    pub ssao: bool,
+    /// Per-object motion blur from the motion-vector prepass (boost flight, replays).
+    pub motion_blur: bool,
+    /// Fraction of a frame the virtual shutter is open (0.5 = 180°; above 1 over-blurs).
+    pub motion_blur_shutter_angle: f32,
+    /// Blur samples taken in each direction per pixel.
+    pub motion_blur_samples: u32,
+    /// Screen-space light shafts from the brightest on-screen hex faces (costly at high
+    /// sample counts).
+    pub god_rays: bool,
+    /// Number of brightest faces used as shaft sources (clamped to [`GOD_RAY_SOURCES`]).
+    pub god_rays_sources: usize,
+    /// Brightness of the shafts added to the frame.
+    pub god_rays_intensity: f32,
+    /// Per-sample falloff along a shaft (closer to 1 = longer streaks).
+    pub god_rays_decay: f32,
+    /// Fraction of the way toward a source each pixel marches.
+    pub god_rays_density: f32,
+    /// Luminance a pixel needs before it feeds the shafts.
+    pub god_rays_threshold: f32,
+    /// Samples per source and pixel.
+    pub god_rays_samples: u32,
+}
+
+impl Default for PostFxConfig {
+    fn default() -> Self {
+        Self {
+            crt: false,
+            crt_scanline_count: 320.0,
+            crt_scanline_strength: 0.3,
+            crt_curvature: 0.06,
+            crt_glow: 0.35,
+            vignette_strength: 0.25,
+            vignette_radius: 0.55,
+            vignette_softness: 0.45,
+            chromatic_aberration: 0.0,
+            msaa_samples: 4,
+            render_scale: 1.0,
+            ssao: false,
+            motion_blur: false,
+            motion_blur_shutter_angle: 0.5,
+            motion_blur_samples: 2,
+            god_rays: false,
+            god_rays_sources: 3,
+            god_rays_intensity: 0.8,
+            god_rays_decay: 0.96,
+            god_rays_density: 0.7,
+            god_rays_threshold: 0.5,
+            god_rays_samples: 32,
+        }
+    }
+}
+
+/// CRT post-process settings, uploaded as the pass uniform.
+#[derive(Component, ExtractComponent, Clone, Copy, Debug, Default, ShaderType, Reflect)]
+pub struct CrtEffect {
+    /// Number of scanlines across the screen height.
+    pub scanline_count: f32,
+    /// Scanline darkening (0..=1).
+    pub scanline_strength: f32,
+    /// Barrel distortion amount.
+    pub curvature: f32,
+    /// Phosphor glow amount.
+    pub glow: f32,
+}
+
+impl CrtEffect {
+    /// Builds the effect settings from `cfg`.
+    pub fn new(cfg: &PostFxConfig) -> Self {
+        Self {
+            scanline_count: cfg.crt_scanline_count,
+            scanline_strength: cfg.crt_scanline_strength,
+            curvature: cfg.crt_curvature,
+            glow: cfg.crt_glow,
+        }
+    }
+}
+
+impl FullscreenMaterial for CrtEffect {
+    fn fragment_shader() -> ShaderRef {
+        CRT_SHADER_PATH.into()
+    }
+
+    fn node_edges() -> Vec<InternedRenderLabel> {
+        vec![
+            Node3d::Tonemapping.intern(),
+            Self::node_label().intern(),
+            Node3d::EndMainPassPostProcessing.intern(),
+        ]
+    }
+}
+
+/// Vignette settings, uploaded as the pass uniform.
+#[derive(Component, ExtractComponent, Clone, Copy, Debug, Default, ShaderType, Reflect)]
+pub struct VignetteEffect {
+    /// Corner darkening (0..=1).
+    pub strength: f32,
+    /// Normalized distance from the center where darkening begins.
+    pub radius: f32,
+    /// Width of the falloff.
+    pub softness: f32,
+}
+
+impl VignetteEffect {
+    /// Builds the effect settings from `cfg`.
+    pub fn new(cfg: &PostFxConfig) -> Self {
+        Self {
+            strength: cfg.vignette_strength,
+            radius: cfg.vignette_radius,
+            softness: cfg.vignette_softness,
+        }
+    }
+}
+
+impl FullscreenMaterial for VignetteEffect {
+    fn fragment_shader() -> ShaderRef {
+        VIGNETTE_SHADER_PATH.into()
+    }
+
+    fn node_edges() -> Vec<InternedRenderLabel> {
+        vec![
+            Node3d::Tonemapping.intern(),
+            Self::node_label().intern(),
+            Node3d::EndMainPassPostProcessing.intern(),
+        ]
+    }
+}
+
+/// God-ray settings and current sources, uploaded as the pass uniform.
+#[derive(Component, ExtractComponent, Clone, Copy, Debug, Default, ShaderType, Reflect)]
+pub struct GodRaysEffect {
+    /// Source UVs in `xy` and weights in `z` (weight 0 = unused slot).
+    pub sources: [Vec4; GOD_RAY_SOURCES],
+    /// Brightness of the shafts.
+    pub intensity: f32,
+    /// Per-sample falloff.
+    pub decay: f32,
+    /// Fraction of the way toward a source marched.
+    pub density: f32,
+    /// Luminance threshold for contributing pixels.
+    pub threshold: f32,
+    /// Samples per source and pixel.
+    pub samples: u32,
+}
+
+impl GodRaysEffect {
+    /// Builds the effect settings from `cfg`, with no sources yet.
+    pub fn new(cfg: &PostFxConfig) -> Self {
+        Self {
+            sources: [Vec4::ZERO; GOD_RAY_SOURCES],
+            intensity: cfg.god_rays_intensity,
+            decay: cfg.god_rays_decay,
+            density: cfg.god_rays_density,
+            threshold: cfg.god_rays_threshold,
+            samples: cfg.god_rays_samples,
+        }
+    }
+}
+
+impl FullscreenMaterial for GodRaysEffect {
+    fn fragment_shader() -> ShaderRef {
+        GOD_RAYS_SHADER_PATH.into()
+    }
+
+    fn node_edges() -> Vec<InternedRenderLabel> {
+        vec![
+            Node3d::Tonemapping.intern(),
+            Self::node_label().intern(),
+            Node3d::EndMainPassPostProcessing.intern(),
+        ]
+    }
+}
+
+/// Camera post-processing plugin.
+pub struct PostFxPlugin {
+    /// Per-plugin configuration.
+    pub config: PostFxConfig,
+}
+
+impl Plugin for PostFxPlugin {
+    fn build(&self, app: &mut App) {
+        embedded_asset!(app, "post_fx/crt.wgsl");
+        embedded_asset!(app, "post_fx/vignette.wgsl");
+        embedded_asset!(app, "post_fx/god_rays.wgsl");
+        app.add_plugins(FullscreenMaterialPlugin::<CrtEffect>::default())
+            .add_plugins(FullscreenMaterialPlugin::<VignetteEffect>::default())
+            .add_plugins(FullscreenMaterialPlugin::<GodRaysEffect>::default())
+            .register_type::<PostFxConfig>()
+            .register_type::<CrtEffect>()
+            .register_type::<VignetteEffect>()
+            .register_type::<GodRaysEffect>()
+            .register_type::<RenderScalePresenter>()
+            .insert_resource(self.config.clone())
+            .add_systems(
+                Update,
+                (
+                    apply_post_fx.run_if(resource_changed::<PostFxConfig>),
+                    update_god_ray_sources
+                        .after(apply_post_fx)
+                        .run_if(|cfg: Res<PostFxConfig>| cfg.god_rays),
+                    apply_render_scale,
+                ),
+            );
+    }
+}
+
+/// Inserts or removes effect components on the drone camera to match [`PostFxConfig`].
+fn apply_post_fx(
+    cfg: Res<PostFxConfig>,
+    camera: Single<Entity, With<Player>>,
+    mut commands: Commands,
+) {
+    let mut camera = commands.entity(*camera);
+    if cfg.crt {
+        camera.insert(CrtEffect::new(&cfg));
+    } else {
+        camera.remove::<CrtEffect>();
+    }
+    if cfg.vignette_strength > 0.0 {
+        camera.insert(VignetteEffect::new(&cfg));
+    } else {
+        camera.remove::<VignetteEffect>();
+    }
+    if cfg.chromatic_aberration > 0.0 {
+        camera.insert(ChromaticAberration {
+            intensity: cfg.chromatic_aberration,
+            ..default()
+        });
+    } else {
+        camera.remove::<ChromaticAberration>();
+    }
+    // SSAO needs compute + storage textures, which WebGL2 lacks.
+    let ssao = cfg.ssao && !cfg!(target_arch = "wasm32");
+    if ssao {
+        camera.insert((ScreenSpaceAmbientOcclusion::default(), Msaa::Off));
+    } else {
+        camera
+            .remove::<(ScreenSpaceAmbientOcclusion, NormalPrepass)>()
+            .insert(Msaa::from_samples(cfg.msaa_samples));
+    }
+    if cfg.god_rays {
+        camera.insert(GodRaysEffect::new(&cfg));
+    } else {
+        camera.remove::<GodRaysEffect>();
+    }
+    if cfg.motion_blur {
+        camera.insert(MotionBlur {
+            shutter_angle: cfg.motion_blur_shutter_angle,
+            samples: cfg.motion_blur_samples,
+        });
+    } else {
+        camera.remove::<(MotionBlur, MotionVectorPrepass)>();
+    }
+    // The depth prepass came in as a required component of SSAO or motion blur; drop it once
+    // neither needs it, so the camera doesn't keep paying for the extra pass.
+    if !ssao && !cfg.motion_blur {
+        camera.remove::<DepthPrepass>();
+    }
+}
+
+/// The `count` brightest on-screen candidates as shader sources.
+///
+/// Candidates are `(uv, brightness)`; the chosen weights are normalized to sum to 1 so the
+/// shaft strength doesn't grow with the source count.
+pub fn god_ray_sources(
+    candidates: impl IntoIterator<Item = (Vec2, f32)>,
+    count: usize,
+) -> [Vec4; GOD_RAY_SOURCES] {
+    let mut picked: Vec<(Vec2, f32)> = candidates.into_iter().filter(|(_, b)| *b > 0.0).collect();
+    picked.sort_by(|a, b| b.1.total_cmp(&a.1));
+    picked.truncate(count.min(GOD_RAY_SOURCES));
+    let total: f32 = picked.iter().map(|(_, b)| b).sum();
+    let mut sources = [Vec4::ZERO; GOD_RAY_SOURCES];
+    for (slot, (uv, b)) in sources.iter_mut().zip(picked) {
+        *slot = uv.extend(b / total).extend(0.0);
+    }
+    sources
+}
+
+/// Re-picks the god-ray sources from the brightest [`HexFace`]s in front of the camera.
+///
+/// Brightness is the luminance of the face material's base color plus emissive, so lit
+/// (FoV), glowing, and highlighted faces win over fogged ones.
+fn update_god_ray_sources(
+    cfg: Res<PostFxConfig>,
+    mut camera: Query<(&Camera, &GlobalTransform, &mut GodRaysEffect), With<Player>>,
+    faces: Query<(&GlobalTransform, &MeshMaterial3d<StandardMaterial>), With<HexFace>>,
+    materials: Res<Assets<StandardMaterial>>,
+) {
+    let Ok((camera, cam_tf, mut effect)) = camera.single_mut() else {
+        return;
+    };
+    let candidates = faces.iter().filter_map(|(tf, mat)| {
+        let ndc = camera.world_to_ndc(cam_tf, tf.translation())?;
+        if ndc.z <= 0.0 || ndc.z > 1.0 || ndc.x.abs() > 1.0 || ndc.y.abs() > 1.0 {
+            return None;
+        }
+        let mat = materials.get(&mat.0)?;
+        let brightness = LinearRgba::from(mat.base_color).luminance() + mat.emissive.luminance();
+        Some((Vec2::new(ndc.x * 0.5 + 0.5, 0.5 - ndc.y * 0.5), brightness))
+    });
+    effect.sources = god_ray_sources(candidates, cfg.god_rays_sources);
+}
+
+/// Marker on the camera and quad that upscale the drone camera's scaled render target.
+#[derive(Component, Reflect)]
+pub struct RenderScalePresenter;
+
+/// Physical size of the drone camera's render target for a `window` size and `scale`.
+pub fn render_target_size(window: UVec2, scale: f32) -> UVec2 {
+    (window.as_vec2() * scale.clamp(0.5, 2.0))
+        .round()
+        .as_uvec2()
+        .max(UVec2::ONE)
+}
+
+/// Retargets the drone camera to match [`PostFxConfig::render_scale`] and the window size.
+///
+/// At scale 1.0 the camera renders to the window. Otherwise it renders into an offscreen
+/// image (resized along with the window) and the primary egui context moves to the
+/// presenter camera, so overlays stay crisp and receive window input.
+#[allow(clippy::too_many_arguments)]
+fn apply_render_scale(
+    cfg: Res<PostFxConfig>,
+    window: Single<&Window, With<PrimaryWindow>>,
+    camera: Single<(Entity, &RenderTarget), With<Player>>,
+    presenters: Query<Entity, With<RenderScalePresenter>>,
+    egui: Query<Entity, With<PrimaryEguiContext>>,
+    mut images: ResMut<Assets<Image>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut commands: Commands,
+) {
+    let (player, target) = *camera;
+    if (cfg.render_scale - 1.0).abs() < 1e-3 {
+        if !presenters.is_empty() {
+            for entity in &presenters {
+                commands.entity(entity).despawn();
+            }
+            commands
+                .entity(player)
+                .insert((
+                    RenderTarget::Window(WindowRef::Primary),
+                    EguiContext::default(),
+                ))
+                .insert(PrimaryEguiContext);
+        }
+        return;
+    }
+
+    let size = render_target_size(window.physical_size(), cfg.render_scale);
+    if let Some(image) = target.as_image() {
+        if let Some(image) = images.get_mut(image)
+            && image.size() != size
+        {
+            image.resize(Extent3d {
+                width: size.x,
+                height: size.y,
+                ..default()
+            });
+        }
+        return;
+    }
+
+    let image = images.add(Image::new_target_texture(
+        size.x,
+        size.y,
+        TextureFormat::Bgra8UnormSrgb,
+        None,
+    ));
+    commands
+        .entity(player)
+        .insert(RenderTarget::from(image.clone()));
+    for entity in &egui {
+        commands
+            .entity(entity)
+            .remove::<(EguiContext, PrimaryEguiContext)>();
+    }
+    let layer = RenderLayers::layer(PRESENT_LAYER);
+    commands.spawn((
+        RenderScalePresenter,
+        Camera3d::default(),
+        Camera {
+            order: 1,
+            ..default()
+        },
+        Projection::from(OrthographicProjection {
+            scaling_mode: ScalingMode::Fixed {
+                width: 1.0,
+                height: 1.0,
+            },
+            ..OrthographicProjection::default_3d()
+        }),
+        Tonemapping::None,
+        Msaa::Off,
+        layer.clone(),
+        Transform::from_xyz(0.0, 0.0, 1.0),
+        EguiContext::default(),
+        PrimaryEguiContext,
+    ));
+    commands.spawn((
+        RenderScalePresenter,
+        Mesh3d(meshes.add(Rectangle::new(1.0, 1.0))),
+        MeshMaterial3d(materials.add(StandardMaterial {
+            base_color_texture: Some(image),
+            unlit: true,
+            ..default()
+        })),
+        layer,
+    ));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spawn_player(cfg: PostFxConfig) -> (App, Entity) {
+        let mut app = App::new();
+        app.insert_resource(cfg).add_systems(
+            Update,
+            apply_post_fx.run_if(resource_changed::<PostFxConfig>),
+        );
+        let player = app.world_mut().spawn(Player).id();
+        app.update();
+        (app, player)
+    }
+
+    #[test]
+    fn crt_attached_only_when_enabled() {
+        let (app, player) = spawn_player(PostFxConfig {
+            crt: true,
+            ..default()
+        });
+        let crt = app.world().get::<CrtEffect>(player).unwrap();
+        assert_eq!(crt.curvature, PostFxConfig::default().crt_curvature);
+
+        let (app, player) = spawn_player(PostFxConfig::default());
+        assert!(app.world().get::<CrtEffect>(player).is_none());
+    }
+
+    #[test]
+    fn motion_blur_follows_config() {
+        let (mut app, player) = spawn_player(PostFxConfig {
+            motion_blur: true,
+            motion_blur_shutter_angle: 1.25,
+            ..default()
+        });
+        let blur = app.world().get::<MotionBlur>(player).unwrap();
+        assert_eq!(blur.shutter_angle, 1.25);
+        assert!(app.world().get::<MotionVectorPrepass>(player).is_some());
+
+        app.world_mut().resource_mut::<PostFxConfig>().motion_blur = false;
+        app.update();
+        assert!(app.world().get::<MotionBlur>(player).is_none());
+        assert!(app.world().get::<MotionVectorPrepass>(player).is_none());
+        assert!(app.world().get::<DepthPrepass>(player).is_none());
+    }
+
+    #[test]
+    fn depth_prepass_stays_while_ssao_needs_it() {
+        let (mut app, player) = spawn_player(PostFxConfig {
+            ssao: true,
+            motion_blur: true,
+            ..default()
+        });
+        assert!(app.world().get::<NormalPrepass>(player).is_some());
+
+        app.world_mut().resource_mut::<PostFxConfig>().motion_blur = false;
+        app.update();
+        assert!(app.world().get::<DepthPrepass>(player).is_some());
+
+        app.world_mut().resource_mut::<PostFxConfig>().ssao = false;
+        app.update();
+        assert!(app.world().get::<DepthPrepass>(player).is_none());
+        assert!(app.world().get::<NormalPrepass>(player).is_none());
+    }
+
+    #[test]
+    fn god_rays_attached_only_when_enabled() {
+        let (mut app, player) = spawn_player(PostFxConfig {
+            god_rays: true,
+            god_rays_decay: 0.9,
+            ..default()
+        });
+        let rays = app.world().get::<GodRaysEffect>(player).unwrap();
+        assert_eq!(rays.decay, 0.9);
+        assert_eq!(rays.sources, [Vec4::ZERO; GOD_RAY_SOURCES]);
+
+        app.world_mut().resource_mut::<PostFxConfig>().god_rays = false;
+        app.update();
+        assert!(app.world().get::<GodRaysEffect>(player).is_none());
+    }
+
+    #[test]
+    fn god_ray_sources_pick_the_brightest_with_normalized_weights() {
+        let candidates = [
+            (Vec2::new(0.1, 0.1), 1.0),
+            (Vec2::new(0.5, 0.2), 3.0),
+            (Vec2::new(0.9, 0.9), 0.0),
+            (Vec2::new(0.3, 0.7), 2.0),
+        ];
+        let sources = god_ray_sources(candidates, 2);
+        assert_eq!(sources[0], Vec4::new(0.5, 0.2, 0.6, 0.0));
+        assert_eq!(sources[1], Vec4::new(0.3, 0.7, 0.4, 0.0));
+        assert_eq!(sources[2], Vec4::ZERO);
+
+        // Dark candidates never become sources, and the count is capped by the array.
+        let sources = god_ray_sources(candidates, 10);
+        assert_eq!(sources.iter().filter(|s| s.z > 0.0).count(), 3);
+        assert_eq!(god_ray_sources([(Vec2::ZERO, 0.0)], 4), [Vec4::ZERO; 4]);
+    }
+
+    #[test]
+    fn config_changes_update_camera_effects() {
+        let (mut app, player) = spawn_player(PostFxConfig::default());
+        assert!(app.world().get::<VignetteEffect>(player).is_some());
+        assert!(app.world().get::<ChromaticAberration>(player).is_none());
+        assert!(
+            app.world()
+                .get::<ScreenSpaceAmbientOcclusion>(player)
+                .is_none()
+        );
+
+        {
+            let mut cfg = app.world_mut().resource_mut::<PostFxConfig>();
+            cfg.vignette_strength = 0.0;
+            cfg.chromatic_aberration = 0.01;
+            cfg.ssao = true;
+        }
+        app.update();
+
+        assert!(
+            app.world()
+                .get::<ScreenSpaceAmbientOcclusion>(player)
+                .is_some()
+        );
+        assert_eq!(app.world().get::<Msaa>(player), Some(&Msaa::Off));
+
+        {
+            let mut cfg = app.world_mut().resource_mut::<PostFxConfig>();
+            cfg.ssao = false;
+            cfg.msaa_samples = 8;
+        }
+        app.update();
+        assert_eq!(app.world().get::<Msaa>(player), Some(&Msaa::Sample8));
+
+        assert!(app.world().get::<VignetteEffect>(player).is_none());
+        let ca = app.world().get::<ChromaticAberration>(player).unwrap();
+        assert_eq!(ca.intensity, 0.01);
+    }
+
+    #[test]
+    fn render_scale_targets_a_scaled_image_and_restores_the_window() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+            .init_asset::<Image>()
+            .init_asset::<Mesh>()
+            .init_asset::<StandardMaterial>()
+            .insert_resource(PostFxConfig {
+                render_scale: 0.5,
+                ..default()
+            })
+            .add_systems(Update, apply_render_scale);
+        let window = Window {
+            resolution: bevy::window::WindowResolution::new(1280, 720),
+            ..default()
+        };
+        app.world_mut().spawn((window, PrimaryWindow));
+        let player = app
+            .world_mut()
+            .spawn((Player, Camera3d::default(), EguiContext::default()))
+            .insert(PrimaryEguiContext)
+            .id();
+        app.update();
+
+        let image = app.world().get::<RenderTarget>(player).unwrap();
+        let image = image.as_image().expect("scaled camera renders offscreen");
+        let images = app.world().resource::<Assets<Image>>();
+        assert_eq!(images.get(image).unwrap().size(), UVec2::new(640, 360));
+        let w = app.world_mut();
+        assert_eq!(w.query::<&RenderScalePresenter>().iter(w).count(), 2);
+        let egui = w
+            .query_filtered::<Entity, With<PrimaryEguiContext>>()
+            .single(w)
+            .unwrap();
+        assert!(w.get::<RenderScalePresenter>(egui).is_some());
+
+        app.world_mut().resource_mut::<PostFxConfig>().render_scale = 1.0;
+        app.update();
+        let w = app.world_mut();
+        assert!(matches!(
+            w.get::<RenderTarget>(player),
+            Some(RenderTarget::Window(WindowRef::Primary))
+        ));
+        assert_eq!(w.query::<&RenderScalePresenter>().iter(w).count(), 0);
+        assert!(w.get::<PrimaryEguiContext>(player).is_some());
+    }
+
+    #[test]
+    fn render_target_size_scales_and_clamps() {
+        let window = UVec2::new(1920, 1080);
+        assert_eq!(render_target_size(window, 1.5), UVec2::new(2880, 1620));
+        assert_eq!(render_target_size(window, 0.1), UVec2::new(960, 540));
+        assert_eq!(render_target_size(window, 9.0), UVec2::new(3840, 2160));
+        assert_eq!(render_target_size(UVec2::ZERO, 1.0), UVec2::ONE);
+    }
+}
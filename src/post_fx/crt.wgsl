@@ -0,0 +1,50 @@
+// Retro CRT look: barrel distortion, scanlines, and a soft phosphor glow.
+//
+// Runs after tonemapping on the full-screen triangle; the input is the tonemapped frame.
+
+#import bevy_core_pipeline::fullscreen_vertex_shader::FullscreenVertexOutput
+
+struct CrtEffect {
+    scanline_count: f32,
+    scanline_strength: f32,
+    curvature: f32,
+    glow: f32,
+}
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var texture_sampler: sampler;
+@group(0) @binding(2) var<uniform> settings: CrtEffect;
+
+const TAU: f32 = 6.283185307;
+
+// Pushes UVs outward with the squared distance from the center, like a curved tube face.
+fn barrel(uv: vec2<f32>) -> vec2<f32> {
+    let c = uv * 2.0 - 1.0;
+    let warped = c * (1.0 + settings.curvature * dot(c, c));
+    return warped * 0.5 + 0.5;
+}
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+    let uv = barrel(in.uv);
+    let texel = 1.0 / vec2<f32>(textureDimensions(screen_texture));
+
+    let color = textureSample(screen_texture, texture_sampler, uv).rgb;
+
+    // Phosphor glow: wide cross-shaped blur, squared so only bright pixels bleed.
+    let o = texel * 2.5;
+    let blur = (
+        textureSample(screen_texture, texture_sampler, uv + vec2(o.x, 0.0)).rgb +
+        textureSample(screen_texture, texture_sampler, uv - vec2(o.x, 0.0)).rgb +
+        textureSample(screen_texture, texture_sampler, uv + vec2(0.0, o.y)).rgb +
+        textureSample(screen_texture, texture_sampler, uv - vec2(0.0, o.y)).rgb
+    ) * 0.25;
+    var out = color + settings.glow * blur * blur;
+
+    let scan = 0.5 + 0.5 * cos(uv.y * settings.scanline_count * TAU);
+    out *= mix(1.0, scan, settings.scanline_strength);
+
+    // Black outside the curved screen.
+    let inside = all(uv >= vec2(0.0)) && all(uv <= vec2(1.0));
+    return vec4(select(vec3(0.0), out, inside), 1.0);
+}
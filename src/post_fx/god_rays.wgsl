@@ -0,0 +1,53 @@
+// Screen-space light shafts: radial blur of bright pixels toward a few on-screen sources.
+//
+// Each pixel marches toward every active source, summing the thresholded brightness it
+// crosses with exponential decay, so bright hexes smear into streaks pointing away from them.
+
+#import bevy_core_pipeline::fullscreen_vertex_shader::FullscreenVertexOutput
+
+struct GodRaysEffect {
+    // xy = source UV, z = weight (0 = inactive).
+    sources: array<vec4<f32>, 4>,
+    intensity: f32,
+    decay: f32,
+    density: f32,
+    threshold: f32,
+    samples: u32,
+}
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var texture_sampler: sampler;
+@group(0) @binding(2) var<uniform> settings: GodRaysEffect;
+
+const LUMA: vec3<f32> = vec3(0.2126, 0.7152, 0.0722);
+
+// Part of a texel's color above the brightness threshold.
+fn bright(uv: vec2<f32>) -> vec3<f32> {
+    let c = textureSampleLevel(screen_texture, texture_sampler, uv, 0.0).rgb;
+    let over = max(dot(c, LUMA) - settings.threshold, 0.0);
+    return c * over / max(dot(c, LUMA), 1e-4);
+}
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+    let color = textureSample(screen_texture, texture_sampler, in.uv).rgb;
+    let samples = max(settings.samples, 1u);
+    var shafts = vec3(0.0);
+    for (var s = 0u; s < 4u; s++) {
+        let source = settings.sources[s];
+        if source.z <= 0.0 {
+            continue;
+        }
+        let step = (source.xy - in.uv) * settings.density / f32(samples);
+        var uv = in.uv;
+        var falloff = 1.0;
+        var sum = vec3(0.0);
+        for (var i = 0u; i < samples; i++) {
+            uv += step;
+            sum += bright(uv) * falloff;
+            falloff *= settings.decay;
+        }
+        shafts += sum * source.z / f32(samples);
+    }
+    return vec4(color + shafts * settings.intensity, 1.0);
+}
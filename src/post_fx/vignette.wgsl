@@ -0,0 +1,24 @@
+// Darkens the frame toward the corners.
+//
+// Runs after tonemapping on the full-screen triangle.
+
+#import bevy_core_pipeline::fullscreen_vertex_shader::FullscreenVertexOutput
+
+struct VignetteEffect {
+    strength: f32,
+    radius: f32,
+    softness: f32,
+}
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var texture_sampler: sampler;
+@group(0) @binding(2) var<uniform> settings: VignetteEffect;
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+    let color = textureSample(screen_texture, texture_sampler, in.uv);
+    // 0 at the center, ~1 in the corners.
+    let d = length(in.uv - 0.5) * sqrt(2.0);
+    let shade = smoothstep(settings.radius, settings.radius + settings.softness, d);
+    return vec4(color.rgb * (1.0 - settings.strength * shade), color.a);
+}
@@ -0,0 +1,373 @@
+//! Sandbox props: simple emissive shapes placed on hexes from inspect mode.
+//!
+//! The prop palette ([`draw_prop_palette`]) picks a [`PropKind`] (pillar, arch, sphere
+//! cluster), a rotation, and a scale, and places the prop on the [`SelectedHex`] with a
+//! [`SpawnProp`]; "Clear" writes a [`ClearProps`] for the hex. [`spawn_props`] adds each
+//! [`Prop`] as a child of its [`HCell`], so height edits (mining, quakes, the hex panel)
+//! carry it along, and regenerating the grid removes it with the cells. Props are saved
+//! with the session and re-placed when it is resumed.
+
+use bevy::prelude::*;
+use bevy_egui::egui;
+use hexx::Hex;
+use serde::{Deserialize, Serialize};
+
+use crate::GameState;
+use crate::h_terrain::{HCell, SelectedHex};
+use crate::locale::Locale;
+
+/// Per-plugin configuration for sandbox props.
+#[derive(Resource, Clone, Debug, Reflect)]
+pub struct PropsConfig {
+    /// Prop color.
+    pub color: Color,
+    /// Emissive multiplier on `color` (drives bloom).
+    pub glow: f32,
+    /// Largest scale offered by the palette.
+    pub max_scale: f32,
+}
+
+impl Default for PropsConfig {
+    fn default() -> Self {
+        Self {
+            color: Color::srgb(1.0, 0.4, 0.9),
+            glow: 4.0,
+            max_scale: 4.0,
+        }
+    }
+}
+
+/// Shape of a prop.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Reflect, Serialize, Deserialize)]
+pub enum PropKind {
+    /// A tall round column.
+    Pillar,
+    /// Two posts and a lintel.
+    Arch,
+    /// A heap of spheres.
+    SphereCluster,
+}
+
+impl PropKind {
+    /// Every kind, in palette order.
+    pub const ALL: [Self; 3] = [Self::Pillar, Self::Arch, Self::SphereCluster];
+
+    /// English name (a [`Locale`] key).
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Pillar => "Pillar",
+            Self::Arch => "Arch",
+            Self::SphereCluster => "Sphere cluster",
+        }
+    }
+
+    /// Unit-scale mesh standing on the origin.
+    pub fn mesh(self) -> Mesh {
+        let parts: Vec<Mesh> = match self {
+            Self::Pillar => vec![Mesh::from(Cylinder::new(0.3, 4.0)).translated_by(Vec3::Y * 2.0)],
+            Self::Arch => vec![
+                Mesh::from(Cuboid::new(0.4, 3.0, 0.4)).translated_by(Vec3::new(-1.0, 1.5, 0.0)),
+                Mesh::from(Cuboid::new(0.4, 3.0, 0.4)).translated_by(Vec3::new(1.0, 1.5, 0.0)),
+                Mesh::from(Cuboid::new(2.8, 0.4, 0.4)).translated_by(Vec3::Y * 3.2),
+            ],
+            Self::SphereCluster => [
+                (0.6, Vec3::new(0.0, 0.6, 0.0)),
+                (0.4, Vec3::new(0.7, 0.4, 0.3)),
+                (0.35, Vec3::new(-0.5, 0.35, 0.5)),
+                (0.3, Vec3::new(0.1, 1.4, -0.2)),
+            ]
+            .into_iter()
+            .map(|(radius, at)| Mesh::from(Sphere::new(radius)).translated_by(at))
+            .collect(),
+        };
+        parts
+            .into_iter()
+            .reduce(|mut mesh, part| {
+                mesh.merge(&part)
+                    .expect("primitive meshes share attributes");
+                mesh
+            })
+            .expect("every kind has parts")
+    }
+}
+
+/// A placed prop (child of its [`HCell`]).
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect, Serialize, Deserialize)]
+pub struct Prop {
+    /// Shape.
+    pub kind: PropKind,
+    /// Rotation about the vertical axis in degrees.
+    pub yaw: f32,
+    /// Uniform scale.
+    pub scale: f32,
+}
+
+impl Prop {
+    /// Local transform on its cell.
+    pub fn transform(&self) -> Transform {
+        Transform::from_rotation(Quat::from_rotation_y(self.yaw.to_radians()))
+            .with_scale(Vec3::splat(self.scale))
+    }
+}
+
+/// Places a prop on a hex.
+#[derive(Message, Clone, Copy, Debug, PartialEq)]
+pub struct SpawnProp {
+    /// Hex to place it on.
+    pub hex: Hex,
+    /// The prop.
+    pub prop: Prop,
+}
+
+/// Removes every prop on a hex.
+#[derive(Message, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ClearProps(pub Hex);
+
+/// Palette settings for the next placed prop.
+#[derive(Resource, Clone, Debug, Reflect)]
+pub struct PropPalette(pub Prop);
+
+impl Default for PropPalette {
+    fn default() -> Self {
+        Self(Prop {
+            kind: PropKind::Pillar,
+            yaw: 0.0,
+            scale: 1.0,
+        })
+    }
+}
+
+/// Shared prop meshes (in [`PropKind::ALL`] order) and emissive material.
+#[derive(Resource)]
+struct PropAssets {
+    meshes: Vec<Handle<Mesh>>,
+    material: Handle<StandardMaterial>,
+}
+
+/// Sandbox props plugin.
+pub struct PropsPlugin {
+    /// Per-plugin configuration.
+    pub config: PropsConfig,
+}
+
+impl Plugin for PropsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<PropsConfig>()
+            .register_type::<Prop>()
+            .register_type::<PropPalette>()
+            .insert_resource(self.config.clone())
+            .init_resource::<PropPalette>()
+            .add_message::<SpawnProp>()
+            .add_message::<ClearProps>()
+            .add_systems(Startup, create_prop_assets)
+            .add_systems(
+                Update,
+                draw_prop_palette.run_if(in_state(GameState::Inspecting)),
+            )
+            .add_systems(
+                Update,
+                (
+                    clear_props.run_if(on_message::<ClearProps>),
+                    spawn_props.run_if(on_message::<SpawnProp>),
+                )
+                    .chain()
+                    .after(draw_prop_palette),
+            );
+    }
+}
+
+/// Builds one merged mesh per [`PropKind`] and the shared emissive material.
+fn create_prop_assets(
+    cfg: Res<PropsConfig>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut commands: Commands,
+) {
+    commands.insert_resource(PropAssets {
+        meshes: PropKind::ALL
+            .iter()
+            .map(|kind| meshes.add(kind.mesh()))
+            .collect(),
+        material: materials.add(StandardMaterial {
+            base_color: cfg.color,
+            emissive: LinearRgba::from(cfg.color) * cfg.glow,
+            ..default()
+        }),
+    });
+}
+
+/// Kind buttons, rotation and scale sliders, and place / clear for the selected hex.
+fn draw_prop_palette(
+    mut egui_ctx: Single<&mut bevy_egui::EguiContext>,
+    cfg: Res<PropsConfig>,
+    selected: Res<SelectedHex>,
+    mut palette: ResMut<PropPalette>,
+    mut spawn: MessageWriter<SpawnProp>,
+    mut clear: MessageWriter<ClearProps>,
+    locale: Res<Locale>,
+) {
+    egui::Window::new(locale.t("Props"))
+        .id(egui::Id::new("prop_palette"))
+        .default_pos([8.0, 520.0])
+        .resizable(false)
+        .show(egui_ctx.get_mut(), |ui| {
+            let prop = &mut palette.0;
+            ui.horizontal(|ui| {
+                for kind in PropKind::ALL {
+                    ui.selectable_value(&mut prop.kind, kind, locale.t(kind.label()));
+                }
+            });
+            ui.add(
+                egui::Slider::new(&mut prop.yaw, 0.0..=345.0)
+                    .step_by(15.0)
+                    .suffix("°")
+                    .text(locale.t("Rotation")),
+            );
+            ui.add(
+                egui::Slider::new(&mut prop.scale, 0.25..=cfg.max_scale.max(0.25))
+                    .text(locale.t("Scale")),
+            );
+            let Some(hex) = selected.0 else {
+                ui.label(locale.t("Click a hex to place props"));
+                return;
+            };
+            ui.horizontal(|ui| {
+                if ui
+                    .button(locale.fmt("Place on {0}", &[&format!("{:+},{:+}", hex.x, hex.y)]))
+                    .clicked()
+                {
+                    spawn.write(SpawnProp { hex, prop: *prop });
+                }
+                if ui.button(locale.t("Clear")).clicked() {
+                    clear.write(ClearProps(hex));
+                }
+            });
+        });
+}
+
+/// Adds each requested prop as a child of its cell.
+fn spawn_props(
+    assets: Res<PropAssets>,
+    mut requests: MessageReader<SpawnProp>,
+    cells: Query<(Entity, &HCell)>,
+    mut commands: Commands,
+) {
+    for &SpawnProp { hex, prop } in requests.read() {
+        let Some((cell, _)) = cells.iter().find(|(_, c)| c.hex == hex) else {
+            warn!("Prop target {hex:?} is outside the grid");
+            continue;
+        };
+        let index = PropKind::ALL
+            .iter()
+            .position(|&k| k == prop.kind)
+            .expect("ALL lists every kind");
+        commands.entity(cell).with_child((
+            Name::new("Prop"),
+            prop,
+            prop.transform(),
+            Mesh3d(assets.meshes[index].clone()),
+            MeshMaterial3d(assets.material.clone()),
+        ));
+    }
+}
+
+/// Despawns the props on each cleared hex.
+fn clear_props(
+    mut requests: MessageReader<ClearProps>,
+    props: Query<(Entity, &ChildOf), With<Prop>>,
+    cells: Query<&HCell>,
+    mut commands: Commands,
+) {
+    for &ClearProps(hex) in requests.read() {
+        for (prop, parent) in &props {
+            if cells.get(parent.parent()).is_ok_and(|c| c.hex == hex) {
+                commands.entity(prop).despawn();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn props_are_parented_to_their_cell_and_cleared_per_hex() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_message::<SpawnProp>()
+            .add_message::<ClearProps>()
+            .insert_resource(PropAssets {
+                meshes: vec![Handle::default(); PropKind::ALL.len()],
+                material: Handle::default(),
+            })
+            .add_systems(
+                Update,
+                (
+                    clear_props.run_if(on_message::<ClearProps>),
+                    spawn_props.run_if(on_message::<SpawnProp>),
+                )
+                    .chain(),
+            );
+        let a = app.world_mut().spawn(HCell { hex: Hex::ZERO }).id();
+        let b = app
+            .world_mut()
+            .spawn(HCell {
+                hex: Hex::new(1, 0),
+            })
+            .id();
+        let arch = Prop {
+            kind: PropKind::Arch,
+            yaw: 90.0,
+            scale: 2.0,
+        };
+        for (hex, prop) in [
+            (Hex::ZERO, arch),
+            (Hex::ZERO, PropPalette::default().0),
+            (Hex::new(1, 0), arch),
+            (Hex::new(9, 9), arch),
+        ] {
+            app.world_mut().write_message(SpawnProp { hex, prop });
+        }
+        app.update();
+
+        let parents = |app: &mut App| -> Vec<Entity> {
+            let w = app.world_mut();
+            let mut q = w.query_filtered::<&ChildOf, With<Prop>>();
+            q.iter(w).map(ChildOf::parent).collect()
+        };
+        let mut placed = parents(&mut app);
+        placed.sort();
+        let mut expected = vec![a, a, b];
+        expected.sort();
+        // The off-grid request is dropped.
+        assert_eq!(placed, expected);
+        let w = app.world_mut();
+        let mut q = w.query::<(&Prop, &Transform)>();
+        let (_, tf) = q.iter(w).find(|(p, _)| p.kind == PropKind::Arch).unwrap();
+        assert_eq!(tf.scale, Vec3::splat(2.0));
+        assert!((tf.rotation * Vec3::X).abs_diff_eq(Vec3::NEG_Z, 1e-5));
+
+        app.world_mut().write_message(ClearProps(Hex::ZERO));
+        app.update();
+        assert_eq!(parents(&mut app), [b]);
+    }
+
+    #[test]
+    fn every_kind_merges_into_one_mesh_on_the_ground() {
+        for kind in PropKind::ALL {
+            let mesh = kind.mesh();
+            let heights: Vec<f32> = mesh
+                .attribute(Mesh::ATTRIBUTE_POSITION)
+                .and_then(|a| a.as_float3())
+                .unwrap()
+                .iter()
+                .map(|p| p[1])
+                .collect();
+            let low = heights.iter().copied().fold(f32::INFINITY, f32::min);
+            let high = heights.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+            assert!(low.abs() < 1e-4, "{kind:?}");
+            assert!(high > 1.0, "{kind:?}");
+        }
+    }
+}
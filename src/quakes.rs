@@ -0,0 +1,313 @@
+//! Seismic events: a region of hexes rises and sinks over a few seconds.
+//!
+//! A [`StartQuake`] (from the `quake` console command, or every
+//! [`QuakesConfig::interval_secs`] with [`QuakesConfig::periodic`]) is turned into a
+//! [`Quake`] by [`start_quakes`]: a center hex (given, or a seeded pick among the grid's cells)
+//! and a height shift per hex within [`QuakesConfig::radius`] rings ([`quake_shifts`]),
+//! strongest in the middle. Each frame [`advance_quakes`] writes the change in
+//! [`quake_progress`] as [`EditHexHeight`] deltas, so the shifts go through the same
+//! deformation pipeline as laser mining: cells, faces and the gap mesh move together, with a
+//! rumbling overshoot before they settle. Regenerating the grid cancels running quakes.
+
+use std::collections::HashSet;
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+use hexx::Hex;
+
+use crate::h_terrain::{EditHexHeight, HCell, RegenerateTerrain};
+use crate::locale::Locale;
+use crate::math::hash_roll;
+use crate::toast::Toast;
+use crate::{GameState, PlayerMoved};
+
+/// Per-plugin configuration for quakes.
+#[derive(Resource, Clone, Debug, Reflect)]
+pub struct QuakesConfig {
+    /// Start a quake every [`interval_secs`](Self::interval_secs) while flying.
+    pub periodic: bool,
+    /// Seconds between periodic quakes.
+    pub interval_secs: f32,
+    /// Seconds a quake takes to settle.
+    pub duration_secs: f32,
+    /// Rings around the center that shift.
+    pub radius: u32,
+    /// Largest height shift (at the center), in world units.
+    pub amplitude: f32,
+    /// Seed for region picks and shift directions.
+    pub seed: u64,
+}
+
+impl Default for QuakesConfig {
+    fn default() -> Self {
+        Self {
+            periodic: false,
+            interval_secs: 120.0,
+            duration_secs: 3.0,
+            radius: 4,
+            amplitude: 3.0,
+            seed: 0x009e_a4e5,
+        }
+    }
+}
+
+/// Request a quake centered on a hex, or on a seeded pick when `None`.
+#[derive(Message, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StartQuake(pub Option<Hex>);
+
+/// A quake in progress.
+#[derive(Clone, Debug)]
+pub struct Quake {
+    /// Seconds since it started.
+    pub elapsed: f32,
+    /// Final height change per hex.
+    pub shifts: Vec<(Hex, f32)>,
+}
+
+/// Quakes in progress.
+#[derive(Resource, Default)]
+pub struct Quakes(pub Vec<Quake>);
+
+/// Final height change for every hex within `radius` rings of `center`: up or down per hex
+/// (seeded), scaled by `amplitude` and fading linearly to a quarter at the edge.
+pub fn quake_shifts(center: Hex, radius: u32, amplitude: f32, seed: u64) -> Vec<(Hex, f32)> {
+    center
+        .range(radius)
+        .map(|hex| {
+            let falloff =
+                1.0 - 0.75 * hex.unsigned_distance_to(center) as f32 / radius.max(1) as f32;
+            let direction = hash_roll(hex, seed) * 2.0 - 1.0;
+            (hex, amplitude * falloff * direction)
+        })
+        .collect()
+}
+
+/// Share of the shift applied at `t` in `0.0..=1.0` of the quake: a decaying rumble
+/// around the final height, 0 at the start and exactly 1 at the end.
+pub fn quake_progress(t: f32) -> f32 {
+    let t = t.clamp(0.0, 1.0);
+    1.0 - (1.0 - t).powi(2) * (3.0 * TAU * t).cos()
+}
+
+/// Quakes plugin.
+pub struct QuakesPlugin {
+    /// Per-plugin configuration.
+    pub config: QuakesConfig,
+}
+
+impl Plugin for QuakesPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<QuakesConfig>()
+            .insert_resource(self.config.clone())
+            .init_resource::<Quakes>()
+            .add_message::<StartQuake>()
+            .add_systems(
+                Update,
+                schedule_quakes.run_if(
+                    in_state(GameState::Running).and(|cfg: Res<QuakesConfig>| cfg.periodic),
+                ),
+            )
+            .add_systems(
+                Update,
+                (
+                    cancel_quakes.run_if(on_message::<RegenerateTerrain>),
+                    start_quakes.run_if(on_message::<StartQuake>),
+                    advance_quakes.run_if(|quakes: Res<Quakes>| !quakes.0.is_empty()),
+                )
+                    .chain()
+                    .after(schedule_quakes),
+            );
+    }
+}
+
+/// Writes a [`StartQuake`] every [`QuakesConfig::interval_secs`] of flight.
+fn schedule_quakes(
+    time: Res<Time>,
+    cfg: Res<QuakesConfig>,
+    mut since: Local<f32>,
+    mut start: MessageWriter<StartQuake>,
+) {
+    *since += time.delta_secs();
+    if *since >= cfg.interval_secs {
+        *since = 0.0;
+        start.write(StartQuake(None));
+    }
+}
+
+/// Drops running quakes when the grid is rebuilt.
+fn cancel_quakes(mut quakes: ResMut<Quakes>) {
+    quakes.0.clear();
+}
+
+/// Turns requests into [`Quake`]s over the current grid.
+fn start_quakes(
+    cfg: Res<QuakesConfig>,
+    cells: Query<&HCell>,
+    mut requests: MessageReader<StartQuake>,
+    mut quakes: ResMut<Quakes>,
+    mut count: Local<u64>,
+    mut toasts: MessageWriter<Toast>,
+    locale: Res<Locale>,
+) {
+    let hexes: HashSet<Hex> = cells.iter().map(|c| c.hex).collect();
+    for &StartQuake(center) in requests.read() {
+        *count += 1;
+        let seed = cfg.seed ^ count.wrapping_mul(0x9e37_79b9_7f4a_7c15);
+        let center = center.or_else(|| {
+            hexes
+                .iter()
+                .copied()
+                .min_by(|a, b| hash_roll(*a, seed).total_cmp(&hash_roll(*b, seed)))
+        });
+        let Some(center) = center.filter(|hex| hexes.contains(hex)) else {
+            warn!("Quake center {center:?} is outside the grid");
+            continue;
+        };
+        let shifts = quake_shifts(center, cfg.radius, cfg.amplitude, seed)
+            .into_iter()
+            .filter(|(hex, _)| hexes.contains(hex))
+            .collect();
+        quakes.0.push(Quake {
+            elapsed: 0.0,
+            shifts,
+        });
+        toasts.write(Toast(
+            locale.fmt("Quake at {0}", &[&format!("{:+},{:+}", center.x, center.y)]),
+        ));
+    }
+}
+
+/// Applies this frame's share of every running quake as height edits.
+fn advance_quakes(
+    time: Res<Time>,
+    cfg: Res<QuakesConfig>,
+    mut quakes: ResMut<Quakes>,
+    mut edits: MessageWriter<EditHexHeight>,
+    mut moved: ResMut<PlayerMoved>,
+) {
+    let duration = cfg.duration_secs.max(f32::EPSILON);
+    let dt = time.delta_secs();
+    for quake in &mut quakes.0 {
+        let before = quake_progress(quake.elapsed / duration);
+        quake.elapsed += dt;
+        let step = quake_progress(quake.elapsed / duration) - before;
+        for &(hex, shift) in &quake.shifts {
+            edits.write(EditHexHeight {
+                hex,
+                delta: shift * step,
+            });
+        }
+    }
+    quakes.0.retain(|q| q.elapsed < duration);
+    // The ground may have moved under the drone.
+    moved.0 = true;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::time::Duration;
+
+    use bevy::time::TimeUpdateStrategy;
+    use hexx::shapes;
+
+    use super::*;
+
+    #[test]
+    fn shifts_cover_the_region_and_fade_outward() {
+        let shifts = quake_shifts(Hex::new(2, -1), 3, 4.0, 7);
+        assert_eq!(shifts.len(), 37);
+        assert!(shifts.iter().all(|(h, s)| {
+            let cap = 4.0 * (1.0 - 0.75 * h.unsigned_distance_to(Hex::new(2, -1)) as f32 / 3.0);
+            s.abs() <= cap + 1e-5
+        }));
+        assert!(shifts.iter().any(|(_, s)| *s > 0.0));
+        assert!(shifts.iter().any(|(_, s)| *s < 0.0));
+        assert_eq!(shifts, quake_shifts(Hex::new(2, -1), 3, 4.0, 7));
+    }
+
+    #[test]
+    fn progress_rumbles_and_settles() {
+        assert_eq!(quake_progress(0.0), 0.0);
+        assert_eq!(quake_progress(1.0), 1.0);
+        assert_eq!(quake_progress(2.0), 1.0);
+        // Overshoots the final height on the way.
+        assert!((0..100).any(|i| quake_progress(i as f32 / 100.0) > 1.0));
+    }
+
+    /// Net height change per hex from the edits written so far.
+    #[derive(Resource, Default)]
+    struct Net(HashMap<Hex, f32>);
+
+    fn sum_edits(mut edits: MessageReader<EditHexHeight>, mut net: ResMut<Net>) {
+        for edit in edits.read() {
+            *net.0.entry(edit.hex).or_default() += edit.delta;
+        }
+    }
+
+    #[test]
+    fn a_quake_adds_up_to_its_shifts_and_ends() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_message::<Toast>()
+            .add_message::<EditHexHeight>()
+            .add_message::<StartQuake>()
+            .add_message::<RegenerateTerrain>()
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                100,
+            )))
+            .insert_resource(QuakesConfig {
+                radius: 2,
+                duration_secs: 1.0,
+                ..default()
+            })
+            .init_resource::<Quakes>()
+            .init_resource::<PlayerMoved>()
+            .init_resource::<Locale>()
+            .init_resource::<Net>()
+            .add_systems(
+                Update,
+                (
+                    cancel_quakes.run_if(on_message::<RegenerateTerrain>),
+                    start_quakes.run_if(on_message::<StartQuake>),
+                    advance_quakes.run_if(|quakes: Res<Quakes>| !quakes.0.is_empty()),
+                    sum_edits,
+                )
+                    .chain(),
+            );
+        for hex in shapes::hexagon(Hex::ZERO, 3) {
+            app.world_mut().spawn(HCell { hex });
+        }
+
+        // Off the grid: ignored.
+        app.world_mut()
+            .write_message(StartQuake(Some(Hex::new(9, 9))));
+        app.update();
+        assert!(app.world().resource::<Quakes>().0.is_empty());
+
+        // On the rim: only in-grid hexes shift.
+        app.world_mut()
+            .write_message(StartQuake(Some(Hex::new(3, 0))));
+        app.update();
+        let quake = app.world().resource::<Quakes>().0[0].clone();
+        assert!(quake.shifts.iter().any(|(h, _)| *h == Hex::new(3, 0)));
+        assert!(quake.shifts.iter().all(|(h, _)| h.ulength() <= 3));
+        for _ in 0..12 {
+            app.update();
+        }
+        let w = app.world();
+        assert!(w.resource::<Quakes>().0.is_empty());
+        let net = &w.resource::<Net>().0;
+        for (hex, shift) in &quake.shifts {
+            assert!((net[hex] - shift).abs() < 1e-4, "{hex:?}");
+        }
+
+        // Regenerating mid-quake cancels it.
+        app.world_mut().write_message(StartQuake(None));
+        app.update();
+        assert_eq!(app.world().resource::<Quakes>().0.len(), 1);
+        app.world_mut().write_message(RegenerateTerrain);
+        app.update();
+        assert!(app.world().resource::<Quakes>().0.is_empty());
+    }
+}
@@ -0,0 +1,505 @@
+//! Time-trial race mode: fly through a seeded sequence of checkpoint hexes against the clock.
+//!
+//! A race starts from the pause menu's "Time Trial" button ([`StartRace`]), or on every new grid
+//! when [`RaceConfig::enabled`] is set (`--race`). [`start_race`] plans the route with
+//! [`plan_route`], which only depends on the grid and its height seed, so each world always gets
+//! the same course. Each remaining checkpoint carries a [`Gate`] ring facing along the leg into it,
+//! and the next one also a [`CheckpointBeacon`] pillar. While flying, [`tick_race`] runs the timer
+//! and advances when the drone flies through the next gate ([`GatePassed`]); flying past it
+//! ([`GateMissed`]) only toasts. Finishing writes [`RaceFinished`] for the [`Leaderboard`], which
+//! holds the best time per course, and toasts the time. A timer panel sits on the right edge (clear
+//! of the toasts under the HUD strip) while a race is on.
+
+use std::collections::HashSet;
+
+use bevy::prelude::*;
+use bevy_egui::egui;
+use hexx::Hex;
+
+use crate::GameState;
+use crate::gates::{Gate, GateMissed, GatePassed, GatesConfig, gate_bundle};
+use crate::h_terrain::{HCell, HTerrainConfig, HTerrainPhase};
+use crate::leaderboard::Leaderboard;
+use crate::locale::Locale;
+use crate::math::hash_roll;
+use crate::toast::Toast;
+
+/// Per-plugin configuration for the time trial.
+#[derive(Resource, Clone, Debug, Reflect)]
+pub struct RaceConfig {
+    /// Start a race on every new grid (`--race`).
+    pub enabled: bool,
+    /// Checkpoints per course.
+    pub checkpoints: usize,
+    /// Minimum hex distance between consecutive checkpoints (and from the origin).
+    pub min_step: u32,
+    /// Maximum hex distance between consecutive checkpoints.
+    pub max_step: u32,
+    /// Checkpoint pillar height above the hex face.
+    pub height: f32,
+    /// Checkpoint pillar diameter.
+    pub width: f32,
+    /// Checkpoint pillar color; also tints the timer.
+    pub color: Color,
+    /// Emissive multiplier on `color` (drives bloom).
+    pub glow: f32,
+}
+
+impl Default for RaceConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            checkpoints: 5,
+            min_step: 5,
+            max_step: 10,
+            height: 30.0,
+            width: 0.6,
+            color: Color::srgb(0.2, 1.0, 0.3),
+            glow: 10.0,
+        }
+    }
+}
+
+/// The course in progress.
+#[derive(Resource, Default, Debug)]
+pub struct Race {
+    /// Checkpoint hexes in order; empty when no race is on.
+    pub checkpoints: Vec<Hex>,
+    /// Index of the next checkpoint (`checkpoints.len()` once finished).
+    pub next: usize,
+    /// Seconds since the start.
+    pub elapsed: f32,
+    /// Height seed of the course's grid.
+    pub seed: u32,
+}
+
+impl Race {
+    /// Whether a course is set and not yet finished.
+    pub fn running(&self) -> bool {
+        self.next < self.checkpoints.len()
+    }
+}
+
+/// Starts (or restarts) a race on the current grid.
+#[derive(Message, Clone, Copy, Debug, Default)]
+pub struct StartRace;
+
+/// Written when a race is finished.
+#[derive(Message, Clone, Copy, Debug, PartialEq)]
+pub struct RaceFinished {
+    /// Height seed of the course's grid.
+    pub seed: u32,
+    /// [`course_id`] of the checkpoints.
+    pub course: u32,
+    /// Finish time in seconds.
+    pub secs: f32,
+}
+
+/// Pillar marking the next checkpoint (child of its [`HCell`]).
+#[derive(Component, Reflect)]
+pub struct CheckpointBeacon;
+
+/// Shared checkpoint pillar mesh and material.
+#[derive(Resource)]
+struct RaceAssets {
+    mesh: Handle<Mesh>,
+    material: Handle<StandardMaterial>,
+}
+
+/// Picks `count` checkpoints from `hexes`, each `min_step..=max_step` hexes from the one
+/// before (the first from the origin) and none repeated. Deterministic for a given `seed`;
+/// stops early when no hex fits.
+pub fn plan_route(
+    hexes: &[Hex],
+    seed: u64,
+    count: usize,
+    min_step: u32,
+    max_step: u32,
+) -> Vec<Hex> {
+    let mut route = Vec::with_capacity(count);
+    let mut used = HashSet::new();
+    let mut from = Hex::ZERO;
+    for leg in 0..count {
+        let leg_seed = seed ^ (leg as u64).wrapping_mul(0x9e37_79b9_7f4a_7c15);
+        let Some(&pick) = hexes
+            .iter()
+            .filter(|h| (min_step..=max_step).contains(&h.unsigned_distance_to(from)))
+            .filter(|h| !used.contains(*h))
+            .min_by(|a, b| hash_roll(**a, leg_seed).total_cmp(&hash_roll(**b, leg_seed)))
+        else {
+            break;
+        };
+        used.insert(pick);
+        route.push(pick);
+        from = pick;
+    }
+    route
+}
+
+/// Stable id for a course: an FNV-1a hash of its checkpoint coordinates, in order.
+pub fn course_id(checkpoints: &[Hex]) -> u32 {
+    checkpoints
+        .iter()
+        .flat_map(|h| h.to_array())
+        .flat_map(i32::to_le_bytes)
+        .fold(0x811c_9dc5, |hash, byte| {
+            (hash ^ u32::from(byte)).wrapping_mul(0x0100_0193)
+        })
+}
+
+/// `m:ss.t` for the timer and toasts.
+pub fn format_race_time(secs: f32) -> String {
+    let tenths = (secs.max(0.0) * 10.0).round() as u32;
+    format!("{}:{:02}.{}", tenths / 600, tenths / 10 % 60, tenths % 10)
+}
+
+/// Run condition: new grids get a fresh course (`--race`, or a race in progress).
+fn race_enabled(cfg: Res<RaceConfig>, race: Res<Race>) -> bool {
+    cfg.enabled || race.running()
+}
+
+/// Time-trial plugin.
+pub struct RacePlugin {
+    /// Per-plugin configuration.
+    pub config: RaceConfig,
+}
+
+impl Plugin for RacePlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<RaceConfig>()
+            .register_type::<CheckpointBeacon>()
+            .add_message::<StartRace>()
+            .add_message::<RaceFinished>()
+            .insert_resource(self.config.clone())
+            .init_resource::<Race>()
+            .add_systems(Startup, create_race_assets);
+
+        app.add_systems(
+            Update,
+            (
+                start_race.run_if(
+                    on_message::<StartRace>.or(race_enabled.and(any_match_filter::<Added<HCell>>)),
+                ),
+                tick_race.run_if(in_state(GameState::Running)),
+                place_course.run_if(resource_changed::<Race>),
+            )
+                .chain()
+                .after(HTerrainPhase::TrackFov)
+                .after(crate::gates::detect_gates),
+        )
+        .add_systems(
+            Update,
+            draw_race_timer.run_if(
+                in_state(GameState::Running).and(|race: Res<Race>| !race.checkpoints.is_empty()),
+            ),
+        );
+    }
+}
+
+/// Builds the shared checkpoint pillar mesh (base at the origin) and emissive material.
+fn create_race_assets(
+    cfg: Res<RaceConfig>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut commands: Commands,
+) {
+    let mesh = Mesh::from(Cylinder::new(cfg.width / 2.0, cfg.height))
+        .translated_by(Vec3::Y * cfg.height / 2.0);
+    commands.insert_resource(RaceAssets {
+        mesh: meshes.add(mesh),
+        material: materials.add(StandardMaterial {
+            base_color: cfg.color,
+            emissive: LinearRgba::from(cfg.color) * cfg.glow,
+            ..default()
+        }),
+    });
+}
+
+/// Plans a course on the current grid and starts the clock.
+fn start_race(
+    mut starts: MessageReader<StartRace>,
+    cfg: Res<RaceConfig>,
+    terrain: Res<HTerrainConfig>,
+    cells: Query<&HCell>,
+    mut race: ResMut<Race>,
+    mut toasts: MessageWriter<Toast>,
+    locale: Res<Locale>,
+) {
+    starts.clear();
+    let mut hexes: Vec<Hex> = cells.iter().map(|c| c.hex).collect();
+    // Query order isn't stable across runs; the route must be.
+    hexes.sort_unstable_by_key(|h| h.to_array());
+    let seed = terrain.grid.height_noise_seed;
+    let checkpoints = plan_route(
+        &hexes,
+        u64::from(seed),
+        cfg.checkpoints,
+        cfg.min_step,
+        cfg.max_step,
+    );
+    if checkpoints.is_empty() {
+        toasts.write(Toast(locale.t("No room for a race course").into()));
+        return;
+    }
+    toasts.write(Toast(
+        locale.fmt("Time trial: {0} checkpoints", &[&checkpoints.len()]),
+    ));
+    *race = Race {
+        checkpoints,
+        next: 0,
+        elapsed: 0.0,
+        seed,
+    };
+}
+
+/// Runs the clock and advances through the gates; reports the time at the finish.
+#[allow(clippy::too_many_arguments)]
+pub fn tick_race(
+    time: Res<Time>,
+    mut passed: MessageReader<GatePassed>,
+    mut missed: MessageReader<GateMissed>,
+    mut race: ResMut<Race>,
+    board: Option<Res<Leaderboard>>,
+    mut finished: MessageWriter<RaceFinished>,
+    mut toasts: MessageWriter<Toast>,
+    locale: Res<Locale>,
+) {
+    if !race.running() {
+        passed.clear();
+        missed.clear();
+        return;
+    }
+    // The clock alone doesn't move the gates.
+    race.bypass_change_detection().elapsed += time.delta_secs();
+    let next = race.next;
+    // Drain both readers fully so stale crossings never count against a later gate.
+    if missed.read().filter(|m| m.id == next).count() > 0 {
+        toasts.write(Toast(locale.t("Missed the gate").into()));
+    }
+    if passed.read().filter(|p| p.id == next).count() == 0 {
+        return;
+    }
+    race.next += 1;
+    if race.running() {
+        toasts.write(Toast(
+            locale.fmt("Checkpoint {0}/{1}", &[&race.next, &race.checkpoints.len()]),
+        ));
+        return;
+    }
+    let course = course_id(&race.checkpoints);
+    finished.write(RaceFinished {
+        seed: race.seed,
+        course,
+        secs: race.elapsed,
+    });
+    let time = format_race_time(race.elapsed);
+    // The leaderboard records the run after this system, so it still holds the previous best.
+    let beaten = board.is_some_and(|b| {
+        b.best(race.seed, course)
+            .is_none_or(|best| race.elapsed < best)
+    });
+    if beaten {
+        toasts.write(Toast(locale.fmt("Finished in {0}: new best!", &[&time])));
+    } else {
+        toasts.write(Toast(locale.fmt("Finished in {0}", &[&time])));
+    }
+}
+
+/// Respawns the gates of the remaining checkpoints and the pillar on the next one.
+#[allow(clippy::type_complexity)]
+fn place_course(
+    race: Res<Race>,
+    assets: Res<RaceAssets>,
+    gates_cfg: Res<GatesConfig>,
+    cells: Query<(Entity, &HCell, &Transform)>,
+    markers: Query<Entity, Or<(With<CheckpointBeacon>, With<Gate>)>>,
+    mut commands: Commands,
+) {
+    for marker in &markers {
+        commands.entity(marker).despawn();
+    }
+    let find = |hex: Hex| cells.iter().find(|(_, c, _)| c.hex == hex);
+    for (index, &hex) in race.checkpoints.iter().enumerate().skip(race.next) {
+        let Some((cell, _, tf)) = find(hex) else {
+            continue;
+        };
+        // Face along the leg into this checkpoint (the first leg starts at the origin).
+        let from = index
+            .checked_sub(1)
+            .map_or(Hex::ZERO, |prev| race.checkpoints[prev]);
+        let normal = find(from).map_or(Vec3::Z, |(_, _, prev)| tf.translation - prev.translation);
+        commands
+            .entity(cell)
+            .with_child(gate_bundle(index, normal, &gates_cfg));
+        if index == race.next {
+            commands.entity(cell).with_child((
+                Name::new("CheckpointBeacon"),
+                CheckpointBeacon,
+                Mesh3d(assets.mesh.clone()),
+                MeshMaterial3d(assets.material.clone()),
+                Transform::default(),
+            ));
+        }
+    }
+}
+
+/// Shows checkpoint progress, the clock, and the course's best time on the right edge.
+fn draw_race_timer(
+    mut egui_ctx: Single<&mut bevy_egui::EguiContext>,
+    cfg: Res<RaceConfig>,
+    race: Res<Race>,
+    board: Option<Res<Leaderboard>>,
+    locale: Res<Locale>,
+) {
+    let [r, g, b, _] = cfg.color.to_srgba().to_u8_array();
+    let mut text = format!(
+        "{} {}/{}   {}",
+        locale.t("RACE"),
+        race.next,
+        race.checkpoints.len(),
+        format_race_time(race.elapsed),
+    );
+    let best = board.and_then(|b| b.best(race.seed, course_id(&race.checkpoints)));
+    if let Some(secs) = best {
+        text += &format!("   {} {}", locale.t("BEST"), format_race_time(secs));
+    }
+    egui::Area::new(egui::Id::new("race_timer"))
+        .anchor(egui::Align2::RIGHT_CENTER, [-8.0, 0.0])
+        .interactable(false)
+        .show(egui_ctx.get_mut(), |ui| {
+            egui::Frame::new()
+                .fill(egui::Color32::from_black_alpha(140))
+                .inner_margin(egui::Margin::symmetric(10, 4))
+                .corner_radius(4.0)
+                .show(ui, |ui| {
+                    ui.label(
+                        egui::RichText::new(text)
+                            .color(egui::Color32::from_rgb(r, g, b))
+                            .font(egui::FontId::monospace(14.0)),
+                    );
+                });
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::state::app::StatesPlugin;
+    use hexx::shapes;
+
+    use super::*;
+    use crate::leaderboard::RaceRun;
+
+    #[test]
+    fn routes_are_seeded_and_keep_their_spacing() {
+        let hexes: Vec<Hex> = shapes::hexagon(Hex::ZERO, 20).collect();
+        let route = plan_route(&hexes, 3, 6, 4, 8);
+        assert_eq!(route.len(), 6);
+        assert_eq!(route, plan_route(&hexes, 3, 6, 4, 8));
+        assert_ne!(route, plan_route(&hexes, 4, 6, 4, 8));
+        let mut from = Hex::ZERO;
+        for &hex in &route {
+            assert!((4..=8).contains(&hex.unsigned_distance_to(from)));
+            from = hex;
+        }
+        assert_eq!(route.iter().collect::<HashSet<_>>().len(), 6);
+        // Too small a grid for the spacing: the course stops early.
+        let tiny: Vec<Hex> = shapes::hexagon(Hex::ZERO, 2).collect();
+        assert!(plan_route(&tiny, 3, 6, 4, 8).is_empty());
+    }
+
+    #[test]
+    fn race_advances_through_its_gates_and_records_the_finish() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin))
+            .add_plugins(AssetPlugin::default())
+            .init_asset::<Mesh>()
+            .init_asset::<StandardMaterial>()
+            .add_message::<Toast>()
+            .add_message::<StartRace>()
+            .add_message::<GatePassed>()
+            .add_message::<GateMissed>()
+            .add_message::<RaceFinished>()
+            .init_state::<GameState>()
+            .insert_resource(RaceConfig {
+                checkpoints: 2,
+                min_step: 1,
+                max_step: 2,
+                ..default()
+            })
+            .init_resource::<GatesConfig>()
+            .init_resource::<HTerrainConfig>()
+            .init_resource::<Race>()
+            .init_resource::<Locale>()
+            .add_systems(Startup, create_race_assets)
+            .add_systems(
+                Update,
+                (
+                    start_race.run_if(on_message::<StartRace>),
+                    tick_race,
+                    place_course.run_if(resource_changed::<Race>),
+                )
+                    .chain(),
+            );
+        for hex in shapes::hexagon(Hex::ZERO, 3) {
+            let [x, z] = hex.to_array().map(|c| c as f32 * 10.0);
+            app.world_mut()
+                .spawn((HCell { hex }, Transform::from_xyz(x, 0.0, z)));
+        }
+        app.world_mut().write_message(StartRace);
+        app.update();
+        let route = app.world().resource::<Race>().checkpoints.clone();
+        assert_eq!(route.len(), 2);
+        let seed = app.world().resource::<Race>().seed;
+        app.world_mut().insert_resource(Leaderboard {
+            runs: vec![RaceRun {
+                seed,
+                course: course_id(&route),
+                secs: 1000.0,
+                date: 0,
+            }],
+        });
+
+        let course = |app: &mut App| {
+            let w = app.world_mut();
+            let hex_of = |w: &World, child: &ChildOf| w.get::<HCell>(child.parent()).unwrap().hex;
+            let mut beacons = w.query_filtered::<&ChildOf, With<CheckpointBeacon>>();
+            let beacons: Vec<Hex> = beacons.iter(w).map(|c| hex_of(w, c)).collect();
+            let mut gates = w.query::<(&Gate, &ChildOf)>();
+            let mut gates: Vec<(usize, Hex)> =
+                gates.iter(w).map(|(g, c)| (g.id, hex_of(w, c))).collect();
+            gates.sort_unstable_by_key(|(id, _)| *id);
+            (beacons, gates)
+        };
+        assert_eq!(
+            course(&mut app),
+            (vec![route[0]], vec![(0, route[0]), (1, route[1])])
+        );
+
+        // Passing a later gate out of order doesn't count.
+        let pass = |app: &mut App, id| {
+            app.world_mut().write_message(GatePassed {
+                gate: Entity::PLACEHOLDER,
+                id,
+            });
+            app.update();
+        };
+        pass(&mut app, 1);
+        assert_eq!(app.world().resource::<Race>().next, 0);
+        pass(&mut app, 0);
+        assert_eq!(course(&mut app), (vec![route[1]], vec![(1, route[1])]));
+        pass(&mut app, 1);
+        assert!(!app.world().resource::<Race>().running());
+        let toasts = app.world().resource::<Messages<Toast>>();
+        assert!(
+            toasts
+                .iter_current_update_messages()
+                .any(|t| t.0.ends_with("new best!"))
+        );
+        let finished = app.world().resource::<Messages<RaceFinished>>();
+        let finished: Vec<_> = finished.iter_current_update_messages().collect();
+        assert_eq!(finished.len(), 1);
+        assert_eq!(finished[0].course, course_id(&route));
+        assert_eq!(finished[0].seed, seed);
+        assert_eq!(course(&mut app), (vec![], vec![]));
+    }
+}
@@ -0,0 +1,272 @@
+//! Peak radar: chevrons toward the tallest unvisited hexes nearby.
+//!
+//! While flying, [`scan_peaks`] picks the [`RadarConfig::count`] tallest cells within
+//! [`RadarConfig::range`] that aren't [`Visited`] and stand above the grid's mean height
+//! ([`TerrainStats`]), rescanning whenever the player moves or visits a cell. The result is
+//! kept in [`RadarContacts`] with absolute compass bearings, so [`draw_radar`] only has to
+//! turn them by the camera heading each frame: a round scope in the bottom-left corner,
+//! ahead up, with a chevron per peak at its bearing and distance, brighter the closer the
+//! peak is to the grid's tallest.
+
+use bevy::prelude::*;
+use bevy_egui::egui;
+use hexx::Hex;
+
+use crate::drone::Player;
+use crate::h_terrain::{HCell, TerrainStats, Visited};
+use crate::hud::compass_heading;
+use crate::locale::Locale;
+use crate::photo::PhotoMode;
+use crate::{GameState, PlayerPos};
+
+/// Per-plugin configuration for the peak radar.
+#[derive(Resource, Clone, Debug, Reflect)]
+pub struct RadarConfig {
+    /// Show the radar while flying.
+    pub enabled: bool,
+    /// Peaks tracked at once.
+    pub count: usize,
+    /// Farthest tracked peak (world units, horizontal).
+    pub range: f32,
+    /// Scope diameter in pixels.
+    pub size: f32,
+}
+
+impl Default for RadarConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            count: 3,
+            range: 120.0,
+            size: 120.0,
+        }
+    }
+}
+
+/// A tracked peak.
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+pub struct RadarContact {
+    /// The peak's hex.
+    #[reflect(ignore)]
+    pub hex: Hex,
+    /// Compass bearing from the player in degrees (0 = -Z / north, 90 = +X / east).
+    pub bearing: f32,
+    /// Horizontal distance from the player.
+    pub distance: f32,
+    /// Cell height.
+    pub height: f32,
+}
+
+/// Current radar contacts, tallest first.
+#[derive(Resource, Default, PartialEq, Reflect)]
+pub struct RadarContacts(pub Vec<RadarContact>);
+
+/// The `count` tallest of `cells` (hex, cell center, visited) that are unvisited, above
+/// `floor`, and within `range` of `here`, tallest first.
+pub fn radar_contacts(
+    here: Vec2,
+    cells: impl IntoIterator<Item = (Hex, Vec3, bool)>,
+    floor: f32,
+    count: usize,
+    range: f32,
+) -> Vec<RadarContact> {
+    let mut contacts: Vec<_> = cells
+        .into_iter()
+        .filter(|&(_, p, visited)| !visited && p.y > floor)
+        .filter_map(|(hex, p, _)| {
+            let offset = p.xz() - here;
+            let distance = offset.length();
+            (distance <= range).then(|| RadarContact {
+                hex,
+                bearing: compass_heading(offset.extend(0.0).xzy()),
+                distance,
+                height: p.y,
+            })
+        })
+        .collect();
+    contacts.sort_by(|a, b| {
+        b.height
+            .total_cmp(&a.height)
+            .then(a.distance.total_cmp(&b.distance))
+    });
+    contacts.truncate(count);
+    contacts
+}
+
+/// Peak radar plugin.
+pub struct RadarPlugin {
+    /// Per-plugin configuration.
+    pub config: RadarConfig,
+}
+
+impl Plugin for RadarPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<RadarConfig>()
+            .register_type::<RadarContacts>()
+            .insert_resource(self.config.clone())
+            .init_resource::<RadarContacts>()
+            .add_systems(
+                Update,
+                (
+                    scan_peaks.run_if(
+                        resource_changed::<PlayerPos>.or(any_match_filter::<Added<Visited>>),
+                    ),
+                    draw_radar.run_if(|photo: Option<Res<PhotoMode>>| !photo.is_some_and(|p| p.0)),
+                )
+                    .chain()
+                    .after(crate::drone::systems::fly)
+                    .run_if(in_state(GameState::Running))
+                    .run_if(|cfg: Res<RadarConfig>| cfg.enabled),
+            );
+    }
+}
+
+/// Rescans the grid for the tallest unvisited peaks in range.
+fn scan_peaks(
+    cfg: Res<RadarConfig>,
+    player: Res<PlayerPos>,
+    stats: Res<TerrainStats>,
+    cells: Query<(&HCell, &GlobalTransform, Has<Visited>)>,
+    mut contacts: ResMut<RadarContacts>,
+) {
+    let found = radar_contacts(
+        player.xz,
+        cells
+            .iter()
+            .map(|(c, tf, visited)| (c.hex, tf.translation(), visited)),
+        stats.mean_height,
+        cfg.count,
+        cfg.range,
+    );
+    contacts.set_if_neq(RadarContacts(found));
+}
+
+/// Draws the scope with a chevron per contact, turned so the camera heading points up.
+fn draw_radar(
+    mut egui_ctx: Single<&mut bevy_egui::EguiContext>,
+    cfg: Res<RadarConfig>,
+    camera: Single<&GlobalTransform, With<Player>>,
+    contacts: Res<RadarContacts>,
+    stats: Res<TerrainStats>,
+    locale: Res<Locale>,
+) {
+    let accent = egui::Color32::from_rgb(0, 255, 200);
+    let heading = compass_heading(*camera.forward());
+    let span = (stats.max_height - stats.mean_height).max(f32::EPSILON);
+    egui::Area::new(egui::Id::new("peak_radar"))
+        .anchor(egui::Align2::LEFT_BOTTOM, [8.0, -8.0])
+        .interactable(false)
+        .show(egui_ctx.get_mut(), |ui| {
+            let (rect, _) =
+                ui.allocate_exact_size(egui::vec2(cfg.size, cfg.size), egui::Sense::hover());
+            let painter = ui.painter_at(rect);
+            let center = rect.center();
+            let radius = cfg.size / 2.0 - 2.0;
+            painter.circle_filled(center, radius, egui::Color32::from_black_alpha(150));
+            let ring = egui::Stroke::new(1.0, accent.gamma_multiply(0.35));
+            painter.circle_stroke(center, radius, ring);
+            painter.circle_stroke(center, radius / 2.0, ring);
+            painter.text(
+                center - egui::vec2(0.0, radius - 2.0),
+                egui::Align2::CENTER_TOP,
+                locale.t("PEAKS"),
+                egui::FontId::monospace(10.0),
+                accent.gamma_multiply(0.6),
+            );
+            painter.circle_filled(center, 2.0, egui::Color32::WHITE);
+
+            for contact in &contacts.0 {
+                let angle = (contact.bearing - heading).to_radians();
+                let dir = egui::vec2(angle.sin(), -angle.cos());
+                let r = radius * (contact.distance / cfg.range.max(f32::EPSILON)).min(1.0);
+                let tip = center + dir * r.max(10.0);
+                let prominence = ((contact.height - stats.mean_height) / span).clamp(0.0, 1.0);
+                let color = accent.gamma_multiply(0.4 + 0.6 * prominence);
+                let side = egui::vec2(-dir.y, dir.x) * 5.0;
+                let back = tip - dir * 6.0;
+                painter.add(egui::Shape::line(
+                    vec![back + side, tip, back - side],
+                    egui::Stroke::new(2.0, color),
+                ));
+                painter.text(
+                    back - dir * 3.0,
+                    egui::Align2::CENTER_CENTER,
+                    format!("{:.0}", contact.distance),
+                    egui::FontId::monospace(9.0),
+                    color,
+                );
+            }
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn contacts_are_the_tallest_unvisited_peaks_in_range() {
+        let cells = [
+            (Hex::new(0, -1), Vec3::new(0.0, 9.0, -10.0), false),
+            (Hex::new(1, 0), Vec3::new(10.0, 7.0, 0.0), false),
+            (Hex::new(2, 0), Vec3::new(20.0, 12.0, 0.0), true),
+            (Hex::new(9, 0), Vec3::new(90.0, 15.0, 0.0), false),
+            (Hex::new(0, 1), Vec3::new(0.0, 2.0, 10.0), false),
+            (Hex::new(-1, 0), Vec3::new(-10.0, 8.0, 0.0), false),
+        ];
+        let contacts = radar_contacts(Vec2::ZERO, cells, 5.0, 2, 50.0);
+        // Visited, out of range, below the floor, and past the count: dropped.
+        let hexes: Vec<_> = contacts.iter().map(|c| c.hex).collect();
+        assert_eq!(hexes, [Hex::new(0, -1), Hex::new(-1, 0)]);
+        assert!(contacts[0].bearing.abs() < 1e-4);
+        assert!((contacts[1].bearing - 270.0).abs() < 1e-4);
+        assert!((contacts[1].distance - 10.0).abs() < 1e-4);
+        assert!(radar_contacts(Vec2::ZERO, cells, 20.0, 3, 50.0).is_empty());
+    }
+
+    #[test]
+    fn scanning_follows_the_player_and_drops_visited_peaks() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<PlayerPos>()
+            .insert_resource(TerrainStats {
+                mean_height: 3.0,
+                max_height: 10.0,
+                ..default()
+            })
+            .insert_resource(RadarConfig {
+                count: 1,
+                range: 30.0,
+                ..default()
+            })
+            .init_resource::<RadarContacts>()
+            .add_systems(
+                Update,
+                scan_peaks
+                    .run_if(resource_changed::<PlayerPos>.or(any_match_filter::<Added<Visited>>)),
+            );
+        let spawn = |app: &mut App, hex, x: f32, height| {
+            let tf = Transform::from_xyz(x, height, 0.0);
+            app.world_mut()
+                .spawn((HCell { hex }, tf, GlobalTransform::from(tf)))
+                .id()
+        };
+        let near = spawn(&mut app, Hex::new(1, 0), 20.0, 6.0);
+        spawn(&mut app, Hex::new(5, 0), 60.0, 10.0);
+        let contacts = |app: &App| -> Vec<Hex> {
+            let contacts = app.world().resource::<RadarContacts>();
+            contacts.0.iter().map(|c| c.hex).collect()
+        };
+
+        app.update();
+        assert_eq!(contacts(&app), [Hex::new(1, 0)]);
+        // Moving closer brings the taller, farther peak into range.
+        app.world_mut().resource_mut::<PlayerPos>().xz = Vec2::new(35.0, 0.0);
+        app.update();
+        assert_eq!(contacts(&app), [Hex::new(5, 0)]);
+        // Back home, the near peak is gone once visited.
+        app.world_mut().resource_mut::<PlayerPos>().xz = Vec2::ZERO;
+        app.world_mut().entity_mut(near).insert(Visited);
+        app.update();
+        assert!(contacts(&app).is_empty());
+    }
+}
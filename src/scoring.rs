@@ -0,0 +1,510 @@
+//! Exploration scoring and the end-of-session summary.
+//!
+//! [`track_session`] accumulates [`SessionStats`] while flying: revealed hexes, distance flown
+//! (jumps longer than [`ScoringConfig::max_step`], i.e. teleports, are skipped), landmark peaks
+//! visited (hexes [`classify_target`] rates [`TargetKind::Landmark`]), and flight time.
+//! [`score`] turns the stats into a [`ScoreBreakdown`] with one [`ScoreLine`] per
+//! [`ScoreCategory`]. The time bonus pays for seconds under [`ScoringConfig::par_secs`],
+//! scaled by the revealed share.
+//!
+//! [`GameState::Summary`] shows the breakdown over a dimmed backdrop. It opens when the whole
+//! grid is revealed or from the pause menu's Quit (the [`SummaryReason`]). Like the pause
+//! menu, it frees the cursor and pauses [`Time<Virtual>`]. "Keep flying" resumes and Quit
+//! exits. Stats reset whenever the grid is regenerated. Race medals earned since startup
+//! ([`SessionMedals`]) are listed under the score.
+
+use std::collections::HashSet;
+
+#[cfg(not(target_arch = "wasm32"))]
+use bevy::app::AppExit;
+use bevy::prelude::*;
+use bevy::window::CursorOptions;
+use bevy_egui::egui;
+use hexx::Hex;
+
+use crate::h_terrain::{
+    ActiveHex, HCell, HGrid, HTerrainConfig, HTerrainPhase, Revealed, TargetKind, classify_target,
+};
+use crate::leaderboard::SessionMedals;
+use crate::locale::Locale;
+use crate::race::format_race_time;
+use crate::{GameState, PlayerPos, set_cursor_free};
+
+/// Backdrop opacity over the summary (0–255).
+const BACKDROP_ALPHA: u8 = 170;
+/// Summary button size in points.
+const BUTTON_SIZE: egui::Vec2 = egui::vec2(200.0, 32.0);
+
+/// Per-plugin configuration for exploration scoring.
+#[derive(Resource, Clone, Debug, Reflect)]
+pub struct ScoringConfig {
+    /// Points per revealed hex.
+    pub hex_points: f32,
+    /// Points per world unit flown.
+    pub distance_points: f32,
+    /// Points per landmark peak visited.
+    pub peak_points: f32,
+    /// Flight time the time bonus counts down from, in seconds.
+    pub par_secs: f32,
+    /// Points per second under `par_secs`, at a fully revealed grid.
+    pub time_points: f32,
+    /// Longest per-frame move counted as flight (longer jumps are teleports).
+    pub max_step: f32,
+    /// Open the summary once the whole grid is revealed.
+    pub summary_on_complete: bool,
+}
+
+impl Default for ScoringConfig {
+    fn default() -> Self {
+        Self {
+            hex_points: 10.0,
+            distance_points: 0.5,
+            peak_points: 250.0,
+            par_secs: 900.0,
+            time_points: 2.0,
+            max_step: 20.0,
+            summary_on_complete: true,
+        }
+    }
+}
+
+/// What the player has done on the current grid.
+#[derive(Resource, Default, Reflect)]
+pub struct SessionStats {
+    /// Revealed [`HCell`]s.
+    pub revealed: usize,
+    /// All [`HCell`]s in the grid.
+    pub total: usize,
+    /// Horizontal distance flown.
+    pub distance: f32,
+    /// Landmark peaks flown over.
+    #[reflect(ignore)]
+    pub peaks: HashSet<Hex>,
+    /// Seconds spent flying (virtual time).
+    pub elapsed: f32,
+    /// The grid has been fully revealed (the summary opened once).
+    pub completed: bool,
+}
+
+/// A scored category.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+pub enum ScoreCategory {
+    /// Revealed hexes.
+    Revealed,
+    /// Distance flown.
+    Distance,
+    /// Landmark peaks visited.
+    Peaks,
+    /// Seconds under par, scaled by the revealed share.
+    Time,
+}
+
+impl ScoreCategory {
+    /// Every category, in summary order.
+    pub const ALL: [Self; 4] = [Self::Revealed, Self::Distance, Self::Peaks, Self::Time];
+
+    /// English label (a locale key).
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Revealed => "Hexes revealed",
+            Self::Distance => "Distance flown",
+            Self::Peaks => "Peaks visited",
+            Self::Time => "Time",
+        }
+    }
+}
+
+/// One row of the summary.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ScoreLine {
+    /// Category scored.
+    pub category: ScoreCategory,
+    /// Raw amount: hexes, units, peaks, or seconds flown.
+    pub amount: f32,
+    /// Points earned.
+    pub points: u32,
+}
+
+/// Per-category points and their sum.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScoreBreakdown {
+    /// One line per [`ScoreCategory::ALL`] entry.
+    pub lines: [ScoreLine; 4],
+    /// Sum of the line points.
+    pub total: u32,
+}
+
+/// Scores `stats` under `cfg`.
+pub fn score(stats: &SessionStats, cfg: &ScoringConfig) -> ScoreBreakdown {
+    let share = if stats.total == 0 {
+        0.0
+    } else {
+        stats.revealed as f32 / stats.total as f32
+    };
+    let lines = ScoreCategory::ALL.map(|category| {
+        let (amount, points) = match category {
+            ScoreCategory::Revealed => {
+                let n = stats.revealed as f32;
+                (n, n * cfg.hex_points)
+            }
+            ScoreCategory::Distance => (stats.distance, stats.distance * cfg.distance_points),
+            ScoreCategory::Peaks => {
+                let n = stats.peaks.len() as f32;
+                (n, n * cfg.peak_points)
+            }
+            ScoreCategory::Time => {
+                let under_par = (cfg.par_secs - stats.elapsed).max(0.0);
+                (stats.elapsed, under_par * cfg.time_points * share)
+            }
+        };
+        ScoreLine {
+            category,
+            amount,
+            points: points.round() as u32,
+        }
+    });
+    let total = lines.iter().map(|l| l.points).sum();
+    ScoreBreakdown { lines, total }
+}
+
+/// Why the summary opened.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+pub enum SummaryReason {
+    /// Quit chosen in the pause menu.
+    #[default]
+    Quit,
+    /// The whole grid was revealed.
+    Completed,
+}
+
+/// Reason shown in the open summary.
+#[derive(Resource, Default, Reflect)]
+pub struct SessionSummary(pub SummaryReason);
+
+/// Exploration scoring plugin.
+pub struct ScoringPlugin {
+    /// Per-plugin configuration.
+    pub config: ScoringConfig,
+}
+
+impl Plugin for ScoringPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<ScoringConfig>()
+            .register_type::<SessionStats>()
+            .register_type::<SessionSummary>()
+            .insert_resource(self.config.clone())
+            .init_resource::<SessionStats>()
+            .init_resource::<SessionSummary>()
+            .add_systems(
+                Update,
+                reset_session.run_if(any_match_filter::<Added<HCell>>),
+            )
+            .add_systems(
+                Update,
+                track_session
+                    .after(HTerrainPhase::TrackFov)
+                    .run_if(in_state(GameState::Running)),
+            )
+            .add_systems(Update, draw_summary.run_if(in_state(GameState::Summary)))
+            .add_systems(
+                OnEnter(GameState::Summary),
+                |mut time: ResMut<Time<Virtual>>| time.pause(),
+            )
+            .add_systems(
+                OnExit(GameState::Summary),
+                |mut time: ResMut<Time<Virtual>>| time.unpause(),
+            );
+    }
+}
+
+/// Starts a fresh session for a newly generated grid.
+fn reset_session(mut stats: ResMut<SessionStats>) {
+    *stats = SessionStats::default();
+}
+
+/// Accumulates [`SessionStats`] and opens the summary once the grid is fully revealed.
+#[allow(clippy::too_many_arguments)]
+pub fn track_session(
+    time: Res<Time>,
+    cfg: Res<ScoringConfig>,
+    terrain: Res<HTerrainConfig>,
+    player: Res<PlayerPos>,
+    active: Res<ActiveHex>,
+    grid: Option<Single<&HGrid>>,
+    cells: Query<Has<Revealed>, With<HCell>>,
+    mut stats: ResMut<SessionStats>,
+    mut last: Local<Option<(Vec2, Option<Hex>)>>,
+    mut summary: ResMut<SessionSummary>,
+    mut next: ResMut<NextState<GameState>>,
+    mut windows: Query<(&mut CursorOptions, &mut Window)>,
+) {
+    stats.elapsed += time.delta_secs();
+    let (last_xz, last_hex) = last.unwrap_or((player.xz, None));
+    let step = player.xz.distance(last_xz);
+    if step <= cfg.max_step {
+        stats.distance += step;
+    }
+    *last = Some((player.xz, active.current));
+
+    if let Some(hex) = active.current.filter(|&h| last_hex != Some(h))
+        && let Some(grid) = &grid
+    {
+        let height_of = |h: Hex| grid.terrain.height(&h);
+        if let Some(height) = height_of(hex)
+            && classify_target(hex, height, terrain.grid.max_height, height_of)
+                == TargetKind::Landmark
+        {
+            stats.peaks.insert(hex);
+        }
+    }
+
+    stats.total = cells.iter().count();
+    stats.revealed = cells.iter().filter(|&revealed| revealed).count();
+    if cfg.summary_on_complete
+        && !stats.completed
+        && stats.total > 0
+        && stats.revealed == stats.total
+    {
+        stats.completed = true;
+        summary.0 = SummaryReason::Completed;
+        next.set(GameState::Summary);
+        set_cursor_free(&mut windows, true);
+    }
+}
+
+/// Summary choice made this frame.
+enum SummaryAction {
+    KeepFlying,
+    #[cfg(not(target_arch = "wasm32"))]
+    Quit,
+}
+
+/// Draws the dimmed backdrop and the score breakdown, then applies the chosen action.
+#[allow(clippy::too_many_arguments)]
+fn draw_summary(
+    mut egui_ctx: Single<&mut bevy_egui::EguiContext>,
+    cfg: Res<ScoringConfig>,
+    stats: Res<SessionStats>,
+    summary: Res<SessionSummary>,
+    mut next: ResMut<NextState<GameState>>,
+    mut windows: Query<(&mut CursorOptions, &mut Window)>,
+    medals: Option<Res<SessionMedals>>,
+    locale: Res<Locale>,
+    #[cfg(not(target_arch = "wasm32"))] mut exit: MessageWriter<AppExit>,
+) {
+    let ctx = egui_ctx.get_mut();
+    let breakdown = score(&stats, &cfg);
+    let accent = egui::Color32::from_rgb(0, 255, 128);
+    let mono = egui::FontId::monospace(14.0);
+    let mut action = None;
+
+    egui::Area::new(egui::Id::new("summary_backdrop"))
+        .fixed_pos(egui::pos2(0.0, 0.0))
+        .order(egui::Order::Background)
+        .interactable(false)
+        .show(ctx, |ui| {
+            ui.painter().rect_filled(
+                ui.ctx().content_rect(),
+                0.0,
+                egui::Color32::from_black_alpha(BACKDROP_ALPHA),
+            );
+        });
+
+    egui::Area::new(egui::Id::new("session_summary"))
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .order(egui::Order::Foreground)
+        .show(ctx, |ui| {
+            egui::Frame::new()
+                .fill(egui::Color32::from_black_alpha(220))
+                .inner_margin(egui::Margin::same(16))
+                .corner_radius(6.0)
+                .show(ui, |ui| {
+                    ui.vertical_centered(|ui| {
+                        let title = match summary.0 {
+                            SummaryReason::Completed => "GRID COMPLETE",
+                            SummaryReason::Quit => "SESSION SUMMARY",
+                        };
+                        ui.label(
+                            egui::RichText::new(locale.t(title))
+                                .color(accent)
+                                .font(egui::FontId::monospace(22.0)),
+                        );
+                        ui.add_space(8.0);
+                        egui::Grid::new("summary_lines")
+                            .num_columns(3)
+                            .spacing([24.0, 4.0])
+                            .show(ui, |ui| {
+                                for line in &breakdown.lines {
+                                    let amount = match line.category {
+                                        ScoreCategory::Revealed => {
+                                            format!("{:.0} / {}", line.amount, stats.total)
+                                        }
+                                        ScoreCategory::Distance => format!("{:.0}", line.amount),
+                                        ScoreCategory::Peaks => format!("{:.0}", line.amount),
+                                        ScoreCategory::Time => {
+                                            let secs = line.amount as u32;
+                                            format!("{}:{:02}", secs / 60, secs % 60)
+                                        }
+                                    };
+                                    ui.label(locale.t(line.category.label()));
+                                    ui.label(egui::RichText::new(amount).font(mono.clone()));
+                                    ui.label(
+                                        egui::RichText::new(format!("{:>6}", line.points))
+                                            .font(mono.clone()),
+                                    );
+                                    ui.end_row();
+                                }
+                            });
+                        ui.separator();
+                        ui.label(
+                            egui::RichText::new(locale.fmt("Score: {0}", &[&breakdown.total]))
+                                .color(accent)
+                                .font(egui::FontId::monospace(18.0)),
+                        );
+                        if let Some(medals) = medals.filter(|m| !m.0.is_empty()) {
+                            ui.add_space(4.0);
+                            for (medal, run) in &medals.0 {
+                                ui.label(
+                                    egui::RichText::new(locale.fmt(
+                                        "{0}  {1}  course {2}",
+                                        &[
+                                            &locale.t(medal.label()),
+                                            &format_race_time(run.secs),
+                                            &format!("{:08x}", run.course),
+                                        ],
+                                    ))
+                                    .color(medal.color())
+                                    .font(mono.clone()),
+                                );
+                            }
+                        }
+                        ui.add_space(8.0);
+                        let mut button = |label: &str, choice: SummaryAction| {
+                            if ui
+                                .add_sized(BUTTON_SIZE, egui::Button::new(label))
+                                .clicked()
+                            {
+                                action = Some(choice);
+                            }
+                        };
+                        button(locale.t("Keep flying"), SummaryAction::KeepFlying);
+                        #[cfg(not(target_arch = "wasm32"))]
+                        button(locale.t("Quit"), SummaryAction::Quit);
+                    });
+                });
+        });
+
+    match action {
+        None => {}
+        Some(SummaryAction::KeepFlying) => {
+            next.set(GameState::Running);
+            set_cursor_free(&mut windows, false);
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        Some(SummaryAction::Quit) => {
+            exit.write(AppExit::Success);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::state::app::StatesPlugin;
+
+    use super::*;
+    use crate::h_terrain::{HGridLayout, HGridSettings};
+
+    #[test]
+    fn score_sums_the_category_points() {
+        let cfg = ScoringConfig::default();
+        let stats = SessionStats {
+            revealed: 50,
+            total: 100,
+            distance: 300.0,
+            peaks: HashSet::from([Hex::ZERO, Hex::ONE]),
+            elapsed: 600.0,
+            completed: false,
+        };
+        let breakdown = score(&stats, &cfg);
+        let points: Vec<u32> = breakdown.lines.iter().map(|l| l.points).collect();
+        // 50 × 10, 300 × 0.5, 2 × 250, (900 − 600) × 2 × 0.5 revealed.
+        assert_eq!(points, [500, 150, 500, 300]);
+        assert_eq!(breakdown.total, 1450);
+
+        let slow = SessionStats {
+            elapsed: 2000.0,
+            ..stats
+        };
+        assert_eq!(score(&slow, &cfg).lines[3].points, 0);
+    }
+
+    #[test]
+    fn tracker_skips_teleports_counts_peaks_and_opens_summary_on_completion() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin))
+            .init_state::<GameState>()
+            .init_resource::<ScoringConfig>()
+            .init_resource::<HTerrainConfig>()
+            .init_resource::<PlayerPos>()
+            .init_resource::<ActiveHex>()
+            .init_resource::<SessionStats>()
+            .init_resource::<SessionSummary>()
+            .add_systems(Update, track_session);
+        // A tall center hex over a low ring: a landmark peak.
+        let grid = HGridSettings {
+            radius: 1,
+            ..HTerrainConfig::default().grid
+        };
+        let mut terrain = HGridLayout::from_settings(&grid);
+        for hex in terrain.hexes().collect::<Vec<_>>() {
+            let height = if hex == Hex::ZERO {
+                grid.max_height
+            } else {
+                1.0
+            };
+            terrain.set_height(hex, height);
+        }
+        app.world_mut().spawn(HGrid {
+            terrain,
+            hex_entities: default(),
+        });
+        app.world_mut().spawn((HCell { hex: Hex::ZERO }, Revealed));
+        for hex in Hex::ZERO.all_neighbors() {
+            app.world_mut().spawn(HCell { hex });
+        }
+        app.update();
+
+        app.world_mut().resource_mut::<PlayerPos>().xz = Vec2::new(3.0, 4.0);
+        app.world_mut().resource_mut::<ActiveHex>().current = Some(Hex::ZERO);
+        app.update();
+        // Teleport: not counted as flight.
+        app.world_mut().resource_mut::<PlayerPos>().xz = Vec2::new(500.0, 0.0);
+        app.update();
+        let stats = app.world().resource::<SessionStats>();
+        assert_eq!(stats.distance, 5.0);
+        assert_eq!(stats.peaks, HashSet::from([Hex::ZERO]));
+        assert_eq!((stats.revealed, stats.total), (1, 7));
+        assert!(!stats.completed);
+
+        let cells: Vec<Entity> = app
+            .world_mut()
+            .query_filtered::<Entity, Without<Revealed>>()
+            .iter(app.world())
+            .filter(|&e| app.world().get::<HCell>(e).is_some())
+            .collect();
+        for cell in cells {
+            app.world_mut().entity_mut(cell).insert(Revealed);
+        }
+        app.update();
+        app.update();
+        assert!(app.world().resource::<SessionStats>().completed);
+        assert_eq!(
+            app.world().resource::<SessionSummary>().0,
+            SummaryReason::Completed
+        );
+        assert_eq!(
+            *app.world().resource::<State<GameState>>().get(),
+            GameState::Summary
+        );
+    }
+}
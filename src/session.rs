@@ -0,0 +1,448 @@
+//! Session persistence: continue an exploration where it was left off.
+//!
+//! On exit ([`AppExit`]), [`snapshot_session`] collects a [`Session`]: the grid settings, the
+//! hex heights that were edited since generation (quakes, mining, the hex panel), the
+//! [`PlayerPos`], the camera rotation, the hexes that are [`Revealed`] and [`Visited`],
+//! every claimed hex with its [`Owner`], every placed [`Prop`], the [`HexNotes`], and the
+//! [`Beacons`]. [`write_session`] saves it to [`SessionConfig::path`] with
+//! [`persist::save_ron`] (native only, with [`SessionConfig::autosave`]).
+//!
+//! `--continue` loads the file with [`persist::load_ron`] in `main` (a missing or unreadable
+//! one gives [`Session::default`]) before the plugins are built, so the terrain is generated
+//! from the saved settings, and hands it to [`SessionPlugin::resume`]. The first frame of
+//! flight ([`resume_session`]) then re-applies the height edits with [`EditHexHeight`], puts
+//! the drone back, re-marks the cells, re-places the props with [`SpawnProp`], and restores
+//! the notes and beacons. Restored cells are announced with [`HexRevealed`], so their faces
+//! grow in and the fog lifts from them the same way it does for scanner reveals.
+
+use bevy::app::AppExit;
+use bevy::prelude::*;
+use hexx::Hex;
+use serde::{Deserialize, Serialize};
+
+use crate::beacons::{Beacon, Beacons};
+use crate::claims::Owner;
+use crate::drone::Player;
+use crate::h_terrain::{
+    EditHexHeight, HCell, HGrid, HGridLayout, HGridSettings, HTerrainConfig, HexNotes, HexRevealed,
+    Revealed, Visited,
+};
+use crate::locale::Locale;
+use crate::props::{Prop, SpawnProp};
+use crate::toast::Toast;
+use crate::{GameState, PlayerMoved, PlayerPos, persist};
+
+/// Per-plugin configuration for session persistence.
+#[derive(Resource, Clone, Debug, Reflect)]
+pub struct SessionConfig {
+    /// Save the session on exit (native only).
+    pub autosave: bool,
+    /// File the session is saved to and `--continue` reads.
+    pub path: String,
+}
+
+impl Default for SessionConfig {
+    fn default() -> Self {
+        Self {
+            autosave: true,
+            path: "session.ron".into(),
+        }
+    }
+}
+
+/// Everything needed to pick an exploration back up.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Session {
+    /// Grid layout and noise parameters the terrain was generated from.
+    pub grid: HGridSettings,
+    /// Hexes whose height differs from the generated terrain, with their height.
+    #[serde(default)]
+    pub heights: Vec<([i32; 2], f32)>,
+    /// [`PlayerPos::xz`].
+    pub xz: [f32; 2],
+    /// [`PlayerPos::offset`].
+    pub offset: f32,
+    /// Camera rotation quaternion `[x, y, z, w]`.
+    pub rotation: [f32; 4],
+    /// Axial coordinates of the revealed hexes.
+    #[serde(default)]
+    pub revealed: Vec<[i32; 2]>,
+    /// Axial coordinates of the visited hexes.
+    #[serde(default)]
+    pub visited: Vec<[i32; 2]>,
+    /// Claimed hexes and their faction.
+    #[serde(default)]
+    pub claimed: Vec<([i32; 2], u8)>,
+    /// Placed props and their hex.
+    #[serde(default)]
+    pub props: Vec<([i32; 2], Prop)>,
+    /// Hex notes.
+    #[serde(default)]
+    pub notes: Vec<([i32; 2], String)>,
+    /// Dropped beacons, in drop order.
+    #[serde(default)]
+    pub beacons: Vec<Beacon>,
+}
+
+impl Session {
+    /// Generates the terrain from the session's grid settings.
+    pub fn apply_grid(&self, terrain: &mut HTerrainConfig) {
+        terrain.grid = self.grid.clone();
+    }
+}
+
+impl Default for Session {
+    fn default() -> Self {
+        Self {
+            grid: HTerrainConfig::default().grid,
+            heights: Vec::new(),
+            xz: [0.0; 2],
+            offset: 0.0,
+            rotation: Quat::IDENTITY.to_array(),
+            revealed: Vec::new(),
+            visited: Vec::new(),
+            claimed: Vec::new(),
+            props: Vec::new(),
+            notes: Vec::new(),
+            beacons: Vec::new(),
+        }
+    }
+}
+
+/// A loaded session waiting for the first frame of flight.
+#[derive(Resource)]
+struct PendingSession(Session);
+
+/// Session persistence plugin.
+pub struct SessionPlugin {
+    /// Per-plugin configuration.
+    pub config: SessionConfig,
+    /// Session loaded by `--continue`, restored once flight starts.
+    pub resume: Option<Session>,
+}
+
+impl Plugin for SessionPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<SessionConfig>()
+            .insert_resource(self.config.clone());
+        if let Some(session) = &self.resume {
+            app.insert_resource(PendingSession(session.clone()));
+        }
+        app.add_systems(
+            Update,
+            resume_session
+                .run_if(in_state(GameState::Running).and(resource_exists::<PendingSession>)),
+        )
+        .add_systems(
+            Last,
+            snapshot_session
+                .pipe(write_session)
+                .run_if(on_message::<AppExit>.and(|cfg: Res<SessionConfig>| cfg.autosave)),
+        );
+    }
+}
+
+/// Collects the current session.
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+pub fn snapshot_session(
+    terrain: Res<HTerrainConfig>,
+    grid: Query<&HGrid>,
+    player: Res<PlayerPos>,
+    camera: Query<&Transform, With<Player>>,
+    cells: Query<(&HCell, Has<Revealed>, Has<Visited>, Option<&Owner>)>,
+    props: Query<(&Prop, &ChildOf)>,
+    notes: Res<HexNotes>,
+    beacons: Option<Res<Beacons>>,
+) -> Session {
+    let mut session = Session {
+        grid: terrain.grid.clone(),
+        xz: player.xz.to_array(),
+        offset: player.offset,
+        rotation: camera
+            .single()
+            .map_or(Quat::IDENTITY, |tf| tf.rotation)
+            .to_array(),
+        beacons: beacons.map_or_else(Vec::new, |b| b.0.clone()),
+        ..default()
+    };
+    if let Ok(grid) = grid.single() {
+        let generated = HGridLayout::from_settings(&terrain.grid);
+        session.heights = grid
+            .terrain
+            .hexes()
+            .filter_map(|hex| {
+                let height = grid.terrain.height(&hex)?;
+                let edited = generated
+                    .height(&hex)
+                    .is_none_or(|h| (h - height).abs() > 1e-4);
+                edited.then_some((hex.to_array(), height))
+            })
+            .collect();
+    }
+    for (cell, revealed, visited, owner) in &cells {
+        let hex = cell.hex.to_array();
+        if revealed {
+            session.revealed.push(hex);
+        }
+        if visited {
+            session.visited.push(hex);
+        }
+        if let Some(owner) = owner {
+            session.claimed.push((hex, owner.0));
+        }
+    }
+    for (prop, parent) in &props {
+        if let Ok((cell, ..)) = cells.get(parent.parent()) {
+            session.props.push((cell.hex.to_array(), *prop));
+        }
+    }
+    session.notes = notes
+        .0
+        .iter()
+        .map(|(hex, text)| (hex.to_array(), text.trim().to_owned()))
+        .collect();
+    // Query order isn't stable; keep the file diffable.
+    session.heights.sort_by_key(|(hex, _)| *hex);
+    session.revealed.sort_unstable();
+    session.visited.sort_unstable();
+    session.claimed.sort_unstable();
+    session.props.sort_by_key(|(hex, _)| *hex);
+    session.notes.sort_unstable();
+    session
+}
+
+/// Writes the collected session to [`SessionConfig::path`] (no-op on WASM).
+fn write_session(In(session): In<Session>, cfg: Res<SessionConfig>) {
+    persist::save_ron(&session, &cfg.path, "session");
+}
+
+/// Re-applies the saved height edits, puts the drone back where the session left it, and
+/// re-marks the saved cells.
+#[allow(clippy::too_many_arguments)]
+fn resume_session(
+    pending: Res<PendingSession>,
+    grid: Query<&HGrid>,
+    mut edits: MessageWriter<EditHexHeight>,
+    mut player: ResMut<PlayerPos>,
+    mut moved: ResMut<PlayerMoved>,
+    mut camera: Query<&mut Transform, With<Player>>,
+    cells: Query<(Entity, &HCell)>,
+    mut props: MessageWriter<SpawnProp>,
+    mut reveals: MessageWriter<HexRevealed>,
+    mut notes: ResMut<HexNotes>,
+    beacons: Option<ResMut<Beacons>>,
+    mut toasts: MessageWriter<Toast>,
+    locale: Res<Locale>,
+    mut commands: Commands,
+) {
+    let session = &pending.0;
+    if let Ok(grid) = grid.single() {
+        edits.write_batch(session.heights.iter().filter_map(|&(hex, height)| {
+            let hex = Hex::from(hex);
+            let delta = height - grid.terrain.height(&hex)?;
+            Some(EditHexHeight { hex, delta })
+        }));
+    }
+    player.xz = Vec2::from_array(session.xz);
+    player.offset = session.offset;
+    moved.0 = true;
+    if let Ok(mut tf) = camera.single_mut() {
+        tf.rotation = Quat::from_array(session.rotation).normalize();
+    }
+    let revealed: std::collections::HashSet<Hex> =
+        session.revealed.iter().copied().map(Hex::from).collect();
+    let visited: std::collections::HashSet<Hex> =
+        session.visited.iter().copied().map(Hex::from).collect();
+    for (entity, cell) in &cells {
+        let mut cmds = commands.entity(entity);
+        if visited.contains(&cell.hex) {
+            cmds.insert(Visited);
+        }
+        if revealed.contains(&cell.hex) {
+            cmds.insert(Revealed);
+            reveals.write(HexRevealed {
+                cell: entity,
+                delay: 0.0,
+            });
+        }
+        if let Some(&(_, owner)) = session
+            .claimed
+            .iter()
+            .find(|(hex, _)| Hex::from(*hex) == cell.hex)
+        {
+            cmds.insert(Owner(owner));
+        }
+    }
+    props.write_batch(session.props.iter().map(|&(hex, prop)| SpawnProp {
+        hex: Hex::from(hex),
+        prop,
+    }));
+    for (hex, text) in &session.notes {
+        notes.set(Hex::from(*hex), text);
+    }
+    if let Some(mut beacons) = beacons {
+        beacons.0.clone_from(&session.beacons);
+    }
+    toasts.write(Toast(locale.t("Session restored").into()));
+    commands.remove_resource::<PendingSession>();
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::state::app::StatesPlugin;
+
+    use super::*;
+    use crate::props::PropKind;
+
+    fn test_app() -> App {
+        let mut terrain = HTerrainConfig::default();
+        terrain.grid.radius = 3;
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin))
+            .add_message::<Toast>()
+            .add_message::<SpawnProp>()
+            .add_message::<HexRevealed>()
+            .add_message::<EditHexHeight>()
+            .init_state::<GameState>()
+            .insert_resource(terrain.clone())
+            .init_resource::<PlayerPos>()
+            .init_resource::<PlayerMoved>()
+            .init_resource::<Locale>()
+            .init_resource::<HexNotes>()
+            .init_resource::<Beacons>()
+            .add_systems(
+                Update,
+                resume_session.run_if(resource_exists::<PendingSession>),
+            );
+        let hex_entities = (0..3)
+            .map(|q| {
+                let hex = Hex::new(q, 0);
+                (hex, app.world_mut().spawn(HCell { hex }).id())
+            })
+            .collect();
+        app.world_mut().spawn(HGrid {
+            terrain: HGridLayout::from_settings(&terrain.grid),
+            hex_entities,
+        });
+        app.world_mut().spawn((Player, Transform::default()));
+        app
+    }
+
+    #[test]
+    fn sessions_round_trip_through_ron() {
+        let mut grid = HTerrainConfig::default().grid;
+        grid.radius = 12;
+        grid.height_noise_seed = 7;
+        let session = Session {
+            grid,
+            heights: vec![([0, 1], 3.5)],
+            xz: [1.5, -2.0],
+            offset: 12.0,
+            rotation: Quat::from_rotation_y(0.5).to_array(),
+            revealed: vec![[0, 0], [1, 0]],
+            visited: vec![[0, 0]],
+            claimed: vec![([1, 0], 0)],
+            props: vec![(
+                [1, 0],
+                Prop {
+                    kind: PropKind::Arch,
+                    yaw: 45.0,
+                    scale: 1.5,
+                },
+            )],
+            notes: vec![([0, 0], "twin spires".into())],
+            beacons: vec![Beacon {
+                name: "spire".into(),
+                hex: [1, 0],
+            }],
+        };
+        let text = ron::to_string(&session).unwrap();
+        assert_eq!(ron::from_str::<Session>(&text).unwrap(), session);
+        // Files without height edits or cell lists still load.
+        let bare = format!(
+            "(grid: {}, xz: (0.0, 0.0), offset: 2.0, rotation: (0.0, 0.0, 0.0, 1.0))",
+            ron::to_string(&HTerrainConfig::default().grid).unwrap()
+        );
+        let bare: Session = ron::from_str(&bare).unwrap();
+        assert!(bare.heights.is_empty() && bare.revealed.is_empty());
+    }
+
+    #[test]
+    fn a_snapshot_restores_into_a_fresh_world() {
+        let mut app = test_app();
+        let w = app.world_mut();
+        let cells: Vec<Entity> = w.query_filtered::<Entity, With<HCell>>().iter(w).collect();
+        w.entity_mut(cells[0]).insert((Revealed, Visited, Owner(0)));
+        w.entity_mut(cells[1]).insert(Revealed);
+        let arch = Prop {
+            kind: PropKind::Arch,
+            yaw: 30.0,
+            scale: 2.0,
+        };
+        w.spawn((arch, ChildOf(cells[1])));
+        w.resource_mut::<HexNotes>()
+            .set(Hex::new(2, 0), "deep crater  ");
+        w.resource_mut::<Beacons>().drop_at(Hex::new(1, 0));
+        let mut grid = w.query::<&mut HGrid>();
+        let mut grid = grid.single_mut(w).unwrap();
+        let quaked = grid.terrain.height(&Hex::new(1, 0)).unwrap() + 3.0;
+        grid.terrain.set_height(Hex::new(1, 0), quaked);
+        w.resource_mut::<PlayerPos>().xz = Vec2::new(3.0, 4.0);
+        w.resource_mut::<PlayerPos>().offset = 20.0;
+        let rotation = Quat::from_rotation_y(1.0);
+        let mut camera = w.query_filtered::<&mut Transform, With<Player>>();
+        camera.single_mut(w).unwrap().rotation = rotation;
+        let session = w.run_system_cached(snapshot_session).unwrap();
+        assert_eq!(session.grid.radius, 3);
+        assert_eq!(session.heights, [([1, 0], quaked)]);
+        assert_eq!(session.revealed.len(), 2);
+        assert_eq!(session.visited.len(), 1);
+        assert_eq!(session.claimed.len(), 1);
+        let prop_hex = w.get::<HCell>(cells[1]).unwrap().hex;
+        assert_eq!(session.props, [(prop_hex.to_array(), arch)]);
+        assert_eq!(session.notes, [([2, 0], "deep crater".to_owned())]);
+        assert_eq!(session.beacons.len(), 1);
+
+        let mut fresh = test_app();
+        fresh.insert_resource(PendingSession(session));
+        fresh.update();
+        let w = fresh.world_mut();
+        assert!(!w.contains_resource::<PendingSession>());
+        assert_eq!(w.resource::<PlayerPos>().xz, Vec2::new(3.0, 4.0));
+        assert_eq!(w.resource::<PlayerPos>().offset, 20.0);
+        let mut camera = w.query_filtered::<&Transform, With<Player>>();
+        assert!(
+            camera
+                .single(w)
+                .unwrap()
+                .rotation
+                .abs_diff_eq(rotation, 1e-6)
+        );
+        let mut revealed = w.query_filtered::<&HCell, With<Revealed>>();
+        assert_eq!(revealed.iter(w).count(), 2);
+        let mut owners = w.query::<(&HCell, &Owner)>();
+        let owners: Vec<_> = owners.iter(w).map(|(c, o)| (c.hex, *o)).collect();
+        assert_eq!(owners.len(), 1);
+        assert_eq!(owners[0].1, Owner(0));
+        assert_eq!(w.query::<&Visited>().iter(w).count(), 1);
+        assert_eq!(
+            w.resource::<HexNotes>().get(Hex::new(2, 0)),
+            Some("deep crater")
+        );
+        assert_eq!(w.resource::<Beacons>().0[0].hex(), Hex::new(1, 0));
+        let edits = w.resource::<Messages<EditHexHeight>>();
+        let edits: Vec<_> = edits.iter_current_update_messages().collect();
+        assert_eq!(edits.len(), 1);
+        assert_eq!(edits[0].hex, Hex::new(1, 0));
+        assert!((edits[0].delta - 3.0).abs() < 1e-4);
+        let spawns = w.resource::<Messages<SpawnProp>>();
+        let spawns: Vec<_> = spawns.iter_current_update_messages().copied().collect();
+        assert_eq!(
+            spawns,
+            [SpawnProp {
+                hex: prop_hex,
+                prop: arch
+            }]
+        );
+    }
+}
@@ -0,0 +1,1106 @@
+//! Runtime settings panel (shown while [`GameState::Inspecting`], Tab to toggle).
+//!
+//! Edits [`HTerrainConfig`], [`DroneConfig`], [`PostFxConfig`], [`IntroConfig`], [`HudConfig`], the
+//! [`BudgetConfig`] limits, the [`GlowLevel`] dial, the [`Theme`] preset (with face/edge/gap
+//! swatches), the [`FpsCap`], and the primary window's [`PresentMode`] live. Each section edits a
+//! copy and writes it back only when a widget changed, so `resource_changed` systems don't fire
+//! every frame. Grid layout and noise settings only take effect on a rebuild: they are staged in
+//! [`SettingsDraft`] until "Apply & Regenerate" writes them and sends [`RegenerateTerrain`]. When
+//! the grid changes from elsewhere (hot reload, the console), a draft without pending edits follows
+//! it ([`refresh_settings_draft`]), so applying never pushes stale values.
+//!
+//! A small seed widget in the top-left corner always shows the current noise seeds; with a
+//! free cursor (`Inspecting`) the seeds can be typed or randomized to regenerate at once.
+//!
+//! The Display section also picks the UI [`Language`] ([`LocaleConfig`]) and the
+//! [`UiConfig::scale`] (applied when the slider is released); the panel's section
+//! headers, buttons, and toasts are localized, the tuning slider names stay English.
+//!
+//! "Export settings" / "Import settings" (native only) write and read
+//! [`SettingsConfig::file`]: the [`Settings`] ([`HTerrainConfig`], [`DroneConfig`],
+//! [`IntroConfig`], the [`Theme`], the [`LocaleConfig`], and the [`UiConfig`]) as RON,
+//! serialized through reflection. Importing regenerates the terrain. `--config` loads the
+//! same file at startup via [`load_settings_file`], before the plugins are built; CLI flags
+//! given alongside it still win; a file that fails to load is reported as a toast
+//! ([`SettingsConfig::load_error`]) and the app starts from the defaults. Sections or fields
+//! missing from a file keep their current values, so older files still load.
+//!
+//! With [`SettingsConfig::hot_reload`] (native only, on with `--config`),
+//! [`hot_reload_settings`] polls the file's modification time and re-imports it on change:
+//! tuning applies live, grid layout and noise changes regenerate the terrain.
+
+use std::ops::RangeInclusive;
+
+use bevy::post_process::bloom::BloomCompositeMode;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistry;
+use bevy::reflect::serde::{TypedReflectDeserializer, TypedReflectSerializer};
+use bevy::window::{PresentMode, PrimaryWindow};
+use bevy_egui::egui;
+
+use crate::GameState;
+use crate::budget::BudgetConfig;
+use crate::drone::{AltitudeMode, DroneConfig};
+use crate::frame_limit::{FpsCap, FrameLimitConfig};
+use crate::h_terrain::{
+    GlowLevel, HGridSettings, HTerrainConfig, HexLabelMode, Palette, RegenerateTerrain, RevealMode,
+    RevealStrategy, Theme, ThemeKind,
+};
+use crate::hud::HudConfig;
+use crate::intro::IntroConfig;
+use crate::locale::{Language, Locale, LocaleConfig};
+use crate::neon_ui::UiConfig;
+use crate::post_fx::PostFxConfig;
+use crate::toast::Toast;
+
+/// Per-plugin configuration for the settings panel.
+#[derive(Resource, Clone, Debug, Reflect)]
+pub struct SettingsConfig {
+    /// RON file written by "Export settings" and read by "Import settings".
+    pub file: String,
+    /// Re-import [`file`](Self::file) whenever it changes on disk (native only; on with
+    /// `--config`).
+    pub hot_reload: bool,
+    /// Real seconds between modification-time checks while hot-reloading.
+    pub poll_secs: f32,
+    /// Why the `--config` file failed to load at startup; toasted once the app runs, which
+    /// then starts from the defaults.
+    pub load_error: Option<String>,
+}
+
+impl Default for SettingsConfig {
+    fn default() -> Self {
+        Self {
+            file: "settings.ron".into(),
+            hot_reload: false,
+            poll_secs: 0.5,
+            load_error: None,
+        }
+    }
+}
+
+/// Hot-reload bookkeeping: real time since the last poll and the file's last seen
+/// modification time.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Resource, Default)]
+struct SettingsWatch {
+    since_poll: f32,
+    modified: Option<std::time::SystemTime>,
+}
+
+/// Grid settings being edited, applied on "Apply & Regenerate".
+#[derive(Resource, Default)]
+pub struct SettingsDraft {
+    /// Staged grid settings (`None` until the panel first opens).
+    pub grid: Option<HGridSettings>,
+}
+
+/// Settings panel plugin.
+pub struct SettingsPlugin {
+    /// Per-plugin configuration.
+    pub config: SettingsConfig,
+}
+
+impl Plugin for SettingsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<SettingsConfig>()
+            .insert_resource(self.config.clone())
+            .init_resource::<SettingsDraft>()
+            .add_systems(Startup, report_load_error)
+            .add_systems(
+                Update,
+                refresh_settings_draft
+                    .before(draw_settings_panel)
+                    .run_if(resource_changed::<HTerrainConfig>),
+            )
+            .add_systems(
+                Update,
+                draw_settings_panel.run_if(in_state(GameState::Inspecting)),
+            )
+            .add_systems(
+                Update,
+                draw_seed_widget
+                    .after(draw_settings_panel)
+                    .run_if(in_state(GameState::Running).or(in_state(GameState::Inspecting))),
+            );
+
+        #[cfg(not(target_arch = "wasm32"))]
+        app.insert_resource(SettingsWatch {
+            since_poll: 0.0,
+            modified: file_modified(&self.config.file),
+        })
+        .add_systems(
+            Update,
+            hot_reload_settings.run_if(|cfg: Res<SettingsConfig>| cfg.hot_reload),
+        );
+    }
+}
+
+/// Replaces a draft that has no pending edits with the changed [`HTerrainConfig::grid`].
+///
+/// The draft has pending edits when it differs from the grid seen on the previous change.
+fn refresh_settings_draft(
+    terrain: Res<HTerrainConfig>,
+    mut draft: ResMut<SettingsDraft>,
+    mut last: Local<Option<HGridSettings>>,
+) {
+    let edited = match (&draft.grid, last.as_ref()) {
+        (Some(grid), Some(last)) => grid.reflect_partial_eq(last) != Some(true),
+        _ => false,
+    };
+    if draft.grid.is_some() && !edited {
+        draft.grid = Some(terrain.grid.clone());
+    }
+    *last = Some(terrain.grid.clone());
+}
+
+/// Height and radius noise seeds derived from `entropy` (splitmix64).
+pub fn random_seeds(entropy: u64) -> (u32, u32) {
+    let mut z = entropy.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^= z >> 31;
+    ((z >> 32) as u32, z as u32)
+}
+
+/// The shareable settings: one section per resource in a settings file.
+#[derive(Clone, Debug, Default)]
+pub struct Settings {
+    /// `terrain` section.
+    pub terrain: HTerrainConfig,
+    /// `drone` section.
+    pub drone: DroneConfig,
+    /// `intro` section.
+    pub intro: IntroConfig,
+    /// `theme` section.
+    pub theme: Theme,
+    /// `locale` section.
+    pub locale: LocaleConfig,
+    /// `ui` section.
+    pub ui: UiConfig,
+}
+
+/// The live resources behind each [`Settings`] section.
+#[derive(bevy::ecs::system::SystemParam)]
+struct SettingsResources<'w> {
+    terrain: ResMut<'w, HTerrainConfig>,
+    drone: ResMut<'w, DroneConfig>,
+    intro: ResMut<'w, IntroConfig>,
+    theme: ResMut<'w, Theme>,
+    locale: ResMut<'w, LocaleConfig>,
+    ui: ResMut<'w, UiConfig>,
+}
+
+impl SettingsResources<'_> {
+    /// Copies the current values into a [`Settings`].
+    fn snapshot(&self) -> Settings {
+        Settings {
+            terrain: self.terrain.clone(),
+            drone: self.drone.clone(),
+            intro: self.intro.clone(),
+            theme: self.theme.clone(),
+            locale: self.locale.clone(),
+            ui: self.ui.clone(),
+        }
+    }
+
+    /// Writes back the sections of `settings` that differ; returns whether any did.
+    fn apply(&mut self, settings: Settings) -> bool {
+        [
+            set_if_changed(&mut self.terrain, settings.terrain),
+            set_if_changed(&mut self.drone, settings.drone),
+            set_if_changed(&mut self.intro, settings.intro),
+            set_if_changed(&mut self.theme, settings.theme),
+            set_if_changed(&mut self.locale, settings.locale),
+            set_if_changed(&mut self.ui, settings.ui),
+        ]
+        .contains(&true)
+    }
+}
+
+/// Shareable settings, serialized as one RON struct with a section per resource.
+#[derive(serde::Serialize)]
+struct SettingsOut<'a> {
+    terrain: TypedReflectSerializer<'a>,
+    drone: TypedReflectSerializer<'a>,
+    intro: TypedReflectSerializer<'a>,
+    theme: TypedReflectSerializer<'a>,
+    locale: TypedReflectSerializer<'a>,
+    ui: TypedReflectSerializer<'a>,
+}
+
+/// Serializes the shareable settings to RON.
+pub fn export_settings(registry: &TypeRegistry, settings: &Settings) -> Result<String, ron::Error> {
+    let out = SettingsOut {
+        terrain: TypedReflectSerializer::new(&settings.terrain, registry),
+        drone: TypedReflectSerializer::new(&settings.drone, registry),
+        intro: TypedReflectSerializer::new(&settings.intro, registry),
+        theme: TypedReflectSerializer::new(&settings.theme, registry),
+        locale: TypedReflectSerializer::new(&settings.locale, registry),
+        ui: TypedReflectSerializer::new(&settings.ui, registry),
+    };
+    ron::ser::to_string_pretty(&out, ron::ser::PrettyConfig::default())
+}
+
+/// Reads a settings file's sections and applies them onto the given values.
+struct SettingsIn<'a> {
+    registry: &'a TypeRegistry,
+    settings: &'a mut Settings,
+}
+
+/// Top-level sections of a settings file.
+const SECTIONS: &[&str] = &["terrain", "drone", "intro", "theme", "locale", "ui"];
+
+impl<'de> serde::de::DeserializeSeed<'de> for SettingsIn<'_> {
+    type Value = ();
+
+    fn deserialize<D: serde::Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_struct("Settings", SECTIONS, self)
+    }
+}
+
+impl<'de> serde::de::Visitor<'de> for SettingsIn<'_> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("a settings struct with terrain, drone, intro, theme, locale, and ui sections")
+    }
+
+    fn visit_map<A: serde::de::MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+        use serde::de::Error;
+
+        while let Some(key) = map.next_key::<String>()? {
+            let target: &mut dyn PartialReflect = match key.as_str() {
+                "terrain" => &mut self.settings.terrain,
+                "drone" => &mut self.settings.drone,
+                "intro" => &mut self.settings.intro,
+                "theme" => &mut self.settings.theme,
+                "locale" => &mut self.settings.locale,
+                "ui" => &mut self.settings.ui,
+                other => return Err(A::Error::unknown_field(other, SECTIONS)),
+            };
+            let registration = target
+                .get_represented_type_info()
+                .and_then(|info| self.registry.get(info.type_id()))
+                .ok_or_else(|| A::Error::custom(format!("`{key}` type is not registered")))?;
+            let value =
+                map.next_value_seed(TypedReflectDeserializer::new(registration, self.registry))?;
+            target
+                .try_apply(value.as_ref())
+                .map_err(|e| A::Error::custom(format!("`{key}`: {e}")))?;
+        }
+        Ok(())
+    }
+}
+
+/// Parses RON settings and applies them onto `settings`.
+///
+/// Missing sections and fields keep their current values. On error the values may be
+/// partially updated; apply to a copy when that matters.
+pub fn import_settings(
+    registry: &TypeRegistry,
+    text: &str,
+    settings: &mut Settings,
+) -> Result<(), String> {
+    use serde::de::DeserializeSeed;
+
+    let mut de = ron::Deserializer::from_str(text).map_err(|e| e.to_string())?;
+    SettingsIn { registry, settings }
+        .deserialize(&mut de)
+        .map_err(|e| e.to_string())?;
+    de.end().map_err(|e| e.to_string())
+}
+
+/// Type registry with every settings section registered, for use before the app exists.
+pub fn settings_registry() -> TypeRegistry {
+    let mut registry = TypeRegistry::new();
+    registry.register::<HTerrainConfig>();
+    registry.register::<DroneConfig>();
+    registry.register::<IntroConfig>();
+    registry.register::<Theme>();
+    registry.register::<LocaleConfig>();
+    registry.register::<UiConfig>();
+    registry
+}
+
+/// Loads `path` onto the startup `settings` (`--config`), before the plugins are built.
+///
+/// The theme starts from the preset and palette named by `settings.terrain`, so files
+/// without a `theme` section keep it.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn load_settings_file(path: &str, settings: &mut Settings) -> Result<(), String> {
+    let text = read_settings_file(path).map_err(|e| format!("{path}: {e}"))?;
+    settings.theme = Theme::from(settings.terrain.theme).with_palette(settings.terrain.palette);
+    import_settings(&settings_registry(), &text, settings)
+}
+
+/// Writes exported settings `text` to `path`.
+#[cfg(not(target_arch = "wasm32"))]
+fn write_settings_file(path: &str, text: &str) -> Result<(), String> {
+    std::fs::write(path, text).map_err(|e| e.to_string())
+}
+
+/// Settings files are unavailable in the browser.
+#[cfg(target_arch = "wasm32")]
+fn write_settings_file(_path: &str, _text: &str) -> Result<(), String> {
+    Err("settings files are not available on the web".into())
+}
+
+/// Reads the settings file at `path`.
+#[cfg(not(target_arch = "wasm32"))]
+fn read_settings_file(path: &str) -> Result<String, String> {
+    std::fs::read_to_string(path).map_err(|e| e.to_string())
+}
+
+/// Settings files are unavailable in the browser.
+#[cfg(target_arch = "wasm32")]
+fn read_settings_file(_path: &str) -> Result<String, String> {
+    Err("settings files are not available on the web".into())
+}
+
+/// Toasts a `--config` load failure ([`SettingsConfig::load_error`]) once the UI is up.
+fn report_load_error(
+    cfg: Res<SettingsConfig>,
+    locale: Res<Locale>,
+    mut toasts: MessageWriter<Toast>,
+) {
+    if let Some(e) = &cfg.load_error {
+        toasts.write(Toast(locale.fmt("Failed to load --config {0}", &[e])));
+    }
+}
+
+/// Modification time of `path`, or `None` when it can't be read.
+#[cfg(not(target_arch = "wasm32"))]
+fn file_modified(path: &str) -> Option<std::time::SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+/// Writes `value` into `res` unless they reflect equal; returns whether it wrote.
+fn set_if_changed<T: Resource + PartialReflect>(res: &mut ResMut<T>, value: T) -> bool {
+    let changed = res.as_ref().reflect_partial_eq(&value) != Some(true);
+    if changed {
+        **res = value;
+    }
+    changed
+}
+
+/// Re-imports [`SettingsConfig::file`] after it changes on disk ([`SettingsConfig::hot_reload`]).
+///
+/// Everything but the grid applies at once, through the usual change detection (speeds,
+/// colors, fade distances, bloom, the theme). Grid layout and noise changes are written with
+/// a [`RegenerateTerrain`], like "Apply & Regenerate". Only reloads that changed something
+/// toast, so the panel's own exports stay quiet.
+#[cfg(not(target_arch = "wasm32"))]
+#[allow(clippy::too_many_arguments)]
+fn hot_reload_settings(
+    time: Res<Time<Real>>,
+    cfg: Res<SettingsConfig>,
+    mut watch: ResMut<SettingsWatch>,
+    registry: Res<AppTypeRegistry>,
+    mut sections: SettingsResources,
+    mut draft: ResMut<SettingsDraft>,
+    mut regenerate: MessageWriter<RegenerateTerrain>,
+    (locale, mut toasts): (Res<Locale>, MessageWriter<Toast>),
+) {
+    watch.since_poll += time.delta_secs();
+    if watch.since_poll < cfg.poll_secs {
+        return;
+    }
+    watch.since_poll = 0.0;
+    let modified = file_modified(&cfg.file);
+    if modified.is_none() || modified == watch.modified {
+        return;
+    }
+    watch.modified = modified;
+
+    let mut reloaded = sections.snapshot();
+    let result = read_settings_file(&cfg.file)
+        .and_then(|text| import_settings(&registry.read(), &text, &mut reloaded));
+    if let Err(e) = result {
+        toasts.write(Toast(locale.fmt("Reload failed: {0}", &[&e])));
+        return;
+    }
+
+    if reloaded
+        .terrain
+        .grid
+        .reflect_partial_eq(&sections.terrain.grid)
+        != Some(true)
+    {
+        draft.grid = Some(reloaded.terrain.grid.clone());
+        regenerate.write(RegenerateTerrain);
+    }
+    if sections.apply(reloaded) {
+        toasts.write(Toast(
+            locale.fmt("Settings reloaded from {0}", &[&cfg.file]),
+        ));
+    }
+}
+
+/// Labeled slider; returns whether the value changed.
+fn slider<N: egui::emath::Numeric>(
+    ui: &mut egui::Ui,
+    label: &str,
+    value: &mut N,
+    range: RangeInclusive<N>,
+) -> bool {
+    ui.add(egui::Slider::new(value, range).text(label))
+        .changed()
+}
+
+/// Labeled checkbox; returns whether the value changed.
+fn toggle(ui: &mut egui::Ui, label: &str, value: &mut bool) -> bool {
+    ui.checkbox(value, label).changed()
+}
+
+/// Face / edge / gap preview squares for `kind` (displayable colors, as in low-gfx).
+fn theme_swatches(ui: &mut egui::Ui, kind: ThemeKind, palette: Palette) {
+    const SWATCH: egui::Vec2 = egui::vec2(12.0, 12.0);
+    let preview = Theme::from(kind).with_palette(palette).flattened();
+    for slot in [preview.face, preview.edge, preview.gap] {
+        let [r, g, b, _] = slot.base.to_srgba().to_u8_array();
+        let (rect, _) = ui.allocate_exact_size(SWATCH, egui::Sense::hover());
+        ui.painter()
+            .rect_filled(rect, 2.0, egui::Color32::from_rgb(r, g, b));
+    }
+}
+
+/// Present modes offered in the Display section (the `--present-mode` choices).
+const PRESENT_MODES: [PresentMode; 6] = [
+    PresentMode::AutoVsync,
+    PresentMode::AutoNoVsync,
+    PresentMode::Fifo,
+    PresentMode::FifoRelaxed,
+    PresentMode::Immediate,
+    PresentMode::Mailbox,
+];
+
+/// Draws the settings window and applies edits.
+#[allow(clippy::too_many_arguments)]
+fn draw_settings_panel(
+    mut egui_ctx: Single<&mut bevy_egui::EguiContext>,
+    sections: SettingsResources,
+    mut post_fx: ResMut<PostFxConfig>,
+    mut glow: ResMut<GlowLevel>,
+    mut hud: ResMut<HudConfig>,
+    mut frame_limit: ResMut<FrameLimitConfig>,
+    mut window: Single<&mut Window, With<PrimaryWindow>>,
+    mut draft: ResMut<SettingsDraft>,
+    mut regenerate: MessageWriter<RegenerateTerrain>,
+    cfg: Res<SettingsConfig>,
+    registry: Res<AppTypeRegistry>,
+    mut toasts: MessageWriter<Toast>,
+    (locale, mut budget): (Res<Locale>, ResMut<BudgetConfig>),
+) {
+    let SettingsResources {
+        mut terrain,
+        mut drone,
+        mut intro,
+        mut theme,
+        locale: mut locale_cfg,
+        ui: mut ui_cfg,
+    } = sections;
+    let grid = draft.grid.get_or_insert_with(|| terrain.grid.clone());
+    let mut t = terrain.clone();
+    let mut d = drone.clone();
+    let mut p = post_fx.clone();
+    let mut i = intro.clone();
+    let mut b = budget.clone();
+    let mut level = glow.0;
+    let mut hud_enabled = hud.enabled;
+    let mut altitude_control = hud.altitude_control;
+    let mut kind = theme.kind;
+    let mut fps_cap = frame_limit.fps_cap;
+    let mut present_mode = window.present_mode;
+    let mut language = locale_cfg.language;
+    let mut ui_scale = ui_cfg.scale;
+    let mut ui_scale_done = false;
+    let (mut t_changed, mut d_changed, mut p_changed, mut i_changed) = (false, false, false, false);
+    let mut b_changed = false;
+    let mut apply_grid = false;
+    let (mut export, mut import) = (false, false);
+
+    egui::Window::new(locale.t("Settings"))
+        .id(egui::Id::new("settings_panel"))
+        .anchor(egui::Align2::RIGHT_TOP, [-8.0, 8.0])
+        .default_width(300.0)
+        .vscroll(true)
+        .show(egui_ctx.get_mut(), |ui| {
+            ui.collapsing(locale.t("Grid (regenerates terrain)"), |ui| {
+                slider(ui, "radius", &mut grid.radius, 1..=40);
+                slider(ui, "fov reach", &mut grid.fov_reach, 0..=6);
+                slider(ui, "point spacing", &mut grid.point_spacing, 1.0..=10.0);
+                slider(ui, "max height", &mut grid.max_height, 1.0..=60.0);
+                slider(ui, "min hex radius", &mut grid.min_hex_radius, 0.05..=3.0);
+                slider(ui, "max hex radius", &mut grid.max_hex_radius, 0.1..=4.0);
+                slider(ui, "height seed", &mut grid.height_noise_seed, 0..=999);
+                slider(ui, "height octaves", &mut grid.height_noise_octaves, 1..=8);
+                slider(
+                    ui,
+                    "height scale",
+                    &mut grid.height_noise_scale,
+                    5.0..=200.0,
+                );
+                slider(ui, "radius seed", &mut grid.radius_noise_seed, 0..=999);
+                slider(ui, "radius octaves", &mut grid.radius_noise_octaves, 1..=8);
+                slider(
+                    ui,
+                    "radius scale",
+                    &mut grid.radius_noise_scale,
+                    5.0..=200.0,
+                );
+                ui.horizontal(|ui| {
+                    apply_grid = ui.button(locale.t("Apply & Regenerate")).clicked();
+                    if ui.button(locale.t("Revert")).clicked() {
+                        *grid = terrain.grid.clone();
+                    }
+                });
+            });
+
+            ui.collapsing(locale.t("Terrain"), |ui| {
+                t_changed |= slider(ui, "FoV fade secs", &mut t.fov_transition_secs, 0.0..=3.0);
+                t_changed |= slider(ui, "look-ahead", &mut t.lookahead_distance, 0.0..=20.0);
+                t_changed |= slider(ui, "active pulse", &mut t.active_pulse_amplitude, 0.0..=4.0);
+                t_changed |= slider(ui, "reveal particles", &mut t.reveal_particle_count, 0..=48);
+                t_changed |= slider(ui, "reveal anim secs", &mut t.reveal_anim_secs, 0.0..=2.0);
+                ui.horizontal_wrapped(|ui| {
+                    ui.label("reveal mode");
+                    for mode in RevealMode::ALL {
+                        t_changed |= ui
+                            .selectable_value(&mut t.reveal_mode, mode, mode.label())
+                            .changed();
+                    }
+                });
+                ui.horizontal_wrapped(|ui| {
+                    ui.label("reveal shape");
+                    for strategy in RevealStrategy::ALL {
+                        let current = std::mem::discriminant(&t.reveal_strategy)
+                            == std::mem::discriminant(&strategy);
+                        if ui.selectable_label(current, strategy.label()).clicked() && !current {
+                            t.reveal_strategy = strategy;
+                            t_changed = true;
+                        }
+                    }
+                });
+                if let RevealStrategy::Cone { angle, range } = &mut t.reveal_strategy {
+                    t_changed |= slider(ui, "cone angle", angle, 10.0..=360.0);
+                    t_changed |= slider(ui, "cone range", range, 4.0..=80.0);
+                }
+                t_changed |= slider(ui, "scan speed", &mut t.scanner_speed, 5.0..=100.0);
+                t_changed |= slider(ui, "scan range", &mut t.scanner_range, 10.0..=200.0);
+                t_changed |= slider(
+                    ui,
+                    "scan cooldown",
+                    &mut t.scanner_cooldown_secs,
+                    0.0..=20.0,
+                );
+                t_changed |= slider(
+                    ui,
+                    "outline width",
+                    &mut t.selection_outline_width,
+                    0.0..=0.3,
+                );
+                t_changed |= toggle(ui, "glitch", &mut t.glitch);
+                t_changed |= slider(ui, "glitch rate", &mut t.glitch_rate, 0.0..=30.0);
+                t_changed |= toggle(ui, "minimap", &mut t.minimap);
+                t_changed |= toggle(ui, "hex tooltip", &mut t.hex_tooltip);
+                ui.horizontal_wrapped(|ui| {
+                    ui.label("hex labels");
+                    for mode in HexLabelMode::ALL {
+                        t_changed |= ui
+                            .selectable_value(&mut t.hex_labels, mode, mode.label())
+                            .changed();
+                    }
+                });
+                t_changed |= slider(ui, "label distance", &mut t.hex_label_distance, 5.0..=150.0);
+                t_changed |= slider(ui, "label size", &mut t.hex_label_font_size, 6.0..=32.0);
+                t_changed |= slider(ui, "hex edit step", &mut t.hex_edit_step, 0.05..=2.0);
+                t_changed |= slider(ui, "note distance", &mut t.hex_note_distance, 5.0..=200.0);
+                t_changed |= toggle(ui, "lens flare", &mut t.lens_flare);
+                t_changed |= slider(ui, "lens flare count", &mut t.lens_flare_count, 0..=8);
+            });
+
+            ui.collapsing(locale.t("Drone"), |ui| {
+                d_changed |= slider(ui, "move speed", &mut d.move_speed, 1.0..=60.0);
+                d_changed |= slider(ui, "key look speed", &mut d.key_look_speed, 0.1..=5.0);
+                d_changed |= slider(ui, "scroll step", &mut d.scroll_sensitivity, 0.5..=10.0);
+                d_changed |= slider(ui, "height lerp", &mut d.height_lerp, 0.01..=1.0);
+                d_changed |= slider(ui, "aim speed", &mut d.aim_speed, 1.0..=40.0);
+                ui.horizontal(|ui| {
+                    for (mode, label) in [
+                        (AltitudeMode::Reactive, "reactive"),
+                        (AltitudeMode::Contour, "contour"),
+                    ] {
+                        d_changed |= ui
+                            .selectable_value(&mut d.altitude_mode, mode, label)
+                            .changed();
+                    }
+                });
+                ui.separator();
+                d_changed |= slider(ui, "bloom", &mut d.bloom_intensity, 0.0..=1.0);
+                d_changed |= slider(
+                    ui,
+                    "bloom boost",
+                    &mut d.bloom_low_frequency_boost,
+                    0.0..=1.0,
+                );
+                d_changed |= slider(ui, "bloom threshold", &mut d.bloom_threshold, 0.0..=2.0);
+                d_changed |= slider(
+                    ui,
+                    "threshold softness",
+                    &mut d.bloom_threshold_softness,
+                    0.0..=1.0,
+                );
+                ui.horizontal(|ui| {
+                    for (mode, label) in [
+                        (BloomCompositeMode::Additive, "additive"),
+                        (BloomCompositeMode::EnergyConserving, "energy conserving"),
+                    ] {
+                        d_changed |= ui
+                            .selectable_value(&mut d.bloom_composite_mode, mode, label)
+                            .changed();
+                    }
+                });
+                d_changed |= toggle(ui, "auto exposure", &mut d.auto_exposure);
+            });
+
+            ui.collapsing(locale.t("Display"), |ui| {
+                egui::ComboBox::from_label(locale.t("language"))
+                    .selected_text(language.label())
+                    .show_ui(ui, |ui| {
+                        for option in Language::ALL {
+                            ui.selectable_value(&mut language, option, option.label());
+                        }
+                    });
+                // Rescaling mid-drag would move the slider under the pointer.
+                let scale = ui.add(
+                    egui::Slider::new(&mut ui_scale, UiConfig::SCALE_RANGE)
+                        .step_by(0.05)
+                        .text(locale.t("UI scale")),
+                );
+                ui_scale_done = scale.drag_stopped() || (scale.changed() && !scale.dragged());
+                ui.horizontal(|ui| {
+                    egui::ComboBox::from_label("theme")
+                        .selected_text(kind.label())
+                        .show_ui(ui, |ui| {
+                            for option in ThemeKind::ALL {
+                                ui.horizontal(|ui| {
+                                    theme_swatches(ui, option, theme.palette);
+                                    ui.selectable_value(&mut kind, option, option.label());
+                                });
+                            }
+                        });
+                    theme_swatches(ui, kind, theme.palette);
+                });
+                slider(ui, "glow level", &mut level, 0.0..=3.0);
+                toggle(ui, "HUD", &mut hud_enabled);
+                toggle(ui, "altitude control", &mut altitude_control);
+                egui::ComboBox::from_label("present mode")
+                    .selected_text(format!("{present_mode:?}"))
+                    .show_ui(ui, |ui| {
+                        for mode in PRESENT_MODES {
+                            ui.selectable_value(&mut present_mode, mode, format!("{mode:?}"));
+                        }
+                    });
+                ui.horizontal(|ui| {
+                    ui.label("fps cap");
+                    for cap in FpsCap::ALL {
+                        ui.selectable_value(&mut fps_cap, cap, cap.label());
+                    }
+                });
+                p_changed |= slider(ui, "render scale", &mut p.render_scale, 0.5..=2.0);
+                p_changed |= slider(ui, "vignette", &mut p.vignette_strength, 0.0..=1.0);
+                p_changed |= slider(
+                    ui,
+                    "chromatic aberration",
+                    &mut p.chromatic_aberration,
+                    0.0..=0.05,
+                );
+                p_changed |= toggle(ui, "CRT", &mut p.crt);
+                p_changed |= toggle(ui, "god rays", &mut p.god_rays);
+                p_changed |= toggle(ui, "motion blur", &mut p.motion_blur);
+            });
+
+            ui.collapsing(locale.t("Intro (next time it plays)"), |ui| {
+                i_changed |= slider(ui, "tilt-up secs", &mut i.tilt_up_duration, 0.0..=5.0);
+                i_changed |= slider(ui, "highlight delay", &mut i.highlight_delay, 0.0..=2.0);
+                i_changed |= slider(ui, "tilt-down secs", &mut i.tilt_down_duration, 0.0..=2.0);
+                i_changed |= slider(ui, "tilt-down angle", &mut i.tilt_down_angle, 0.0..=45.0);
+            });
+
+            ui.collapsing(locale.t("Entity budgets"), |ui| {
+                b_changed |= slider(ui, "gaps", &mut b.gaps, 1_000..=100_000);
+                b_changed |= slider(ui, "edges", &mut b.edges, 1_000..=300_000);
+                b_changed |= slider(ui, "fov gaps", &mut b.fov_gaps, 10..=5_000);
+                b_changed |= slider(ui, "particles", &mut b.particles, 100..=10_000);
+                b_changed |= toggle(ui, "auto-reduce fov reach", &mut b.auto_reduce_reach);
+                b_changed |= slider(ui, "min fov reach", &mut b.min_reach, 0..=6);
+            });
+
+            ui.separator();
+            ui.horizontal(|ui| {
+                export = ui.button(locale.t("Export settings")).clicked();
+                import = ui.button(locale.t("Import settings")).clicked();
+            });
+            ui.small(&cfg.file);
+        });
+
+    if apply_grid {
+        t.grid = grid.clone();
+        t_changed = true;
+        regenerate.write(RegenerateTerrain);
+    }
+    if t_changed {
+        *terrain = t;
+    }
+    if d_changed {
+        *drone = d;
+    }
+    if p_changed {
+        *post_fx = p;
+    }
+    if i_changed {
+        *intro = i;
+    }
+    if b_changed {
+        *budget = b;
+    }
+    if level != glow.0 {
+        glow.0 = level;
+    }
+    if hud_enabled != hud.enabled {
+        hud.enabled = hud_enabled;
+    }
+    if altitude_control != hud.altitude_control {
+        hud.altitude_control = altitude_control;
+    }
+    if fps_cap != frame_limit.fps_cap {
+        frame_limit.fps_cap = fps_cap;
+    }
+    if present_mode != window.present_mode {
+        window.present_mode = present_mode;
+    }
+    if kind != theme.kind {
+        *theme = theme.switched_to(kind);
+    }
+    if language != locale_cfg.language {
+        locale_cfg.language = language;
+    }
+    if ui_scale_done && ui_scale != ui_cfg.scale {
+        ui_cfg.scale = ui_scale;
+    }
+
+    if export || import {
+        let mut current = Settings {
+            terrain: terrain.clone(),
+            drone: drone.clone(),
+            intro: intro.clone(),
+            theme: theme.clone(),
+            locale: locale_cfg.clone(),
+            ui: ui_cfg.clone(),
+        };
+        if export {
+            let result = export_settings(&registry.read(), &current)
+                .map_err(|e| e.to_string())
+                .and_then(|text| write_settings_file(&cfg.file, &text));
+            toasts.write(Toast(match result {
+                Ok(()) => locale.fmt("Settings exported to {0}", &[&cfg.file]),
+                Err(e) => locale.fmt("Export failed: {0}", &[&e]),
+            }));
+        }
+        if import {
+            let result = read_settings_file(&cfg.file)
+                .and_then(|text| import_settings(&registry.read(), &text, &mut current));
+            match result {
+                Ok(()) => {
+                    draft.grid = Some(current.terrain.grid.clone());
+                    *terrain = current.terrain;
+                    *drone = current.drone;
+                    *intro = current.intro;
+                    *theme = current.theme;
+                    *locale_cfg = current.locale;
+                    *ui_cfg = current.ui;
+                    regenerate.write(RegenerateTerrain);
+                    toasts.write(Toast(
+                        locale.fmt("Settings imported from {0}", &[&cfg.file]),
+                    ));
+                }
+                Err(e) => {
+                    toasts.write(Toast(locale.fmt("Import failed: {0}", &[&e])));
+                }
+            }
+        }
+    }
+}
+
+/// Shows the current seeds; in `Inspecting`, edits them and regenerates on demand.
+#[allow(clippy::too_many_arguments)]
+fn draw_seed_widget(
+    mut egui_ctx: Single<&mut bevy_egui::EguiContext>,
+    state: Res<State<GameState>>,
+    time: Res<Time<Real>>,
+    mut terrain: ResMut<HTerrainConfig>,
+    mut draft: ResMut<SettingsDraft>,
+    mut regenerate: MessageWriter<RegenerateTerrain>,
+    locale: Res<Locale>,
+    mut presses: Local<u64>,
+) {
+    let editable = *state.get() == GameState::Inspecting;
+    let current = (
+        terrain.grid.height_noise_seed,
+        terrain.grid.radius_noise_seed,
+    );
+    let grid = draft.grid.get_or_insert_with(|| terrain.grid.clone());
+    let mut apply = None;
+
+    egui::Area::new(egui::Id::new("seed_widget"))
+        .anchor(egui::Align2::LEFT_TOP, [8.0, 32.0])
+        .interactable(editable)
+        .show(egui_ctx.get_mut(), |ui| {
+            egui::Frame::new()
+                .fill(egui::Color32::from_black_alpha(140))
+                .inner_margin(egui::Margin::symmetric(8, 4))
+                .corner_radius(4.0)
+                .show(ui, |ui| {
+                    ui.horizontal(|ui| {
+                        if !editable {
+                            ui.monospace(
+                                locale.fmt(
+                                    "seeds  height {0}  radius {1}",
+                                    &[&current.0, &current.1],
+                                ),
+                            );
+                            return;
+                        }
+                        ui.monospace("height");
+                        ui.add(egui::DragValue::new(&mut grid.height_noise_seed));
+                        ui.monospace("radius");
+                        ui.add(egui::DragValue::new(&mut grid.radius_noise_seed));
+                        if ui.button(locale.t("Regenerate")).clicked() {
+                            apply = Some((grid.height_noise_seed, grid.radius_noise_seed));
+                        }
+                        if ui.button(locale.t("Randomize")).clicked() {
+                            *presses += 1;
+                            let entropy = time.elapsed().as_nanos() as u64 ^ *presses;
+                            apply = Some(random_seeds(entropy));
+                        }
+                    });
+                });
+        });
+
+    if let Some((height, radius)) = apply {
+        grid.height_noise_seed = height;
+        grid.radius_noise_seed = radius;
+        terrain.grid.height_noise_seed = height;
+        terrain.grid.radius_noise_seed = radius;
+        regenerate.write(RegenerateTerrain);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn random_seeds_differ_between_presses() {
+        let seeds: Vec<(u32, u32)> = (0..64).map(random_seeds).collect();
+        for (i, a) in seeds.iter().enumerate() {
+            assert_ne!(a.0, a.1);
+            for b in &seeds[i + 1..] {
+                assert_ne!(a, b);
+            }
+        }
+        assert_eq!(random_seeds(7), random_seeds(7));
+    }
+
+    #[test]
+    fn an_untouched_draft_follows_outside_grid_changes() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<HTerrainConfig>()
+            .init_resource::<SettingsDraft>()
+            .add_systems(
+                Update,
+                refresh_settings_draft.run_if(resource_changed::<HTerrainConfig>),
+            );
+        app.update();
+        let grid = app.world().resource::<HTerrainConfig>().grid.clone();
+        app.world_mut().resource_mut::<SettingsDraft>().grid = Some(grid);
+
+        app.world_mut()
+            .resource_mut::<HTerrainConfig>()
+            .grid
+            .height_noise_seed = 11;
+        app.update();
+        let draft = |app: &App| {
+            app.world()
+                .resource::<SettingsDraft>()
+                .grid
+                .clone()
+                .unwrap()
+        };
+        assert_eq!(draft(&app).height_noise_seed, 11);
+
+        // Pending edits survive an outside change.
+        app.world_mut()
+            .resource_mut::<SettingsDraft>()
+            .grid
+            .as_mut()
+            .unwrap()
+            .radius = 3;
+        app.world_mut()
+            .resource_mut::<HTerrainConfig>()
+            .grid
+            .height_noise_seed = 12;
+        app.update();
+        assert_eq!(draft(&app).radius, 3);
+        assert_eq!(draft(&app).height_noise_seed, 11);
+    }
+
+    #[test]
+    fn exported_settings_import_back() {
+        let registry = settings_registry();
+        let mut settings = Settings::default();
+        settings.terrain.grid.radius = 7;
+        settings.terrain.hex_labels = HexLabelMode::Cube;
+        settings.terrain.face_height_gradient = Some(crate::h_terrain::HeightGradient::glacier());
+        settings.drone = DroneConfig {
+            altitude_mode: AltitudeMode::Contour,
+            fog_color: Some(Color::srgb(0.1, 0.2, 0.3)),
+            ..default()
+        };
+        settings.intro.tilt_up_duration = 4.0;
+        settings.theme = Theme::from(ThemeKind::AmberCrt).flattened();
+        settings.locale = LocaleConfig {
+            language: Language::German,
+        };
+        settings.ui = UiConfig { scale: 1.5 };
+        let text = export_settings(&registry, &settings).unwrap();
+
+        let mut s = Settings::default();
+        import_settings(&registry, &text, &mut s).unwrap();
+        assert_eq!(s.terrain.grid.radius, 7);
+        assert_eq!(s.terrain.hex_labels, HexLabelMode::Cube);
+        assert!(s.terrain.face_height_gradient.is_some());
+        assert_eq!(s.drone.altitude_mode, AltitudeMode::Contour);
+        assert_eq!(s.drone.fog_color, settings.drone.fog_color);
+        assert_eq!(s.intro.tilt_up_duration, 4.0);
+        assert_eq!(s.theme.kind, ThemeKind::AmberCrt);
+        assert!(s.theme.flat);
+        assert_eq!(s.theme.clear_color, settings.theme.clear_color);
+        assert_eq!(s.locale.language, Language::German);
+        assert_eq!(s.ui.scale, 1.5);
+    }
+
+    #[test]
+    fn partial_settings_keep_other_values() {
+        let registry = settings_registry();
+        let mut s = Settings::default();
+        import_settings(&registry, "(drone: (move_speed: 3.5))", &mut s).unwrap();
+        assert_eq!(s.drone.move_speed, 3.5);
+        assert_eq!(
+            s.drone.mouse_sensitivity_x,
+            DroneConfig::default().mouse_sensitivity_x
+        );
+        assert_eq!(s.terrain.grid.radius, HTerrainConfig::default().grid.radius);
+        assert_eq!(
+            s.intro.tilt_up_duration,
+            IntroConfig::default().tilt_up_duration
+        );
+
+        assert_eq!(s.locale.language, Language::English);
+
+        assert!(import_settings(&registry, "(sound: ())", &mut s).is_err());
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn a_missing_config_file_is_toasted() {
+        let mut startup = Settings::default();
+        let e = load_settings_file("/nonexistent/hex-terrain.ron", &mut startup).unwrap_err();
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_message::<Toast>()
+            .init_resource::<Locale>()
+            .insert_resource(SettingsConfig {
+                load_error: Some(e),
+                ..default()
+            })
+            .add_systems(Startup, report_load_error);
+        app.update();
+        let toasts = app.world().resource::<Messages<Toast>>();
+        assert_eq!(toasts.len(), 1);
+        assert!(
+            toasts
+                .iter_current_update_messages()
+                .all(|t| t.0.contains("/nonexistent/hex-terrain.ron"))
+        );
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn hot_reload_applies_live_fields_and_queues_grid_changes() {
+        let path =
+            std::env::temp_dir().join(format!("hex-terrain-reload-{}.ron", std::process::id()));
+        let file = path.to_string_lossy().into_owned();
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .register_type::<HTerrainConfig>()
+            .register_type::<DroneConfig>()
+            .register_type::<IntroConfig>()
+            .register_type::<Theme>()
+            .register_type::<LocaleConfig>()
+            .register_type::<UiConfig>()
+            .add_message::<RegenerateTerrain>()
+            .add_message::<Toast>()
+            .init_resource::<HTerrainConfig>()
+            .init_resource::<DroneConfig>()
+            .init_resource::<IntroConfig>()
+            .init_resource::<Theme>()
+            .init_resource::<LocaleConfig>()
+            .init_resource::<UiConfig>()
+            .init_resource::<Locale>()
+            .init_resource::<SettingsDraft>()
+            .insert_resource(SettingsConfig {
+                file,
+                hot_reload: true,
+                poll_secs: 0.0,
+                ..default()
+            })
+            .init_resource::<SettingsWatch>()
+            .add_systems(Update, hot_reload_settings);
+
+        std::fs::write(&path, "(drone: (move_speed: 3.5))").unwrap();
+        app.update();
+        assert_eq!(app.world().resource::<DroneConfig>().move_speed, 3.5);
+        assert!(
+            app.world()
+                .resource::<Messages<RegenerateTerrain>>()
+                .is_empty()
+        );
+
+        std::fs::write(
+            &path,
+            "(drone: (move_speed: 3.5), terrain: (grid: (radius: 5)))",
+        )
+        .unwrap();
+        // Force a new modification time even on coarse-grained filesystems.
+        app.world_mut().resource_mut::<SettingsWatch>().modified = None;
+        app.update();
+        assert_eq!(app.world().resource::<HTerrainConfig>().grid.radius, 5);
+        assert!(
+            !app.world()
+                .resource::<Messages<RegenerateTerrain>>()
+                .is_empty()
+        );
+        assert_eq!(
+            app.world()
+                .resource::<SettingsDraft>()
+                .grid
+                .as_ref()
+                .map(|g| g.radius),
+            Some(5)
+        );
+        std::fs::remove_file(&path).unwrap();
+    }
+}
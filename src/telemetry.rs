@@ -0,0 +1,318 @@
+//! Flight telemetry: altitude, ground height, and speed over the last few seconds (F5).
+//!
+//! [`record_telemetry`] samples the camera height, the [`GroundLevel`] under the drone, and
+//! the horizontal speed every [`TelemetryConfig::sample_secs`] of virtual time into fixed-size
+//! [`Series`] ring buffers. The panel plots altitude over ground on one chart (the gap between
+//! the two lines is the terrain-follow lag `height_lerp` smooths) and speed on a second.
+
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+use bevy_egui::egui;
+
+use crate::controls::{Binding, BindingCategory, RegisterBinding, Trigger};
+use crate::drone::Player;
+use crate::h_terrain::HTerrainPhase;
+use crate::locale::Locale;
+use crate::{GameState, GroundLevel, PlayerPos};
+
+/// Chart size in points.
+const CHART_SIZE: egui::Vec2 = egui::vec2(260.0, 70.0);
+
+const ALTITUDE_COLOR: egui::Color32 = egui::Color32::from_rgb(0, 255, 200);
+const GROUND_COLOR: egui::Color32 = egui::Color32::from_rgb(255, 140, 0);
+const SPEED_COLOR: egui::Color32 = egui::Color32::from_rgb(255, 0, 200);
+
+/// Per-plugin configuration for flight telemetry.
+#[derive(Resource, Clone, Debug, Reflect)]
+pub struct TelemetryConfig {
+    /// Samples kept per series (the chart's time window is `capacity × sample_secs`).
+    pub capacity: usize,
+    /// Seconds of virtual time between samples.
+    pub sample_secs: f32,
+}
+
+impl Default for TelemetryConfig {
+    fn default() -> Self {
+        Self {
+            capacity: 240,
+            sample_secs: 0.05,
+        }
+    }
+}
+
+/// Fixed-capacity ring buffer of samples, oldest first.
+#[derive(Clone, Debug, Default, Reflect)]
+pub struct Series(VecDeque<f32>);
+
+impl Series {
+    /// Appends `value`, dropping the oldest samples beyond `capacity`.
+    pub fn push(&mut self, value: f32, capacity: usize) {
+        self.0.push_back(value);
+        while self.0.len() > capacity {
+            self.0.pop_front();
+        }
+    }
+
+    /// Samples, oldest first.
+    pub fn values(&self) -> impl ExactSizeIterator<Item = f32> + '_ {
+        self.0.iter().copied()
+    }
+
+    /// Most recent sample.
+    pub fn last(&self) -> Option<f32> {
+        self.0.back().copied()
+    }
+
+    /// `(min, max)` over the samples, or `None` when empty.
+    pub fn range(&self) -> Option<(f32, f32)> {
+        self.values().fold(None, |acc, v| match acc {
+            None => Some((v, v)),
+            Some((lo, hi)) => Some((lo.min(v), hi.max(v))),
+        })
+    }
+}
+
+/// Recorded flight telemetry.
+#[derive(Resource, Default, Reflect)]
+pub struct Telemetry {
+    /// Camera height (world Y).
+    pub altitude: Series,
+    /// Terrain height under the drone.
+    pub ground: Series,
+    /// Horizontal speed in world units per second.
+    pub speed: Series,
+    /// Virtual time since the last sample.
+    since_sample: f32,
+    /// Player xz at the last sample.
+    last_xz: Option<Vec2>,
+}
+
+/// Whether the telemetry panel is shown.
+#[derive(Resource, Default, Reflect)]
+pub struct TelemetryVisible(pub bool);
+
+/// Flight telemetry plugin.
+pub struct TelemetryPlugin {
+    /// Per-plugin configuration.
+    pub config: TelemetryConfig,
+}
+
+impl Plugin for TelemetryPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<TelemetryConfig>()
+            .register_type::<Telemetry>()
+            .register_type::<TelemetryVisible>()
+            .insert_resource(self.config.clone())
+            .init_resource::<Telemetry>()
+            .init_resource::<TelemetryVisible>()
+            .register_binding(Binding::new(
+                BindingCategory::App,
+                "Telemetry graphs",
+                [Trigger::Key(KeyCode::F5)],
+            ))
+            .add_systems(
+                Update,
+                record_telemetry
+                    .after(HTerrainPhase::UpdateGround)
+                    .run_if(in_state(GameState::Running)),
+            )
+            .add_systems(
+                Update,
+                (
+                    toggle_telemetry,
+                    draw_telemetry_panel.run_if(|v: Res<TelemetryVisible>| v.0),
+                )
+                    .chain()
+                    .run_if(in_state(GameState::Running).or(in_state(GameState::Inspecting))),
+            );
+    }
+}
+
+/// Samples altitude, ground height, and speed every [`TelemetryConfig::sample_secs`].
+pub fn record_telemetry(
+    time: Res<Time>,
+    cfg: Res<TelemetryConfig>,
+    camera: Single<&GlobalTransform, With<Player>>,
+    ground: Res<GroundLevel>,
+    player: Res<PlayerPos>,
+    mut telemetry: ResMut<Telemetry>,
+) {
+    telemetry.since_sample += time.delta_secs();
+    if telemetry.since_sample < cfg.sample_secs {
+        return;
+    }
+    let dt = telemetry.since_sample;
+    telemetry.since_sample = 0.0;
+    let Some(ground) = ground.0 else {
+        return;
+    };
+    let speed = telemetry
+        .last_xz
+        .map_or(0.0, |last| player.xz.distance(last) / dt);
+    telemetry.last_xz = Some(player.xz);
+    let capacity = cfg.capacity;
+    telemetry.altitude.push(camera.translation().y, capacity);
+    telemetry.ground.push(ground, capacity);
+    telemetry.speed.push(speed, capacity);
+}
+
+/// Toggles [`TelemetryVisible`] on F5.
+fn toggle_telemetry(keys: Res<ButtonInput<KeyCode>>, mut visible: ResMut<TelemetryVisible>) {
+    if keys.just_pressed(KeyCode::F5) {
+        visible.0 = !visible.0;
+    }
+}
+
+/// Y coordinate of `v` in a chart spanning `top..bottom` for values `min..max`.
+pub fn chart_y(v: f32, (min, max): (f32, f32), top: f32, bottom: f32) -> f32 {
+    let t = ((v - min) / (max - min).max(1e-3)).clamp(0.0, 1.0);
+    bottom + (top - bottom) * t
+}
+
+/// Draws `series` as lines over one shared value range, oldest sample on the left.
+fn chart(ui: &mut egui::Ui, series: &[(&Series, egui::Color32)], capacity: usize) {
+    let (rect, _) = ui.allocate_exact_size(CHART_SIZE, egui::Sense::hover());
+    let painter = ui.painter_at(rect);
+    painter.rect_filled(rect, 2.0, egui::Color32::from_black_alpha(120));
+    let Some(range) = series
+        .iter()
+        .filter_map(|(s, _)| s.range())
+        .reduce(|a, b| (a.0.min(b.0), a.1.max(b.1)))
+    else {
+        return;
+    };
+    let dx = rect.width() / capacity.saturating_sub(1).max(1) as f32;
+    for &(s, color) in series {
+        // Right-aligned, so the newest sample sits on the right edge while the buffer fills.
+        let start = rect.right() - s.values().len().saturating_sub(1) as f32 * dx;
+        let points = s
+            .values()
+            .enumerate()
+            .map(|(i, v)| {
+                egui::pos2(
+                    start + i as f32 * dx,
+                    chart_y(v, range, rect.top(), rect.bottom()),
+                )
+            })
+            .collect();
+        painter.add(egui::Shape::line(points, egui::Stroke::new(1.0, color)));
+    }
+    painter.text(
+        rect.left_top() + egui::vec2(2.0, 1.0),
+        egui::Align2::LEFT_TOP,
+        format!("{:.1}", range.1),
+        egui::FontId::monospace(10.0),
+        egui::Color32::from_gray(150),
+    );
+    painter.text(
+        rect.left_bottom() + egui::vec2(2.0, -1.0),
+        egui::Align2::LEFT_BOTTOM,
+        format!("{:.1}", range.0),
+        egui::FontId::monospace(10.0),
+        egui::Color32::from_gray(150),
+    );
+}
+
+/// Legend entry: a colored label with the latest value.
+fn legend(ui: &mut egui::Ui, label: &str, series: &Series, color: egui::Color32) {
+    let value = series
+        .last()
+        .map_or_else(|| "--".to_string(), |v| format!("{v:.2}"));
+    ui.label(
+        egui::RichText::new(format!("{label} {value}"))
+            .color(color)
+            .monospace(),
+    );
+}
+
+/// Draws the telemetry window (bottom-left, above the heatmap legend) with the charts.
+fn draw_telemetry_panel(
+    mut egui_ctx: Single<&mut bevy_egui::EguiContext>,
+    cfg: Res<TelemetryConfig>,
+    telemetry: Res<Telemetry>,
+    locale: Res<Locale>,
+) {
+    egui::Window::new(locale.t("Telemetry"))
+        .id(egui::Id::new("telemetry_panel"))
+        .anchor(egui::Align2::LEFT_BOTTOM, [8.0, -140.0])
+        .resizable(false)
+        .show(egui_ctx.get_mut(), |ui| {
+            ui.horizontal(|ui| {
+                legend(
+                    ui,
+                    locale.t("altitude"),
+                    &telemetry.altitude,
+                    ALTITUDE_COLOR,
+                );
+                legend(ui, locale.t("ground"), &telemetry.ground, GROUND_COLOR);
+            });
+            chart(
+                ui,
+                &[
+                    (&telemetry.ground, GROUND_COLOR),
+                    (&telemetry.altitude, ALTITUDE_COLOR),
+                ],
+                cfg.capacity,
+            );
+            legend(ui, locale.t("speed"), &telemetry.speed, SPEED_COLOR);
+            chart(ui, &[(&telemetry.speed, SPEED_COLOR)], cfg.capacity);
+            ui.small(format!("{:.0} s", cfg.capacity as f32 * cfg.sample_secs));
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::time::TimeUpdateStrategy;
+
+    use super::*;
+
+    #[test]
+    fn series_keeps_the_newest_samples() {
+        let mut series = Series::default();
+        for v in [3.0, 1.0, 4.0, 1.5, 9.0] {
+            series.push(v, 3);
+        }
+        assert_eq!(series.values().collect::<Vec<_>>(), [4.0, 1.5, 9.0]);
+        assert_eq!(series.last(), Some(9.0));
+        assert_eq!(series.range(), Some((1.5, 9.0)));
+        assert_eq!(Series::default().range(), None);
+        assert_eq!(chart_y(1.5, (1.5, 9.0), 0.0, 70.0), 70.0);
+        assert_eq!(chart_y(9.0, (1.5, 9.0), 0.0, 70.0), 0.0);
+    }
+
+    #[test]
+    fn recorder_samples_at_the_configured_interval() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                25,
+            )))
+            .insert_resource(TelemetryConfig {
+                capacity: 8,
+                sample_secs: 0.04,
+            })
+            .insert_resource(GroundLevel(Some(2.0)))
+            .init_resource::<PlayerPos>()
+            .init_resource::<Telemetry>()
+            .add_systems(Update, record_telemetry);
+        app.world_mut().spawn((
+            Player,
+            GlobalTransform::from(Transform::from_xyz(0.0, 5.0, 0.0)),
+        ));
+
+        for _ in 0..9 {
+            app.world_mut().resource_mut::<PlayerPos>().xz.x += 0.1;
+            app.update();
+        }
+        let telemetry = app.world().resource::<Telemetry>();
+        assert_eq!(telemetry.altitude.values().len(), 4);
+        assert_eq!(telemetry.ground.last(), Some(2.0));
+        assert_eq!(telemetry.altitude.last(), Some(5.0));
+        // Every other frame: 0.2 units per 0.05 s.
+        let speed = telemetry.speed.last().unwrap();
+        assert!((speed - 4.0).abs() < 1e-3, "{speed}");
+    }
+}
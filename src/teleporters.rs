@@ -0,0 +1,493 @@
+//! Paired teleporter hexes: fly low over one pad to warp above its partner.
+//!
+//! Whenever the grid is (re)generated, [`pair_teleporters`] picks up to
+//! [`TeleportersConfig::pairs`] pairs of new cells with [`teleporter_pairs`] and tags both pads
+//! of a pair with a [`Teleporter`] pointing at the other. Both pads share one overlay material
+//! per pair (colors from [`TeleportersConfig::colors`]), so linked pads pulse together
+//! ([`pulse_teleporters`]).
+//!
+//! While flying, [`enter_teleporters`] starts a [`Warp`] when the drone sits within
+//! [`TeleportersConfig::trigger_offset`] of a pad's ground. [`advance_warp`] fades the screen
+//! out, then at full black writes [`TeleportToHex`] for the partner (which moves the drone and
+//! the [`ActiveHex`](crate::h_terrain::ActiveHex) with it), lifts the drone to
+//! [`TeleportersConfig::arrival_offset`], reveals the cells around the destination, and fades
+//! back in ([`draw_warp_fade`]). The destination pad won't fire again until the drone has left
+//! it.
+
+use std::collections::HashMap;
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+use bevy_egui::egui;
+use hexx::Hex;
+
+use crate::h_terrain::{
+    ActiveHex, HCell, HTerrainConfig, HTerrainPhase, HexFace, HexRevealed, Revealed, TeleportToHex,
+    overlay_lift,
+};
+use crate::locale::Locale;
+use crate::math::hash_roll;
+use crate::toast::Toast;
+use crate::{GameState, PlayerMoved, PlayerPos};
+
+/// Per-plugin configuration for teleporter pads.
+#[derive(Resource, Clone, Debug, Reflect)]
+pub struct TeleportersConfig {
+    /// Pad pairs per grid (0 disables).
+    pub pairs: usize,
+    /// Mixed into the grid's height seed to pick the pads.
+    pub seed: u64,
+    /// Minimum hex distance between the two pads of a pair.
+    pub min_distance: u32,
+    /// Maximum offset above ground that triggers a pad.
+    pub trigger_offset: f32,
+    /// Offset the drone arrives at above the partner pad.
+    pub arrival_offset: f32,
+    /// Seconds for each half of the fade (out, then back in).
+    pub fade_secs: f32,
+    /// Rings around the destination pad revealed on arrival.
+    pub reveal_radius: u32,
+    /// Overlay color per pair, wrapping around.
+    pub colors: Vec<Color>,
+    /// Peak emissive multiplier on the pair color (drives bloom).
+    pub glow: f32,
+    /// Pad pulses per second.
+    pub pulse_hz: f32,
+}
+
+impl Default for TeleportersConfig {
+    fn default() -> Self {
+        Self {
+            pairs: 3,
+            seed: 0x7e1e_90a7,
+            min_distance: 10,
+            trigger_offset: 4.0,
+            arrival_offset: 12.0,
+            fade_secs: 0.35,
+            reveal_radius: 2,
+            colors: vec![
+                Color::srgb(0.2, 0.6, 1.0),
+                Color::srgb(1.0, 0.85, 0.1),
+                Color::srgb(0.2, 1.0, 0.5),
+            ],
+            glow: 4.0,
+            pulse_hz: 0.8,
+        }
+    }
+}
+
+/// A teleporter pad on an [`HCell`], linked to its partner.
+#[derive(Component, Clone, Debug, Reflect)]
+pub struct Teleporter {
+    /// Pair index (picks the color).
+    pub pair: usize,
+    /// The partner pad's [`HCell`].
+    pub partner: Entity,
+}
+
+/// Marker on the overlay child of a pad's [`HexFace`] (see [`overlay_lift`]).
+#[derive(Component, Reflect)]
+pub struct TeleporterOverlay;
+
+/// Teleport in progress, and the pad the drone last arrived on.
+#[derive(Resource, Clone, Debug, Default, Reflect)]
+pub struct Warp {
+    /// Seconds since the jump started, and the pad it leads to.
+    pub jump: Option<(f32, Entity)>,
+    /// Pad the drone arrived on; it must be left before it fires.
+    pub landed: Option<Entity>,
+}
+
+/// Shared overlay material per pair color, in [`TeleportersConfig::colors`] order.
+#[derive(Resource)]
+struct TeleporterMaterials(Vec<Handle<StandardMaterial>>);
+
+/// Picks up to `pairs` pad pairs from `hexes`, the two pads of a pair at least
+/// `min_distance` apart and no hex used twice. Deterministic for a given `seed`; stops early
+/// when no partner fits.
+pub fn teleporter_pairs(
+    hexes: &[Hex],
+    seed: u64,
+    pairs: usize,
+    min_distance: u32,
+) -> Vec<(Hex, Hex)> {
+    let mut order = hexes.to_vec();
+    order.sort_unstable_by(|a, b| {
+        hash_roll(*a, seed)
+            .total_cmp(&hash_roll(*b, seed))
+            .then((a.x, a.y).cmp(&(b.x, b.y)))
+    });
+    let mut picked = Vec::with_capacity(pairs);
+    while picked.len() < pairs && !order.is_empty() {
+        let from = order.remove(0);
+        let Some(index) = order
+            .iter()
+            .position(|h| h.unsigned_distance_to(from) >= min_distance)
+        else {
+            break;
+        };
+        picked.push((from, order.remove(index)));
+    }
+    picked
+}
+
+/// Screen blackout in `0.0..=1.0` `t` seconds into a warp: up over the first `fade`
+/// seconds, back down over the next.
+pub fn warp_fade(t: f32, fade: f32) -> f32 {
+    let fade = fade.max(f32::EPSILON);
+    (1.0 - (t / fade - 1.0).abs()).clamp(0.0, 1.0)
+}
+
+/// Teleporter pads plugin.
+pub struct TeleportersPlugin {
+    /// Per-plugin configuration.
+    pub config: TeleportersConfig,
+}
+
+impl Plugin for TeleportersPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<TeleportersConfig>()
+            .register_type::<Teleporter>()
+            .register_type::<TeleporterOverlay>()
+            .register_type::<Warp>()
+            .insert_resource(self.config.clone())
+            .init_resource::<Warp>()
+            .add_systems(Startup, create_teleporter_materials)
+            .add_systems(
+                Update,
+                pair_teleporters.run_if(any_match_filter::<Added<HCell>>),
+            )
+            .add_systems(Update, pulse_teleporters)
+            .add_systems(
+                Update,
+                (enter_teleporters, advance_warp)
+                    .chain()
+                    .after(crate::drone::systems::fly)
+                    .before(HTerrainPhase::UpdateGround)
+                    .run_if(in_state(GameState::Running)),
+            )
+            .add_systems(
+                Update,
+                draw_warp_fade.run_if(|warp: Res<Warp>| warp.jump.is_some()),
+            );
+    }
+}
+
+/// Creates one translucent overlay material per pair color.
+fn create_teleporter_materials(
+    cfg: Res<TeleportersConfig>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut commands: Commands,
+) {
+    let colors = if cfg.colors.is_empty() {
+        vec![Color::WHITE]
+    } else {
+        cfg.colors.clone()
+    };
+    let handles = colors
+        .into_iter()
+        .map(|color| {
+            materials.add(StandardMaterial {
+                base_color: color.with_alpha(0.5),
+                emissive: LinearRgba::from(color) * cfg.glow,
+                alpha_mode: AlphaMode::Blend,
+                unlit: true,
+                ..default()
+            })
+        })
+        .collect();
+    commands.insert_resource(TeleporterMaterials(handles));
+}
+
+/// Links pad pairs among freshly generated cells and overlays their faces.
+fn pair_teleporters(
+    cfg: Res<TeleportersConfig>,
+    terrain: Res<HTerrainConfig>,
+    materials: Res<TeleporterMaterials>,
+    cells: Query<(Entity, &HCell, &Children), Added<HCell>>,
+    faces: Query<&Mesh3d, With<HexFace>>,
+    mut commands: Commands,
+) {
+    let by_hex: HashMap<Hex, (Entity, &Children)> = cells
+        .iter()
+        .map(|(entity, cell, children)| (cell.hex, (entity, children)))
+        .collect();
+    let hexes: Vec<Hex> = by_hex.keys().copied().collect();
+    let seed = cfg.seed ^ u64::from(terrain.grid.height_noise_seed);
+    for (pair, (a, b)) in teleporter_pairs(&hexes, seed, cfg.pairs, cfg.min_distance)
+        .into_iter()
+        .enumerate()
+    {
+        let material = &materials.0[pair % materials.0.len()];
+        for (pad, partner) in [(a, b), (b, a)] {
+            let (entity, children) = by_hex[&pad];
+            commands.entity(entity).insert(Teleporter {
+                pair,
+                partner: by_hex[&partner].0,
+            });
+            for face in children.iter() {
+                let Ok(mesh) = faces.get(face) else {
+                    continue;
+                };
+                commands.entity(face).with_child((
+                    TeleporterOverlay,
+                    Mesh3d(mesh.0.clone()),
+                    MeshMaterial3d(material.clone()),
+                    Transform::from_xyz(0.0, overlay_lift::TELEPORTER, 0.0),
+                ));
+            }
+        }
+    }
+}
+
+/// Pulses each pair's shared glow, pairs out of phase with each other.
+fn pulse_teleporters(
+    time: Res<Time>,
+    cfg: Res<TeleportersConfig>,
+    handles: Res<TeleporterMaterials>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let count = handles.0.len() as f32;
+    for (i, handle) in handles.0.iter().enumerate() {
+        let Some(mat) = materials.get_mut(handle) else {
+            continue;
+        };
+        let phase = time.elapsed_secs() * cfg.pulse_hz * TAU + i as f32 / count * TAU;
+        let color = cfg.colors.get(i).copied().unwrap_or(Color::WHITE);
+        mat.emissive = LinearRgba::from(color) * cfg.glow * (0.6 + 0.4 * phase.sin());
+    }
+}
+
+/// Starts a warp when the drone sits low over a pad it hasn't just arrived on.
+fn enter_teleporters(
+    cfg: Res<TeleportersConfig>,
+    player: Res<PlayerPos>,
+    active: Res<ActiveHex>,
+    pads: Query<&Teleporter>,
+    mut warp: ResMut<Warp>,
+) {
+    if warp.jump.is_some() {
+        return;
+    }
+    if warp.landed.is_some() && warp.landed != active.cell {
+        warp.landed = None;
+    }
+    if player.offset > cfg.trigger_offset || warp.landed.is_some() {
+        return;
+    }
+    if let Some(pad) = active.cell.and_then(|cell| pads.get(cell).ok()) {
+        warp.jump = Some((0.0, pad.partner));
+    }
+}
+
+/// Runs the warp clock; at full black moves the drone to the partner pad and reveals it.
+#[allow(clippy::too_many_arguments)]
+fn advance_warp(
+    time: Res<Time>,
+    cfg: Res<TeleportersConfig>,
+    terrain: Res<HTerrainConfig>,
+    mut warp: ResMut<Warp>,
+    pads: Query<&HCell, With<Teleporter>>,
+    hidden: Query<(Entity, &HCell), Without<Revealed>>,
+    mut player: ResMut<PlayerPos>,
+    mut moved: ResMut<PlayerMoved>,
+    mut teleport: MessageWriter<TeleportToHex>,
+    mut reveals: MessageWriter<HexRevealed>,
+    mut toasts: MessageWriter<Toast>,
+    locale: Res<Locale>,
+    mut commands: Commands,
+) {
+    let Some((t, target)) = warp.jump else {
+        return;
+    };
+    let next = t + time.delta_secs();
+    if t < cfg.fade_secs && next >= cfg.fade_secs {
+        if let Ok(cell) = pads.get(target) {
+            teleport.write(TeleportToHex(cell.hex));
+            player.offset = player.offset.max(cfg.arrival_offset);
+            moved.0 = true;
+            for (entity, other) in &hidden {
+                let rings = other.hex.unsigned_distance_to(cell.hex);
+                if rings <= cfg.reveal_radius {
+                    commands.entity(entity).insert(Revealed);
+                    reveals.write(HexRevealed {
+                        cell: entity,
+                        delay: rings as f32 * terrain.reveal_stagger_secs,
+                    });
+                }
+            }
+            toasts.write(Toast(locale.fmt(
+                "Teleported to {0}",
+                &[&format!("{:+},{:+}", cell.hex.x, cell.hex.y)],
+            )));
+        }
+        warp.landed = Some(target);
+    }
+    warp.jump = (next < cfg.fade_secs * 2.0).then_some((next, target));
+}
+
+/// Blacks out the screen through the warp.
+fn draw_warp_fade(
+    mut egui_ctx: Single<&mut bevy_egui::EguiContext>,
+    cfg: Res<TeleportersConfig>,
+    warp: Res<Warp>,
+) {
+    let Some((t, _)) = warp.jump else {
+        return;
+    };
+    let alpha = (warp_fade(t, cfg.fade_secs) * 255.0) as u8;
+    egui::Area::new(egui::Id::new("warp_fade"))
+        .fixed_pos(egui::pos2(0.0, 0.0))
+        .order(egui::Order::Foreground)
+        .interactable(false)
+        .show(egui_ctx.get_mut(), |ui| {
+            ui.painter().rect_filled(
+                ui.ctx().content_rect(),
+                0.0,
+                egui::Color32::from_black_alpha(alpha),
+            );
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::time::TimeUpdateStrategy;
+    use hexx::shapes;
+
+    use super::*;
+
+    #[test]
+    fn pairs_are_seeded_distinct_and_far_apart() {
+        let hexes: Vec<Hex> = shapes::hexagon(Hex::ZERO, 12).collect();
+        let pairs = teleporter_pairs(&hexes, 5, 3, 10);
+        assert_eq!(pairs.len(), 3);
+        assert_eq!(pairs, teleporter_pairs(&hexes, 5, 3, 10));
+        assert_ne!(pairs, teleporter_pairs(&hexes, 6, 3, 10));
+        let mut used: Vec<Hex> = pairs.iter().flat_map(|&(a, b)| [a, b]).collect();
+        assert!(pairs.iter().all(|(a, b)| a.unsigned_distance_to(*b) >= 10));
+        used.sort_unstable_by_key(|h| (h.x, h.y));
+        used.dedup();
+        assert_eq!(used.len(), 6);
+        // No hex is far enough from anything: no pairs.
+        assert!(teleporter_pairs(&hexes, 5, 3, 100).is_empty());
+    }
+
+    #[test]
+    fn the_fade_peaks_at_the_jump() {
+        assert_eq!(warp_fade(0.0, 0.5), 0.0);
+        assert_eq!(warp_fade(0.5, 0.5), 1.0);
+        assert!((warp_fade(0.25, 0.5) - 0.5).abs() < 1e-6);
+        assert_eq!(warp_fade(1.0, 0.5), 0.0);
+    }
+
+    /// Hexes written as [`TeleportToHex`] so far.
+    #[derive(Resource, Default)]
+    struct Jumps(Vec<Hex>);
+
+    fn record_jumps(mut requests: MessageReader<TeleportToHex>, mut jumps: ResMut<Jumps>) {
+        jumps.0.extend(requests.read().map(|t| t.0));
+    }
+
+    #[test]
+    fn a_low_pass_warps_to_the_partner_and_reveals_it() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_plugins(AssetPlugin::default())
+            .init_asset::<Mesh>()
+            .init_asset::<StandardMaterial>()
+            .add_message::<Toast>()
+            .add_message::<TeleportToHex>()
+            .add_message::<HexRevealed>()
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                100,
+            )))
+            .insert_resource(TeleportersConfig {
+                pairs: 1,
+                min_distance: 4,
+                reveal_radius: 1,
+                ..default()
+            })
+            .init_resource::<HTerrainConfig>()
+            .init_resource::<ActiveHex>()
+            .init_resource::<PlayerPos>()
+            .init_resource::<PlayerMoved>()
+            .init_resource::<Locale>()
+            .init_resource::<Warp>()
+            .init_resource::<Jumps>()
+            .add_systems(Startup, create_teleporter_materials)
+            .add_systems(
+                Update,
+                (
+                    pair_teleporters.run_if(any_match_filter::<Added<HCell>>),
+                    enter_teleporters,
+                    advance_warp,
+                    record_jumps,
+                )
+                    .chain(),
+            );
+        let mesh = app
+            .world_mut()
+            .resource_mut::<Assets<Mesh>>()
+            .add(Cuboid::default());
+        for hex in shapes::hexagon(Hex::ZERO, 3) {
+            let cell = app.world_mut().spawn(HCell { hex }).id();
+            app.world_mut()
+                .spawn((HexFace, Mesh3d(mesh.clone()), ChildOf(cell)));
+        }
+        app.world_mut().resource_mut::<PlayerPos>().offset = 30.0;
+        app.update();
+
+        let w = app.world_mut();
+        let pads: Vec<(Entity, Teleporter)> = w
+            .query::<(Entity, &Teleporter)>()
+            .iter(w)
+            .map(|(e, t)| (e, t.clone()))
+            .collect();
+        assert_eq!(pads.len(), 2);
+        assert_eq!(w.query::<&TeleporterOverlay>().iter(w).count(), 2);
+        let (pad, link) = pads[0].clone();
+        assert_eq!(link.partner, pads[1].0);
+        assert_eq!(pads[1].1.partner, pad);
+        let partner_hex = w.get::<HCell>(link.partner).unwrap().hex;
+
+        // Too high over the pad: nothing.
+        w.resource_mut::<ActiveHex>().cell = Some(pad);
+        app.update();
+        assert!(app.world().resource::<Warp>().jump.is_none());
+
+        // Down low: fade out, jump at full black, fade back in.
+        app.world_mut().resource_mut::<PlayerPos>().offset = 1.0;
+        for _ in 0..10 {
+            app.update();
+            // Stand-in for the ground update following the teleport.
+            let w = app.world_mut();
+            if !w.resource::<Jumps>().0.is_empty() {
+                w.resource_mut::<ActiveHex>().cell = Some(link.partner);
+            }
+        }
+        let w = app.world_mut();
+        assert_eq!(w.resource::<Jumps>().0, vec![partner_hex]);
+        let warp = w.resource::<Warp>();
+        assert!(warp.jump.is_none());
+        assert_eq!(warp.landed, Some(link.partner));
+        assert!(w.resource::<PlayerPos>().offset >= TeleportersConfig::default().arrival_offset);
+        let mut revealed = w.query_filtered::<&HCell, With<Revealed>>();
+        let revealed: Vec<Hex> = revealed.iter(w).map(|c| c.hex).collect();
+        let around = shapes::hexagon(Hex::ZERO, 3)
+            .filter(|h| h.unsigned_distance_to(partner_hex) <= 1)
+            .count();
+        assert_eq!(revealed.len(), around);
+        assert!(
+            revealed
+                .iter()
+                .all(|h| h.unsigned_distance_to(partner_hex) <= 1)
+        );
+
+        // Sinking onto the arrival pad doesn't bounce back.
+        w.resource_mut::<PlayerPos>().offset = 1.0;
+        app.update();
+        assert!(app.world().resource::<Warp>().jump.is_none());
+    }
+}
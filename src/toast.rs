@@ -0,0 +1,172 @@
+//! Transient on-screen notifications ("Bookmark 3 stored", "Theme: Neon").
+//!
+//! Any system writes a [`Toast`] message; [`collect_toasts`] logs it and queues it in
+//! [`Toasts`], and `draw_toasts` stacks the newest [`ToastConfig::max_stack`] under the HUD
+//! strip, fading each out over its last [`ToastConfig::fade_secs`]. Ages use real time so
+//! toasts still expire while the game is paused.
+
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+use bevy_egui::egui;
+
+/// Per-plugin configuration for toasts.
+#[derive(Resource, Clone, Debug, Reflect)]
+pub struct ToastConfig {
+    /// Seconds a toast stays on screen, including the fade.
+    pub duration: f32,
+    /// Fade-out time at the end of [`duration`](Self::duration).
+    pub fade_secs: f32,
+    /// Most toasts shown at once; older ones are dropped first.
+    pub max_stack: usize,
+}
+
+impl Default for ToastConfig {
+    fn default() -> Self {
+        Self {
+            duration: 2.5,
+            fade_secs: 0.5,
+            max_stack: 4,
+        }
+    }
+}
+
+/// Request to show a notification.
+#[derive(Message, Clone, Debug, PartialEq)]
+pub struct Toast(pub String);
+
+/// Toasts on screen, oldest first, with the real-time second each was shown.
+#[derive(Resource, Default, Debug)]
+pub struct Toasts(pub VecDeque<(String, f32)>);
+
+impl Toasts {
+    /// Queues `text` shown at `now`, dropping the oldest beyond `max_stack`.
+    pub fn push(&mut self, text: String, now: f32, max_stack: usize) {
+        self.0.push_back((text, now));
+        let excess = self.0.len().saturating_sub(max_stack);
+        self.0.drain(..excess);
+    }
+
+    /// Drops toasts older than `duration` at `now`.
+    pub fn expire(&mut self, now: f32, duration: f32) {
+        self.0.retain(|&(_, shown)| now - shown < duration);
+    }
+}
+
+/// Opacity in `0.0..=1.0` of a toast `age` seconds old: 1 until the last `fade` seconds of
+/// `duration`, then linear to 0.
+pub fn toast_alpha(age: f32, duration: f32, fade: f32) -> f32 {
+    ((duration - age) / fade.max(f32::EPSILON)).clamp(0.0, 1.0)
+}
+
+/// Toast notifications plugin.
+pub struct ToastPlugin {
+    /// Per-plugin configuration.
+    pub config: ToastConfig,
+}
+
+impl Plugin for ToastPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<ToastConfig>()
+            .insert_resource(self.config.clone())
+            .init_resource::<Toasts>()
+            .add_message::<Toast>()
+            .add_systems(
+                Update,
+                (
+                    collect_toasts,
+                    draw_toasts.run_if(|toasts: Res<Toasts>| !toasts.0.is_empty()),
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// Logs and queues new [`Toast`]s, and expires old ones.
+pub fn collect_toasts(
+    mut incoming: MessageReader<Toast>,
+    cfg: Res<ToastConfig>,
+    time: Res<Time<Real>>,
+    mut toasts: ResMut<Toasts>,
+) {
+    let now = time.elapsed_secs();
+    for Toast(text) in incoming.read() {
+        info!("{text}");
+        toasts.push(text.clone(), now, cfg.max_stack);
+    }
+    toasts.expire(now, cfg.duration);
+}
+
+/// Stacks the queued toasts, newest at the bottom, centered under the HUD strip.
+fn draw_toasts(
+    mut egui_ctx: Single<&mut bevy_egui::EguiContext>,
+    cfg: Res<ToastConfig>,
+    time: Res<Time<Real>>,
+    toasts: Res<Toasts>,
+) {
+    let now = time.elapsed_secs();
+    egui::Area::new(egui::Id::new("toasts"))
+        .anchor(egui::Align2::CENTER_TOP, [0.0, 48.0])
+        .order(egui::Order::Foreground)
+        .interactable(false)
+        .show(egui_ctx.get_mut(), |ui| {
+            for (text, shown) in &toasts.0 {
+                ui.scope(|ui| {
+                    ui.set_opacity(toast_alpha(now - shown, cfg.duration, cfg.fade_secs));
+                    egui::Frame::new()
+                        .fill(egui::Color32::from_black_alpha(180))
+                        .inner_margin(egui::Margin::symmetric(10, 4))
+                        .corner_radius(4.0)
+                        .show(ui, |ui| {
+                            ui.label(
+                                egui::RichText::new(text)
+                                    .color(egui::Color32::from_rgb(0, 255, 200))
+                                    .font(egui::FontId::monospace(14.0)),
+                            );
+                        });
+                });
+            }
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn queue_caps_the_stack_and_expires_old_toasts() {
+        let mut toasts = Toasts::default();
+        for i in 0..5 {
+            toasts.push(i.to_string(), i as f32, 3);
+        }
+        let texts: Vec<&str> = toasts.0.iter().map(|(t, _)| t.as_str()).collect();
+        assert_eq!(texts, ["2", "3", "4"]);
+
+        toasts.expire(5.5, 2.0);
+        let texts: Vec<&str> = toasts.0.iter().map(|(t, _)| t.as_str()).collect();
+        assert_eq!(texts, ["4"]);
+    }
+
+    #[test]
+    fn alpha_holds_then_fades() {
+        assert_eq!(toast_alpha(0.0, 2.5, 0.5), 1.0);
+        assert_eq!(toast_alpha(2.0, 2.5, 0.5), 1.0);
+        assert!((toast_alpha(2.25, 2.5, 0.5) - 0.5).abs() < 1e-6);
+        assert_eq!(toast_alpha(3.0, 2.5, 0.5), 0.0);
+    }
+
+    #[test]
+    fn written_toasts_are_queued() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<ToastConfig>()
+            .init_resource::<Toasts>()
+            .add_message::<Toast>()
+            .add_systems(Update, collect_toasts);
+        app.world_mut().write_message(Toast("World saved".into()));
+        app.update();
+        let toasts = &app.world().resource::<Toasts>().0;
+        assert_eq!(toasts.len(), 1);
+        assert_eq!(toasts[0].0, "World saved");
+    }
+}
@@ -0,0 +1,315 @@
+//! Wind: a noise-driven, slowly shifting vector field that pushes the drone around.
+//!
+//! [`WindField`] samples two Perlin layers (one per horizontal axis) over world xz and time
+//! ([`wind_at`]). Each flying frame [`sample_wind`] stores the field at the [`PlayerPos`] in
+//! [`Wind`]; `fly` adds it to the drone's position as drift. [`emit_wind_streaks`] spawns
+//! thin [`WindStreak`]s around the drone that fly along the wind and shrink away
+//! ([`animate_wind_streaks`]), more of them the stronger it blows. The HUD shows the speed
+//! and the compass direction the wind blows toward. Wind is opt-in: [`WindConfig::strength`]
+//! defaults to 0 (still air), and `--wind <speed>` turns it on.
+
+use bevy::prelude::*;
+use noise::{NoiseFn, Perlin};
+
+use crate::drone::Player;
+use crate::{GameState, PlayerPos};
+
+/// Angle between successive streak spawns (radians): spreads them evenly around the drone.
+const GOLDEN_ANGLE: f32 = 2.399_963;
+
+/// Per-plugin configuration for the wind field.
+#[derive(Resource, Clone, Debug, Reflect)]
+pub struct WindConfig {
+    /// Peak drift speed in world units per second (0, the default, disables wind).
+    pub strength: f32,
+    /// Seed for the two noise layers.
+    pub seed: u32,
+    /// World distance over which the wind direction turns noticeably.
+    pub scale: f32,
+    /// How fast the field evolves (noise units per second).
+    pub gust_speed: f32,
+    /// Streaks spawned per second at full strength.
+    pub streak_rate: f32,
+    /// Seconds a streak lives.
+    pub streak_lifetime: f32,
+    /// Streaks spawn within this distance of the drone.
+    pub streak_radius: f32,
+    /// Streak length at full strength.
+    pub streak_length: f32,
+    /// Streaks travel this many times faster than the wind, so they read as motion.
+    pub streak_speed: f32,
+    /// Streak color.
+    pub color: Color,
+    /// Emissive multiplier on `color` (drives bloom).
+    pub glow: f32,
+}
+
+impl Default for WindConfig {
+    fn default() -> Self {
+        Self {
+            strength: 0.0,
+            seed: 0x77_1d,
+            scale: 120.0,
+            gust_speed: 0.05,
+            streak_rate: 30.0,
+            streak_lifetime: 1.2,
+            streak_radius: 25.0,
+            streak_length: 2.5,
+            streak_speed: 4.0,
+            color: Color::srgb(0.7, 0.9, 1.0),
+            glow: 2.0,
+        }
+    }
+}
+
+/// Wind at the drone this frame.
+#[derive(Resource, Clone, Copy, Debug, Default, Reflect)]
+pub struct Wind {
+    /// Drift velocity over the ground (x, z) in world units per second.
+    pub velocity: Vec2,
+}
+
+/// Noise layers behind the wind field, one per horizontal axis.
+#[derive(Resource, Clone)]
+pub struct WindField {
+    x: Perlin,
+    z: Perlin,
+}
+
+impl WindField {
+    /// Field for `seed`.
+    pub fn new(seed: u32) -> Self {
+        Self {
+            x: Perlin::new(seed),
+            z: Perlin::new(seed.wrapping_add(1)),
+        }
+    }
+}
+
+/// A short-lived streak drifting with the wind.
+#[derive(Component, Reflect)]
+pub struct WindStreak {
+    /// World velocity.
+    pub velocity: Vec3,
+    /// Seconds since spawn.
+    pub age: f32,
+    /// Length at spawn.
+    pub length: f32,
+}
+
+/// Shared streak mesh and material.
+#[derive(Resource)]
+struct StreakAssets {
+    mesh: Handle<Mesh>,
+    material: Handle<StandardMaterial>,
+}
+
+/// Wind velocity at world `xz` and time `t`, at most [`WindConfig::strength`] long.
+pub fn wind_at(field: &WindField, cfg: &WindConfig, xz: Vec2, t: f32) -> Vec2 {
+    let p = [
+        f64::from(xz.x / cfg.scale.max(f32::EPSILON)),
+        f64::from(xz.y / cfg.scale.max(f32::EPSILON)),
+        f64::from(t * cfg.gust_speed),
+    ];
+    let v = Vec2::new(field.x.get(p) as f32, field.z.get(p) as f32);
+    v.clamp_length_max(1.0) * cfg.strength
+}
+
+/// Wind plugin.
+pub struct WindPlugin {
+    /// Per-plugin configuration.
+    pub config: WindConfig,
+}
+
+impl Plugin for WindPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<WindConfig>()
+            .register_type::<Wind>()
+            .register_type::<WindStreak>()
+            .insert_resource(self.config.clone())
+            .insert_resource(WindField::new(self.config.seed))
+            .init_resource::<Wind>()
+            .add_systems(Startup, create_streak_assets)
+            .add_systems(
+                Update,
+                sample_wind
+                    .before(crate::drone::systems::fly)
+                    .run_if(in_state(GameState::Running)),
+            )
+            .add_systems(
+                Update,
+                (emit_wind_streaks, animate_wind_streaks)
+                    .chain()
+                    .after(sample_wind)
+                    .run_if(in_state(GameState::Running)),
+            );
+    }
+}
+
+/// Builds the shared streak mesh (a unit bar along X) and material.
+fn create_streak_assets(
+    cfg: Res<WindConfig>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut commands: Commands,
+) {
+    commands.insert_resource(StreakAssets {
+        mesh: meshes.add(Cuboid::new(1.0, 0.02, 0.02)),
+        material: materials.add(StandardMaterial {
+            base_color: cfg.color.with_alpha(0.6),
+            emissive: LinearRgba::from(cfg.color) * cfg.glow,
+            alpha_mode: AlphaMode::Blend,
+            unlit: true,
+            ..default()
+        }),
+    });
+}
+
+/// Samples the field at the drone.
+fn sample_wind(
+    time: Res<Time>,
+    cfg: Res<WindConfig>,
+    field: Res<WindField>,
+    player: Res<PlayerPos>,
+    mut wind: ResMut<Wind>,
+) {
+    wind.velocity = wind_at(&field, &cfg, player.xz, time.elapsed_secs());
+}
+
+/// Spawns streaks around the drone at a rate scaled by the wind speed.
+#[allow(clippy::too_many_arguments)]
+fn emit_wind_streaks(
+    time: Res<Time>,
+    cfg: Res<WindConfig>,
+    wind: Res<Wind>,
+    assets: Res<StreakAssets>,
+    drone: Single<&GlobalTransform, With<Player>>,
+    mut budget: Local<f32>,
+    mut spawned: Local<u32>,
+    mut commands: Commands,
+) {
+    let share = if cfg.strength > 0.0 {
+        (wind.velocity.length() / cfg.strength).min(1.0)
+    } else {
+        0.0
+    };
+    *budget += cfg.streak_rate * share * time.delta_secs();
+    let direction = wind.velocity.extend(0.0).xzy().normalize_or_zero();
+    let center = drone.translation();
+    while *budget >= 1.0 {
+        *budget -= 1.0;
+        *spawned = spawned.wrapping_add(1);
+        // Golden-angle spiral with low-discrepancy radius and height: even cover without rng.
+        let n = *spawned as f32;
+        let angle = n * GOLDEN_ANGLE;
+        let reach = cfg.streak_radius * (n * 0.618_034).fract().sqrt();
+        let rise = ((n * 0.414_214).fract() - 0.5) * cfg.streak_radius * 0.5;
+        let offset = Vec3::new(angle.cos() * reach, rise, angle.sin() * reach);
+        let length = cfg.streak_length * share;
+        commands.spawn((
+            WindStreak {
+                velocity: direction * wind.velocity.length() * cfg.streak_speed,
+                age: 0.0,
+                length,
+            },
+            Mesh3d(assets.mesh.clone()),
+            MeshMaterial3d(assets.material.clone()),
+            Transform::from_translation(center + offset)
+                .with_rotation(Quat::from_rotation_arc(Vec3::X, direction))
+                .with_scale(Vec3::new(length, 1.0, 1.0)),
+        ));
+    }
+}
+
+/// Moves and shrinks streaks, despawning them at the end of their lifetime.
+fn animate_wind_streaks(
+    time: Res<Time>,
+    cfg: Res<WindConfig>,
+    mut streaks: Query<(Entity, &mut WindStreak, &mut Transform)>,
+    mut commands: Commands,
+) {
+    let dt = time.delta_secs();
+    for (entity, mut streak, mut tf) in &mut streaks {
+        streak.age += dt;
+        if streak.age >= cfg.streak_lifetime {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        tf.translation += streak.velocity * dt;
+        tf.scale.x = streak.length * (1.0 - streak.age / cfg.streak_lifetime);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::time::TimeUpdateStrategy;
+
+    use super::*;
+
+    #[test]
+    fn the_field_is_bounded_smooth_and_calm_at_zero_strength() {
+        let cfg = WindConfig {
+            strength: 3.0,
+            ..default()
+        };
+        let field = WindField::new(cfg.seed);
+        let mut max = 0.0f32;
+        for i in 0..200 {
+            let xz = Vec2::new(i as f32 * 7.3, i as f32 * -3.1);
+            let v = wind_at(&field, &cfg, xz, i as f32);
+            assert!(v.length() <= cfg.strength + 1e-4);
+            max = max.max(v.length());
+            // A step much smaller than the scale barely changes it.
+            let near = wind_at(&field, &cfg, xz + Vec2::X * 0.1, i as f32);
+            assert!(v.distance(near) < cfg.strength * 0.05);
+        }
+        assert!(max > 0.0, "the field blows somewhere");
+        let calm = WindConfig::default();
+        assert_eq!(
+            wind_at(&field, &calm, Vec2::new(13.0, 7.0), 5.0),
+            Vec2::ZERO
+        );
+    }
+
+    #[test]
+    fn streaks_follow_the_wind_and_expire() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_plugins(AssetPlugin::default())
+            .init_asset::<Mesh>()
+            .init_asset::<StandardMaterial>()
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                100,
+            )))
+            .insert_resource(WindConfig {
+                strength: 3.0,
+                streak_rate: 20.0,
+                ..default()
+            })
+            .insert_resource(Wind {
+                velocity: Vec2::new(3.0, 0.0),
+            })
+            .add_systems(Startup, create_streak_assets)
+            .add_systems(Update, (emit_wind_streaks, animate_wind_streaks).chain());
+        app.world_mut().spawn((Player, GlobalTransform::default()));
+        app.update();
+        app.update();
+        let w = app.world_mut();
+        let streaks: Vec<_> = w.query::<&WindStreak>().iter(w).collect();
+        assert!(!streaks.is_empty());
+        assert!(
+            streaks
+                .iter()
+                .all(|s| s.velocity.x > 0.0 && s.velocity.z == 0.0)
+        );
+
+        // Still air: no new streaks, and the old ones age out.
+        w.resource_mut::<Wind>().velocity = Vec2::ZERO;
+        w.resource_mut::<WindConfig>().streak_lifetime = 0.0;
+        app.update();
+        let w = app.world_mut();
+        assert_eq!(w.query::<&WindStreak>().iter(w).count(), 0);
+    }
+}
@@ -0,0 +1,364 @@
+//! Full-screen world map (M while flying).
+//!
+//! Entering [`GameState::Map`] frees the cursor, pauses [`Time<Virtual>`], and rasterizes
+//! every [`HCell`] into an egui texture: hexes tinted by height with the heatmap ramp,
+//! fogged where not yet [`Revealed`]. The map shows the player arrow and the stored
+//! bookmarks; clicking a hex stores a waypoint there in the first free bookmark slot, ready
+//! to fly to with its number key or from the waypoint panel. Without the bookmarks plugin the
+//! map only shows the player. M or Escape returns to flight.
+
+use bevy::prelude::*;
+use bevy::window::CursorOptions;
+use bevy_egui::egui;
+
+use crate::bookmarks::{Bookmark, Bookmarks, BookmarksConfig, save_bookmarks};
+use crate::controls::{Binding, BindingCategory, RegisterBinding, Trigger};
+use crate::drone::Player;
+use crate::h_terrain::{HCell, HTerrainConfig, HeightGradient, Revealed};
+use crate::locale::Locale;
+use crate::toast::Toast;
+use crate::{GameState, PlayerPos, set_cursor_free};
+
+/// Per-plugin configuration for the world map.
+#[derive(Resource, Clone, Debug, Reflect)]
+pub struct WorldMapConfig {
+    /// Side length of the map texture in pixels.
+    pub resolution: usize,
+    /// Brightness of unrevealed hexes relative to their height color (0–1).
+    pub fog_brightness: f32,
+}
+
+impl Default for WorldMapConfig {
+    fn default() -> Self {
+        Self {
+            resolution: 512,
+            fog_brightness: 0.15,
+        }
+    }
+}
+
+/// Maps world xz to normalized map coordinates (`0..=1`, +Z down), centered on the grid.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct MapFrame {
+    /// World distance from the grid center to the map edge.
+    pub extent: f32,
+}
+
+impl MapFrame {
+    /// Normalized map position of world `xz`.
+    pub fn to_uv(self, xz: Vec2) -> Vec2 {
+        xz / (2.0 * self.extent.max(1e-3)) + Vec2::splat(0.5)
+    }
+
+    /// World xz at normalized map position `uv`.
+    pub fn to_world(self, uv: Vec2) -> Vec2 {
+        (uv - Vec2::splat(0.5)) * 2.0 * self.extent
+    }
+}
+
+/// One hex as the map sees it.
+#[derive(Clone, Copy, Debug)]
+pub struct MapCell {
+    /// Hex center on the terrain plane.
+    pub xz: Vec2,
+    /// Height normalized to `0..=1` of `max_height`.
+    pub height: f32,
+    /// Whether the player has revealed the hex.
+    pub revealed: bool,
+}
+
+/// Rasterizes `cells` into a `size`² image: each pixel takes the nearest hex center within
+/// `hex_radius` (so cells come out as hexagons), colored by height and dimmed to
+/// `fog` brightness when unrevealed. Pixels off the grid stay transparent.
+pub fn rasterize_map(
+    cells: &[MapCell],
+    frame: MapFrame,
+    size: usize,
+    hex_radius: f32,
+    fog: f32,
+) -> egui::ColorImage {
+    let ramp = HeightGradient::heatmap();
+    let mut pixels = vec![egui::Color32::TRANSPARENT; size * size];
+    let mut nearest = vec![f32::MAX; size * size];
+    let radius_px = hex_radius * size as f32 / (2.0 * frame.extent.max(1e-3));
+
+    for cell in cells {
+        let mut color = ramp.sample(cell.height);
+        if !cell.revealed {
+            color *= fog;
+        }
+        let [r, g, b, _] = Color::from(color).to_srgba().to_u8_array();
+        let color = egui::Color32::from_rgb(r, g, b);
+
+        let center = frame.to_uv(cell.xz) * size as f32;
+        let lo = (center - Vec2::splat(radius_px)).floor().max(Vec2::ZERO);
+        let hi = (center + Vec2::splat(radius_px))
+            .ceil()
+            .min(Vec2::splat(size as f32));
+        for y in lo.y as usize..hi.y as usize {
+            for x in lo.x as usize..hi.x as usize {
+                let d = (Vec2::new(x as f32, y as f32) + Vec2::splat(0.5)).distance(center);
+                let i = y * size + x;
+                if d <= radius_px && d < nearest[i] {
+                    nearest[i] = d;
+                    pixels[i] = color;
+                }
+            }
+        }
+    }
+    egui::ColorImage::new([size, size], pixels)
+}
+
+/// The rendered map texture and the layout it was drawn with.
+#[derive(Resource, Default)]
+struct WorldMapTexture {
+    texture: Option<egui::TextureHandle>,
+    frame: MapFrame,
+    /// Hex centers, for snapping clicks to the grid.
+    centers: Vec<Vec2>,
+    hex_radius: f32,
+}
+
+/// World map plugin.
+pub struct WorldMapPlugin {
+    /// Per-plugin configuration.
+    pub config: WorldMapConfig,
+}
+
+impl Plugin for WorldMapPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<WorldMapConfig>()
+            .insert_resource(self.config.clone())
+            .init_resource::<WorldMapTexture>()
+            .register_binding(Binding::new(
+                BindingCategory::App,
+                "World map",
+                [Trigger::Key(KeyCode::KeyM)],
+            ))
+            .add_systems(
+                Update,
+                toggle_world_map.run_if(in_state(GameState::Running).or(in_state(GameState::Map))),
+            )
+            .add_systems(Update, draw_world_map.run_if(in_state(GameState::Map)))
+            .add_systems(
+                OnEnter(GameState::Map),
+                (render_world_map, |mut time: ResMut<Time<Virtual>>| {
+                    time.pause()
+                }),
+            )
+            .add_systems(OnExit(GameState::Map), |mut time: ResMut<Time<Virtual>>| {
+                time.unpause()
+            });
+    }
+}
+
+/// M opens the map from flight; M or Escape closes it.
+fn toggle_world_map(
+    keys: Res<ButtonInput<KeyCode>>,
+    state: Res<State<GameState>>,
+    mut next: ResMut<NextState<GameState>>,
+    mut windows: Query<(&mut CursorOptions, &mut Window)>,
+) {
+    let open = *state.get() == GameState::Map;
+    if keys.just_pressed(KeyCode::KeyM) || (open && keys.just_pressed(KeyCode::Escape)) {
+        next.set(if open {
+            GameState::Running
+        } else {
+            GameState::Map
+        });
+        set_cursor_free(&mut windows, !open);
+    }
+}
+
+/// Rasterizes the current grid into the map texture.
+fn render_world_map(
+    mut egui_ctx: Single<&mut bevy_egui::EguiContext>,
+    cfg: Res<WorldMapConfig>,
+    terrain: Res<HTerrainConfig>,
+    cells: Query<(&GlobalTransform, Has<Revealed>), With<HCell>>,
+    mut map: ResMut<WorldMapTexture>,
+) {
+    let hex_radius = terrain.grid.point_spacing;
+    let cells: Vec<MapCell> = cells
+        .iter()
+        .map(|(tf, revealed)| {
+            let p = tf.translation();
+            MapCell {
+                xz: p.xz(),
+                height: p.y / terrain.grid.max_height,
+                revealed,
+            }
+        })
+        .collect();
+    let extent = cells
+        .iter()
+        .map(|c| c.xz.abs().max_element())
+        .fold(0.0, f32::max)
+        + hex_radius;
+    let frame = MapFrame { extent };
+    let image = rasterize_map(
+        &cells,
+        frame,
+        cfg.resolution,
+        hex_radius,
+        cfg.fog_brightness,
+    );
+    map.texture = Some(egui_ctx.get_mut().load_texture(
+        "world_map",
+        image,
+        egui::TextureOptions::LINEAR,
+    ));
+    map.frame = frame;
+    map.centers = cells.iter().map(|c| c.xz).collect();
+    map.hex_radius = hex_radius;
+}
+
+/// Draws the map with the player and bookmark markers; a click stores a waypoint.
+#[allow(clippy::too_many_arguments)]
+fn draw_world_map(
+    mut egui_ctx: Single<&mut bevy_egui::EguiContext>,
+    map: Res<WorldMapTexture>,
+    player: Res<PlayerPos>,
+    camera: Single<&Transform, With<Player>>,
+    bookmark_cfg: Option<Res<BookmarksConfig>>,
+    bookmarks: Option<ResMut<Bookmarks>>,
+    mut toasts: MessageWriter<Toast>,
+    locale: Res<Locale>,
+) {
+    let Some(texture) = &map.texture else {
+        return;
+    };
+    let forward = camera.forward().xz().normalize_or(Vec2::NEG_Y);
+    let mut clicked = None;
+
+    egui::CentralPanel::default()
+        .frame(egui::Frame::new().fill(egui::Color32::from_black_alpha(235)))
+        .show(egui_ctx.get_mut(), |ui| {
+            ui.vertical_centered(|ui| {
+                ui.label(
+                    egui::RichText::new(locale.t("WORLD MAP"))
+                        .color(egui::Color32::from_rgb(0, 255, 128))
+                        .font(egui::FontId::monospace(22.0)),
+                );
+                ui.small(locale.t(if bookmarks.is_some() {
+                    "click: store waypoint   M / Esc: close"
+                } else {
+                    "M / Esc: close"
+                }));
+                let side = ui.available_width().min(ui.available_height()) - 8.0;
+                let sense = if bookmarks.is_some() {
+                    egui::Sense::click()
+                } else {
+                    egui::Sense::hover()
+                };
+                let (rect, response) = ui.allocate_exact_size(egui::vec2(side, side), sense);
+                let to_screen = |xz: Vec2| {
+                    let uv = map.frame.to_uv(xz);
+                    rect.min + egui::vec2(uv.x, uv.y) * side
+                };
+                let painter = ui.painter_at(rect);
+                painter.image(
+                    texture.id(),
+                    rect,
+                    egui::Rect::from_min_max(egui::pos2(0.0, 0.0), egui::pos2(1.0, 1.0)),
+                    egui::Color32::WHITE,
+                );
+
+                if let Some(bookmarks) = &bookmarks {
+                    for (&slot, bookmark) in &bookmarks.0 {
+                        let p = to_screen(Vec2::from(bookmark.xz));
+                        painter.circle_stroke(p, 6.0, egui::Stroke::new(2.0, egui::Color32::WHITE));
+                        painter.text(
+                            p + egui::vec2(8.0, -8.0),
+                            egui::Align2::LEFT_BOTTOM,
+                            bookmarks.label(slot, &locale),
+                            egui::FontId::monospace(12.0),
+                            egui::Color32::WHITE,
+                        );
+                    }
+                }
+
+                let at = to_screen(player.xz);
+                let dir = egui::vec2(forward.x, forward.y);
+                let side_vec = egui::vec2(-forward.y, forward.x) * 6.0;
+                painter.add(egui::Shape::convex_polygon(
+                    vec![
+                        at + dir * 12.0,
+                        at - dir * 5.0 + side_vec,
+                        at - dir * 5.0 - side_vec,
+                    ],
+                    egui::Color32::WHITE,
+                    egui::Stroke::new(1.0, egui::Color32::BLACK),
+                ));
+
+                if response.clicked()
+                    && let Some(pos) = response.interact_pointer_pos()
+                {
+                    let uv = (pos - rect.min) / side;
+                    clicked = Some(map.frame.to_world(Vec2::new(uv.x, uv.y)));
+                }
+            });
+        });
+
+    let (Some(target), Some(bookmark_cfg), Some(mut bookmarks)) =
+        (clicked, bookmark_cfg, bookmarks)
+    else {
+        return;
+    };
+    let on_grid = map
+        .centers
+        .iter()
+        .any(|c| c.distance(target) <= map.hex_radius);
+    if !on_grid {
+        return;
+    }
+    let Some(slot) = bookmarks.free_slot() else {
+        toasts.write(Toast(locale.t("All bookmark slots are taken").into()));
+        return;
+    };
+    let mut waypoint = Bookmark::capture(&player, camera.rotation);
+    waypoint.xz = target.to_array();
+    waypoint.name = locale.fmt("Waypoint {0}", &[&slot]);
+    bookmarks.0.insert(slot, waypoint);
+    save_bookmarks(&bookmarks, &bookmark_cfg.path);
+    toasts.write(Toast(locale.fmt("Waypoint {0} stored", &[&slot])));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frame_round_trips_world_positions() {
+        let frame = MapFrame { extent: 20.0 };
+        assert_eq!(frame.to_uv(Vec2::ZERO), Vec2::splat(0.5));
+        assert_eq!(frame.to_uv(Vec2::new(-20.0, 20.0)), Vec2::new(0.0, 1.0));
+        let p = Vec2::new(3.5, -7.25);
+        assert!(frame.to_world(frame.to_uv(p)).abs_diff_eq(p, 1e-5));
+    }
+
+    #[test]
+    fn rasterized_hexes_are_fogged_until_revealed() {
+        let cells = [
+            MapCell {
+                xz: Vec2::new(-5.0, 0.0),
+                height: 1.0,
+                revealed: true,
+            },
+            MapCell {
+                xz: Vec2::new(5.0, 0.0),
+                height: 1.0,
+                revealed: false,
+            },
+        ];
+        let image = rasterize_map(&cells, MapFrame { extent: 10.0 }, 20, 2.0, 0.2);
+        let at = |x: usize, y: usize| image.pixels[y * 20 + x];
+        let lit = at(5, 10);
+        let fogged = at(15, 10);
+        assert_ne!(lit, egui::Color32::TRANSPARENT);
+        assert_ne!(fogged, egui::Color32::TRANSPARENT);
+        assert!(fogged.r() < lit.r(), "{fogged:?} vs {lit:?}");
+        assert_eq!(at(0, 0), egui::Color32::TRANSPARENT);
+        assert_eq!(at(10, 10), egui::Color32::TRANSPARENT);
+    }
+}